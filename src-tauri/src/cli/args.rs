@@ -0,0 +1,667 @@
+use crate::models::CaptureSource;
+use std::collections::BTreeMap;
+
+/// 検索結果のデフォルト件数
+pub const DEFAULT_SEARCH_LIMIT: usize = 20;
+
+/// `add` サブコマンドのオプション
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AddArgs {
+    pub title: Option<String>,
+    pub category: Option<String>,
+    pub severity: Option<String>,
+    pub symptoms: Option<String>,
+    pub procedure: Option<String>,
+    pub notes: Option<String>,
+    pub links: Option<String>,
+    pub slug: Option<String>,
+    pub alerts: Vec<String>,
+    /// JSONファイルのパス（`-` は標準入力）
+    pub json: Option<String>,
+    /// コミットせずに下書きとして保存
+    pub draft: bool,
+    /// 保存先プロファイル（未指定時は使用中のプロファイル）
+    pub profile: Option<String>,
+    /// コミットメッセージに追加する課題キー
+    pub issue: Option<String>,
+    /// コミットメッセージに追加する共同作成者（`Name <email>`）
+    pub co_authors: Vec<String>,
+}
+
+/// `draft` サブコマンド
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DraftCommand {
+    List,
+    Show {
+        id: String,
+        json: bool,
+    },
+    Remove {
+        id: String,
+    },
+    /// 複数の下書きをまとめて公開
+    Publish {
+        ids: Vec<String>,
+        profile: Option<String>,
+        separate_commits: bool,
+        issue: Option<String>,
+        co_authors: Vec<String>,
+    },
+}
+
+/// `config` サブコマンド
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConfigCommand {
+    Show,
+    Path,
+    Get { key: String },
+    Set { key: String, value: String },
+}
+
+/// `profile` サブコマンド
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ProfileCommand {
+    List,
+    Use { name: String },
+}
+
+/// `validate` の出力形式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReportFormat {
+    Text,
+    Json,
+    Sarif,
+}
+
+/// CLIコマンド
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CliCommand {
+    Add(Box<AddArgs>),
+    Draft(DraftCommand),
+    Search {
+        query: String,
+        limit: usize,
+        json: bool,
+    },
+    /// ファイルを添削（`None` または `-` は標準入力）
+    Proofread {
+        file: Option<String>,
+    },
+    Config(ConfigCommand),
+    Profile(ProfileCommand),
+    /// エクスポートを下書きとして取り込む
+    Import {
+        path: String,
+        category: Option<String>,
+        severity: Option<String>,
+        json: bool,
+    },
+    /// Slackスレッド・端末操作の記録・シェル履歴を下書きとして取り込む（`None` または `-` は標準入力）
+    Capture {
+        source: CaptureSource,
+        file: Option<String>,
+        title: Option<String>,
+        category: Option<String>,
+        severity: Option<String>,
+    },
+    /// Runbookリポジトリを検証（`path` 未指定時は設定の保存先）
+    Validate {
+        path: Option<String>,
+        format: ReportFormat,
+    },
+    /// 既存Runbookをカテゴリ定義に合わせて移行
+    MigrateCategories {
+        dry_run: bool,
+    },
+    /// MCPサーバーとして標準入出力で待ち受け
+    Mcp,
+    Help,
+    Version,
+}
+
+/// 引数の解析エラー（使い方の誤り）
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UsageError(pub String);
+
+impl std::fmt::Display for UsageError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// 解析済みのオプションと位置引数
+#[derive(Debug, Default)]
+struct ParsedOptions {
+    values: BTreeMap<String, Vec<String>>,
+    flags: Vec<String>,
+    positionals: Vec<String>,
+}
+
+impl ParsedOptions {
+    /// 値を取るオプションと真偽値フラグを指定して解析
+    ///
+    /// `--name value` と `--name=value` の両方を受け付けます。`--` 以降はすべて位置引数です。
+    fn parse(
+        args: &[String],
+        value_options: &[&str],
+        flag_options: &[&str],
+    ) -> Result<Self, UsageError> {
+        let mut parsed = ParsedOptions::default();
+        let mut iter = args.iter();
+
+        while let Some(arg) = iter.next() {
+            if arg == "--" {
+                parsed.positionals.extend(iter.by_ref().cloned());
+                break;
+            }
+
+            let Some(option) = arg.strip_prefix("--").filter(|o| !o.is_empty()) else {
+                parsed.positionals.push(arg.clone());
+                continue;
+            };
+
+            let (name, inline_value) = match option.split_once('=') {
+                Some((name, value)) => (name, Some(value.to_string())),
+                None => (option, None),
+            };
+
+            if value_options.contains(&name) {
+                let value = match inline_value {
+                    Some(value) => value,
+                    None => iter
+                        .next()
+                        .cloned()
+                        .ok_or_else(|| UsageError(format!("Option --{} requires a value", name)))?,
+                };
+                parsed
+                    .values
+                    .entry(name.to_string())
+                    .or_default()
+                    .push(value);
+            } else if flag_options.contains(&name) && inline_value.is_none() {
+                parsed.flags.push(name.to_string());
+            } else {
+                return Err(UsageError(format!("Unknown option: --{}", name)));
+            }
+        }
+
+        Ok(parsed)
+    }
+
+    /// 値を取得（複数指定時は最後の値）
+    fn value(&self, name: &str) -> Option<String> {
+        self.values
+            .get(name)
+            .and_then(|values| values.last())
+            .cloned()
+    }
+
+    fn values(&self, name: &str) -> Vec<String> {
+        self.values.get(name).cloned().unwrap_or_default()
+    }
+
+    fn flag(&self, name: &str) -> bool {
+        self.flags.iter().any(|flag| flag == name)
+    }
+
+    /// 位置引数の数を検証
+    fn expect_positionals(&self, count: usize, usage: &str) -> Result<(), UsageError> {
+        if self.positionals.len() != count {
+            return Err(UsageError(format!("Usage: {}", usage)));
+        }
+        Ok(())
+    }
+}
+
+/// コマンドライン引数（プログラム名を除く）を解析
+pub fn parse_args(args: &[String]) -> Result<CliCommand, UsageError> {
+    let Some((command, rest)) = args.split_first() else {
+        return Ok(CliCommand::Help);
+    };
+
+    match command.as_str() {
+        "add" => parse_add(rest),
+        "draft" | "drafts" => parse_draft(rest),
+        "search" => {
+            let parsed = ParsedOptions::parse(rest, &["limit"], &["json"])?;
+            if parsed.positionals.is_empty() {
+                return Err(UsageError("Usage: worknote search <query>...".to_string()));
+            }
+            let limit = match parsed.value("limit") {
+                Some(limit) => limit
+                    .parse()
+                    .map_err(|_| UsageError(format!("Invalid --limit: {}", limit)))?,
+                None => DEFAULT_SEARCH_LIMIT,
+            };
+            Ok(CliCommand::Search {
+                query: parsed.positionals.join(" "),
+                limit,
+                json: parsed.flag("json"),
+            })
+        }
+        "proofread" => {
+            let parsed = ParsedOptions::parse(rest, &[], &[])?;
+            if parsed.positionals.len() > 1 {
+                return Err(UsageError("Usage: worknote proofread [FILE|-]".to_string()));
+            }
+            Ok(CliCommand::Proofread {
+                file: parsed.positionals.first().cloned(),
+            })
+        }
+        "config" => parse_config(rest),
+        "profile" | "profiles" => parse_profile(rest),
+        "import" => {
+            let parsed = ParsedOptions::parse(rest, &["category", "severity"], &["json"])?;
+            parsed.expect_positionals(
+                1,
+                "worknote import <FILE|DIR|ZIP> [--category NAME] [--severity LEVEL] [--json]",
+            )?;
+            Ok(CliCommand::Import {
+                path: parsed.positionals[0].clone(),
+                category: parsed.value("category"),
+                severity: parsed.value("severity"),
+                json: parsed.flag("json"),
+            })
+        }
+        "capture" => {
+            const USAGE: &str = "Usage: worknote capture <slack|asciinema|script|history> [FILE|-] [--title TEXT] [--category NAME] [--severity LEVEL]";
+            let parsed = ParsedOptions::parse(rest, &["title", "category", "severity"], &[])?;
+            if parsed.positionals.is_empty() || parsed.positionals.len() > 2 {
+                return Err(UsageError(USAGE.to_string()));
+            }
+            let source = match parsed.positionals[0].as_str() {
+                "slack" => CaptureSource::Slack,
+                "asciinema" | "cast" => CaptureSource::Asciinema,
+                "script" => CaptureSource::Script,
+                "history" => CaptureSource::History,
+                other => {
+                    return Err(UsageError(format!(
+                        "Unknown capture source: {}\n{}",
+                        other, USAGE
+                    )))
+                }
+            };
+            Ok(CliCommand::Capture {
+                source,
+                file: parsed.positionals.get(1).cloned(),
+                title: parsed.value("title"),
+                category: parsed.value("category"),
+                severity: parsed.value("severity"),
+            })
+        }
+        "validate" | "lint" => {
+            let parsed = ParsedOptions::parse(rest, &["format"], &[])?;
+            if parsed.positionals.len() > 1 {
+                return Err(UsageError(
+                    "Usage: worknote validate [DIR] [--format text|json|sarif]".to_string(),
+                ));
+            }
+            let format = match parsed.value("format").as_deref() {
+                None | Some("text") => ReportFormat::Text,
+                Some("json") => ReportFormat::Json,
+                Some("sarif") => ReportFormat::Sarif,
+                Some(other) => return Err(UsageError(format!("Invalid --format: {}", other))),
+            };
+            Ok(CliCommand::Validate {
+                path: parsed.positionals.first().cloned(),
+                format,
+            })
+        }
+        "migrate-categories" => {
+            let parsed = ParsedOptions::parse(rest, &[], &["dry-run"])?;
+            parsed.expect_positionals(0, "worknote migrate-categories [--dry-run]")?;
+            Ok(CliCommand::MigrateCategories {
+                dry_run: parsed.flag("dry-run"),
+            })
+        }
+        "mcp" => {
+            ParsedOptions::parse(rest, &[], &[])?.expect_positionals(0, "worknote mcp")?;
+            Ok(CliCommand::Mcp)
+        }
+        "help" | "--help" | "-h" => Ok(CliCommand::Help),
+        "version" | "--version" | "-V" => Ok(CliCommand::Version),
+        other => Err(UsageError(format!("Unknown command: {}", other))),
+    }
+}
+
+fn parse_add(args: &[String]) -> Result<CliCommand, UsageError> {
+    let parsed = ParsedOptions::parse(
+        args,
+        &[
+            "title",
+            "category",
+            "severity",
+            "symptoms",
+            "procedure",
+            "notes",
+            "links",
+            "slug",
+            "alert",
+            "json",
+            "profile",
+            "issue",
+            "co-author",
+        ],
+        &["draft"],
+    )?;
+    parsed.expect_positionals(0, "worknote add [OPTIONS]")?;
+
+    Ok(CliCommand::Add(Box::new(AddArgs {
+        title: parsed.value("title"),
+        category: parsed.value("category"),
+        severity: parsed.value("severity"),
+        symptoms: parsed.value("symptoms"),
+        procedure: parsed.value("procedure"),
+        notes: parsed.value("notes"),
+        links: parsed.value("links"),
+        slug: parsed.value("slug"),
+        alerts: parsed.values("alert"),
+        json: parsed.value("json"),
+        draft: parsed.flag("draft"),
+        profile: parsed.value("profile"),
+        issue: parsed.value("issue"),
+        co_authors: parsed.values("co-author"),
+    })))
+}
+
+fn parse_draft(args: &[String]) -> Result<CliCommand, UsageError> {
+    let Some((subcommand, rest)) = args.split_first() else {
+        return Ok(CliCommand::Draft(DraftCommand::List));
+    };
+
+    match subcommand.as_str() {
+        "list" | "ls" => {
+            ParsedOptions::parse(rest, &[], &[])?.expect_positionals(0, "worknote draft list")?;
+            Ok(CliCommand::Draft(DraftCommand::List))
+        }
+        "show" => {
+            let parsed = ParsedOptions::parse(rest, &[], &["json"])?;
+            parsed.expect_positionals(1, "worknote draft show <ID> [--json]")?;
+            Ok(CliCommand::Draft(DraftCommand::Show {
+                id: parsed.positionals[0].clone(),
+                json: parsed.flag("json"),
+            }))
+        }
+        "rm" | "remove" | "delete" => {
+            let parsed = ParsedOptions::parse(rest, &[], &[])?;
+            parsed.expect_positionals(1, "worknote draft rm <ID>")?;
+            Ok(CliCommand::Draft(DraftCommand::Remove {
+                id: parsed.positionals[0].clone(),
+            }))
+        }
+        "publish" => {
+            let parsed = ParsedOptions::parse(
+                rest,
+                &["profile", "issue", "co-author"],
+                &["separate-commits"],
+            )?;
+            if parsed.positionals.is_empty() {
+                return Err(UsageError(
+                    "Usage: worknote draft publish <ID>... [--separate-commits] [--profile NAME]"
+                        .to_string(),
+                ));
+            }
+            Ok(CliCommand::Draft(DraftCommand::Publish {
+                ids: parsed.positionals.clone(),
+                profile: parsed.value("profile"),
+                separate_commits: parsed.flag("separate-commits"),
+                issue: parsed.value("issue"),
+                co_authors: parsed.values("co-author"),
+            }))
+        }
+        other => Err(UsageError(format!("Unknown draft command: {}", other))),
+    }
+}
+
+fn parse_config(args: &[String]) -> Result<CliCommand, UsageError> {
+    let Some((subcommand, rest)) = args.split_first() else {
+        return Ok(CliCommand::Config(ConfigCommand::Show));
+    };
+    let parsed = ParsedOptions::parse(rest, &[], &[])?;
+
+    match subcommand.as_str() {
+        "show" => {
+            parsed.expect_positionals(0, "worknote config show")?;
+            Ok(CliCommand::Config(ConfigCommand::Show))
+        }
+        "path" => {
+            parsed.expect_positionals(0, "worknote config path")?;
+            Ok(CliCommand::Config(ConfigCommand::Path))
+        }
+        "get" => {
+            parsed.expect_positionals(1, "worknote config get <KEY>")?;
+            Ok(CliCommand::Config(ConfigCommand::Get {
+                key: parsed.positionals[0].clone(),
+            }))
+        }
+        "set" => {
+            parsed.expect_positionals(2, "worknote config set <KEY> <VALUE>")?;
+            Ok(CliCommand::Config(ConfigCommand::Set {
+                key: parsed.positionals[0].clone(),
+                value: parsed.positionals[1].clone(),
+            }))
+        }
+        other => Err(UsageError(format!("Unknown config command: {}", other))),
+    }
+}
+
+fn parse_profile(args: &[String]) -> Result<CliCommand, UsageError> {
+    let Some((subcommand, rest)) = args.split_first() else {
+        return Ok(CliCommand::Profile(ProfileCommand::List));
+    };
+    let parsed = ParsedOptions::parse(rest, &[], &[])?;
+
+    match subcommand.as_str() {
+        "list" | "ls" => {
+            parsed.expect_positionals(0, "worknote profile list")?;
+            Ok(CliCommand::Profile(ProfileCommand::List))
+        }
+        "use" => {
+            parsed.expect_positionals(1, "worknote profile use <NAME>")?;
+            Ok(CliCommand::Profile(ProfileCommand::Use {
+                name: parsed.positionals[0].clone(),
+            }))
+        }
+        other => Err(UsageError(format!("Unknown profile command: {}", other))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_parse_add() {
+        let command = parse_args(&args(&[
+            "add",
+            "--title",
+            "CPU高騰対応",
+            "--category=alerts",
+            "--severity",
+            "high",
+            "--alert",
+            "HighCPUUsage",
+            "--alert",
+            "datadog:12345",
+            "--issue",
+            "OPS-123",
+            "--co-author",
+            "Alice <alice@example.com>",
+            "--draft",
+        ]))
+        .unwrap();
+
+        assert_eq!(
+            command,
+            CliCommand::Add(Box::new(AddArgs {
+                title: Some("CPU高騰対応".to_string()),
+                category: Some("alerts".to_string()),
+                severity: Some("high".to_string()),
+                alerts: vec!["HighCPUUsage".to_string(), "datadog:12345".to_string()],
+                issue: Some("OPS-123".to_string()),
+                co_authors: vec!["Alice <alice@example.com>".to_string()],
+                draft: true,
+                ..AddArgs::default()
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_add_errors() {
+        assert!(parse_args(&args(&["add", "--title"])).is_err());
+        assert!(parse_args(&args(&["add", "--unknown", "x"])).is_err());
+        assert!(parse_args(&args(&["add", "positional"])).is_err());
+        assert!(parse_args(&args(&["add", "--draft=yes"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_draft() {
+        assert_eq!(
+            parse_args(&args(&["draft"])).unwrap(),
+            CliCommand::Draft(DraftCommand::List)
+        );
+        assert_eq!(
+            parse_args(&args(&["draft", "show", "abc", "--json"])).unwrap(),
+            CliCommand::Draft(DraftCommand::Show {
+                id: "abc".to_string(),
+                json: true
+            })
+        );
+        assert_eq!(
+            parse_args(&args(&["draft", "rm", "abc"])).unwrap(),
+            CliCommand::Draft(DraftCommand::Remove {
+                id: "abc".to_string()
+            })
+        );
+        assert!(parse_args(&args(&["draft", "show"])).is_err());
+
+        assert_eq!(
+            parse_args(&args(&[
+                "draft",
+                "publish",
+                "a",
+                "b",
+                "--separate-commits",
+                "--issue",
+                "OPS-1",
+            ]))
+            .unwrap(),
+            CliCommand::Draft(DraftCommand::Publish {
+                ids: vec!["a".to_string(), "b".to_string()],
+                profile: None,
+                separate_commits: true,
+                issue: Some("OPS-1".to_string()),
+                co_authors: Vec::new(),
+            })
+        );
+        assert!(parse_args(&args(&["draft", "publish", "--profile", "x"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_search() {
+        assert_eq!(
+            parse_args(&args(&["search", "DB", "接続", "--limit", "5"])).unwrap(),
+            CliCommand::Search {
+                query: "DB 接続".to_string(),
+                limit: 5,
+                json: false
+            }
+        );
+        assert!(parse_args(&args(&["search"])).is_err());
+        assert!(parse_args(&args(&["search", "x", "--limit", "many"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_config() {
+        assert_eq!(
+            parse_args(&args(&[
+                "config",
+                "set",
+                "git.commitMode",
+                "feature-branch"
+            ]))
+            .unwrap(),
+            CliCommand::Config(ConfigCommand::Set {
+                key: "git.commitMode".to_string(),
+                value: "feature-branch".to_string()
+            })
+        );
+        assert!(parse_args(&args(&["config", "get"])).is_err());
+    }
+
+    #[test]
+    fn test_parse_profile() {
+        assert_eq!(
+            parse_args(&args(&["profile"])).unwrap(),
+            CliCommand::Profile(ProfileCommand::List)
+        );
+        assert_eq!(
+            parse_args(&args(&["profile", "use", "notes"])).unwrap(),
+            CliCommand::Profile(ProfileCommand::Use {
+                name: "notes".to_string()
+            })
+        );
+        assert!(parse_args(&args(&["profile", "use"])).is_err());
+        assert_eq!(
+            parse_args(&args(&["add", "--title", "x", "--profile", "notes"])).unwrap(),
+            CliCommand::Add(Box::new(AddArgs {
+                title: Some("x".to_string()),
+                profile: Some("notes".to_string()),
+                ..AddArgs::default()
+            }))
+        );
+    }
+
+    #[test]
+    fn test_parse_misc() {
+        assert_eq!(parse_args(&[]).unwrap(), CliCommand::Help);
+        assert_eq!(
+            parse_args(&args(&["--version"])).unwrap(),
+            CliCommand::Version
+        );
+        assert_eq!(
+            parse_args(&args(&["proofread", "-"])).unwrap(),
+            CliCommand::Proofread {
+                file: Some("-".to_string())
+            }
+        );
+        assert_eq!(parse_args(&args(&["mcp"])).unwrap(), CliCommand::Mcp);
+        assert_eq!(
+            parse_args(&args(&["validate", "--format", "sarif"])).unwrap(),
+            CliCommand::Validate {
+                path: None,
+                format: ReportFormat::Sarif
+            }
+        );
+        assert!(parse_args(&args(&["validate", "--format", "xml"])).is_err());
+        assert_eq!(
+            parse_args(&args(&["import", "export.zip", "--category", "ops"])).unwrap(),
+            CliCommand::Import {
+                path: "export.zip".to_string(),
+                category: Some("ops".to_string()),
+                severity: None,
+                json: false
+            }
+        );
+        assert_eq!(
+            parse_args(&args(&["capture", "history", "-", "--title", "DB再起動"])).unwrap(),
+            CliCommand::Capture {
+                source: CaptureSource::History,
+                file: Some("-".to_string()),
+                title: Some("DB再起動".to_string()),
+                category: None,
+                severity: None
+            }
+        );
+        assert!(parse_args(&args(&["capture", "teams", "log.json"])).is_err());
+        assert_eq!(
+            parse_args(&args(&["migrate-categories", "--dry-run"])).unwrap(),
+            CliCommand::MigrateCategories { dry_run: true }
+        );
+        assert!(parse_args(&args(&["unknown"])).is_err());
+    }
+}
@@ -0,0 +1,178 @@
+use crate::cli::args::AddArgs;
+use crate::models::{Category, KnowledgeInput, Result, Severity, WorkNoteError};
+use std::fs;
+use std::io::{self, BufRead, IsTerminal, Read, Write};
+
+/// 複数行入力の終端
+const MULTILINE_TERMINATOR: &str = ".";
+
+/// `add` の引数からナレッジ入力データを組み立てる
+///
+/// 優先順位は「フラグ > `--json` の内容」です。必須項目が不足している場合、
+/// 標準入力が端末であれば対話的に入力を求め、そうでなければエラーにします。
+pub fn build_knowledge_input(args: &AddArgs) -> Result<KnowledgeInput> {
+    let mut stdin_used = false;
+    let mut base = match args.json.as_deref() {
+        Some(source) => {
+            stdin_used = source == "-";
+            let content = read_source(source)?;
+            serde_json::from_str::<serde_json::Value>(&content).map_err(|e| {
+                WorkNoteError::ValidationError(format!("Invalid knowledge JSON: {}", e))
+            })?
+        }
+        None => serde_json::Value::Object(Default::default()),
+    };
+
+    let fields = [
+        ("title", &args.title),
+        ("category", &args.category),
+        ("severity", &args.severity),
+        ("symptoms", &args.symptoms),
+        ("procedure", &args.procedure),
+        ("notes", &args.notes),
+        ("relatedLinks", &args.links),
+        ("slug", &args.slug),
+    ];
+    let object = base.as_object_mut().ok_or_else(|| {
+        WorkNoteError::ValidationError("Knowledge JSON must be an object".to_string())
+    })?;
+    for (key, value) in fields {
+        let Some(value) = value else {
+            continue;
+        };
+        // `-` は標準入力から読み込む（1項目のみ）
+        let value = if value == "-" {
+            if stdin_used {
+                return Err(WorkNoteError::ValidationError(
+                    "Standard input can only be used once".to_string(),
+                ));
+            }
+            stdin_used = true;
+            read_source("-")?.trim_end().to_string()
+        } else {
+            value.clone()
+        };
+        object.insert(key.to_string(), serde_json::Value::String(value));
+    }
+    if !args.alerts.is_empty() {
+        object.insert(
+            "relatedAlerts".to_string(),
+            serde_json::Value::from(args.alerts.clone()),
+        );
+    }
+
+    let interactive = !stdin_used && io::stdin().is_terminal();
+    for key in ["title", "category", "severity", "symptoms", "procedure"] {
+        let missing = object
+            .get(key)
+            .and_then(|v| v.as_str())
+            .is_none_or(|v| v.trim().is_empty());
+        if !missing {
+            continue;
+        }
+        if !interactive {
+            return Err(WorkNoteError::ValidationError(format!(
+                "Missing required field: {} (use --{} or --json)",
+                key, key
+            )));
+        }
+        object.insert(
+            key.to_string(),
+            serde_json::Value::String(prompt_field(key)?),
+        );
+    }
+
+    serde_json::from_value(base)
+        .map_err(|e| WorkNoteError::ValidationError(format!("Invalid knowledge input: {}", e)))
+}
+
+/// ファイルまたは標準入力（`-`）から読み込む
+pub fn read_source(source: &str) -> Result<String> {
+    if source == "-" {
+        let mut content = String::new();
+        io::stdin()
+            .read_to_string(&mut content)
+            .map_err(|e| WorkNoteError::FileError(format!("Failed to read stdin: {}", e)))?;
+        Ok(content)
+    } else {
+        fs::read_to_string(source)
+            .map_err(|e| WorkNoteError::FileError(format!("Failed to read {}: {}", source, e)))
+    }
+}
+
+/// 項目を対話的に入力
+fn prompt_field(key: &str) -> Result<String> {
+    loop {
+        let value = match key {
+            "title" => prompt_line("タイトル")?,
+            "category" => prompt_line(&format!(
+                "カテゴリ ({})",
+                [
+                    Category::Alerts,
+                    Category::Ops,
+                    Category::Troubleshooting,
+                    Category::Inquiry
+                ]
+                .iter()
+                .map(Category::as_str)
+                .collect::<Vec<_>>()
+                .join("/")
+            ))?,
+            "severity" => prompt_line(&format!(
+                "重要度 ({})",
+                [
+                    Severity::Low,
+                    Severity::Medium,
+                    Severity::High,
+                    Severity::Critical
+                ]
+                .iter()
+                .map(Severity::as_str)
+                .collect::<Vec<_>>()
+                .join("/")
+            ))?,
+            "symptoms" => prompt_multiline("症状")?,
+            _ => prompt_multiline("対応手順")?,
+        };
+
+        let valid = match key {
+            "category" => serde_json::from_value::<Category>(value.clone().into()).is_ok(),
+            "severity" => serde_json::from_value::<Severity>(value.clone().into()).is_ok(),
+            _ => !value.trim().is_empty(),
+        };
+        if valid {
+            return Ok(value);
+        }
+        eprintln!("入力が正しくありません。もう一度入力してください。");
+    }
+}
+
+/// 1行入力
+fn prompt_line(label: &str) -> Result<String> {
+    eprint!("{}: ", label);
+    io::stderr().flush()?;
+
+    let mut line = String::new();
+    if io::stdin().lock().read_line(&mut line)? == 0 {
+        return Err(WorkNoteError::ValidationError("Input aborted".to_string()));
+    }
+    Ok(line.trim().to_string())
+}
+
+/// 複数行入力（`.` のみの行またはEOFで終了）
+fn prompt_multiline(label: &str) -> Result<String> {
+    eprintln!(
+        "{}（複数行可。`{}` のみの行で終了）:",
+        label, MULTILINE_TERMINATOR
+    );
+
+    let mut lines = Vec::new();
+    for line in io::stdin().lock().lines() {
+        let line = line?;
+        if line.trim() == MULTILINE_TERMINATOR {
+            break;
+        }
+        lines.push(line);
+    }
+    Ok(lines.join("\n").trim_end().to_string())
+}
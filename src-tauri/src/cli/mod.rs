@@ -0,0 +1,557 @@
+//! `worknote` CLI
+//!
+//! GUIと同じサービス層・同じアプリデータディレクトリを使い、
+//! ターミナルからナレッジの登録・下書き管理・検索・添削・設定変更を行います。
+
+pub mod args;
+pub mod input;
+
+use crate::mcp::McpServer;
+use crate::models::{CaptureOptions, CommitTrailers, Config, ImportOptions, Result, WorkNoteError};
+use crate::services::{
+    CategoryMigrator, ConfigManager, DraftManager, DraftPublisher, FileGenerator, KnowledgeService,
+    ProofreadService, RunbookImporter, RunbookRepository, RunbookValidator, SessionCapture,
+};
+use args::{parse_args, CliCommand, ConfigCommand, DraftCommand, ProfileCommand, ReportFormat};
+use serde_json::Value;
+use std::path::PathBuf;
+
+/// アプリ識別子（tauri.conf.json の `identifier`）
+///
+/// GUIの `app_data_dir()` と同じディレクトリを使うために使用します。
+pub const APP_IDENTIFIER: &str = "com.arigatatsuya.tauri-app";
+
+/// データディレクトリを上書きする環境変数
+pub const DATA_DIR_ENV: &str = "WORKNOTE_DATA_DIR";
+
+/// 終了コード: 正常終了
+pub const EXIT_OK: i32 = 0;
+/// 終了コード: 実行時エラー
+pub const EXIT_FAILURE: i32 = 1;
+/// 終了コード: 使い方の誤り
+pub const EXIT_USAGE: i32 = 2;
+
+const USAGE: &str = "\
+WorkNote CLI - ターミナルからナレッジを記録します
+
+Usage: worknote <COMMAND> [OPTIONS]
+
+Commands:
+  add                      ナレッジを登録（未指定の必須項目は対話入力）
+      --title <TEXT>       タイトル
+      --category <NAME>    カテゴリ（設定のカテゴリID。既定: alerts/ops/troubleshooting/inquiry）
+      --severity <LEVEL>   重要度（設定の重要度ID。既定: low/medium/high/critical）
+      --symptoms <TEXT>    症状（`-` で標準入力）
+      --procedure <TEXT>   対応手順（`-` で標準入力）
+      --notes <TEXT>       注意点
+      --links <TEXT>       関連リンク（[[runbook-id]] で他のRunbookを参照）
+      --alert <NAME>       関連アラート（複数指定可）
+      --slug <SLUG>        ファイル名に使用するslug
+      --json <FILE|->      KnowledgeInput形式のJSONから読み込む
+      --draft              コミットせずに下書きとして保存
+      --profile <NAME>     保存先プロファイル（未指定時は使用中のプロファイル）
+      --issue <KEY>        コミットメッセージに課題キーを追加（Issue: trailer）
+      --co-author <NAME <EMAIL>>
+                           共同作成者を追加（Co-authored-by: trailer。複数指定可）
+  draft list               下書き一覧
+  draft show <ID> [--json] 下書きを表示
+  draft rm <ID>            下書きを削除
+  draft publish <ID>...    下書きをまとめて保存・コミット・Push（PR作成モードではPRを1つ作成）
+      --separate-commits   下書きごとにコミット（既定: 1つのコミットにまとめる）
+      --profile <NAME>     保存先プロファイル（未指定時は下書きのプロファイル）
+      --issue <KEY>        コミットメッセージに課題キーを追加
+      --co-author <NAME <EMAIL>>
+                           共同作成者を追加（複数指定可）
+  search <QUERY>... [--limit N] [--json]
+                           Runbookを全文検索
+  proofread [FILE|-]       Markdownを添削して標準出力に出力
+  config show              設定を表示
+  config path              設定ファイルのディレクトリを表示
+  config get <KEY>         設定値を取得（例: git.commitMode）
+  config set <KEY> <VALUE> 設定値を変更
+  profile list             保存先プロファイルの一覧（* は使用中）
+  profile use <NAME>       使用中のプロファイルを切り替え（`default` で既定に戻す）
+  import <FILE|DIR|ZIP>     Confluence/NotionのエクスポートをRunbookの下書きとして取り込む
+      --category <NAME>    カテゴリを指定（未指定時は推定）
+      --severity <LEVEL>   重要度を指定（未指定時は推定）
+      --json               結果をJSONで出力
+  capture <slack|asciinema|script|history> [FILE|-]
+                           Slackスレッド・端末操作の記録・シェル履歴を下書きとして取り込む
+      --title <TEXT>       タイトルを指定（未指定時は内容から生成）
+      --category <NAME>    カテゴリを指定（既定: troubleshooting）
+      --severity <LEVEL>   重要度を指定（既定: medium）
+  validate [DIR] [--format text|json|sarif]
+                           Runbookのフロントマター・セクション・リンクを検証
+                           （エラーがあれば終了コード1）
+  migrate-categories [--dry-run]
+                           既存Runbookをカテゴリ定義に合わせて移行
+                           （廃止カテゴリの書き換え・ディレクトリ移動。コミットはしない）
+  mcp                      MCPサーバーとして起動（標準入出力）
+  help                     このヘルプを表示
+  version                  バージョンを表示
+
+Environment:
+  WORKNOTE_DATA_DIR        アプリデータディレクトリ（既定: GUIと同じ場所）
+";
+
+/// CLIを実行して終了コードを返す
+///
+/// # Arguments
+/// * `args` - コマンドライン引数（プログラム名を除く）
+pub fn run(args: Vec<String>) -> i32 {
+    let command = match parse_args(&args) {
+        Ok(command) => command,
+        Err(e) => {
+            eprintln!("worknote: {}", e);
+            eprintln!("Run `worknote help` for usage.");
+            return EXIT_USAGE;
+        }
+    };
+
+    match execute(command) {
+        Ok(()) => EXIT_OK,
+        Err(e) => {
+            eprintln!("worknote: {}", e);
+            EXIT_FAILURE
+        }
+    }
+}
+
+/// GUIと共通のアプリデータディレクトリを取得
+pub fn app_data_dir() -> Result<PathBuf> {
+    if let Some(dir) = std::env::var_os(DATA_DIR_ENV).filter(|d| !d.is_empty()) {
+        return Ok(PathBuf::from(dir));
+    }
+
+    dirs::data_dir()
+        .map(|dir| dir.join(APP_IDENTIFIER))
+        .ok_or_else(|| WorkNoteError::ConfigError("Failed to determine app data dir".to_string()))
+}
+
+/// リポジトリ共有設定（`.worknote.toml`）を重ねた設定を読み込む
+fn load_config() -> Result<Config> {
+    Ok(ConfigManager::new(app_data_dir()?)
+        .load_effective_config()?
+        .config)
+}
+
+fn execute(command: CliCommand) -> Result<()> {
+    match command {
+        CliCommand::Add(args) => {
+            let input = input::build_knowledge_input(&args)?;
+            if args.draft {
+                let draft = DraftManager::new(app_data_dir()?)
+                    .create_draft_for_profile(input, args.profile)?;
+                println!("下書きを保存しました: {}", draft.id);
+                return Ok(());
+            }
+
+            let config = ConfigManager::new(app_data_dir()?)
+                .load_profile_config(args.profile.as_deref())?
+                .config;
+            let trailers = CommitTrailers {
+                issue_key: args.issue.clone(),
+                co_authors: args.co_authors.clone(),
+            };
+            let response = KnowledgeService::new(config)
+                .with_trailers(trailers)
+                .save(&input)?;
+            println!("保存しました: {}", response.file_path);
+            println!("コミット: {}", response.commit_hash);
+            if let Some(url) = response.pr_url {
+                println!("PR作成URL: {}", url);
+            }
+            Ok(())
+        }
+        CliCommand::Draft(command) => execute_draft(command),
+        CliCommand::Profile(command) => execute_profile(command),
+        CliCommand::Search { query, limit, json } => {
+            let config = load_config()?;
+            let repository = RunbookRepository::new(
+                PathBuf::from(&config.git.repository_path).join(&config.git.save_path),
+            );
+            let results = repository.search(&query, limit)?;
+
+            if json {
+                println!("{}", serde_json::to_string_pretty(&results)?);
+            } else if results.is_empty() {
+                eprintln!("一致するRunbookはありません");
+            } else {
+                for result in results {
+                    println!("{}\t{}", result.entry.path, result.entry.title);
+                    if !result.snippet.is_empty() {
+                        println!("    {}", result.snippet);
+                    }
+                }
+            }
+            Ok(())
+        }
+        CliCommand::Proofread { file } => {
+            let content = input::read_source(file.as_deref().unwrap_or("-"))?;
+            let custom_prompt = load_config()?.proofread.and_then(|p| p.effective_prompt());
+            let result = ProofreadService::with_custom_prompt(custom_prompt).proofread(&content)?;
+            println!("{}", result);
+            Ok(())
+        }
+        CliCommand::Config(command) => execute_config(command),
+        CliCommand::Import {
+            path,
+            category,
+            severity,
+            json,
+        } => {
+            let options = ImportOptions {
+                category: parse_option_value("category", category)?,
+                severity: parse_option_value("severity", severity)?,
+            };
+            let report =
+                RunbookImporter::new(app_data_dir()?, options).import(&PathBuf::from(path))?;
+
+            if json {
+                println!("{}", serde_json::to_string_pretty(&report)?);
+                return Ok(());
+            }
+            for document in &report.imported {
+                println!(
+                    "{}\t{}\t{}",
+                    document.draft_id, document.title, document.source
+                );
+                for section in &document.unmapped_sections {
+                    println!("    未対応の見出し: {}", section);
+                }
+                for warning in &document.warnings {
+                    println!("    {}", warning);
+                }
+            }
+            for skipped in &report.skipped {
+                eprintln!("スキップ: {} ({})", skipped.source, skipped.reason);
+            }
+            eprintln!(
+                "{}件を下書きとして取り込みました（スキップ: {}件）",
+                report.imported.len(),
+                report.skipped.len()
+            );
+            Ok(())
+        }
+        CliCommand::Capture {
+            source,
+            file,
+            title,
+            category,
+            severity,
+        } => {
+            let content = input::read_source(file.as_deref().unwrap_or("-"))?;
+            let options = CaptureOptions {
+                source,
+                title,
+                category: parse_option_value("category", category)?,
+                severity: parse_option_value("severity", severity)?,
+            };
+            let draft = SessionCapture::capture(app_data_dir()?, &content, &options)?;
+            println!("下書きを保存しました: {}", draft.id);
+            eprintln!("タイトル: {}", draft.data.title);
+            Ok(())
+        }
+        CliCommand::Validate { path, format } => execute_validate(path, format),
+        CliCommand::MigrateCategories { dry_run } => {
+            let config = load_config()?;
+            let migrator = CategoryMigrator::new(
+                PathBuf::from(&config.git.repository_path).join(&config.git.save_path),
+                config.taxonomy,
+            );
+            let report = migrator.migrate(dry_run)?;
+            for file in &report.migrated {
+                println!("{} -> {}", file.from, file.to);
+            }
+            for skipped in &report.skipped {
+                eprintln!("スキップ: {} ({})", skipped.path, skipped.reason);
+            }
+            eprintln!(
+                "{}{}件を移行しました（確認: {}件、スキップ: {}件）",
+                if dry_run { "[dry-run] " } else { "" },
+                report.migrated.len(),
+                report.files_checked,
+                report.skipped.len()
+            );
+            Ok(())
+        }
+        CliCommand::Mcp => {
+            let server = McpServer::new(app_data_dir()?);
+            server.serve(std::io::stdin().lock(), std::io::stdout().lock())?;
+            Ok(())
+        }
+        CliCommand::Help => {
+            print!("{}", USAGE);
+            Ok(())
+        }
+        CliCommand::Version => {
+            println!("worknote {}", env!("CARGO_PKG_VERSION"));
+            Ok(())
+        }
+    }
+}
+
+/// `--category`・`--severity` の値を列挙型に変換
+fn parse_option_value<T: serde::de::DeserializeOwned>(
+    name: &str,
+    value: Option<String>,
+) -> Result<Option<T>> {
+    value
+        .map(|v| serde_json::from_value(Value::String(v)))
+        .transpose()
+        .map_err(|e| WorkNoteError::ValidationError(format!("Invalid {}: {}", name, e)))
+}
+
+fn execute_validate(path: Option<String>, format: ReportFormat) -> Result<()> {
+    // カテゴリ・重要度の定義は設定があれば使用
+    let (save_dir, uri_prefix, taxonomy) = match path {
+        Some(path) => {
+            // パス指定時は、そのパスを含むリポジトリの共有設定（.worknote.toml）を個人設定に重ねる
+            let save_dir = PathBuf::from(path);
+            let local = app_data_dir()
+                .and_then(|dir| ConfigManager::new(dir).load_config())
+                .and_then(|config| config.resolve_profile(None))
+                .unwrap_or_default();
+            let config = match ConfigManager::find_repository_root(&save_dir) {
+                Some(root) => ConfigManager::apply_repo_config(local, &root)?.config,
+                None => local,
+            };
+            (save_dir, String::new(), config.taxonomy)
+        }
+        None => {
+            let config = load_config()?;
+            let save_path = config.git.save_path.trim_matches('/').to_string();
+            (
+                PathBuf::from(&config.git.repository_path).join(&save_path),
+                format!("{}/", save_path),
+                config.taxonomy,
+            )
+        }
+    };
+
+    let report = RunbookValidator::new(save_dir)
+        .with_taxonomy(taxonomy)
+        .validate_all()?;
+    match format {
+        ReportFormat::Text => print!("{}", report.to_text()),
+        ReportFormat::Json => println!("{}", serde_json::to_string_pretty(&report)?),
+        ReportFormat::Sarif => println!(
+            "{}",
+            serde_json::to_string_pretty(&report.to_sarif(&uri_prefix))?
+        ),
+    }
+
+    if report.has_errors() {
+        return Err(WorkNoteError::ValidationError(format!(
+            "{} error(s) found",
+            report.error_count()
+        )));
+    }
+    Ok(())
+}
+
+fn execute_draft(command: DraftCommand) -> Result<()> {
+    let manager = DraftManager::new(app_data_dir()?);
+
+    match command {
+        DraftCommand::List => {
+            for summary in manager.list_drafts()? {
+                println!(
+                    "{}\t{}\t{}\t{}",
+                    summary.id,
+                    summary.updated_at.format("%Y-%m-%d %H:%M"),
+                    summary.category,
+                    summary.title
+                );
+            }
+        }
+        DraftCommand::Show { id, json } => {
+            let draft = manager.load_draft(&id)?;
+            if json {
+                println!("{}", serde_json::to_string_pretty(&draft)?);
+            } else {
+                print!(
+                    "{}",
+                    FileGenerator::generate_markdown_for_preview(&draft.data)
+                );
+            }
+        }
+        DraftCommand::Remove { id } => {
+            manager.delete_draft(&id)?;
+            println!("下書きを削除しました: {}", id);
+        }
+        DraftCommand::Publish {
+            ids,
+            profile,
+            separate_commits,
+            issue,
+            co_authors,
+        } => {
+            let trailers = CommitTrailers {
+                issue_key: issue,
+                co_authors,
+            };
+            let response = DraftPublisher::new(app_data_dir()?).publish(
+                &ids,
+                profile.as_deref(),
+                separate_commits,
+                trailers,
+            )?;
+            for path in &response.file_paths {
+                println!("保存しました: {}", path);
+            }
+            for hash in &response.commit_hashes {
+                println!("コミット: {}", hash);
+            }
+            if let Some(url) = response.pr_url {
+                println!("PR作成URL: {}", url);
+            }
+            for id in ids
+                .iter()
+                .filter(|id| !response.published_drafts.contains(id))
+            {
+                eprintln!("下書きを削除できませんでした: {}", id);
+            }
+        }
+    }
+    Ok(())
+}
+
+fn execute_profile(command: ProfileCommand) -> Result<()> {
+    let manager = ConfigManager::new(app_data_dir()?);
+
+    match command {
+        ProfileCommand::List => {
+            for profile in manager.load_config()?.profile_summaries() {
+                println!(
+                    "{} {}\t{}\t{}",
+                    if profile.active { "*" } else { " " },
+                    profile.name,
+                    profile.label,
+                    profile.repository_path
+                );
+            }
+        }
+        ProfileCommand::Use { name } => {
+            manager.set_active_profile(&name)?;
+            println!("プロファイルを切り替えました: {}", name);
+        }
+    }
+    Ok(())
+}
+
+fn execute_config(command: ConfigCommand) -> Result<()> {
+    let data_dir = app_data_dir()?;
+    let manager = ConfigManager::new(data_dir.clone());
+
+    match command {
+        ConfigCommand::Show => {
+            println!("{}", serde_json::to_string_pretty(&manager.load_config()?)?);
+        }
+        ConfigCommand::Path => println!("{}", data_dir.display()),
+        ConfigCommand::Get { key } => match get_config_value(&manager.load_config()?, &key)? {
+            Value::String(value) => println!("{}", value),
+            value => println!("{}", serde_json::to_string_pretty(&value)?),
+        },
+        ConfigCommand::Set { key, value } => {
+            let config = set_config_value(&manager.load_config()?, &key, &value)?;
+            manager.save_config(&config)?;
+            println!("{} を更新しました", key);
+        }
+    }
+    Ok(())
+}
+
+/// ドット区切りのキー（例: `git.commitMode`）で設定値を取得
+pub fn get_config_value(config: &Config, key: &str) -> Result<Value> {
+    let mut value = serde_json::to_value(config)?;
+    for part in key.split('.') {
+        value = value
+            .get_mut(part)
+            .map(Value::take)
+            .ok_or_else(|| WorkNoteError::ConfigError(format!("Unknown config key: {}", key)))?;
+    }
+    Ok(value)
+}
+
+/// ドット区切りのキーで設定値を変更した設定を返す
+///
+/// 値はJSONとして解釈できればJSON（数値・真偽値など）、できなければ文字列として扱います。
+/// 変更後の設定が `Config` として不正な場合はエラーになります。
+pub fn set_config_value(config: &Config, key: &str, raw_value: &str) -> Result<Config> {
+    let new_value = serde_json::from_str::<Value>(raw_value)
+        .unwrap_or_else(|_| Value::String(raw_value.to_string()));
+
+    let mut root = serde_json::to_value(config)?;
+    let parts: Vec<&str> = key.split('.').collect();
+    let (last, parents) = parts
+        .split_last()
+        .ok_or_else(|| WorkNoteError::ConfigError("Config key is empty".to_string()))?;
+
+    let mut current = &mut root;
+    for part in parents {
+        let object = current
+            .as_object_mut()
+            .ok_or_else(|| WorkNoteError::ConfigError(format!("Unknown config key: {}", key)))?;
+        // 未設定のオプション項目（author, proofread等）は作成する
+        current = object
+            .entry(part.to_string())
+            .or_insert_with(|| Value::Object(Default::default()));
+        if current.is_null() {
+            *current = Value::Object(Default::default());
+        }
+    }
+    current
+        .as_object_mut()
+        .ok_or_else(|| WorkNoteError::ConfigError(format!("Unknown config key: {}", key)))?
+        .insert(last.to_string(), new_value.clone());
+
+    let updated: Config = serde_json::from_value(root)
+        .map_err(|e| WorkNoteError::ConfigError(format!("Invalid value for {}: {}", key, e)))?;
+
+    // 存在しないキーはデシリアライズ時に無視されるため、反映されたかで判定する
+    if get_config_value(&updated, key).ok() != Some(new_value) {
+        return Err(WorkNoteError::ConfigError(format!(
+            "Unknown config key: {}",
+            key
+        )));
+    }
+    Ok(updated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::CommitMode;
+
+    #[test]
+    fn test_get_config_value() {
+        let config = Config::default();
+        assert_eq!(
+            get_config_value(&config, "git.defaultBranch").unwrap(),
+            Value::String("main".to_string())
+        );
+        assert!(get_config_value(&config, "git.unknown").is_err());
+    }
+
+    #[test]
+    fn test_set_config_value() {
+        let config = Config::default();
+
+        let updated = set_config_value(&config, "git.commitMode", "feature-branch").unwrap();
+        assert_eq!(updated.git.commit_mode, CommitMode::FeatureBranch);
+
+        let updated = set_config_value(&updated, "webhook.port", "8080").unwrap();
+        assert_eq!(updated.webhook.port, 8080);
+
+        // 未設定のオプション項目も設定できる
+        let updated = set_config_value(&updated, "proofread.prompt", "添削して").unwrap();
+        assert_eq!(updated.proofread.unwrap().prompt, "添削して");
+
+        // 存在しないキーや型が合わない値はエラー
+        assert!(set_config_value(&config, "git.unknown", "x").is_err());
+        assert!(set_config_value(&config, "unknown.key", "x").is_err());
+        assert!(set_config_value(&config, "webhook.port", "abc").is_err());
+        assert!(set_config_value(&config, "git.commitMode", "unknown").is_err());
+    }
+}
@@ -0,0 +1,19 @@
+use crate::models::{CaptureOptions, Draft, ErrorInfo};
+use crate::services::SessionCapture;
+use tauri::{AppHandle, Manager};
+
+/// Slackスレッド・端末操作の記録・シェル履歴から下書きを作成
+#[tauri::command]
+pub async fn capture_session(
+    app: AppHandle,
+    content: String,
+    options: CaptureOptions,
+) -> std::result::Result<Draft, ErrorInfo> {
+    let app_data_dir = app.path().app_data_dir().map_err(|e| ErrorInfo {
+        error_type: "ConfigError".to_string(),
+        message: format!("Failed to get app data dir: {}", e),
+        details: None,
+    })?;
+
+    SessionCapture::capture(app_data_dir, &content, &options).map_err(ErrorInfo::from)
+}
@@ -1,33 +1,168 @@
-use crate::models::{Config, ErrorInfo};
-use crate::services::ConfigManager;
-use tauri::{AppHandle, Manager};
+use crate::commands::webhook::apply_webhook_config;
+use crate::models::{
+    Config, EffectiveConfig, ErrorInfo, ProfileSummary, Result, SaveConfigResponse,
+    SetActiveProfileResponse, ShortcutAction, ShortcutsConfig, WorkNoteError,
+};
+use crate::services::accelerator::{self, Accelerator};
+use crate::services::{ConfigManager, ShortcutManager};
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager};
+
+/// 設定変更時に全ウィンドウへ送るイベント名（ペイロードは保存後の `Config`）
+pub const CONFIG_CHANGED_EVENT: &str = "config-changed";
+
+/// 登録中のグローバルショートカット（Tauriのmanaged state）
+#[derive(Default)]
+pub struct ShortcutState(Mutex<Vec<(ShortcutAction, Accelerator)>>);
+
+/// 設定に従ってグローバルショートカットを登録し直す
+///
+/// 表記の誤り・割り当ての重複がある場合や、新しいショートカットを登録できない場合は
+/// 元のショートカットを登録したままエラーを返します。
+pub fn apply_shortcut_config(app: &AppHandle, shortcuts: &ShortcutsConfig) -> Result<()> {
+    let bindings = accelerator::parse_bindings(shortcuts)?;
+
+    let state = app.state::<ShortcutState>();
+    let mut registered = state
+        .0
+        .lock()
+        .map_err(|_| WorkNoteError::ShortcutError("Shortcut state is poisoned".to_string()))?;
+    if *registered == bindings {
+        return Ok(());
+    }
+
+    ShortcutManager::new(app.clone()).replace_bindings(&registered, &bindings)?;
+    *registered = bindings;
+    Ok(())
+}
+
+/// 起動時に設定のグローバルショートカットを登録
+///
+/// 他のアプリが使用中などで登録できないショートカットがあっても、残りは登録します。
+/// 登録できたものだけを登録中として記録し、登録できなかったもののエラーを返します。
+pub fn register_startup_shortcuts(app: &AppHandle, shortcuts: &ShortcutsConfig) -> Vec<String> {
+    let bindings = match accelerator::parse_bindings(shortcuts) {
+        Ok(bindings) => bindings,
+        Err(e) => return vec![e.to_string()],
+    };
+
+    let (registered, errors) = ShortcutManager::new(app.clone()).register_each(&bindings);
+    match app.state::<ShortcutState>().0.lock() {
+        Ok(mut state) => *state = registered,
+        Err(_) => return vec!["Shortcut state is poisoned".to_string()],
+    }
+    errors.iter().map(ToString::to_string).collect()
+}
+
+/// 全ウィンドウに `config-changed` を通知し、保存した設定を反映
+///
+/// ショートカットは保存前に登録し直しているため、ここではWebhook受信設定のみ反映します。
+/// 設定は保存済みのため、反映に失敗した場合はエラーにせず警告を返します。
+fn notify_config_changed(app: &AppHandle, config: &Config) -> Vec<String> {
+    if let Err(e) = app.emit(CONFIG_CHANGED_EVENT, config) {
+        tracing::warn!(error = %e, "Failed to emit config-changed event");
+    }
+
+    let mut warnings = Vec::new();
+    if let Err(e) = apply_webhook_config(app, config) {
+        tracing::warn!(error = %e, "Failed to apply webhook config");
+        warnings.push(format!("Webhook受信の開始に失敗しました: {}", e));
+    }
+    warnings
+}
 
 #[tauri::command]
 pub async fn load_config(app: AppHandle) -> std::result::Result<Config, ErrorInfo> {
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| ErrorInfo {
-            error_type: "ConfigError".to_string(),
-            message: format!("Failed to get app data dir: {}", e),
-            details: None,
-        })?;
+    let app_data_dir = app.path().app_data_dir().map_err(|e| ErrorInfo {
+        error_type: "ConfigError".to_string(),
+        message: format!("Failed to get app data dir: {}", e),
+        details: None,
+    })?;
 
     let config_manager = ConfigManager::new(app_data_dir);
     config_manager.load_config().map_err(ErrorInfo::from)
 }
 
+/// リポジトリ共有設定（`.worknote.toml`）を重ねた設定を取得
+///
+/// 入力画面のカテゴリ・必須項目など、実際に適用される値の表示に使います。
+/// `profile` 未指定時は使用中のプロファイルの設定を返します。
 #[tauri::command]
-pub async fn save_config(app: AppHandle, config: Config) -> std::result::Result<(), ErrorInfo> {
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| ErrorInfo {
-            error_type: "ConfigError".to_string(),
-            message: format!("Failed to get app data dir: {}", e),
-            details: None,
-        })?;
+pub async fn load_effective_config(
+    app: AppHandle,
+    profile: Option<String>,
+) -> std::result::Result<EffectiveConfig, ErrorInfo> {
+    let app_data_dir = app.path().app_data_dir().map_err(|e| ErrorInfo {
+        error_type: "ConfigError".to_string(),
+        message: format!("Failed to get app data dir: {}", e),
+        details: None,
+    })?;
 
     let config_manager = ConfigManager::new(app_data_dir);
-    config_manager.save_config(&config).map_err(ErrorInfo::from)
+    config_manager
+        .load_profile_config(profile.as_deref())
+        .map_err(ErrorInfo::from)
+}
+
+#[tauri::command]
+pub async fn save_config(
+    app: AppHandle,
+    config: Config,
+) -> std::result::Result<SaveConfigResponse, ErrorInfo> {
+    let app_data_dir = app.path().app_data_dir().map_err(|e| ErrorInfo {
+        error_type: "ConfigError".to_string(),
+        message: format!("Failed to get app data dir: {}", e),
+        details: None,
+    })?;
+
+    let config_manager = ConfigManager::new(app_data_dir);
+
+    // ショートカットを先に登録し直し、登録できない場合は設定を保存しない
+    apply_shortcut_config(&app, &config.shortcuts).map_err(ErrorInfo::from)?;
+    if let Err(e) = config_manager.save_config(&config) {
+        if let Ok(previous) = config_manager.load_config() {
+            let _ = apply_shortcut_config(&app, &previous.shortcuts);
+        }
+        return Err(ErrorInfo::from(e));
+    }
+
+    // 変更通知とWebhook受信設定の反映
+    Ok(SaveConfigResponse {
+        warnings: notify_config_changed(&app, &config),
+    })
+}
+
+/// 保存先プロファイルの一覧を取得（先頭は `default`）
+#[tauri::command]
+pub async fn list_profiles(app: AppHandle) -> std::result::Result<Vec<ProfileSummary>, ErrorInfo> {
+    let app_data_dir = app.path().app_data_dir().map_err(|e| ErrorInfo {
+        error_type: "ConfigError".to_string(),
+        message: format!("Failed to get app data dir: {}", e),
+        details: None,
+    })?;
+
+    let config = ConfigManager::new(app_data_dir)
+        .load_config()
+        .map_err(ErrorInfo::from)?;
+    Ok(config.profile_summaries())
+}
+
+/// 使用中の保存先プロファイルを切り替え
+#[tauri::command]
+pub async fn set_active_profile(
+    app: AppHandle,
+    name: String,
+) -> std::result::Result<SetActiveProfileResponse, ErrorInfo> {
+    let app_data_dir = app.path().app_data_dir().map_err(|e| ErrorInfo {
+        error_type: "ConfigError".to_string(),
+        message: format!("Failed to get app data dir: {}", e),
+        details: None,
+    })?;
+
+    let config = ConfigManager::new(app_data_dir)
+        .set_active_profile(&name)
+        .map_err(ErrorInfo::from)?;
+    // 変更通知とWebhook受信設定の反映（反映できない項目は警告として返す）
+    let warnings = notify_config_changed(&app, &config);
+    Ok(SetActiveProfileResponse { config, warnings })
 }
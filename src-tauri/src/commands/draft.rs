@@ -1,5 +1,9 @@
-use crate::models::{Draft, DraftSummary, ErrorInfo, KnowledgeInput, WorkNoteError};
+use crate::models::{
+    CommitTrailers, Draft, DraftSummary, ErrorInfo, KnowledgeInput, PublishDraftsResponse,
+    WorkNoteError,
+};
 use crate::services::draft_manager::DraftManager;
+use crate::services::draft_publisher::DraftPublisher;
 use tauri::{AppHandle, Manager};
 
 /// 下書きを保存
@@ -13,16 +17,18 @@ pub async fn save_draft(app: AppHandle, draft: Draft) -> Result<(), ErrorInfo> {
     })?;
 
     let manager = DraftManager::new(app_data_dir);
-    manager
-        .save_draft(&draft)
-        .map_err(|e| ErrorInfo::from(e))?;
+    manager.save_draft(&draft).map_err(ErrorInfo::from)?;
 
     Ok(())
 }
 
 /// 下書きを作成
 #[tauri::command]
-pub async fn create_draft(app: AppHandle, data: KnowledgeInput) -> Result<Draft, ErrorInfo> {
+pub async fn create_draft(
+    app: AppHandle,
+    data: KnowledgeInput,
+    profile: Option<String>,
+) -> Result<Draft, ErrorInfo> {
     let app_data_dir = app.path().app_data_dir().map_err(|e| {
         ErrorInfo::from(WorkNoteError::FileError(format!(
             "Failed to get app data dir: {}",
@@ -32,8 +38,8 @@ pub async fn create_draft(app: AppHandle, data: KnowledgeInput) -> Result<Draft,
 
     let manager = DraftManager::new(app_data_dir);
     let draft = manager
-        .create_draft(data)
-        .map_err(|e| ErrorInfo::from(e))?;
+        .create_draft_for_profile(data, profile)
+        .map_err(ErrorInfo::from)?;
 
     Ok(draft)
 }
@@ -49,7 +55,7 @@ pub async fn load_draft(app: AppHandle, id: String) -> Result<Draft, ErrorInfo>
     })?;
 
     let manager = DraftManager::new(app_data_dir);
-    let draft = manager.load_draft(&id).map_err(|e| ErrorInfo::from(e))?;
+    let draft = manager.load_draft(&id).map_err(ErrorInfo::from)?;
 
     Ok(draft)
 }
@@ -65,7 +71,7 @@ pub async fn list_drafts(app: AppHandle) -> Result<Vec<DraftSummary>, ErrorInfo>
     })?;
 
     let manager = DraftManager::new(app_data_dir);
-    let summaries = manager.list_drafts().map_err(|e| ErrorInfo::from(e))?;
+    let summaries = manager.list_drafts().map_err(ErrorInfo::from)?;
 
     Ok(summaries)
 }
@@ -81,9 +87,7 @@ pub async fn delete_draft(app: AppHandle, id: String) -> Result<(), ErrorInfo> {
     })?;
 
     let manager = DraftManager::new(app_data_dir);
-    manager
-        .delete_draft(&id)
-        .map_err(|e| ErrorInfo::from(e))?;
+    manager.delete_draft(&id).map_err(ErrorInfo::from)?;
 
     Ok(())
 }
@@ -94,6 +98,7 @@ pub async fn update_draft(
     app: AppHandle,
     id: String,
     data: KnowledgeInput,
+    profile: Option<String>,
 ) -> Result<Draft, ErrorInfo> {
     let app_data_dir = app.path().app_data_dir().map_err(|e| {
         ErrorInfo::from(WorkNoteError::FileError(format!(
@@ -104,8 +109,37 @@ pub async fn update_draft(
 
     let manager = DraftManager::new(app_data_dir);
     let draft = manager
-        .update_draft(&id, data)
-        .map_err(|e| ErrorInfo::from(e))?;
+        .update_draft(&id, data, profile)
+        .map_err(ErrorInfo::from)?;
 
     Ok(draft)
 }
+
+/// 複数の下書きをまとめて公開（1つのコミット、または下書きごとのコミットを1回でPush）
+///
+/// PR作成モードでは全てのコミットを1つのfeatureブランチに作成し、PRを1つ作成します。
+/// 下書きはPushに成功した後に削除します。
+#[tauri::command]
+pub async fn publish_drafts(
+    app: AppHandle,
+    draft_ids: Vec<String>,
+    profile: Option<String>,
+    separate_commits: bool,
+    trailers: Option<CommitTrailers>,
+) -> Result<PublishDraftsResponse, ErrorInfo> {
+    let app_data_dir = app.path().app_data_dir().map_err(|e| {
+        ErrorInfo::from(WorkNoteError::FileError(format!(
+            "Failed to get app data dir: {}",
+            e
+        )))
+    })?;
+
+    DraftPublisher::new(app_data_dir)
+        .publish(
+            &draft_ids,
+            profile.as_deref(),
+            separate_commits,
+            trailers.unwrap_or_default(),
+        )
+        .map_err(ErrorInfo::from)
+}
@@ -0,0 +1,100 @@
+use crate::models::{ErrorInfo, KnowledgeInput, WorkNoteError};
+use crate::services::{ConfigManager, FileGenerator, GitService, PdfExporter};
+use std::fs;
+use std::path::PathBuf;
+use tauri::{AppHandle, Manager};
+
+/// 入力中のナレッジをPDFにエクスポート
+#[tauri::command]
+pub async fn export_knowledge_pdf(
+    app: AppHandle,
+    input: KnowledgeInput,
+    output_path: String,
+) -> std::result::Result<String, ErrorInfo> {
+    let app_data_dir = app.path().app_data_dir().map_err(|e| ErrorInfo {
+        error_type: "ConfigError".to_string(),
+        message: format!("Failed to get app data dir: {}", e),
+        details: None,
+    })?;
+
+    let config_manager = ConfigManager::new(app_data_dir);
+    let config = config_manager
+        .load_effective_config()
+        .map_err(ErrorInfo::from)?
+        .config;
+
+    // エクスポートはAuthorが未設定でも実行できるようにする
+    let author = GitService::new(
+        PathBuf::from(&config.git.repository_path),
+        config.git.default_branch.clone(),
+    )
+    .resolve_author(config.author.as_ref())
+    .unwrap_or_else(|_| config.author.clone().unwrap_or_default());
+    let file_generator = FileGenerator::new(
+        PathBuf::from(&config.git.repository_path),
+        config.git.save_path.clone(),
+        author,
+    );
+
+    let markdown = file_generator.generate_markdown(&input);
+    let output_path = PathBuf::from(output_path);
+    PdfExporter::export_markdown(&markdown, &output_path).map_err(ErrorInfo::from)?;
+
+    Ok(output_path.to_string_lossy().to_string())
+}
+
+/// リポジトリ内のRunbookファイルをPDFにエクスポート
+///
+/// `file_path` はリポジトリルートからの相対パス、またはリポジトリ内の絶対パスを受け付けます。
+#[tauri::command]
+pub async fn export_runbook_pdf(
+    app: AppHandle,
+    file_path: String,
+    output_path: String,
+) -> std::result::Result<String, ErrorInfo> {
+    let app_data_dir = app.path().app_data_dir().map_err(|e| ErrorInfo {
+        error_type: "ConfigError".to_string(),
+        message: format!("Failed to get app data dir: {}", e),
+        details: None,
+    })?;
+
+    let config_manager = ConfigManager::new(app_data_dir);
+    let config = config_manager
+        .load_effective_config()
+        .map_err(ErrorInfo::from)?
+        .config;
+
+    let repository_path = PathBuf::from(&config.git.repository_path);
+    let source_path = repository_path.join(&file_path);
+
+    // リポジトリ外のファイルの読み込みを防止
+    let canonical_repo = repository_path.canonicalize().map_err(|e| {
+        ErrorInfo::from(WorkNoteError::FileError(format!(
+            "Failed to canonicalize repository path: {}",
+            e
+        )))
+    })?;
+    let canonical_source = source_path.canonicalize().map_err(|e| {
+        ErrorInfo::from(WorkNoteError::FileError(format!(
+            "Runbook not found: {} ({})",
+            file_path, e
+        )))
+    })?;
+    if !canonical_source.starts_with(&canonical_repo) {
+        return Err(ErrorInfo::from(WorkNoteError::FileError(
+            "Invalid file path: directory traversal detected".to_string(),
+        )));
+    }
+
+    let markdown = fs::read_to_string(&canonical_source).map_err(|e| {
+        ErrorInfo::from(WorkNoteError::FileError(format!(
+            "Failed to read runbook: {}",
+            e
+        )))
+    })?;
+
+    let output_path = PathBuf::from(output_path);
+    PdfExporter::export_markdown(&markdown, &output_path).map_err(ErrorInfo::from)?;
+
+    Ok(output_path.to_string_lossy().to_string())
+}
@@ -0,0 +1,23 @@
+use crate::models::{ErrorInfo, ImportOptions, ImportReport};
+use crate::services::RunbookImporter;
+use std::path::PathBuf;
+use tauri::{AppHandle, Manager};
+
+/// Confluence/Notionのエクスポート（HTML・Markdown・ZIP・ディレクトリ）を下書きとして取り込む
+#[tauri::command]
+pub async fn import_runbooks(
+    app: AppHandle,
+    path: String,
+    options: Option<ImportOptions>,
+) -> std::result::Result<ImportReport, ErrorInfo> {
+    let app_data_dir = app.path().app_data_dir().map_err(|e| ErrorInfo {
+        error_type: "ConfigError".to_string(),
+        message: format!("Failed to get app data dir: {}", e),
+        details: None,
+    })?;
+
+    let importer = RunbookImporter::new(app_data_dir, options.unwrap_or_default());
+    importer
+        .import(&PathBuf::from(path))
+        .map_err(ErrorInfo::from)
+}
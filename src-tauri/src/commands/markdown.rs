@@ -22,6 +22,9 @@ mod tests {
             notes: None,
             related_links: None,
             judgment: None,
+            slug: None,
+            related_alerts: Vec::new(),
+            id: None,
         };
 
         let result = render_markdown(input);
@@ -1,13 +1,37 @@
+pub mod capture;
 pub mod config;
 pub mod draft;
+pub mod export;
+pub mod import;
 pub mod knowledge;
 pub mod markdown;
+pub mod postmortem;
 pub mod proofreader;
+pub mod runbook;
+pub mod timeline;
+pub mod webhook;
 pub mod window;
 
-pub use config::{load_config, save_config};
-pub use draft::{create_draft, delete_draft, list_drafts, load_draft, save_draft, update_draft};
+pub use capture::capture_session;
+pub use config::{
+    list_profiles, load_config, load_effective_config, save_config, set_active_profile,
+};
+pub use draft::{
+    create_draft, delete_draft, list_drafts, load_draft, publish_drafts, save_draft, update_draft,
+};
+pub use export::{export_knowledge_pdf, export_runbook_pdf};
+pub use import::import_runbooks;
 pub use knowledge::{quick_save_knowledge, save_knowledge};
 pub use markdown::render_markdown;
+pub use postmortem::{render_postmortem, save_postmortem};
 pub use proofreader::proofread_markdown;
+pub use runbook::{
+    find_runbooks_by_alert, get_backlinks, get_runbook_blame, get_runbook_diff,
+    get_runbook_history, get_runbook_revision, migrate_categories, search_runbooks,
+};
+pub use timeline::{
+    add_timeline_note, delete_timeline, finalize_timeline, get_active_timeline, list_timelines,
+    load_timeline, remove_timeline_note, start_timeline,
+};
+pub use webhook::{get_webhook_status, restart_webhook_listener};
 pub use window::{hide_quick_input_window, show_quick_input_window};
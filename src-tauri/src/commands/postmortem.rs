@@ -0,0 +1,34 @@
+use crate::models::{CommitTrailers, ErrorInfo, PostmortemInput, SaveKnowledgeResponse};
+use crate::services::{ConfigManager, KnowledgeService, MarkdownRenderer};
+use tauri::{AppHandle, Manager};
+
+/// ポストモーテムを保存してコミット・Push（PR作成モードではPR作成URLを返す）
+#[tauri::command]
+pub async fn save_postmortem(
+    app: AppHandle,
+    input: PostmortemInput,
+    profile: Option<String>,
+    trailers: Option<CommitTrailers>,
+) -> std::result::Result<SaveKnowledgeResponse, ErrorInfo> {
+    let app_data_dir = app.path().app_data_dir().map_err(|e| ErrorInfo {
+        error_type: "ConfigError".to_string(),
+        message: format!("Failed to get app data dir: {}", e),
+        details: None,
+    })?;
+
+    let config = ConfigManager::new(app_data_dir)
+        .load_profile_config(profile.as_deref())
+        .map_err(ErrorInfo::from)?
+        .config;
+
+    KnowledgeService::new(config)
+        .with_trailers(trailers.unwrap_or_default())
+        .save_postmortem(&input)
+        .map_err(ErrorInfo::from)
+}
+
+/// ポストモーテムのHTMLプレビューを生成
+#[tauri::command]
+pub fn render_postmortem(input: PostmortemInput) -> std::result::Result<String, ErrorInfo> {
+    MarkdownRenderer::render_postmortem(&input).map_err(ErrorInfo::from)
+}
@@ -9,20 +9,20 @@ pub async fn proofread_markdown(
     content: String,
 ) -> std::result::Result<String, ErrorInfo> {
     // Configからカスタムプロンプトを取得
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| ErrorInfo {
-            error_type: "ConfigError".to_string(),
-            message: format!("Failed to get app data dir: {}", e),
-            details: None,
-        })?;
+    let app_data_dir = app.path().app_data_dir().map_err(|e| ErrorInfo {
+        error_type: "ConfigError".to_string(),
+        message: format!("Failed to get app data dir: {}", e),
+        details: None,
+    })?;
 
     let config_manager = ConfigManager::new(app_data_dir);
-    let config = config_manager.load_config().map_err(ErrorInfo::from)?;
+    let config = config_manager
+        .load_effective_config()
+        .map_err(ErrorInfo::from)?
+        .config;
 
     // カスタムプロンプトを取得（存在しない場合はNone）
-    let custom_prompt = config.proofread.map(|p| p.prompt);
+    let custom_prompt = config.proofread.and_then(|p| p.effective_prompt());
 
     tokio::task::spawn_blocking(move || {
         let service = ProofreadService::with_custom_prompt(custom_prompt);
@@ -43,20 +43,20 @@ pub async fn proofread_all_fields(
     request: ProofreadRequest,
 ) -> std::result::Result<ProofreadResponse, ErrorInfo> {
     // Configからカスタムプロンプトを取得
-    let app_data_dir = app
-        .path()
-        .app_data_dir()
-        .map_err(|e| ErrorInfo {
-            error_type: "ConfigError".to_string(),
-            message: format!("Failed to get app data dir: {}", e),
-            details: None,
-        })?;
+    let app_data_dir = app.path().app_data_dir().map_err(|e| ErrorInfo {
+        error_type: "ConfigError".to_string(),
+        message: format!("Failed to get app data dir: {}", e),
+        details: None,
+    })?;
 
     let config_manager = ConfigManager::new(app_data_dir);
-    let config = config_manager.load_config().map_err(ErrorInfo::from)?;
+    let config = config_manager
+        .load_effective_config()
+        .map_err(ErrorInfo::from)?
+        .config;
 
     // カスタムプロンプトを取得（存在しない場合はNone）
-    let custom_prompt = config.proofread.map(|p| p.prompt);
+    let custom_prompt = config.proofread.and_then(|p| p.effective_prompt());
 
     tokio::task::spawn_blocking(move || {
         let service = ProofreadService::with_custom_prompt(custom_prompt);
@@ -0,0 +1,187 @@
+use crate::models::{
+    BlameLine, CategoryMigrationReport, ErrorInfo, RunbookCommit, RunbookDiff, RunbookEntry,
+    RunbookRevision, SearchResult,
+};
+use crate::services::{
+    CategoryMigrator, ConfigManager, GitService, RunbookHistory, RunbookRepository,
+};
+use std::path::PathBuf;
+use tauri::{AppHandle, Manager};
+
+/// 指定したRunbookIDを参照しているRunbook一覧を取得（バックリンク）
+#[tauri::command]
+pub async fn get_backlinks(
+    app: AppHandle,
+    runbook_id: String,
+) -> std::result::Result<Vec<RunbookEntry>, ErrorInfo> {
+    let app_data_dir = app.path().app_data_dir().map_err(|e| ErrorInfo {
+        error_type: "ConfigError".to_string(),
+        message: format!("Failed to get app data dir: {}", e),
+        details: None,
+    })?;
+
+    let config_manager = ConfigManager::new(app_data_dir);
+    let config = config_manager
+        .load_effective_config()
+        .map_err(ErrorInfo::from)?
+        .config;
+
+    let repository = RunbookRepository::new(
+        PathBuf::from(&config.git.repository_path).join(&config.git.save_path),
+    );
+    repository.backlinks(&runbook_id).map_err(ErrorInfo::from)
+}
+
+/// 指定したアラート名に対応するRunbook一覧を取得
+///
+/// アラートのannotationからRunbookを引く用途を想定し、大文字小文字を区別しません。
+#[tauri::command]
+pub async fn find_runbooks_by_alert(
+    app: AppHandle,
+    alert_name: String,
+) -> std::result::Result<Vec<RunbookEntry>, ErrorInfo> {
+    let app_data_dir = app.path().app_data_dir().map_err(|e| ErrorInfo {
+        error_type: "ConfigError".to_string(),
+        message: format!("Failed to get app data dir: {}", e),
+        details: None,
+    })?;
+
+    let config_manager = ConfigManager::new(app_data_dir);
+    let config = config_manager
+        .load_effective_config()
+        .map_err(ErrorInfo::from)?
+        .config;
+
+    let repository = RunbookRepository::new(
+        PathBuf::from(&config.git.repository_path).join(&config.git.save_path),
+    );
+    repository
+        .find_by_alert(&alert_name)
+        .map_err(ErrorInfo::from)
+}
+
+/// Runbookを全文検索（タイトル一致を優先）
+#[tauri::command]
+pub async fn search_runbooks(
+    app: AppHandle,
+    query: String,
+    limit: usize,
+) -> std::result::Result<Vec<SearchResult>, ErrorInfo> {
+    let app_data_dir = app.path().app_data_dir().map_err(|e| ErrorInfo {
+        error_type: "ConfigError".to_string(),
+        message: format!("Failed to get app data dir: {}", e),
+        details: None,
+    })?;
+
+    let config_manager = ConfigManager::new(app_data_dir);
+    let config = config_manager
+        .load_effective_config()
+        .map_err(ErrorInfo::from)?
+        .config;
+
+    let repository = RunbookRepository::new(
+        PathBuf::from(&config.git.repository_path).join(&config.git.save_path),
+    );
+    repository.search(&query, limit).map_err(ErrorInfo::from)
+}
+
+/// 既存Runbookをカテゴリ定義に合わせて移行（廃止カテゴリの書き換え・ディレクトリ移動）
+///
+/// 移行結果はコミットしないため、内容を確認してからコミットしてください。
+#[tauri::command]
+pub async fn migrate_categories(
+    app: AppHandle,
+    dry_run: bool,
+) -> std::result::Result<CategoryMigrationReport, ErrorInfo> {
+    let app_data_dir = app.path().app_data_dir().map_err(|e| ErrorInfo {
+        error_type: "ConfigError".to_string(),
+        message: format!("Failed to get app data dir: {}", e),
+        details: None,
+    })?;
+
+    let config_manager = ConfigManager::new(app_data_dir);
+    let config = config_manager
+        .load_effective_config()
+        .map_err(ErrorInfo::from)?
+        .config;
+
+    let migrator = CategoryMigrator::new(
+        PathBuf::from(&config.git.repository_path).join(&config.git.save_path),
+        config.taxonomy,
+    );
+    migrator.migrate(dry_run).map_err(ErrorInfo::from)
+}
+
+/// 使用中のプロファイルのRunbookリポジトリの履歴を参照するRunbookHistoryを作成
+fn runbook_history(app: &AppHandle) -> std::result::Result<RunbookHistory, ErrorInfo> {
+    let app_data_dir = app.path().app_data_dir().map_err(|e| ErrorInfo {
+        error_type: "ConfigError".to_string(),
+        message: format!("Failed to get app data dir: {}", e),
+        details: None,
+    })?;
+
+    let config_manager = ConfigManager::new(app_data_dir);
+    let config = config_manager
+        .load_effective_config()
+        .map_err(ErrorInfo::from)?
+        .config;
+
+    let git_service = GitService::new(
+        PathBuf::from(&config.git.repository_path),
+        config.git.default_branch.clone(),
+    );
+    Ok(RunbookHistory::new(git_service, config.git.save_path))
+}
+
+/// Runbookのコミット履歴を新しい順に取得（移動・リネームを追跡）
+///
+/// `path` は保存先ディレクトリからの相対パス（`RunbookEntry.path`）です。
+#[tauri::command]
+pub async fn get_runbook_history(
+    app: AppHandle,
+    path: String,
+    limit: Option<usize>,
+) -> std::result::Result<Vec<RunbookCommit>, ErrorInfo> {
+    runbook_history(&app)?
+        .log(&path, limit)
+        .map_err(ErrorInfo::from)
+}
+
+/// Runbookの2つのリビジョン間の差分を取得
+///
+/// `from` 未指定時は作成前（空の内容）から、`to` 未指定時は `HEAD` までの差分です。
+#[tauri::command]
+pub async fn get_runbook_diff(
+    app: AppHandle,
+    path: String,
+    from: Option<String>,
+    to: Option<String>,
+) -> std::result::Result<RunbookDiff, ErrorInfo> {
+    runbook_history(&app)?
+        .diff(&path, from.as_deref(), to.as_deref())
+        .map_err(ErrorInfo::from)
+}
+
+/// 指定したリビジョンのRunbookの内容を取得
+#[tauri::command]
+pub async fn get_runbook_revision(
+    app: AppHandle,
+    path: String,
+    revision: String,
+) -> std::result::Result<RunbookRevision, ErrorInfo> {
+    runbook_history(&app)?
+        .show(&path, &revision)
+        .map_err(ErrorInfo::from)
+}
+
+/// Runbookの各行を最後に変更したコミットを取得（`revision` 未指定時は `HEAD`）
+#[tauri::command]
+pub async fn get_runbook_blame(
+    app: AppHandle,
+    path: String,
+    revision: Option<String>,
+) -> std::result::Result<Vec<BlameLine>, ErrorInfo> {
+    runbook_history(&app)?
+        .blame(&path, revision.as_deref())
+        .map_err(ErrorInfo::from)
+}
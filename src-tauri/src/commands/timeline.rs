@@ -0,0 +1,116 @@
+use crate::models::{
+    ErrorInfo, FinalizeTimelineOptions, KnowledgeInput, TimelineNote, TimelineSession,
+    TimelineSummary, TimelineTag,
+};
+use crate::services::timeline_manager::SUMMARY_PROMPT;
+use crate::services::{ProofreadService, TimelineManager};
+use std::path::PathBuf;
+use tauri::{AppHandle, Manager};
+
+fn app_data_dir(app: &AppHandle) -> std::result::Result<PathBuf, ErrorInfo> {
+    app.path().app_data_dir().map_err(|e| ErrorInfo {
+        error_type: "ConfigError".to_string(),
+        message: format!("Failed to get app data dir: {}", e),
+        details: None,
+    })
+}
+
+/// タイムラインを開始
+#[tauri::command]
+pub async fn start_timeline(
+    app: AppHandle,
+    title: String,
+) -> std::result::Result<TimelineSession, ErrorInfo> {
+    TimelineManager::new(app_data_dir(&app)?)
+        .start(&title)
+        .map_err(ErrorInfo::from)
+}
+
+/// タイムラインにメモを追加（`id` 未指定時は進行中の最新のタイムライン）
+#[tauri::command]
+pub async fn add_timeline_note(
+    app: AppHandle,
+    id: Option<String>,
+    text: String,
+    tag: Option<TimelineTag>,
+) -> std::result::Result<TimelineNote, ErrorInfo> {
+    TimelineManager::new(app_data_dir(&app)?)
+        .add_note(id.as_deref(), &text, tag)
+        .map_err(ErrorInfo::from)
+}
+
+/// タイムラインからメモを削除
+#[tauri::command]
+pub async fn remove_timeline_note(
+    app: AppHandle,
+    id: String,
+    note_id: String,
+) -> std::result::Result<TimelineSession, ErrorInfo> {
+    TimelineManager::new(app_data_dir(&app)?)
+        .remove_note(&id, &note_id)
+        .map_err(ErrorInfo::from)
+}
+
+/// 進行中のタイムラインを取得
+#[tauri::command]
+pub async fn get_active_timeline(
+    app: AppHandle,
+) -> std::result::Result<Option<TimelineSession>, ErrorInfo> {
+    TimelineManager::new(app_data_dir(&app)?)
+        .active()
+        .map_err(ErrorInfo::from)
+}
+
+/// タイムラインを読み込み
+#[tauri::command]
+pub async fn load_timeline(
+    app: AppHandle,
+    id: String,
+) -> std::result::Result<TimelineSession, ErrorInfo> {
+    TimelineManager::new(app_data_dir(&app)?)
+        .load(&id)
+        .map_err(ErrorInfo::from)
+}
+
+/// タイムライン一覧を取得
+#[tauri::command]
+pub async fn list_timelines(
+    app: AppHandle,
+) -> std::result::Result<Vec<TimelineSummary>, ErrorInfo> {
+    TimelineManager::new(app_data_dir(&app)?)
+        .list()
+        .map_err(ErrorInfo::from)
+}
+
+/// タイムラインを削除
+#[tauri::command]
+pub async fn delete_timeline(app: AppHandle, id: String) -> std::result::Result<(), ErrorInfo> {
+    TimelineManager::new(app_data_dir(&app)?)
+        .delete(&id)
+        .map_err(ErrorInfo::from)
+}
+
+/// タイムラインを確定してナレッジ入力データに変換（要約は添削バックエンドを使用）
+#[tauri::command]
+pub async fn finalize_timeline(
+    app: AppHandle,
+    id: String,
+    options: Option<FinalizeTimelineOptions>,
+) -> std::result::Result<KnowledgeInput, ErrorInfo> {
+    let manager = TimelineManager::new(app_data_dir(&app)?);
+    let options = options.unwrap_or_default();
+
+    tokio::task::spawn_blocking(move || {
+        manager.finalize(&id, &options, |request| {
+            ProofreadService::with_custom_prompt(Some(SUMMARY_PROMPT.to_string()))
+                .proofread_all(request)
+        })
+    })
+    .await
+    .map_err(|e| ErrorInfo {
+        error_type: "ProofreadError".to_string(),
+        message: format!("Task join error: {}", e),
+        details: None,
+    })?
+    .map_err(ErrorInfo::from)
+}
@@ -0,0 +1,135 @@
+use crate::models::{Config, ErrorInfo, Result, WebhookDraftEvent, WorkNoteError};
+use crate::services::{ConfigManager, DraftManager, WebhookServer};
+use serde::Serialize;
+use std::sync::Mutex;
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_notification::NotificationExt;
+
+/// 下書き作成時にフロントエンドへ送るイベント名
+pub const WEBHOOK_DRAFT_EVENT: &str = "webhook-draft-created";
+
+/// Webhookリスナーの状態（Tauriのmanaged state）
+#[derive(Default)]
+pub struct WebhookState(Mutex<Option<WebhookServer>>);
+
+/// Webhookリスナーの稼働状況
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookStatus {
+    pub running: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub address: Option<String>,
+}
+
+/// 設定に従ってWebhookリスナーを起動・停止
+///
+/// 既に同じポート・同じトークンで起動している場合は何もしません。
+pub fn apply_webhook_config(app: &AppHandle, config: &Config) -> Result<()> {
+    let state = app.state::<WebhookState>();
+    let mut server = state
+        .0
+        .lock()
+        .map_err(|_| WorkNoteError::NetworkError("Webhook state is poisoned".to_string()))?;
+
+    // 設定のポートと比較する（0の場合は空きポートで起動しているため、実際のポートとは一致しない）
+    let unchanged = server
+        .as_ref()
+        .is_some_and(|s| s.port() == config.webhook.port && s.token() == config.webhook.token);
+    if config.webhook.enabled && unchanged {
+        return Ok(());
+    }
+
+    // 停止してから必要に応じて起動し直す（同じポートを再bindするため）
+    if let Some(current) = server.take() {
+        current.stop();
+    }
+    if !config.webhook.enabled {
+        return Ok(());
+    }
+
+    let app_data_dir = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| WorkNoteError::ConfigError(format!("Failed to get app data dir: {}", e)))?;
+    let handle = app.clone();
+    *server = Some(WebhookServer::start(
+        config.webhook.port,
+        config.webhook.token.clone(),
+        DraftManager::new(app_data_dir),
+        move |draft, alert| {
+            let event = WebhookDraftEvent {
+                draft_id: draft.id.clone(),
+                title: draft.data.title.clone(),
+                alert_name: alert.name.clone(),
+                severity: alert.severity.clone(),
+                source: alert.source,
+            };
+            notify_draft_created(&handle, &event);
+            if let Err(e) = handle.emit(WEBHOOK_DRAFT_EVENT, event) {
+                tracing::warn!(error = %e, "Failed to emit webhook event");
+            }
+        },
+    )?);
+
+    Ok(())
+}
+
+/// 下書きの作成をデスクトップ通知で知らせる
+///
+/// ウィンドウを閉じていても届くよう、フロントエンドではなくここから通知します。
+/// 通知の設定は保存時に変わるため、通知のたびに読み込みます。
+fn notify_draft_created(app: &AppHandle, event: &WebhookDraftEvent) {
+    let enabled = app
+        .path()
+        .app_data_dir()
+        .ok()
+        .and_then(|dir| ConfigManager::new(dir).load_config().ok())
+        .is_none_or(|config| config.preferences.show_notifications);
+    if !enabled {
+        return;
+    }
+
+    let result = app
+        .notification()
+        .builder()
+        .title(format!("アラート受信: {}", event.alert_name))
+        .body(format!(
+            "下書き「{}」を作成しました。下書き一覧からRunbookを作成できます。",
+            event.title
+        ))
+        .show();
+    if let Err(e) = result {
+        tracing::warn!(error = %e, "Failed to show webhook notification");
+    }
+}
+
+/// Webhookリスナーの稼働状況を取得
+#[tauri::command]
+pub async fn get_webhook_status(app: AppHandle) -> std::result::Result<WebhookStatus, ErrorInfo> {
+    let state = app.state::<WebhookState>();
+    let server = state.0.lock().map_err(|_| {
+        ErrorInfo::from(WorkNoteError::NetworkError(
+            "Webhook state is poisoned".to_string(),
+        ))
+    })?;
+
+    Ok(WebhookStatus {
+        running: server.is_some(),
+        address: server.as_ref().map(|s| s.local_addr().to_string()),
+    })
+}
+
+/// Webhookリスナーを設定に従って再起動
+#[tauri::command]
+pub async fn restart_webhook_listener(app: AppHandle) -> std::result::Result<(), ErrorInfo> {
+    let app_data_dir = app.path().app_data_dir().map_err(|e| ErrorInfo {
+        error_type: "ConfigError".to_string(),
+        message: format!("Failed to get app data dir: {}", e),
+        details: None,
+    })?;
+
+    let config = ConfigManager::new(app_data_dir)
+        .load_config()
+        .map_err(ErrorInfo::from)?;
+    apply_webhook_config(&app, &config).map_err(ErrorInfo::from)
+}
@@ -1,5 +1,5 @@
-use tauri::{AppHandle, Manager};
 use crate::models::error::{ErrorInfo, WorkNoteError};
+use tauri::{AppHandle, Manager};
 
 /// Show quick-input window
 #[tauri::command]
@@ -1,5 +1,9 @@
+use tauri::Manager;
+
 // Modules
+pub mod cli;
 pub mod commands;
+pub mod mcp;
 pub mod models;
 pub mod services;
 
@@ -14,12 +18,34 @@ pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_opener::init())
         .plugin(tauri_plugin_global_shortcut::Builder::new().build())
+        .plugin(tauri_plugin_notification::init())
         .setup(|app| {
-            // Register default global shortcut on app startup
-            let shortcut_manager = services::ShortcutManager::new(app.handle().clone());
-            if let Err(e) = shortcut_manager.register_shortcut("CommandOrControl+J") {
+            app.manage(commands::config::ShortcutState::default());
+            app.manage(commands::webhook::WebhookState::default());
+
+            let config = app
+                .path()
+                .app_data_dir()
+                .map_err(|e| e.to_string())
+                .and_then(|dir| {
+                    services::ConfigManager::new(dir)
+                        .load_config()
+                        .map_err(|e| e.to_string())
+                })
+                .unwrap_or_else(|e| {
+                    eprintln!("Failed to load config: {}", e);
+                    models::Config::default()
+                });
+
+            // 設定のグローバルショートカットを1つずつ登録（以降は設定の保存時に登録し直す）
+            for e in commands::config::register_startup_shortcuts(app.handle(), &config.shortcuts) {
                 eprintln!("Failed to register global shortcut: {}", e);
             }
+
+            // Webhook受信（設定で有効な場合のみ起動）
+            if let Err(e) = commands::webhook::apply_webhook_config(app.handle(), &config) {
+                eprintln!("Failed to start webhook listener: {}", e);
+            }
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
@@ -27,7 +53,10 @@ pub fn run() {
             commands::knowledge::save_knowledge,
             commands::knowledge::quick_save_knowledge,
             commands::config::load_config,
+            commands::config::load_effective_config,
             commands::config::save_config,
+            commands::config::list_profiles,
+            commands::config::set_active_profile,
             commands::window::show_quick_input_window,
             commands::window::hide_quick_input_window,
             commands::markdown::render_markdown,
@@ -38,7 +67,32 @@ pub fn run() {
             commands::draft::load_draft,
             commands::draft::list_drafts,
             commands::draft::delete_draft,
-            commands::draft::update_draft
+            commands::draft::update_draft,
+            commands::draft::publish_drafts,
+            commands::export::export_knowledge_pdf,
+            commands::export::export_runbook_pdf,
+            commands::runbook::get_backlinks,
+            commands::runbook::find_runbooks_by_alert,
+            commands::runbook::search_runbooks,
+            commands::import::import_runbooks,
+            commands::runbook::migrate_categories,
+            commands::runbook::get_runbook_history,
+            commands::runbook::get_runbook_diff,
+            commands::runbook::get_runbook_revision,
+            commands::runbook::get_runbook_blame,
+            commands::capture::capture_session,
+            commands::timeline::start_timeline,
+            commands::timeline::add_timeline_note,
+            commands::timeline::remove_timeline_note,
+            commands::timeline::get_active_timeline,
+            commands::timeline::load_timeline,
+            commands::timeline::list_timelines,
+            commands::timeline::delete_timeline,
+            commands::timeline::finalize_timeline,
+            commands::postmortem::save_postmortem,
+            commands::postmortem::render_postmortem,
+            commands::webhook::get_webhook_status,
+            commands::webhook::restart_webhook_listener
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -0,0 +1,355 @@
+//! MCP（Model Context Protocol）サーバー
+//!
+//! 標準入出力で改行区切りのJSON-RPC 2.0メッセージをやり取りし、
+//! AIエージェントからRunbookの検索・参照・下書き作成を行えるようにします。
+//! `worknote mcp` で起動します。
+
+pub mod tools;
+
+use serde_json::{json, Value};
+use std::io::{BufRead, Write};
+use std::path::PathBuf;
+use tools::ToolContext;
+
+/// サポートする最新のプロトコルバージョン（クライアントが指定しない・未対応の場合に使用）
+pub const PROTOCOL_VERSION: &str = "2025-06-18";
+
+/// サポートするプロトコルバージョン（新しい順）
+pub const SUPPORTED_PROTOCOL_VERSIONS: &[&str] = &[PROTOCOL_VERSION, "2025-03-26", "2024-11-05"];
+
+/// サーバー名（`serverInfo.name`）
+pub const SERVER_NAME: &str = "worknote";
+
+/// JSON-RPCエラーコード: 解析エラー
+const PARSE_ERROR: i64 = -32700;
+/// JSON-RPCエラーコード: 不正なリクエスト
+const INVALID_REQUEST: i64 = -32600;
+/// JSON-RPCエラーコード: 存在しないメソッド
+const METHOD_NOT_FOUND: i64 = -32601;
+/// JSON-RPCエラーコード: 不正なパラメータ
+const INVALID_PARAMS: i64 = -32602;
+
+/// MCPサーバー
+pub struct McpServer {
+    tools: ToolContext,
+}
+
+impl McpServer {
+    /// 新しいMcpServerインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `app_data_dir` - アプリケーションデータディレクトリ（GUIと共通）
+    pub fn new(app_data_dir: PathBuf) -> Self {
+        McpServer {
+            tools: ToolContext::new(app_data_dir),
+        }
+    }
+
+    /// 入力が終了するまでメッセージを処理
+    ///
+    /// 標準出力はプロトコル専用のため、ログは標準エラー出力に出してください。
+    pub fn serve(&self, reader: impl BufRead, mut writer: impl Write) -> std::io::Result<()> {
+        for line in reader.lines() {
+            let line = line?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            if let Some(response) = self.handle_message(&line) {
+                writeln!(writer, "{}", response)?;
+                writer.flush()?;
+            }
+        }
+        Ok(())
+    }
+
+    /// 1メッセージを処理して応答（通知の場合は `None`）を返す
+    pub fn handle_message(&self, line: &str) -> Option<String> {
+        let message: Value = match serde_json::from_str(line) {
+            Ok(message) => message,
+            Err(e) => {
+                return Some(error_response(
+                    Value::Null,
+                    PARSE_ERROR,
+                    &format!("Parse error: {}", e),
+                ))
+            }
+        };
+
+        // 通知（idなし）には応答しない
+        let id = message.get("id").cloned()?;
+        let Some(method) = message.get("method").and_then(Value::as_str) else {
+            return Some(error_response(id, INVALID_REQUEST, "Invalid request"));
+        };
+        let params = message.get("params").cloned().unwrap_or(Value::Null);
+
+        let response = match self.handle_request(method, &params) {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }).to_string(),
+            Err((code, message)) => error_response(id, code, &message),
+        };
+        Some(response)
+    }
+
+    fn handle_request(&self, method: &str, params: &Value) -> Result<Value, (i64, String)> {
+        match method {
+            "initialize" => {
+                // クライアントのバージョンに対応していなければ、対応する最新のバージョンを返す
+                let protocol_version = params
+                    .get("protocolVersion")
+                    .and_then(Value::as_str)
+                    .filter(|version| SUPPORTED_PROTOCOL_VERSIONS.contains(version))
+                    .unwrap_or(PROTOCOL_VERSION);
+                Ok(json!({
+                    "protocolVersion": protocol_version,
+                    "capabilities": { "tools": {} },
+                    "serverInfo": {
+                        "name": SERVER_NAME,
+                        "version": env!("CARGO_PKG_VERSION")
+                    },
+                    "instructions": "WorkNoteのRunbook（障害対応手順書）を検索・参照できます。新しい手順は create_draft で下書きとして登録し、人間のレビュー後に保存されます。"
+                }))
+            }
+            "ping" => Ok(json!({})),
+            "tools/list" => Ok(json!({ "tools": tools::tool_definitions(&self.tools.taxonomy()) })),
+            "tools/call" => {
+                let name = params
+                    .get("name")
+                    .and_then(Value::as_str)
+                    .ok_or_else(|| (INVALID_PARAMS, "Missing tool name".to_string()))?;
+                let arguments = params
+                    .get("arguments")
+                    .cloned()
+                    .unwrap_or_else(|| json!({}));
+
+                // ツールの実行エラーはプロトコルエラーではなく結果として返す
+                let (text, is_error) = match self.tools.call(name, &arguments) {
+                    Ok(text) => (text, false),
+                    Err(e) => (e.to_string(), true),
+                };
+                Ok(json!({
+                    "content": [{ "type": "text", "text": text }],
+                    "isError": is_error
+                }))
+            }
+            other => Err((METHOD_NOT_FOUND, format!("Method not found: {}", other))),
+        }
+    }
+}
+
+fn error_response(id: Value, code: i64, message: &str) -> String {
+    json!({
+        "jsonrpc": "2.0",
+        "id": id,
+        "error": { "code": code, "message": message }
+    })
+    .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Config;
+    use crate::services::{ConfigManager, DraftManager};
+    use std::fs;
+    use std::io::Cursor;
+    use std::path::Path;
+
+    fn setup_server(name: &str) -> (McpServer, PathBuf, PathBuf) {
+        let base = std::env::temp_dir().join(format!("worknote_mcp_{}", name));
+        let _ = fs::remove_dir_all(&base);
+        let data_dir = base.join("data");
+        let repo_dir = base.join("repo");
+        fs::create_dir_all(repo_dir.join("docs/runbooks/alerts")).unwrap();
+        fs::create_dir_all(repo_dir.join(".git")).unwrap();
+
+        let mut config = Config::default();
+        config.git.repository_path = repo_dir.to_string_lossy().to_string();
+        ConfigManager::new(data_dir.clone())
+            .save_config(&config)
+            .unwrap();
+
+        (McpServer::new(data_dir.clone()), data_dir, repo_dir)
+    }
+
+    fn request(server: &McpServer, method: &str, params: Value) -> Value {
+        let message = json!({ "jsonrpc": "2.0", "id": 1, "method": method, "params": params });
+        serde_json::from_str(&server.handle_message(&message.to_string()).unwrap()).unwrap()
+    }
+
+    fn write_runbook(repo_dir: &Path, name: &str, content: &str) {
+        fs::write(repo_dir.join("docs/runbooks/alerts").join(name), content).unwrap();
+    }
+
+    #[test]
+    fn test_initialize_and_tools_list() {
+        let (server, _, _) = setup_server("initialize");
+
+        let response = request(
+            &server,
+            "initialize",
+            json!({ "protocolVersion": "2025-03-26", "capabilities": {} }),
+        );
+        assert_eq!(response["result"]["protocolVersion"], "2025-03-26");
+        assert_eq!(response["result"]["serverInfo"]["name"], SERVER_NAME);
+
+        // 未対応のバージョンはそのまま返さない
+        let response = request(
+            &server,
+            "initialize",
+            json!({ "protocolVersion": "1999-01-01", "capabilities": {} }),
+        );
+        assert_eq!(response["result"]["protocolVersion"], PROTOCOL_VERSION);
+
+        // 通知には応答しない
+        assert!(server
+            .handle_message(r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#)
+            .is_none());
+
+        let response = request(&server, "tools/list", json!({}));
+        let names: Vec<&str> = response["result"]["tools"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|tool| tool["name"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            names,
+            vec![
+                "search_runbooks",
+                "read_runbook",
+                "list_runbooks",
+                "create_draft"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_protocol_errors() {
+        let (server, _, _) = setup_server("errors");
+
+        let response: Value =
+            serde_json::from_str(&server.handle_message("{not json").unwrap()).unwrap();
+        assert_eq!(response["error"]["code"], PARSE_ERROR);
+
+        let response = request(&server, "unknown/method", json!({}));
+        assert_eq!(response["error"]["code"], METHOD_NOT_FOUND);
+
+        let response = request(&server, "tools/call", json!({}));
+        assert_eq!(response["error"]["code"], INVALID_PARAMS);
+    }
+
+    #[test]
+    fn test_search_read_and_list() {
+        let (server, _, repo_dir) = setup_server("search");
+        write_runbook(
+            &repo_dir,
+            "cpu.md",
+            "---\ntitle: CPU高騰対応\nid: rb-0000cafe\nseverity: high\n---\n\n# CPU高騰対応\n\ntopで確認する\n",
+        );
+        write_runbook(
+            &repo_dir,
+            "disk.md",
+            "---\ntitle: ディスク逼迫\nseverity: low\n---\n\n# ディスク逼迫\n",
+        );
+
+        let response = request(
+            &server,
+            "tools/call",
+            json!({ "name": "search_runbooks", "arguments": { "query": "CPU" } }),
+        );
+        assert_eq!(response["result"]["isError"], false);
+        let text = response["result"]["content"][0]["text"].as_str().unwrap();
+        assert!(text.contains("alerts/cpu.md"));
+        assert!(!text.contains("disk.md"));
+
+        let response = request(
+            &server,
+            "tools/call",
+            json!({ "name": "read_runbook", "arguments": { "id": "rb-0000cafe" } }),
+        );
+        let text = response["result"]["content"][0]["text"].as_str().unwrap();
+        assert!(text.contains("topで確認する"));
+
+        let response = request(
+            &server,
+            "tools/call",
+            json!({ "name": "list_runbooks", "arguments": { "severity": "low" } }),
+        );
+        let text = response["result"]["content"][0]["text"].as_str().unwrap();
+        assert!(text.contains("ディスク逼迫"));
+        assert!(!text.contains("CPU高騰対応"));
+
+        // 保存先ディレクトリ外は読めない
+        fs::write(repo_dir.join("secret.md"), "secret").unwrap();
+        let response = request(
+            &server,
+            "tools/call",
+            json!({ "name": "read_runbook", "arguments": { "path": "../../secret.md" } }),
+        );
+        assert_eq!(response["result"]["isError"], true);
+    }
+
+    #[test]
+    fn test_create_draft() {
+        let (server, data_dir, _) = setup_server("create_draft");
+
+        let response = request(
+            &server,
+            "tools/call",
+            json!({
+                "name": "create_draft",
+                "arguments": {
+                    "title": "メモリリーク対応",
+                    "category": "troubleshooting",
+                    "severity": "medium",
+                    "symptoms": "RSSが増え続ける",
+                    "procedure": "1. プロセスを再起動する",
+                    "relatedAlerts": ["HighMemoryUsage"]
+                }
+            }),
+        );
+        assert_eq!(response["result"]["isError"], false);
+        let text = response["result"]["content"][0]["text"].as_str().unwrap();
+        assert!(text.contains("# メモリリーク対応"));
+
+        let drafts = DraftManager::new(data_dir).list_drafts().unwrap();
+        assert_eq!(drafts.len(), 1);
+        assert_eq!(drafts[0].title, "メモリリーク対応");
+
+        // 検証エラーはツールエラーとして返す
+        let response = request(
+            &server,
+            "tools/call",
+            json!({
+                "name": "create_draft",
+                "arguments": {
+                    "title": "",
+                    "category": "alerts",
+                    "severity": "low",
+                    "symptoms": "x",
+                    "procedure": "y"
+                }
+            }),
+        );
+        assert_eq!(response["result"]["isError"], true);
+    }
+
+    #[test]
+    fn test_serve() {
+        let (server, _, _) = setup_server("serve");
+        let input = concat!(
+            r#"{"jsonrpc":"2.0","id":1,"method":"ping"}"#,
+            "\n\n",
+            r#"{"jsonrpc":"2.0","method":"notifications/initialized"}"#,
+            "\n",
+            r#"{"jsonrpc":"2.0","id":2,"method":"ping"}"#,
+            "\n"
+        );
+
+        let mut output = Vec::new();
+        server.serve(Cursor::new(input), &mut output).unwrap();
+
+        let lines: Vec<&str> = std::str::from_utf8(&output).unwrap().lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[1].contains(r#""id":2"#));
+    }
+}
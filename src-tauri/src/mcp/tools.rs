@@ -0,0 +1,219 @@
+use crate::models::{Config, KnowledgeInput, Result, TaxonomyConfig, WorkNoteError};
+use crate::services::{ConfigManager, DraftManager, FileGenerator, RunbookRepository};
+use serde_json::{json, Value};
+use std::fs;
+use std::path::PathBuf;
+
+/// 検索結果のデフォルト件数
+const DEFAULT_SEARCH_LIMIT: usize = 10;
+
+/// ツール定義一覧（`tools/list` の結果）
+///
+/// カテゴリ・重要度の選択肢は設定の定義から生成します。
+pub fn tool_definitions(taxonomy: &TaxonomyConfig) -> Value {
+    let categories: Vec<&str> = taxonomy.categories.iter().map(|c| c.id.as_str()).collect();
+    let severities: Vec<&str> = taxonomy.severities.iter().map(|s| s.id.as_str()).collect();
+
+    json!([
+        {
+            "name": "search_runbooks",
+            "description": "Runbookを全文検索します。空白区切りのすべての語を含むRunbookを一致度順に返します。",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "query": { "type": "string", "description": "検索語（空白区切りでAND検索）" },
+                    "limit": { "type": "integer", "minimum": 1, "description": "最大件数（既定: 10）" }
+                },
+                "required": ["query"]
+            }
+        },
+        {
+            "name": "read_runbook",
+            "description": "Runbookの本文（Markdown）を取得します。pathまたはidのどちらかを指定してください。",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "path": { "type": "string", "description": "保存先ディレクトリからの相対パス（例: alerts/cpu-high.md）" },
+                    "id": { "type": "string", "description": "RunbookID（例: rb-1a2b3c4d）" }
+                }
+            }
+        },
+        {
+            "name": "list_runbooks",
+            "description": "Runbook一覧を取得します。カテゴリや重要度で絞り込めます。",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "category": { "type": "string", "enum": categories },
+                    "severity": { "type": "string", "enum": severities }
+                }
+            }
+        },
+        {
+            "name": "create_draft",
+            "description": "Runbookの下書きを作成します。GUIと同じ検証を行い、人間がレビューしてから保存します。",
+            "inputSchema": {
+                "type": "object",
+                "properties": {
+                    "title": { "type": "string", "maxLength": 100 },
+                    "category": { "type": "string", "enum": categories },
+                    "severity": { "type": "string", "enum": severities },
+                    "symptoms": { "type": "string", "description": "症状・検知条件" },
+                    "procedure": { "type": "string", "description": "対応手順（Markdown）" },
+                    "notes": { "type": "string", "description": "注意点・落とし穴" },
+                    "relatedLinks": { "type": "string", "description": "関連リンク（[[runbook-id]] で他のRunbookを参照）" },
+                    "relatedAlerts": { "type": "array", "items": { "type": "string" }, "description": "関連アラート名" }
+                },
+                "required": ["title", "category", "severity", "symptoms", "procedure"]
+            }
+        }
+    ])
+}
+
+/// ツール実行のコンテキスト
+pub struct ToolContext {
+    app_data_dir: PathBuf,
+}
+
+impl ToolContext {
+    /// 新しいToolContextインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `app_data_dir` - アプリケーションデータディレクトリ（GUIと共通）
+    pub fn new(app_data_dir: PathBuf) -> Self {
+        ToolContext { app_data_dir }
+    }
+
+    /// ツールを実行してテキスト結果を返す
+    pub fn call(&self, name: &str, arguments: &Value) -> Result<String> {
+        match name {
+            "search_runbooks" => self.search_runbooks(arguments),
+            "read_runbook" => self.read_runbook(arguments),
+            "list_runbooks" => self.list_runbooks(arguments),
+            "create_draft" => self.create_draft(arguments),
+            other => Err(WorkNoteError::ValidationError(format!(
+                "Unknown tool: {}",
+                other
+            ))),
+        }
+    }
+
+    /// 設定は呼び出しごとに読み込み、GUIでの変更・リポジトリ共有設定を反映する
+    fn load_config(&self) -> Result<Config> {
+        Ok(ConfigManager::new(self.app_data_dir.clone())
+            .load_effective_config()?
+            .config)
+    }
+
+    /// カテゴリ・重要度の定義（設定が読めない場合は組み込みの定義）
+    pub fn taxonomy(&self) -> TaxonomyConfig {
+        self.load_config()
+            .map(|config| config.taxonomy)
+            .unwrap_or_default()
+    }
+
+    fn save_dir(&self) -> Result<PathBuf> {
+        let config = self.load_config()?;
+        if config.git.repository_path.is_empty() {
+            return Err(WorkNoteError::ConfigError(
+                "Repository path is not configured".to_string(),
+            ));
+        }
+        Ok(PathBuf::from(&config.git.repository_path).join(&config.git.save_path))
+    }
+
+    fn search_runbooks(&self, arguments: &Value) -> Result<String> {
+        let query = Self::required_str(arguments, "query")?;
+        let limit = arguments
+            .get("limit")
+            .and_then(Value::as_u64)
+            .map(|limit| limit as usize)
+            .unwrap_or(DEFAULT_SEARCH_LIMIT);
+
+        let results = RunbookRepository::new(self.save_dir()?).search(query, limit)?;
+        Ok(serde_json::to_string_pretty(&results)?)
+    }
+
+    fn read_runbook(&self, arguments: &Value) -> Result<String> {
+        let save_dir = self.save_dir()?;
+        let repository = RunbookRepository::new(save_dir.clone());
+
+        let relative = match (
+            arguments.get("path").and_then(Value::as_str),
+            arguments.get("id").and_then(Value::as_str),
+        ) {
+            (Some(path), _) => path.to_string(),
+            (None, Some(id)) => {
+                repository
+                    .find_by_id(id)?
+                    .ok_or_else(|| WorkNoteError::FileError(format!("Runbook not found: {}", id)))?
+                    .path
+            }
+            (None, None) => {
+                return Err(WorkNoteError::ValidationError(
+                    "Either path or id is required".to_string(),
+                ))
+            }
+        };
+
+        // 保存先ディレクトリ外のファイルの読み込みを防止
+        let canonical_dir = save_dir.canonicalize().map_err(|e| {
+            WorkNoteError::FileError(format!("Failed to canonicalize save path: {}", e))
+        })?;
+        let canonical_path = save_dir.join(&relative).canonicalize().map_err(|e| {
+            WorkNoteError::FileError(format!("Runbook not found: {} ({})", relative, e))
+        })?;
+        if !canonical_path.starts_with(&canonical_dir) {
+            return Err(WorkNoteError::FileError(
+                "Invalid file path: directory traversal detected".to_string(),
+            ));
+        }
+
+        fs::read_to_string(&canonical_path)
+            .map_err(|e| WorkNoteError::FileError(format!("Failed to read runbook: {}", e)))
+    }
+
+    fn list_runbooks(&self, arguments: &Value) -> Result<String> {
+        // カテゴリIDは保存先ディレクトリ名に変換して比較
+        let taxonomy = self.taxonomy();
+        let directory = arguments.get("category").and_then(Value::as_str).map(|c| {
+            match taxonomy.categories.iter().find(|d| d.id == c) {
+                Some(definition) => definition.directory.clone(),
+                None => c.to_string(),
+            }
+        });
+        let category = directory.as_deref();
+        let severity = arguments.get("severity").and_then(Value::as_str);
+
+        let entries: Vec<_> = RunbookRepository::new(self.save_dir()?)
+            .list_entries()?
+            .into_iter()
+            .filter(|entry| category.is_none_or(|c| entry.category == c))
+            .filter(|entry| severity.is_none_or(|s| entry.severity.as_deref() == Some(s)))
+            .collect();
+        Ok(serde_json::to_string_pretty(&entries)?)
+    }
+
+    fn create_draft(&self, arguments: &Value) -> Result<String> {
+        let input: KnowledgeInput = serde_json::from_value(arguments.clone())
+            .map_err(|e| WorkNoteError::ValidationError(format!("Invalid arguments: {}", e)))?;
+        input.validate()?;
+        self.taxonomy()
+            .validate_input(&input.category, &input.severity)?;
+
+        let draft = DraftManager::new(self.app_data_dir.clone()).create_draft(input)?;
+        Ok(format!(
+            "下書きを作成しました（ID: {}）。WorkNoteで内容を確認して保存してください。\n\n{}",
+            draft.id,
+            FileGenerator::generate_markdown_for_preview(&draft.data)
+        ))
+    }
+
+    fn required_str<'a>(arguments: &'a Value, key: &str) -> Result<&'a str> {
+        arguments
+            .get(key)
+            .and_then(Value::as_str)
+            .filter(|value| !value.trim().is_empty())
+            .ok_or_else(|| WorkNoteError::ValidationError(format!("{} is required", key)))
+    }
+}
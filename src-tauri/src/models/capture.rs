@@ -0,0 +1,66 @@
+use crate::models::{Category, Severity};
+use serde::{Deserialize, Serialize};
+
+/// 取り込み元の種類
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum CaptureSource {
+    /// Slackのスレッドエクスポート（メッセージ配列のJSON）
+    Slack,
+    /// asciinemaの録画ファイル（.cast）
+    Asciinema,
+    /// `script` コマンドの記録（typescript）
+    Script,
+    /// シェル履歴（bash/zsh/fish、`history` の出力）
+    History,
+}
+
+impl CaptureSource {
+    /// 文字列表現を取得
+    pub fn as_str(&self) -> &str {
+        match self {
+            CaptureSource::Slack => "slack",
+            CaptureSource::Asciinema => "asciinema",
+            CaptureSource::Script => "script",
+            CaptureSource::History => "history",
+        }
+    }
+
+    /// 表示名を取得
+    pub fn label(&self) -> &str {
+        match self {
+            CaptureSource::Slack => "Slackスレッド",
+            CaptureSource::Asciinema => "asciinema録画",
+            CaptureSource::Script => "scriptセッション",
+            CaptureSource::History => "シェル履歴",
+        }
+    }
+}
+
+/// 取り込みのオプション
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CaptureOptions {
+    pub source: CaptureSource,
+    /// タイトル（未指定時は内容から生成）
+    #[serde(default)]
+    pub title: Option<String>,
+    /// カテゴリ（未指定時は troubleshooting）
+    #[serde(default)]
+    pub category: Option<Category>,
+    /// 重要度（未指定時は medium）
+    #[serde(default)]
+    pub severity: Option<Severity>,
+}
+
+impl CaptureOptions {
+    /// 取り込み元のみを指定したオプションを作成
+    pub fn new(source: CaptureSource) -> Self {
+        CaptureOptions {
+            source,
+            title: None,
+            category: None,
+            severity: None,
+        }
+    }
+}
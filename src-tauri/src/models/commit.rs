@@ -0,0 +1,99 @@
+use crate::models::error::{Result, WorkNoteError};
+use serde::{Deserialize, Serialize};
+
+/// コミットメッセージの末尾に追加するtrailer
+///
+/// 保存ごとに指定します（設定には保存しません）。
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CommitTrailers {
+    /// 課題キー（例: `OPS-123`。`Issue:` trailerとして追加）
+    #[serde(default)]
+    pub issue_key: Option<String>,
+    /// 共同作成者（`Name <email>` 形式。`Co-authored-by:` trailerとして追加）
+    #[serde(default)]
+    pub co_authors: Vec<String>,
+}
+
+impl CommitTrailers {
+    /// 指定内容を検証（課題キーは空白を含まない、共同作成者は `Name <email>` 形式）
+    pub fn validate(&self) -> Result<()> {
+        if let Some(issue_key) = &self.issue_key {
+            let issue_key = issue_key.trim();
+            if issue_key.is_empty() || issue_key.contains(char::is_whitespace) {
+                return Err(WorkNoteError::ValidationError(format!(
+                    "Invalid issue key: {:?}",
+                    issue_key
+                )));
+            }
+        }
+
+        for co_author in &self.co_authors {
+            let valid = co_author
+                .trim()
+                .strip_suffix('>')
+                .and_then(|s| s.rsplit_once(" <"))
+                .is_some_and(|(name, email)| {
+                    !name.trim().is_empty()
+                        && !name.contains(['<', '>', '\n'])
+                        && email.contains('@')
+                        && !email.contains(|c: char| c.is_whitespace() || c == '<' || c == '>')
+                });
+            if !valid {
+                return Err(WorkNoteError::ValidationError(format!(
+                    "Co-author must be in \"Name <email>\" format: {:?}",
+                    co_author
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// trailerの行（`Issue: OPS-123`・`Co-authored-by: Name <email>`）
+    pub fn lines(&self) -> Vec<String> {
+        self.issue_key
+            .iter()
+            .map(|key| format!("Issue: {}", key.trim()))
+            .chain(
+                self.co_authors
+                    .iter()
+                    .map(|co_author| format!("Co-authored-by: {}", co_author.trim())),
+            )
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_trailers() {
+        let trailers = CommitTrailers {
+            issue_key: Some(" OPS-123 ".to_string()),
+            co_authors: vec!["Alice Example <alice@example.com>".to_string()],
+        };
+        assert!(trailers.validate().is_ok());
+        assert_eq!(
+            trailers.lines(),
+            [
+                "Issue: OPS-123",
+                "Co-authored-by: Alice Example <alice@example.com>"
+            ]
+        );
+
+        for co_author in ["alice@example.com", "<alice@example.com>", "Alice <alice>"] {
+            let trailers = CommitTrailers {
+                co_authors: vec![co_author.to_string()],
+                ..Default::default()
+            };
+            assert!(trailers.validate().is_err(), "{}", co_author);
+        }
+        let trailers = CommitTrailers {
+            issue_key: Some("OPS 123".to_string()),
+            ..Default::default()
+        };
+        assert!(trailers.validate().is_err());
+    }
+}
@@ -8,26 +8,41 @@ use serde::{Deserialize, Serialize};
 pub struct Draft {
     pub id: String,
     pub data: KnowledgeInput,
+    /// 保存先プロファイル（未設定時は使用中のプロファイル）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+    /// Webhookで作成した下書きのアラートのfingerprint（同じアラートの重複作成を防ぐ）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub alert_fingerprint: Option<String>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
 
 impl Draft {
     /// 新しいDraftを作成
-    pub fn new(data: KnowledgeInput) -> Self {
+    pub fn new(mut data: KnowledgeInput) -> Self {
         let now = Utc::now();
+        // RunbookIDは下書き作成時点で採番し、保存後も同じIDを使う
+        data.ensure_id();
         let id = uuid::Uuid::new_v4().to_string();
 
         Self {
             id,
             data,
+            profile: None,
+            alert_fingerprint: None,
             created_at: now,
             updated_at: now,
         }
     }
 
     /// Draftを更新
-    pub fn update(&mut self, data: KnowledgeInput) {
+    pub fn update(&mut self, mut data: KnowledgeInput) {
+        // フロントエンドがIDを送らない場合も採番済みのIDを維持
+        if data.id.is_none() {
+            data.id = self.data.id.take();
+        }
+        data.ensure_id();
         self.data = data;
         self.updated_at = Utc::now();
     }
@@ -40,6 +55,8 @@ pub struct DraftSummary {
     pub id: String,
     pub title: String,
     pub category: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
     pub updated_at: DateTime<Utc>,
 }
 
@@ -49,6 +66,7 @@ impl From<&Draft> for DraftSummary {
             id: draft.id.clone(),
             title: draft.data.title.clone(),
             category: draft.data.category.as_str().to_string(),
+            profile: draft.profile.clone(),
             updated_at: draft.updated_at,
         }
     }
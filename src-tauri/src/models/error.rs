@@ -28,6 +28,10 @@ pub enum WorkNoteError {
     #[error("Authentication error: {0}")]
     AuthError(String),
 
+    /// コミット署名エラー
+    #[error("Signing error: {0}")]
+    SigningError(String),
+
     /// ショートカットエラー
     #[error("Shortcut error: {0}")]
     ShortcutError(String),
@@ -67,6 +71,7 @@ impl From<WorkNoteError> for ErrorInfo {
             WorkNoteError::ConfigError(_) => "ConfigError",
             WorkNoteError::NetworkError(_) => "NetworkError",
             WorkNoteError::AuthError(_) => "AuthError",
+            WorkNoteError::SigningError(_) => "SigningError",
             WorkNoteError::ShortcutError(_) => "ShortcutError",
             WorkNoteError::WindowNotFoundError(_) => "WindowNotFoundError",
             WorkNoteError::ProofreadError(_) => "ProofreadError",
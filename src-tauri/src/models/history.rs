@@ -0,0 +1,95 @@
+use chrono::{DateTime, FixedOffset};
+use serde::{Deserialize, Serialize};
+
+/// Runbookのコミット履歴（新しい順の1件）
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RunbookCommit {
+    pub hash: String,
+    pub short_hash: String,
+    pub author_name: String,
+    pub author_email: String,
+    /// Authorの日時（コミットしたタイムゾーン）
+    pub date: DateTime<FixedOffset>,
+    /// コミットメッセージの1行目
+    pub subject: String,
+    /// このコミット時点のRunbookのパス（保存先ディレクトリからの相対パス。移動・リネームを追跡）
+    pub path: String,
+}
+
+/// 差分の行の種類
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum DiffLineKind {
+    Context,
+    Added,
+    Removed,
+}
+
+/// 差分の1行
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffLine {
+    pub kind: DiffLineKind,
+    pub content: String,
+    /// 変更前の行番号（追加行は `None`）
+    pub old_line: Option<usize>,
+    /// 変更後の行番号（削除行は `None`）
+    pub new_line: Option<usize>,
+}
+
+/// 差分のハンク（`@@ -a,b +c,d @@` 単位）
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct DiffHunk {
+    pub old_start: usize,
+    pub old_lines: usize,
+    pub new_start: usize,
+    pub new_lines: usize,
+    /// `@@` の後に続く見出し（Markdownでは直前の見出し行）
+    pub section: String,
+    pub lines: Vec<DiffLine>,
+}
+
+/// Runbookの2つのリビジョン間の差分
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RunbookDiff {
+    /// 変更前のコミット（作成前からの差分の場合は `None`）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from_revision: Option<String>,
+    pub to_revision: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old_path: Option<String>,
+    pub new_path: String,
+    /// 変更前の内容（DiffViewerの `original`）
+    pub original: String,
+    /// 変更後の内容（DiffViewerの `modified`）
+    pub modified: String,
+    pub hunks: Vec<DiffHunk>,
+    pub additions: usize,
+    pub deletions: usize,
+}
+
+/// 指定したリビジョンのRunbookの内容
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RunbookRevision {
+    /// コミットハッシュ（指定したリビジョンを解決したもの）
+    pub revision: String,
+    /// このリビジョン時点のパス（保存先ディレクトリからの相対パス）
+    pub path: String,
+    pub content: String,
+}
+
+/// blameの1行（各行を最後に変更したコミット）
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct BlameLine {
+    pub line_number: usize,
+    pub hash: String,
+    pub author_name: String,
+    pub date: DateTime<FixedOffset>,
+    pub subject: String,
+    pub content: String,
+}
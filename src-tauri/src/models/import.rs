@@ -0,0 +1,44 @@
+use crate::models::{Category, Severity};
+use serde::{Deserialize, Serialize};
+
+/// インポートのオプション
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportOptions {
+    /// 全ドキュメントに適用するカテゴリ（未指定時はタイトル・パスから推定）
+    #[serde(default)]
+    pub category: Option<Category>,
+    /// 全ドキュメントに適用する重要度（未指定時は本文から推定）
+    #[serde(default)]
+    pub severity: Option<Severity>,
+}
+
+/// 下書きとして取り込んだドキュメント
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportedDocument {
+    /// 取り込み元のパス（ZIP内は `archive.zip!/path/page.html`）
+    pub source: String,
+    pub draft_id: String,
+    pub title: String,
+    /// どの項目にも対応付けられず「注意点・落とし穴」に退避した見出し
+    pub unmapped_sections: Vec<String>,
+    /// 要確認事項（必須項目の欠落、取り込まれない画像など）
+    pub warnings: Vec<String>,
+}
+
+/// 取り込まなかったファイル
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SkippedDocument {
+    pub source: String,
+    pub reason: String,
+}
+
+/// インポート結果
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ImportReport {
+    pub imported: Vec<ImportedDocument>,
+    pub skipped: Vec<SkippedDocument>,
+}
@@ -1,14 +1,36 @@
+use crate::models::error::{Result, WorkNoteError};
+use crate::models::taxonomy::{is_valid_taxonomy_id, LEGACY_CATEGORY_ALIASES};
 use serde::{Deserialize, Serialize};
 
+/// 各項目の最大文字数（フロントエンドの validation.ts と同じ値）
+const TITLE_MAX_LENGTH: usize = 100;
+const SYMPTOMS_MAX_LENGTH: usize = 5000;
+const PROCEDURE_MAX_LENGTH: usize = 10000;
+const NOTES_MAX_LENGTH: usize = 5000;
+const LINKS_MAX_LENGTH: usize = 5000;
+
+/// 関連アラート1件あたりの最大文字数
+const ALERT_NAME_MAX_LENGTH: usize = 200;
+
+/// 関連アラートの最大件数
+const MAX_RELATED_ALERTS: usize = 50;
+
+/// 設定（`requiredFields`）で入力必須にできる任意項目
+pub const OPTIONAL_FIELDS: [&str; 5] =
+    ["notes", "relatedLinks", "relatedAlerts", "judgment", "slug"];
+
 /// カテゴリ種別
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
-#[serde(rename_all = "lowercase")]
+///
+/// 組み込みのカテゴリに加え、設定（`taxonomy.categories`）で定義したカテゴリを `Custom` で表します。
+/// 廃止済みの `maintenance` は読み込み時に `ops` へ置き換えます。
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(try_from = "String", into = "String")]
 pub enum Category {
     Alerts,
-    Maintenance, // 後方互換性のため残す（新規はOpsを使用）
     Ops,
     Troubleshooting,
     Inquiry,
+    Custom(String),
 }
 
 impl Category {
@@ -16,22 +38,59 @@ impl Category {
     pub fn as_str(&self) -> &str {
         match self {
             Category::Alerts => "alerts",
-            Category::Maintenance => "maintenance",
             Category::Ops => "ops",
             Category::Troubleshooting => "troubleshooting",
             Category::Inquiry => "inquiry",
+            Category::Custom(id) => id,
+        }
+    }
+}
+
+impl TryFrom<String> for Category {
+    type Error = String;
+
+    fn try_from(value: String) -> std::result::Result<Self, Self::Error> {
+        let id = value.trim().to_lowercase();
+        let id = LEGACY_CATEGORY_ALIASES
+            .iter()
+            .find(|(legacy, _)| *legacy == id)
+            .map_or(id.as_str(), |(_, current)| current);
+
+        match id {
+            "alerts" => Ok(Category::Alerts),
+            "ops" => Ok(Category::Ops),
+            "troubleshooting" => Ok(Category::Troubleshooting),
+            "inquiry" => Ok(Category::Inquiry),
+            id if is_valid_taxonomy_id(id) => Ok(Category::Custom(id.to_string())),
+            _ => Err(format!("invalid category: {:?}", value)),
         }
     }
 }
 
+impl From<Category> for String {
+    fn from(category: Category) -> Self {
+        category.as_str().to_string()
+    }
+}
+
+impl std::fmt::Display for Category {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// 重要度
-#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
-#[serde(rename_all = "lowercase")]
+///
+/// 組み込みの4段階に加え、設定（`taxonomy.severities`）で定義した重要度を `Custom` で表します。
+/// 定義順による比較は `TaxonomyConfig::severity_rank` を使用します。
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(try_from = "String", into = "String")]
 pub enum Severity {
     Low,
     Medium,
     High,
     Critical,
+    Custom(String),
 }
 
 impl Severity {
@@ -42,14 +101,45 @@ impl Severity {
             Severity::Medium => "medium",
             Severity::High => "high",
             Severity::Critical => "critical",
+            Severity::Custom(id) => id,
         }
     }
 }
 
+impl TryFrom<String> for Severity {
+    type Error = String;
+
+    fn try_from(value: String) -> std::result::Result<Self, Self::Error> {
+        match value.trim().to_lowercase().as_str() {
+            "low" => Ok(Severity::Low),
+            "medium" => Ok(Severity::Medium),
+            "high" => Ok(Severity::High),
+            "critical" => Ok(Severity::Critical),
+            id if is_valid_taxonomy_id(id) => Ok(Severity::Custom(id.to_string())),
+            _ => Err(format!("invalid severity: {:?}", value)),
+        }
+    }
+}
+
+impl From<Severity> for String {
+    fn from(severity: Severity) -> Self {
+        severity.as_str().to_string()
+    }
+}
+
+impl std::fmt::Display for Severity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 /// ナレッジ入力データ
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct KnowledgeInput {
+    /// RunbookID（フロントマターの `id`。下書き作成時または初回保存時に採番）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
     pub title: String,
     pub category: Category,
     pub severity: Severity,
@@ -62,6 +152,168 @@ pub struct KnowledgeInput {
     /// カテゴリ別判断軸（JSON文字列）
     #[serde(skip_serializing_if = "Option::is_none")]
     pub judgment: Option<String>,
+    /// ファイル名に使用するslug（ユーザー指定）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    /// 関連アラート（Prometheusのアラート名、DatadogのモニターID、PagerDutyのサービス等）
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related_alerts: Vec<String>,
+}
+
+impl KnowledgeInput {
+    /// RunbookIDが未採番であれば採番して返す
+    pub fn ensure_id(&mut self) -> &str {
+        self.id.get_or_insert_with(generate_runbook_id)
+    }
+
+    /// 入力内容を検証
+    ///
+    /// 必須項目と文字数の上限を検証します（フロントエンドの validation.ts と同じ規則）。
+    pub fn validate(&self) -> Result<()> {
+        let required = [
+            ("title", &self.title, TITLE_MAX_LENGTH),
+            ("symptoms", &self.symptoms, SYMPTOMS_MAX_LENGTH),
+            ("procedure", &self.procedure, PROCEDURE_MAX_LENGTH),
+        ];
+        for (field, value, max) in required {
+            if value.trim().is_empty() {
+                return Err(WorkNoteError::ValidationError(format!(
+                    "{} is required",
+                    field
+                )));
+            }
+            Self::validate_length(field, value, max)?;
+        }
+
+        if let Some(notes) = &self.notes {
+            Self::validate_length("notes", notes, NOTES_MAX_LENGTH)?;
+        }
+        if let Some(links) = &self.related_links {
+            Self::validate_length("relatedLinks", links, LINKS_MAX_LENGTH)?;
+        }
+        validate_runbook_id(self.id.as_deref())?;
+
+        self.validate_related_alerts()
+    }
+
+    fn validate_length(field: &str, value: &str, max: usize) -> Result<()> {
+        if value.chars().count() > max {
+            return Err(WorkNoteError::ValidationError(format!(
+                "{} must be at most {} characters",
+                field, max
+            )));
+        }
+        Ok(())
+    }
+
+    /// 関連アラートを正規化して取得
+    ///
+    /// 前後の空白を除去し、空要素と重複（大文字小文字を区別しない）を除外します。
+    pub fn normalized_related_alerts(&self) -> Vec<String> {
+        let mut alerts: Vec<String> = Vec::new();
+        for alert in &self.related_alerts {
+            let alert = alert.trim();
+            if !alert.is_empty() && !alerts.iter().any(|a| a.eq_ignore_ascii_case(alert)) {
+                alerts.push(alert.to_string());
+            }
+        }
+        alerts
+    }
+
+    /// 関連アラートを検証
+    pub fn validate_related_alerts(&self) -> Result<()> {
+        let alerts = self.normalized_related_alerts();
+        if alerts.len() > MAX_RELATED_ALERTS {
+            return Err(WorkNoteError::ValidationError(format!(
+                "Too many related alerts: {} (max {})",
+                alerts.len(),
+                MAX_RELATED_ALERTS
+            )));
+        }
+
+        for alert in &alerts {
+            if alert.chars().count() > ALERT_NAME_MAX_LENGTH {
+                return Err(WorkNoteError::ValidationError(format!(
+                    "Related alert is too long (max {} characters): {}",
+                    ALERT_NAME_MAX_LENGTH, alert
+                )));
+            }
+            if alert.chars().any(|c| c.is_control() || c == '"') {
+                return Err(WorkNoteError::ValidationError(format!(
+                    "Related alert contains invalid characters: {:?}",
+                    alert
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 設定で入力必須とした任意項目が入力されているかを検証
+    ///
+    /// # Arguments
+    /// * `fields` - 入力必須の項目名（`OPTIONAL_FIELDS` のいずれか）
+    pub fn validate_required_fields(&self, fields: &[String]) -> Result<()> {
+        let filled =
+            |value: &Option<String>| value.as_deref().is_some_and(|v| !v.trim().is_empty());
+        for field in fields {
+            let present = match field.as_str() {
+                "notes" => filled(&self.notes),
+                "relatedLinks" => filled(&self.related_links),
+                "relatedAlerts" => !self.normalized_related_alerts().is_empty(),
+                // 判断軸は全項目が空のJSON（`{}` など）を未入力とみなす
+                "judgment" => self
+                    .judgment
+                    .as_deref()
+                    .and_then(|j| serde_json::from_str::<serde_json::Value>(j).ok())
+                    .and_then(|v| v.as_object().cloned())
+                    .is_some_and(|o| {
+                        o.values()
+                            .any(|v| v.as_str().is_some_and(|s| !s.trim().is_empty()))
+                    }),
+                "slug" => filled(&self.slug),
+                other => {
+                    return Err(WorkNoteError::ConfigError(format!(
+                        "Unknown required field: {}",
+                        other
+                    )))
+                }
+            };
+            if !present {
+                return Err(WorkNoteError::ValidationError(format!(
+                    "{} is required",
+                    field
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// 新しいRunbookIDを生成（例: `rb-1a2b3c4d`）
+pub fn generate_runbook_id() -> String {
+    let uuid = uuid::Uuid::new_v4().simple().to_string();
+    format!("rb-{}", &uuid[..8])
+}
+
+/// RunbookIDとして有効な文字列かを判定
+pub fn is_valid_runbook_id(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= 64
+        && id
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+}
+
+/// RunbookIDを検証（フロントマターにそのまま書き込むため、使える文字を制限する）
+pub fn validate_runbook_id(id: Option<&str>) -> Result<()> {
+    match id {
+        Some(id) if !is_valid_runbook_id(id) => Err(WorkNoteError::ValidationError(format!(
+            "Invalid runbook ID: {:?} (use up to 64 letters, digits, '-' or '_')",
+            id
+        ))),
+        _ => Ok(()),
+    }
 }
 
 #[cfg(test)]
@@ -71,10 +323,34 @@ mod tests {
     #[test]
     fn test_category_as_str() {
         assert_eq!(Category::Alerts.as_str(), "alerts");
-        assert_eq!(Category::Maintenance.as_str(), "maintenance");
         assert_eq!(Category::Ops.as_str(), "ops");
         assert_eq!(Category::Troubleshooting.as_str(), "troubleshooting");
         assert_eq!(Category::Inquiry.as_str(), "inquiry");
+        assert_eq!(
+            Category::Custom("security".to_string()).as_str(),
+            "security"
+        );
+    }
+
+    #[test]
+    fn test_category_deserialization() {
+        let parse = |s: &str| serde_json::from_value::<Category>(serde_json::json!(s));
+        assert_eq!(parse("alerts").unwrap(), Category::Alerts);
+        // 廃止済みの maintenance は ops として読み込む
+        assert_eq!(parse("maintenance").unwrap(), Category::Ops);
+        assert_eq!(
+            parse("security").unwrap(),
+            Category::Custom("security".to_string())
+        );
+        assert!(parse("../etc").is_err());
+        assert_eq!(
+            serde_json::to_string(&Category::Custom("release".to_string())).unwrap(),
+            "\"release\""
+        );
+        assert_eq!(
+            serde_json::from_value::<Severity>(serde_json::json!("sev1")).unwrap(),
+            Severity::Custom("sev1".to_string())
+        );
     }
 
     #[test]
@@ -87,14 +363,25 @@ mod tests {
 
     #[test]
     fn test_severity_ordering() {
-        assert!(Severity::Low < Severity::Medium);
-        assert!(Severity::Medium < Severity::High);
-        assert!(Severity::High < Severity::Critical);
+        // 重要度の順序は定義順（カスタム重要度も定義した位置で比較する）
+        let mut taxonomy = crate::models::TaxonomyConfig::default();
+        let rank = |taxonomy: &crate::models::TaxonomyConfig, s: &str| {
+            taxonomy.severity_rank(&Severity::try_from(s.to_string()).unwrap())
+        };
+        assert!(rank(&taxonomy, "low") < rank(&taxonomy, "medium"));
+        assert!(rank(&taxonomy, "medium") < rank(&taxonomy, "high"));
+        assert!(rank(&taxonomy, "high") < rank(&taxonomy, "critical"));
+
+        let mut sev1 = taxonomy.severities[3].clone();
+        sev1.id = "sev1".to_string();
+        taxonomy.severities.insert(2, sev1);
+        assert!(rank(&taxonomy, "sev1") < rank(&taxonomy, "critical"));
     }
 
     #[test]
     fn test_knowledge_input_serialization() {
         let input = KnowledgeInput {
+            id: None,
             title: "Test".to_string(),
             category: Category::Alerts,
             severity: Severity::High,
@@ -103,11 +390,123 @@ mod tests {
             notes: Some("Notes".to_string()),
             related_links: None,
             judgment: None,
+            slug: None,
+            related_alerts: Vec::new(),
         };
 
         let json = serde_json::to_string(&input).unwrap();
         assert!(json.contains("\"title\":\"Test\""));
         assert!(json.contains("\"category\":\"alerts\""));
         assert!(json.contains("\"severity\":\"high\""));
+        assert!(!json.contains("\"id\""));
+    }
+
+    #[test]
+    fn test_ensure_id() {
+        let mut input: KnowledgeInput = serde_json::from_str(
+            r#"{"title":"T","category":"ops","severity":"low","symptoms":"","procedure":""}"#,
+        )
+        .unwrap();
+        assert!(input.id.is_none());
+
+        let id = input.ensure_id().to_string();
+        assert!(id.starts_with("rb-"));
+        assert!(is_valid_runbook_id(&id));
+
+        // 採番済みのIDは変わらない
+        assert_eq!(input.ensure_id(), id);
+    }
+
+    #[test]
+    fn test_validate() {
+        let mut input: KnowledgeInput = serde_json::from_str(
+            r#"{"title":"T","category":"ops","severity":"low","symptoms":"S","procedure":"P"}"#,
+        )
+        .unwrap();
+        assert!(input.validate().is_ok());
+
+        input.title = "あ".repeat(TITLE_MAX_LENGTH);
+        assert!(input.validate().is_ok());
+        input.title = "あ".repeat(TITLE_MAX_LENGTH + 1);
+        assert!(input.validate().is_err());
+
+        input.title = "T".to_string();
+        input.procedure = "  ".to_string();
+        assert!(input.validate().is_err());
+
+        input.procedure = "P".to_string();
+        input.notes = Some("n".repeat(NOTES_MAX_LENGTH + 1));
+        assert!(input.validate().is_err());
+
+        // IDはフロントマターに書き込むため、改行等を含むものは拒否する
+        input.notes = None;
+        input.id = Some("rb-1\nseverity: low".to_string());
+        assert!(input.validate().is_err());
+        input.id = Some("rb-0000abcd".to_string());
+        assert!(input.validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_required_fields() {
+        let mut input: KnowledgeInput = serde_json::from_str(
+            r#"{"title":"T","category":"ops","severity":"low","symptoms":"S","procedure":"P",
+                "notes":"  ","judgment":"{\"timing\":\"\"}"}"#,
+        )
+        .unwrap();
+        assert!(input.validate_required_fields(&[]).is_ok());
+
+        let required = |fields: &[&str]| fields.iter().map(|f| f.to_string()).collect::<Vec<_>>();
+        assert!(input
+            .validate_required_fields(&required(&["notes"]))
+            .is_err());
+        assert!(input
+            .validate_required_fields(&required(&["judgment"]))
+            .is_err());
+
+        input.notes = Some("再起動前に通知".to_string());
+        input.judgment = Some(r#"{"timing":"深夜"}"#.to_string());
+        assert!(input
+            .validate_required_fields(&required(&["notes", "judgment"]))
+            .is_ok());
+        assert!(input
+            .validate_required_fields(&required(&["relatedAlerts"]))
+            .is_err());
+        assert!(input
+            .validate_required_fields(&required(&["owner"]))
+            .is_err());
+    }
+
+    #[test]
+    fn test_related_alerts() {
+        let mut input: KnowledgeInput = serde_json::from_str(
+            r#"{"title":"T","category":"alerts","severity":"high","symptoms":"","procedure":"",
+                "relatedAlerts":[" HighCPUUsage ","highcpuusage","","datadog:12345","PD-SERVICE-API"]}"#,
+        )
+        .unwrap();
+        assert_eq!(
+            input.normalized_related_alerts(),
+            vec!["HighCPUUsage", "datadog:12345", "PD-SERVICE-API"]
+        );
+        assert!(input.validate_related_alerts().is_ok());
+
+        input.related_alerts = vec!["bad\nalert".to_string()];
+        assert!(input.validate_related_alerts().is_err());
+
+        input.related_alerts = vec!["a".repeat(ALERT_NAME_MAX_LENGTH + 1)];
+        assert!(input.validate_related_alerts().is_err());
+
+        input.related_alerts = (0..=MAX_RELATED_ALERTS)
+            .map(|i| format!("alert{}", i))
+            .collect();
+        assert!(input.validate_related_alerts().is_err());
+    }
+
+    #[test]
+    fn test_is_valid_runbook_id() {
+        assert!(is_valid_runbook_id("rb-1a2b3c4d"));
+        assert!(is_valid_runbook_id("db_failover"));
+        assert!(!is_valid_runbook_id(""));
+        assert!(!is_valid_runbook_id("../etc"));
+        assert!(!is_valid_runbook_id("with space"));
     }
 }
@@ -1,14 +1,49 @@
+pub mod capture;
+pub mod commit;
 pub mod config;
 pub mod draft;
 pub mod error;
+pub mod history;
+pub mod import;
 pub mod knowledge;
+pub mod postmortem;
 pub mod proofreader;
+pub mod repo_config;
 pub mod response;
+pub mod runbook;
+pub mod taxonomy;
+pub mod timeline;
+pub mod validation;
+pub mod webhook;
 
 // Re-export commonly used types
-pub use config::{AuthorConfig, CommitMode, Config, GitConfig, PreferencesConfig, ProofreadConfig, ShortcutsConfig};
+pub use capture::{CaptureOptions, CaptureSource};
+pub use commit::CommitTrailers;
+pub use config::{
+    AuthorConfig, CommitMode, Config, GitConfig, GitHosting, NamingConfig, NamingScheme,
+    PreferencesConfig, Profile, ProfileSummary, ProofreadConfig, ProofreadPreset, ShortcutAction,
+    ShortcutsConfig, SigningConfig, SigningMode, WebhookConfig,
+};
 pub use draft::{Draft, DraftSummary};
 pub use error::{ErrorInfo, Result, WorkNoteError};
+pub use history::{
+    BlameLine, DiffHunk, DiffLine, DiffLineKind, RunbookCommit, RunbookDiff, RunbookRevision,
+};
+pub use import::{ImportOptions, ImportReport, ImportedDocument, SkippedDocument};
 pub use knowledge::{Category, KnowledgeInput, Severity};
+pub use postmortem::{ActionItem, ActionItemStatus, PostmortemInput, PostmortemTimelineEntry};
 pub use proofreader::{ProofreadRequest, ProofreadResponse};
-pub use response::SaveKnowledgeResponse;
+pub use repo_config::{EffectiveConfig, RepoConfig, RepoProofreadConfig};
+pub use response::{
+    PublishDraftsResponse, SaveConfigResponse, SaveKnowledgeResponse, SetActiveProfileResponse,
+};
+pub use runbook::{RunbookEntry, SearchResult};
+pub use taxonomy::{
+    CategoryDefinition, CategoryMigrationReport, JudgmentField, MigratedFile, SeverityDefinition,
+    SkippedMigration, TaxonomyConfig,
+};
+pub use timeline::{
+    FinalizeTimelineOptions, TimelineNote, TimelineSession, TimelineSummary, TimelineTag,
+};
+pub use validation::{IssueLevel, ValidationIssue, ValidationReport};
+pub use webhook::{AlertSource, IncomingAlert, WebhookDraftEvent};
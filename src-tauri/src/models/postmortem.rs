@@ -0,0 +1,244 @@
+use crate::models::error::{Result, WorkNoteError};
+use crate::models::knowledge::{is_valid_runbook_id, validate_runbook_id, Severity};
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+/// 各項目の最大文字数
+const TITLE_MAX_LENGTH: usize = 100;
+const TEXT_MAX_LENGTH: usize = 10000;
+
+/// なぜなぜ分析の最大段数
+const MAX_WHYS: usize = 5;
+
+/// 日付の形式（`YYYY-MM-DD`）
+const DATE_FORMAT: &str = "%Y-%m-%d";
+
+/// タイムラインの1行
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct PostmortemTimelineEntry {
+    /// 時刻（`10:05`、`2024-04-01 10:05` など自由形式）
+    pub time: String,
+    pub description: String,
+}
+
+/// アクションアイテムの状態
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+pub enum ActionItemStatus {
+    #[default]
+    Open,
+    InProgress,
+    Done,
+}
+
+impl ActionItemStatus {
+    /// 表示名を取得
+    pub fn label(&self) -> &str {
+        match self {
+            ActionItemStatus::Open => "未着手",
+            ActionItemStatus::InProgress => "対応中",
+            ActionItemStatus::Done => "完了",
+        }
+    }
+}
+
+/// 再発防止のアクションアイテム
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ActionItem {
+    pub description: String,
+    /// 担当者
+    pub owner: String,
+    /// 期限（`YYYY-MM-DD`）
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub due_date: Option<String>,
+    #[serde(default)]
+    pub status: ActionItemStatus,
+}
+
+/// ポストモーテム入力データ
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PostmortemInput {
+    /// ポストモーテムID（フロントマターの `id`。初回保存時に採番）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    pub title: String,
+    /// 発生日（`YYYY-MM-DD`）
+    pub incident_date: String,
+    pub severity: Severity,
+    /// 概要
+    pub summary: String,
+    /// 影響（影響範囲・期間・ユーザー数など）
+    pub impact: String,
+    #[serde(default)]
+    pub timeline: Vec<PostmortemTimelineEntry>,
+    pub root_cause: String,
+    /// なぜなぜ分析（1段目から順に）
+    #[serde(default)]
+    pub five_whys: Vec<String>,
+    #[serde(default)]
+    pub action_items: Vec<ActionItem>,
+    /// 教訓（うまくいったこと・改善点）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lessons_learned: Option<String>,
+    /// 関連RunbookのID
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub related_runbooks: Vec<String>,
+}
+
+impl PostmortemInput {
+    /// ポストモーテムIDが未採番であれば採番して返す
+    pub fn ensure_id(&mut self) -> &str {
+        self.id.get_or_insert_with(generate_postmortem_id)
+    }
+
+    /// 発生日を取得
+    pub fn incident_date(&self) -> Result<NaiveDate> {
+        parse_date("incidentDate", &self.incident_date)
+    }
+
+    /// 入力内容を検証
+    pub fn validate(&self) -> Result<()> {
+        let required = [
+            ("title", &self.title, TITLE_MAX_LENGTH),
+            ("summary", &self.summary, TEXT_MAX_LENGTH),
+            ("impact", &self.impact, TEXT_MAX_LENGTH),
+            ("rootCause", &self.root_cause, TEXT_MAX_LENGTH),
+        ];
+        for (field, value, max) in required {
+            if value.trim().is_empty() {
+                return Err(WorkNoteError::ValidationError(format!(
+                    "{} is required",
+                    field
+                )));
+            }
+            if value.chars().count() > max {
+                return Err(WorkNoteError::ValidationError(format!(
+                    "{} must be at most {} characters",
+                    field, max
+                )));
+            }
+        }
+        self.incident_date()?;
+        validate_runbook_id(self.id.as_deref())?;
+
+        if self.five_whys.len() > MAX_WHYS {
+            return Err(WorkNoteError::ValidationError(format!(
+                "fiveWhys must have at most {} entries",
+                MAX_WHYS
+            )));
+        }
+
+        for entry in &self.timeline {
+            if entry.time.trim().is_empty() || entry.description.trim().is_empty() {
+                return Err(WorkNoteError::ValidationError(
+                    "Timeline entries require both time and description".to_string(),
+                ));
+            }
+        }
+
+        for item in &self.action_items {
+            if item.description.trim().is_empty() {
+                return Err(WorkNoteError::ValidationError(
+                    "Action item description is required".to_string(),
+                ));
+            }
+            if item.owner.trim().is_empty() {
+                return Err(WorkNoteError::ValidationError(format!(
+                    "Action item owner is required: {}",
+                    item.description
+                )));
+            }
+            if let Some(due_date) = &item.due_date {
+                parse_date("dueDate", due_date)?;
+            }
+        }
+
+        if let Some(id) = self
+            .related_runbooks
+            .iter()
+            .find(|id| !is_valid_runbook_id(id.trim()))
+        {
+            return Err(WorkNoteError::ValidationError(format!(
+                "Invalid runbook ID: {:?}",
+                id
+            )));
+        }
+
+        Ok(())
+    }
+}
+
+fn parse_date(field: &str, value: &str) -> Result<NaiveDate> {
+    NaiveDate::parse_from_str(value.trim(), DATE_FORMAT).map_err(|_| {
+        WorkNoteError::ValidationError(format!(
+            "{} must be a date in YYYY-MM-DD format: {:?}",
+            field, value
+        ))
+    })
+}
+
+/// 新しいポストモーテムIDを生成（例: `pm-1a2b3c4d`）
+pub fn generate_postmortem_id() -> String {
+    let uuid = uuid::Uuid::new_v4().simple().to_string();
+    format!("pm-{}", &uuid[..8])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn input() -> PostmortemInput {
+        serde_json::from_str(
+            r#"{
+                "title": "決済APIの障害",
+                "incidentDate": "2024-04-01",
+                "severity": "critical",
+                "summary": "決済APIが30分停止",
+                "impact": "決済の失敗 1,200件",
+                "rootCause": "コネクションプールの枯渇",
+                "fiveWhys": ["なぜ停止したか", "なぜ枯渇したか"],
+                "actionItems": [{"description": "上限の監視を追加", "owner": "alice", "dueDate": "2024-04-15"}],
+                "relatedRunbooks": ["rb-1a2b3c4d"]
+            }"#,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_validate() {
+        let base = input();
+        assert!(base.validate().is_ok());
+        assert_eq!(base.action_items[0].status, ActionItemStatus::Open);
+
+        let mut invalid = base.clone();
+        invalid.incident_date = "2024/04/01".to_string();
+        assert!(invalid.validate().is_err());
+
+        let mut invalid = base.clone();
+        invalid.action_items[0].owner = " ".to_string();
+        assert!(invalid.validate().is_err());
+
+        let mut invalid = base.clone();
+        invalid.action_items[0].due_date = Some("来週".to_string());
+        assert!(invalid.validate().is_err());
+
+        let mut invalid = base.clone();
+        invalid.five_whys = vec!["なぜ".to_string(); 6];
+        assert!(invalid.validate().is_err());
+
+        let mut invalid = base;
+        invalid.related_runbooks.push("../etc".to_string());
+        assert!(invalid.validate().is_err());
+    }
+
+    #[test]
+    fn test_ensure_id() {
+        let mut input = input();
+        let id = input.ensure_id().to_string();
+        assert!(id.starts_with("pm-"));
+        assert_eq!(input.ensure_id(), id);
+    }
+}
@@ -0,0 +1,346 @@
+use crate::models::config::{Config, NamingConfig, ProofreadConfig, ProofreadPreset};
+use crate::models::error::{Result, WorkNoteError};
+use crate::models::knowledge::OPTIONAL_FIELDS;
+use crate::models::taxonomy::{CategoryDefinition, SeverityDefinition};
+use crate::services::CommitTemplate;
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::{Component, Path};
+
+/// リポジトリ共有設定のファイル名（ナレッジリポジトリのルートに配置）
+pub const REPO_CONFIG_FILE_NAME: &str = ".worknote.toml";
+
+/// リポジトリ共有設定の添削設定
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct RepoProofreadConfig {
+    /// 個人で選択していない場合に使うプリセット名
+    pub default_preset: Option<String>,
+    #[serde(default)]
+    pub presets: Vec<ProofreadPreset>,
+}
+
+/// リポジトリ共有設定（`.worknote.toml`）
+///
+/// チーム全員で揃えるべき項目のみを定義できます。優先順位は次のとおりです。
+///
+/// 1. `.worknote.toml` に書かれた項目（ローカル設定より優先）
+/// 2. ローカル設定（`config.json`）
+/// 3. 組み込みの既定値
+///
+/// リポジトリパス・コミットモード・ショートカットなど個人の環境に依存する項目は
+/// `.worknote.toml` では定義できません。添削プロンプトは、個人で選択したプリセット →
+/// `proofread.default_preset` → ローカル設定のプロンプト → 組み込みのプロンプトの順に使います。
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RepoConfig {
+    /// Runbookの保存先（リポジトリルートからの相対パス）
+    pub save_path: Option<String>,
+    pub postmortem_path: Option<String>,
+    pub default_branch: Option<String>,
+    /// コミットメッセージの書式（`{title}`・`{category}`・`{severity}` などを置換）
+    pub commit_message: Option<String>,
+    /// PR作成モードのブランチ名の書式
+    pub branch_name: Option<String>,
+    /// 保存時に入力必須とする任意項目
+    pub required_fields: Option<Vec<String>>,
+    pub naming: Option<NamingConfig>,
+    pub categories: Option<Vec<CategoryDefinition>>,
+    pub severities: Option<Vec<SeverityDefinition>>,
+    pub proofread: Option<RepoProofreadConfig>,
+}
+
+impl RepoConfig {
+    /// TOML文字列から読み込む
+    pub fn parse(content: &str) -> Result<Self> {
+        let config: RepoConfig = toml::from_str(content).map_err(|e| {
+            WorkNoteError::ConfigError(format!("Failed to parse {}: {}", REPO_CONFIG_FILE_NAME, e))
+        })?;
+        config.validate()?;
+        Ok(config)
+    }
+
+    /// 定義されている項目のキー一覧（ローカル設定のキー名。設定画面での表示用）
+    pub fn managed_keys(&self) -> Vec<String> {
+        let keys = [
+            ("git.savePath", self.save_path.is_some()),
+            ("git.postmortemPath", self.postmortem_path.is_some()),
+            ("git.defaultBranch", self.default_branch.is_some()),
+            ("git.commitMessage", self.commit_message.is_some()),
+            ("git.branchName", self.branch_name.is_some()),
+            ("requiredFields", self.required_fields.is_some()),
+            ("naming", self.naming.is_some()),
+            ("taxonomy.categories", self.categories.is_some()),
+            ("taxonomy.severities", self.severities.is_some()),
+            (
+                "proofread.presets",
+                self.proofread
+                    .as_ref()
+                    .is_some_and(|p| !p.presets.is_empty()),
+            ),
+        ];
+        keys.iter()
+            .filter(|(_, defined)| *defined)
+            .map(|(key, _)| key.to_string())
+            .collect()
+    }
+
+    /// ローカル設定に重ねる（定義されている項目で上書き）
+    pub fn apply(&self, config: &mut Config) {
+        if let Some(save_path) = &self.save_path {
+            config.git.save_path = save_path.clone();
+        }
+        if let Some(postmortem_path) = &self.postmortem_path {
+            config.git.postmortem_path = postmortem_path.clone();
+        }
+        if let Some(default_branch) = &self.default_branch {
+            config.git.default_branch = default_branch.clone();
+        }
+        if let Some(commit_message) = &self.commit_message {
+            config.git.commit_message = commit_message.clone();
+        }
+        if let Some(branch_name) = &self.branch_name {
+            config.git.branch_name = branch_name.clone();
+        }
+        if let Some(required_fields) = &self.required_fields {
+            config.required_fields = required_fields.clone();
+        }
+        if let Some(naming) = &self.naming {
+            config.naming = naming.clone();
+        }
+        if let Some(categories) = &self.categories {
+            config.taxonomy.categories = categories.clone();
+        }
+        if let Some(severities) = &self.severities {
+            config.taxonomy.severities = severities.clone();
+        }
+
+        if let Some(repo_proofread) = self.proofread.as_ref().filter(|p| !p.presets.is_empty()) {
+            let proofread = config.proofread.get_or_insert_with(|| ProofreadConfig {
+                prompt: String::new(),
+                preset: None,
+                presets: Vec::new(),
+            });
+            proofread.presets = repo_proofread.presets.clone();
+            // 個人の選択がリポジトリのプリセットにない場合は既定のプリセットを使う
+            let selected = proofread
+                .preset
+                .as_deref()
+                .is_some_and(|name| proofread.presets.iter().any(|p| p.name == name));
+            if !selected {
+                proofread.preset = repo_proofread.default_preset.clone();
+            }
+        }
+    }
+
+    /// 定義内容を検証（パス・必須項目名・プリセット）
+    ///
+    /// カテゴリ定義・命名パターン・コミットメッセージとブランチ名の書式はローカル設定に重ねた後に検証します。
+    fn validate(&self) -> Result<()> {
+        for (key, path) in [
+            ("save_path", &self.save_path),
+            ("postmortem_path", &self.postmortem_path),
+        ] {
+            if let Some(path) = path {
+                let relative = Path::new(path.trim_end_matches('/'));
+                if path.trim().is_empty()
+                    || !relative
+                        .components()
+                        .all(|c| matches!(c, Component::Normal(_)))
+                {
+                    return Err(WorkNoteError::ConfigError(format!(
+                        "{} must be a relative path inside the repository: {:?}",
+                        key, path
+                    )));
+                }
+            }
+        }
+
+        for (key, value) in [
+            ("default_branch", &self.default_branch),
+            ("commit_message", &self.commit_message),
+            ("branch_name", &self.branch_name),
+        ] {
+            if value.as_ref().is_some_and(|v| v.trim().is_empty()) {
+                return Err(WorkNoteError::ConfigError(format!(
+                    "{} must not be empty",
+                    key
+                )));
+            }
+        }
+        // gitの引数に渡すため、`-` で始まる名前などはオプションとして解釈されないよう拒否
+        if let Some(branch) = &self.default_branch {
+            CommitTemplate::validate_ref_name(branch).map_err(|_| {
+                WorkNoteError::ConfigError(format!("Invalid default_branch: {:?}", branch))
+            })?;
+        }
+
+        if let Some(field) = self
+            .required_fields
+            .iter()
+            .flatten()
+            .find(|f| !OPTIONAL_FIELDS.contains(&f.as_str()))
+        {
+            return Err(WorkNoteError::ConfigError(format!(
+                "Unknown required field: {} (expected one of {})",
+                field,
+                OPTIONAL_FIELDS.join(", ")
+            )));
+        }
+
+        if let Some(proofread) = &self.proofread {
+            let mut names = HashSet::new();
+            for preset in &proofread.presets {
+                if preset.name.trim().is_empty() || !names.insert(preset.name.as_str()) {
+                    return Err(WorkNoteError::ConfigError(format!(
+                        "Invalid or duplicate proofread preset name: {:?}",
+                        preset.name
+                    )));
+                }
+                if preset.prompt.trim().is_empty() {
+                    return Err(WorkNoteError::ConfigError(format!(
+                        "Proofread preset prompt is empty: {}",
+                        preset.name
+                    )));
+                }
+            }
+            if let Some(default) = &proofread.default_preset {
+                if !names.contains(default.as_str()) {
+                    return Err(WorkNoteError::ConfigError(format!(
+                        "Unknown default proofread preset: {}",
+                        default
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// リポジトリ共有設定を重ねた設定
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EffectiveConfig {
+    pub config: Config,
+    /// 読み込んだ `.worknote.toml` のパス（存在しない場合は `None`）
+    pub repo_config_path: Option<String>,
+    /// `.worknote.toml` で管理されている項目のキー
+    pub managed_keys: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::NamingScheme;
+
+    const TEAM_CONFIG: &str = r#"
+save_path = "knowledge/runbooks"
+default_branch = "develop"
+commit_message = "runbook: {title} ({category}/{severity})"
+branch_name = "runbook/{category}/{title}"
+required_fields = ["notes", "relatedAlerts"]
+
+[naming]
+scheme = "date-id"
+pattern = "{date}-{slug}"
+
+[[categories]]
+id = "alerts"
+label = "アラート"
+directory = "alerts"
+template = "1. ダッシュボードを確認"
+
+[[categories.judgment_fields]]
+key = "threshold"
+label = "閾値"
+
+[[categories]]
+id = "security"
+label = "セキュリティ"
+directory = "sec"
+
+[proofread]
+default_preset = "strict"
+
+[[proofread.presets]]
+name = "strict"
+label = "厳しめ"
+prompt = "敬体に統一し、曖昧な表現を指摘してください"
+
+[[proofread.presets]]
+name = "light"
+prompt = "誤字脱字のみ修正してください"
+"#;
+
+    #[test]
+    fn test_parse_and_apply() {
+        let repo = RepoConfig::parse(TEAM_CONFIG).unwrap();
+        let mut config = Config::default();
+        config.git.repository_path = "/repo".to_string();
+        config.git.save_path = "docs/runbooks".to_string();
+        repo.apply(&mut config);
+
+        // リポジトリ共有設定が優先、未定義の項目はローカル設定のまま
+        assert_eq!(config.git.repository_path, "/repo");
+        assert_eq!(config.git.save_path, "knowledge/runbooks");
+        assert_eq!(config.git.default_branch, "develop");
+        assert_eq!(config.git.postmortem_path, "docs/postmortems");
+        assert_eq!(config.git.branch_name, "runbook/{category}/{title}");
+        assert_eq!(config.naming.scheme, NamingScheme::DateId);
+        assert_eq!(config.required_fields, vec!["notes", "relatedAlerts"]);
+        assert_eq!(config.taxonomy.categories.len(), 2);
+        assert_eq!(
+            config.taxonomy.categories[0].judgment_fields[0].key,
+            "threshold"
+        );
+        assert_eq!(config.taxonomy.severities.len(), 4);
+        assert!(config.taxonomy.validate().is_ok());
+
+        let proofread = config.proofread.as_ref().unwrap();
+        assert_eq!(proofread.preset.as_deref(), Some("strict"));
+        assert_eq!(
+            proofread.effective_prompt().as_deref(),
+            Some("敬体に統一し、曖昧な表現を指摘してください")
+        );
+
+        assert!(repo.managed_keys().contains(&"git.savePath".to_string()));
+        assert!(!repo
+            .managed_keys()
+            .contains(&"git.postmortemPath".to_string()));
+    }
+
+    #[test]
+    fn test_local_preset_selection_wins() {
+        let repo = RepoConfig::parse(TEAM_CONFIG).unwrap();
+        let mut config = Config {
+            proofread: Some(ProofreadConfig {
+                prompt: "ローカルのプロンプト".to_string(),
+                preset: Some("light".to_string()),
+                presets: Vec::new(),
+            }),
+            ..Default::default()
+        };
+        repo.apply(&mut config);
+        assert_eq!(
+            config.proofread.unwrap().effective_prompt().as_deref(),
+            Some("誤字脱字のみ修正してください")
+        );
+    }
+
+    #[test]
+    fn test_invalid_repo_config() {
+        assert!(RepoConfig::parse("").is_ok());
+        assert!(RepoConfig::parse("save_path = \"../outside\"").is_err());
+        assert!(RepoConfig::parse("save_path = \"/abs\"").is_err());
+        assert!(RepoConfig::parse("required_fields = [\"owner\"]").is_err());
+        assert!(RepoConfig::parse("default_branch = \"--upload-pack=evil\"").is_err());
+        assert!(RepoConfig::parse("default_branch = \"release/v1\"").is_ok());
+        // 個人設定の項目は共有設定に書けない
+        assert!(RepoConfig::parse("repository_path = \"/repo\"").is_err());
+        assert!(RepoConfig::parse(
+            "[proofread]\ndefault_preset = \"none\"\n[[proofread.presets]]\nname = \"a\"\nprompt = \"p\""
+        )
+        .is_err());
+    }
+}
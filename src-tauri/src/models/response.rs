@@ -1,3 +1,4 @@
+use crate::models::Config;
 use serde::{Deserialize, Serialize};
 
 /// ナレッジ保存レスポンス
@@ -10,3 +11,36 @@ pub struct SaveKnowledgeResponse {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub pr_url: Option<String>,
 }
+
+/// 設定保存レスポンス
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SaveConfigResponse {
+    /// 保存した設定を反映できなかった項目の警告（設定自体は保存済み）
+    pub warnings: Vec<String>,
+}
+
+/// 保存先プロファイル切り替えレスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetActiveProfileResponse {
+    /// 切り替え後の設定
+    pub config: Config,
+    /// 切り替えた設定を反映できなかった項目の警告（切り替え自体は保存済み）
+    pub warnings: Vec<String>,
+}
+
+/// 下書きの一括公開レスポンス
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PublishDraftsResponse {
+    pub success: bool,
+    /// 作成したコミットのハッシュ（コミット順）
+    pub commit_hashes: Vec<String>,
+    /// 生成したRunbookのパス（指定した下書きの順）
+    pub file_paths: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pr_url: Option<String>,
+    /// 公開して削除した下書きのID
+    pub published_drafts: Vec<String>,
+}
@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+/// Runbookエントリ（一覧・インデックス表示用）
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct RunbookEntry {
+    /// 保存先ディレクトリ（save_path）からの相対パス（`/` 区切り）
+    pub path: String,
+    /// RunbookID（フロントマターの `id`。ID導入前のRunbookには存在しない）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// カテゴリディレクトリ名
+    pub category: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub severity: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_updated: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<String>,
+}
+
+/// Runbook検索結果
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SearchResult {
+    pub entry: RunbookEntry,
+    /// 最初に一致した行（前後の空白を除去）
+    pub snippet: String,
+    /// 一致度（タイトル一致を優先し、本文の出現回数を加算）
+    pub score: usize,
+}
@@ -0,0 +1,379 @@
+use crate::models::error::{Result, WorkNoteError};
+use crate::models::knowledge::{Category, Severity};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// カテゴリ・重要度IDの最大文字数
+const TAXONOMY_ID_MAX_LENGTH: usize = 32;
+
+/// 廃止済みカテゴリと移行先（旧ファイル・旧下書きの読み込み時に置き換える）
+pub const LEGACY_CATEGORY_ALIASES: [(&str, &str); 1] = [("maintenance", "ops")];
+
+/// カテゴリ・重要度のIDとして有効な文字列かを判定
+///
+/// フロントマターとディレクトリ名に使うため、小文字英数字・`-`・`_` のみ許可します。
+pub fn is_valid_taxonomy_id(id: &str) -> bool {
+    !id.is_empty()
+        && id.len() <= TAXONOMY_ID_MAX_LENGTH
+        && id
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
+}
+
+/// カテゴリ別判断軸の入力項目
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct JudgmentField {
+    /// 判断軸JSONのキー
+    pub key: String,
+    pub label: String,
+    #[serde(default)]
+    pub placeholder: String,
+}
+
+impl JudgmentField {
+    fn new(key: &str, label: &str, placeholder: &str) -> Self {
+        JudgmentField {
+            key: key.to_string(),
+            label: label.to_string(),
+            placeholder: placeholder.to_string(),
+        }
+    }
+}
+
+/// カテゴリ定義
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct CategoryDefinition {
+    /// フロントマターの `category` に書く値
+    pub id: String,
+    /// 表示名
+    pub label: String,
+    /// 保存先ディレクトリ名（save_path 直下）
+    pub directory: String,
+    #[serde(default)]
+    pub icon: String,
+    #[serde(default, alias = "judgment_fields")]
+    pub judgment_fields: Vec<JudgmentField>,
+    /// カテゴリ選択時に対応手順へ入れるテンプレート
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub template: Option<String>,
+}
+
+/// 重要度定義
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SeverityDefinition {
+    /// フロントマターの `severity` に書く値
+    pub id: String,
+    pub label: String,
+    #[serde(default)]
+    pub icon: String,
+}
+
+/// カテゴリ・重要度の定義（一覧の順序が表示順。重要度は後ろほど高い）
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TaxonomyConfig {
+    pub categories: Vec<CategoryDefinition>,
+    pub severities: Vec<SeverityDefinition>,
+}
+
+impl Default for TaxonomyConfig {
+    fn default() -> Self {
+        let category =
+            |id: &str, label: &str, icon: &str, fields: Vec<JudgmentField>| CategoryDefinition {
+                id: id.to_string(),
+                label: label.to_string(),
+                directory: id.to_string(),
+                icon: icon.to_string(),
+                judgment_fields: fields,
+                template: None,
+            };
+        let severity = |id: &str, label: &str, icon: &str| SeverityDefinition {
+            id: id.to_string(),
+            label: label.to_string(),
+            icon: icon.to_string(),
+        };
+
+        TaxonomyConfig {
+            categories: vec![
+                category(
+                    "alerts",
+                    "アラート",
+                    "🚨",
+                    vec![
+                        JudgmentField::new(
+                            "threshold",
+                            "閾値・条件",
+                            "例: CPU使用率が90%を超えた場合、または5分間継続した場合",
+                        ),
+                        JudgmentField::new(
+                            "escalationCriteria",
+                            "エスカレーション基準",
+                            "例: 10分以内に対応できない場合は上長へ報告",
+                        ),
+                    ],
+                ),
+                category(
+                    "ops",
+                    "運用作業",
+                    "🛠️",
+                    vec![
+                        JudgmentField::new(
+                            "workCriteria",
+                            "作業基準",
+                            "例: バックアップ完了後に実施、変更管理チケット必須",
+                        ),
+                        JudgmentField::new(
+                            "timing",
+                            "実施タイミング",
+                            "例: 毎週日曜日 03:00-05:00、サービス影響を最小化するため深夜帯",
+                        ),
+                    ],
+                ),
+                category(
+                    "troubleshooting",
+                    "トラブルシューティング",
+                    "🔍",
+                    vec![
+                        JudgmentField::new(
+                            "rootCauseProcess",
+                            "原因特定プロセス",
+                            "例: ログ確認 → メトリクス分析 → コード変更履歴確認の順で調査",
+                        ),
+                        JudgmentField::new(
+                            "investigationSteps",
+                            "調査手順",
+                            "例: 1. エラーログの時系列確認、2. 関連サービスの状態確認、3. ネットワーク疎通確認",
+                        ),
+                    ],
+                ),
+                category(
+                    "inquiry",
+                    "問い合わせ",
+                    "💬",
+                    vec![
+                        JudgmentField::new(
+                            "priorityCriteria",
+                            "対応優先度基準",
+                            "例: 業務停止につながる場合は即時対応、その他は24時間以内に回答",
+                        ),
+                        JudgmentField::new(
+                            "responseGuideline",
+                            "回答指針",
+                            "例: 公式ドキュメントURLを含める、再現手順を必ず確認",
+                        ),
+                    ],
+                ),
+            ],
+            severities: vec![
+                severity("low", "低", "🟢"),
+                severity("medium", "中", "🟡"),
+                severity("high", "高", "🟠"),
+                severity("critical", "緊急", "🔴"),
+            ],
+        }
+    }
+}
+
+impl TaxonomyConfig {
+    /// カテゴリ定義を取得
+    pub fn category(&self, category: &Category) -> Option<&CategoryDefinition> {
+        self.categories.iter().find(|c| c.id == category.as_str())
+    }
+
+    /// 重要度定義を取得
+    pub fn severity(&self, severity: &Severity) -> Option<&SeverityDefinition> {
+        self.severities.iter().find(|s| s.id == severity.as_str())
+    }
+
+    /// 重要度の順位（定義順。未定義は `None`）
+    pub fn severity_rank(&self, severity: &Severity) -> Option<usize> {
+        self.severities
+            .iter()
+            .position(|s| s.id == severity.as_str())
+    }
+
+    /// カテゴリの保存先ディレクトリ名（未定義のカテゴリはIDをそのまま使用）
+    pub fn directory_for(&self, category: &Category) -> String {
+        self.category(category)
+            .map(|c| c.directory.clone())
+            .unwrap_or_else(|| category.as_str().to_string())
+    }
+
+    /// ディレクトリ名からカテゴリ定義を取得
+    pub fn category_by_directory(&self, directory: &str) -> Option<&CategoryDefinition> {
+        self.categories.iter().find(|c| c.directory == directory)
+    }
+
+    /// 定義済みのカテゴリ・重要度かを検証
+    pub fn validate_input(&self, category: &Category, severity: &Severity) -> Result<()> {
+        if self.category(category).is_none() {
+            return Err(WorkNoteError::ValidationError(format!(
+                "Unknown category: {}",
+                category
+            )));
+        }
+        if self.severity(severity).is_none() {
+            return Err(WorkNoteError::ValidationError(format!(
+                "Unknown severity: {}",
+                severity
+            )));
+        }
+        Ok(())
+    }
+
+    /// 定義自体を検証（IDの形式・重複、ディレクトリ名の重複）
+    pub fn validate(&self) -> Result<()> {
+        if self.categories.is_empty() || self.severities.is_empty() {
+            return Err(WorkNoteError::ValidationError(
+                "At least one category and one severity must be defined".to_string(),
+            ));
+        }
+
+        let mut ids = HashSet::new();
+        let mut directories = HashSet::new();
+        for category in &self.categories {
+            if !is_valid_taxonomy_id(&category.id) {
+                return Err(WorkNoteError::ValidationError(format!(
+                    "Invalid category ID: {:?}",
+                    category.id
+                )));
+            }
+            if !is_valid_taxonomy_id(&category.directory) {
+                return Err(WorkNoteError::ValidationError(format!(
+                    "Invalid category directory: {:?}",
+                    category.directory
+                )));
+            }
+            if category.label.trim().is_empty() {
+                return Err(WorkNoteError::ValidationError(format!(
+                    "Category label is required: {}",
+                    category.id
+                )));
+            }
+            if !ids.insert(category.id.as_str()) {
+                return Err(WorkNoteError::ValidationError(format!(
+                    "Duplicate category ID: {}",
+                    category.id
+                )));
+            }
+            if !directories.insert(category.directory.as_str()) {
+                return Err(WorkNoteError::ValidationError(format!(
+                    "Duplicate category directory: {}",
+                    category.directory
+                )));
+            }
+
+            let mut keys = HashSet::new();
+            for field in &category.judgment_fields {
+                if field.key.trim().is_empty() || !keys.insert(field.key.as_str()) {
+                    return Err(WorkNoteError::ValidationError(format!(
+                        "Invalid or duplicate judgment field key in category {}: {:?}",
+                        category.id, field.key
+                    )));
+                }
+            }
+        }
+
+        let mut ids = HashSet::new();
+        for severity in &self.severities {
+            if !is_valid_taxonomy_id(&severity.id) {
+                return Err(WorkNoteError::ValidationError(format!(
+                    "Invalid severity ID: {:?}",
+                    severity.id
+                )));
+            }
+            if !ids.insert(severity.id.as_str()) {
+                return Err(WorkNoteError::ValidationError(format!(
+                    "Duplicate severity ID: {}",
+                    severity.id
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// カテゴリ移行で移動・書き換えたファイル
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct MigratedFile {
+    /// 移行前のパス（保存先ディレクトリからの相対パス）
+    pub from: String,
+    /// 移行後のパス（保存先ディレクトリからの相対パス）
+    pub to: String,
+    /// フロントマターの `category` を書き換えたか
+    pub category_rewritten: bool,
+}
+
+/// カテゴリ移行の結果
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CategoryMigrationReport {
+    /// 実際にはファイルを変更していない
+    pub dry_run: bool,
+    pub files_checked: usize,
+    pub migrated: Vec<MigratedFile>,
+    /// 移動先に同名ファイルがあるなどで移行できなかったファイル
+    pub skipped: Vec<SkippedMigration>,
+}
+
+/// 移行できなかったファイル
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct SkippedMigration {
+    pub path: String,
+    pub reason: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_matches_built_in_values() {
+        let taxonomy = TaxonomyConfig::default();
+        assert!(taxonomy.validate().is_ok());
+        assert_eq!(taxonomy.directory_for(&Category::Alerts), "alerts");
+        assert!(taxonomy
+            .validate_input(&Category::Ops, &Severity::Critical)
+            .is_ok());
+        assert!(taxonomy
+            .validate_input(&Category::Custom("security".to_string()), &Severity::Low)
+            .is_err());
+        assert_eq!(taxonomy.severity_rank(&Severity::Low), Some(0));
+        assert_eq!(taxonomy.severity_rank(&Severity::Critical), Some(3));
+    }
+
+    #[test]
+    fn test_custom_definitions() {
+        let mut taxonomy = TaxonomyConfig::default();
+        taxonomy.categories.push(CategoryDefinition {
+            id: "security".to_string(),
+            label: "セキュリティ".to_string(),
+            directory: "sec".to_string(),
+            icon: "🔒".to_string(),
+            judgment_fields: Vec::new(),
+            template: Some("1. 影響範囲の特定\n2. 封じ込め".to_string()),
+        });
+        assert!(taxonomy.validate().is_ok());
+
+        let security = Category::Custom("security".to_string());
+        assert_eq!(taxonomy.directory_for(&security), "sec");
+        assert_eq!(
+            taxonomy.category_by_directory("sec").map(|c| c.id.as_str()),
+            Some("security")
+        );
+
+        let mut duplicate = taxonomy.clone();
+        duplicate.categories[4].directory = "alerts".to_string();
+        assert!(duplicate.validate().is_err());
+
+        let mut invalid = taxonomy;
+        invalid.severities[0].id = "Sev 1".to_string();
+        assert!(invalid.validate().is_err());
+    }
+}
@@ -0,0 +1,171 @@
+use crate::models::{Category, Severity};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// タイムラインメモの種別
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TimelineTag {
+    /// 症状・検知
+    Symptom,
+    /// 実施した対応
+    Action,
+    /// 判明した事実・原因
+    Finding,
+    /// 判断・意思決定
+    Decision,
+}
+
+impl TimelineTag {
+    /// 文字列表現を取得
+    pub fn as_str(&self) -> &str {
+        match self {
+            TimelineTag::Symptom => "symptom",
+            TimelineTag::Action => "action",
+            TimelineTag::Finding => "finding",
+            TimelineTag::Decision => "decision",
+        }
+    }
+
+    /// 表示名を取得
+    pub fn label(&self) -> &str {
+        match self {
+            TimelineTag::Symptom => "症状",
+            TimelineTag::Action => "対応",
+            TimelineTag::Finding => "判明",
+            TimelineTag::Decision => "判断",
+        }
+    }
+
+    /// メモ先頭の種別指定（`action: 再起動`、`対応：再起動`）を解釈
+    ///
+    /// 種別指定がなければ `None` と元のテキストを返します。
+    pub fn parse_prefix(text: &str) -> (Option<TimelineTag>, &str) {
+        let text = text.trim();
+        let Some((prefix, rest)) = text.split_once([':', '：']) else {
+            return (None, text);
+        };
+
+        let tag = match prefix.trim().to_lowercase().as_str() {
+            "symptom" | "症状" | "検知" => TimelineTag::Symptom,
+            "action" | "対応" | "作業" => TimelineTag::Action,
+            "finding" | "判明" | "原因" => TimelineTag::Finding,
+            "decision" | "判断" => TimelineTag::Decision,
+            _ => return (None, text),
+        };
+        (Some(tag), rest.trim())
+    }
+}
+
+/// タイムラインの1件のメモ
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelineNote {
+    pub id: String,
+    pub timestamp: DateTime<Utc>,
+    pub text: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tag: Option<TimelineTag>,
+}
+
+/// インシデント対応中のタイムライン
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelineSession {
+    pub id: String,
+    pub title: String,
+    pub started_at: DateTime<Utc>,
+    /// 確定（Runbook化）した日時。未確定の間はメモを追加できる
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub finalized_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub notes: Vec<TimelineNote>,
+}
+
+impl TimelineSession {
+    /// 新しいタイムラインを作成
+    pub fn new(title: String) -> Self {
+        Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            title,
+            started_at: Utc::now(),
+            finalized_at: None,
+            notes: Vec::new(),
+        }
+    }
+
+    /// メモを追加（時刻は現在時刻）
+    pub fn add_note(&mut self, text: String, tag: Option<TimelineTag>) -> TimelineNote {
+        let note = TimelineNote {
+            id: uuid::Uuid::new_v4().to_string(),
+            timestamp: Utc::now(),
+            text,
+            tag,
+        };
+        self.notes.push(note.clone());
+        note
+    }
+
+    pub fn is_finalized(&self) -> bool {
+        self.finalized_at.is_some()
+    }
+}
+
+/// タイムライン一覧の要素
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TimelineSummary {
+    pub id: String,
+    pub title: String,
+    pub started_at: DateTime<Utc>,
+    pub finalized_at: Option<DateTime<Utc>>,
+    pub note_count: usize,
+}
+
+impl From<&TimelineSession> for TimelineSummary {
+    fn from(session: &TimelineSession) -> Self {
+        Self {
+            id: session.id.clone(),
+            title: session.title.clone(),
+            started_at: session.started_at,
+            finalized_at: session.finalized_at,
+            note_count: session.notes.len(),
+        }
+    }
+}
+
+/// タイムライン確定時のオプション
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FinalizeTimelineOptions {
+    /// カテゴリ（未指定時は troubleshooting）
+    #[serde(default)]
+    pub category: Option<Category>,
+    /// 重要度（未指定時は medium）
+    #[serde(default)]
+    pub severity: Option<Severity>,
+    /// 添削バックエンドで症状・対応手順・注意点を要約する
+    #[serde(default)]
+    pub summarize: bool,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_prefix() {
+        assert_eq!(
+            TimelineTag::parse_prefix("action: pod を再起動"),
+            (Some(TimelineTag::Action), "pod を再起動")
+        );
+        assert_eq!(
+            TimelineTag::parse_prefix("症状：5xx が増加"),
+            (Some(TimelineTag::Symptom), "5xx が増加")
+        );
+        assert_eq!(
+            TimelineTag::parse_prefix("10:05 にアラート"),
+            (None, "10:05 にアラート")
+        );
+    }
+}
@@ -0,0 +1,137 @@
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+/// 検証結果の重要度
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "lowercase")]
+pub enum IssueLevel {
+    Warning,
+    Error,
+}
+
+impl IssueLevel {
+    /// 文字列表現を取得
+    pub fn as_str(&self) -> &str {
+        match self {
+            IssueLevel::Warning => "warning",
+            IssueLevel::Error => "error",
+        }
+    }
+}
+
+/// Runbookの検証で見つかった問題
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidationIssue {
+    /// 保存先ディレクトリからの相対パス（`/` 区切り）
+    pub path: String,
+    /// 行番号（1始まり。ファイル全体に関する問題は `None`）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<usize>,
+    pub level: IssueLevel,
+    /// ルールID（例: `broken-link`）
+    pub rule: String,
+    pub message: String,
+}
+
+/// Runbookリポジトリの検証結果
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub struct ValidationReport {
+    pub files_checked: usize,
+    pub issues: Vec<ValidationIssue>,
+}
+
+impl ValidationReport {
+    /// エラー件数
+    pub fn error_count(&self) -> usize {
+        self.count(IssueLevel::Error)
+    }
+
+    /// 警告件数
+    pub fn warning_count(&self) -> usize {
+        self.count(IssueLevel::Warning)
+    }
+
+    /// エラーが1件以上あるか
+    pub fn has_errors(&self) -> bool {
+        self.error_count() > 0
+    }
+
+    fn count(&self, level: IssueLevel) -> usize {
+        self.issues.iter().filter(|i| i.level == level).count()
+    }
+
+    /// 人が読むためのテキスト形式（`path:line: level[rule] message`）
+    pub fn to_text(&self) -> String {
+        let mut text = String::new();
+        for issue in &self.issues {
+            let location = match issue.line {
+                Some(line) => format!("{}:{}", issue.path, line),
+                None => issue.path.clone(),
+            };
+            text.push_str(&format!(
+                "{}: {}[{}] {}\n",
+                location,
+                issue.level.as_str(),
+                issue.rule,
+                issue.message
+            ));
+        }
+        text.push_str(&format!(
+            "{} files checked, {} errors, {} warnings\n",
+            self.files_checked,
+            self.error_count(),
+            self.warning_count()
+        ));
+        text
+    }
+
+    /// SARIF 2.1.0 形式（GitHub Code Scanning等のCIで利用）
+    ///
+    /// # Arguments
+    /// * `uri_prefix` - 各パスの前に付けるリポジトリルートからのパス（例: `docs/runbooks/`）
+    pub fn to_sarif(&self, uri_prefix: &str) -> serde_json::Value {
+        let mut rules: Vec<&str> = self.issues.iter().map(|i| i.rule.as_str()).collect();
+        rules.sort();
+        rules.dedup();
+
+        let results: Vec<serde_json::Value> = self
+            .issues
+            .iter()
+            .map(|issue| {
+                let mut location = json!({
+                    "physicalLocation": {
+                        "artifactLocation": {
+                            "uri": format!("{}{}", uri_prefix, issue.path)
+                        }
+                    }
+                });
+                if let Some(line) = issue.line {
+                    location["physicalLocation"]["region"] = json!({ "startLine": line });
+                }
+                json!({
+                    "ruleId": issue.rule,
+                    "level": issue.level.as_str(),
+                    "message": { "text": issue.message },
+                    "locations": [location]
+                })
+            })
+            .collect();
+
+        json!({
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "worknote",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "rules": rules.iter().map(|id| json!({ "id": id })).collect::<Vec<_>>()
+                    }
+                },
+                "results": results
+            }]
+        })
+    }
+}
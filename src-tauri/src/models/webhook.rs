@@ -0,0 +1,144 @@
+use crate::models::{Category, KnowledgeInput, Severity};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Webhookの送信元
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum AlertSource {
+    Alertmanager,
+    Grafana,
+    Generic,
+}
+
+impl AlertSource {
+    /// 表示用の名前を取得
+    pub fn display_name(&self) -> &str {
+        match self {
+            AlertSource::Alertmanager => "Alertmanager",
+            AlertSource::Grafana => "Grafana",
+            AlertSource::Generic => "Webhook",
+        }
+    }
+}
+
+/// Webhookで受信したアラート
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IncomingAlert {
+    pub source: AlertSource,
+    /// アラート名（Prometheusの `alertname`、Grafanaのルール名など）
+    pub name: String,
+    pub severity: Severity,
+    pub summary: Option<String>,
+    pub description: Option<String>,
+    pub labels: BTreeMap<String, String>,
+    /// 発生時刻（送信元の表記のまま）
+    pub starts_at: Option<String>,
+    /// ダッシュボードや既存Runbookなどの関連URL
+    pub links: Vec<String>,
+    /// アラートの識別子（Alertmanagerの `fingerprint`。同じアラートの再送を判定）
+    pub fingerprint: Option<String>,
+}
+
+impl IncomingAlert {
+    /// 下書き用のナレッジ入力データに変換
+    pub fn to_knowledge_input(&self) -> KnowledgeInput {
+        let mut symptoms = Vec::new();
+        if let Some(summary) = &self.summary {
+            symptoms.push(summary.clone());
+        }
+        if let Some(description) = &self.description {
+            symptoms.push(description.clone());
+        }
+
+        let mut details = vec![format!(
+            "- 検知元: {} (`{}`)",
+            self.source.display_name(),
+            self.name
+        )];
+        if let Some(starts_at) = &self.starts_at {
+            details.push(format!("- 発生時刻: {}", starts_at));
+        }
+        if !self.labels.is_empty() {
+            let labels = self
+                .labels
+                .iter()
+                .map(|(key, value)| format!("`{}={}`", key, value))
+                .collect::<Vec<_>>()
+                .join(", ");
+            details.push(format!("- ラベル: {}", labels));
+        }
+        symptoms.push(details.join("\n"));
+
+        let related_links = if self.links.is_empty() {
+            None
+        } else {
+            Some(
+                self.links
+                    .iter()
+                    .map(|link| format!("- {}", link))
+                    .collect::<Vec<_>>()
+                    .join("\n"),
+            )
+        };
+
+        KnowledgeInput {
+            id: None,
+            title: format!("{} 対応", self.name),
+            category: Category::Alerts,
+            severity: self.severity.clone(),
+            symptoms: symptoms.join("\n\n"),
+            procedure: "(アラート受信時に自動作成。対応手順を記入してください)".to_string(),
+            notes: None,
+            related_links,
+            judgment: None,
+            slug: None,
+            related_alerts: vec![self.name.clone()],
+        }
+    }
+}
+
+/// Webhook受信で下書きを作成したときにフロントエンドへ通知するイベント
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookDraftEvent {
+    pub draft_id: String,
+    pub title: String,
+    pub alert_name: String,
+    pub severity: Severity,
+    pub source: AlertSource,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_knowledge_input() {
+        let alert = IncomingAlert {
+            source: AlertSource::Alertmanager,
+            name: "HighCPUUsage".to_string(),
+            severity: Severity::Critical,
+            summary: Some("CPU使用率が90%を超えています".to_string()),
+            description: Some("web-01 のCPU使用率が5分間90%を超えています".to_string()),
+            labels: BTreeMap::from([("instance".to_string(), "web-01".to_string())]),
+            starts_at: Some("2026-01-31T10:00:00Z".to_string()),
+            links: vec!["http://prometheus.local/graph".to_string()],
+            fingerprint: Some("abc123".to_string()),
+        };
+
+        let input = alert.to_knowledge_input();
+        assert_eq!(input.title, "HighCPUUsage 対応");
+        assert_eq!(input.category, Category::Alerts);
+        assert_eq!(input.severity, Severity::Critical);
+        assert_eq!(input.related_alerts, vec!["HighCPUUsage"]);
+        assert!(input
+            .symptoms
+            .starts_with("CPU使用率が90%を超えています\n\nweb-01"));
+        assert!(input.symptoms.contains("- ラベル: `instance=web-01`"));
+        assert_eq!(
+            input.related_links.as_deref(),
+            Some("- http://prometheus.local/graph")
+        );
+    }
+}
@@ -0,0 +1,397 @@
+use crate::models::{Result, ShortcutAction, ShortcutsConfig, WorkNoteError};
+use std::fmt;
+use std::str::FromStr;
+use tauri_plugin_global_shortcut::{Code, Modifiers, Shortcut};
+
+/// 修飾キー（表記順に並べる）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ModifierKey {
+    /// macOSではCommand、Windows/LinuxではControl
+    CommandOrControl,
+    Control,
+    Alt,
+    Shift,
+    /// macOSのCommand・WindowsのWindowsキー
+    Super,
+}
+
+impl ModifierKey {
+    /// 正規化した表記
+    pub fn name(self) -> &'static str {
+        match self {
+            ModifierKey::CommandOrControl => "CommandOrControl",
+            ModifierKey::Control => "Control",
+            ModifierKey::Alt => "Alt",
+            ModifierKey::Shift => "Shift",
+            ModifierKey::Super => "Super",
+        }
+    }
+
+    /// 表記を解析（大文字小文字を区別しない）
+    fn parse(name: &str) -> Option<Self> {
+        let modifier = match name.to_lowercase().as_str() {
+            "commandorcontrol" | "commandorctrl" | "cmdorctrl" | "cmdorcontrol" => {
+                ModifierKey::CommandOrControl
+            }
+            "control" | "ctrl" => ModifierKey::Control,
+            "alt" | "option" => ModifierKey::Alt,
+            "shift" => ModifierKey::Shift,
+            "super" | "command" | "cmd" | "meta" | "win" => ModifierKey::Super,
+            _ => return None,
+        };
+        Some(modifier)
+    }
+
+    fn modifiers(self) -> Modifiers {
+        match self {
+            // macOSではCommand (SUPER)、Windows/LinuxではControl
+            ModifierKey::CommandOrControl if cfg!(target_os = "macos") => Modifiers::SUPER,
+            ModifierKey::CommandOrControl | ModifierKey::Control => Modifiers::CONTROL,
+            ModifierKey::Alt => Modifiers::ALT,
+            ModifierKey::Shift => Modifiers::SHIFT,
+            ModifierKey::Super => Modifiers::SUPER,
+        }
+    }
+}
+
+/// 修飾キー以外のキー（正規化した表記とキーコード）
+const KEYS: [(&str, Code); 86] = [
+    ("A", Code::KeyA),
+    ("B", Code::KeyB),
+    ("C", Code::KeyC),
+    ("D", Code::KeyD),
+    ("E", Code::KeyE),
+    ("F", Code::KeyF),
+    ("G", Code::KeyG),
+    ("H", Code::KeyH),
+    ("I", Code::KeyI),
+    ("J", Code::KeyJ),
+    ("K", Code::KeyK),
+    ("L", Code::KeyL),
+    ("M", Code::KeyM),
+    ("N", Code::KeyN),
+    ("O", Code::KeyO),
+    ("P", Code::KeyP),
+    ("Q", Code::KeyQ),
+    ("R", Code::KeyR),
+    ("S", Code::KeyS),
+    ("T", Code::KeyT),
+    ("U", Code::KeyU),
+    ("V", Code::KeyV),
+    ("W", Code::KeyW),
+    ("X", Code::KeyX),
+    ("Y", Code::KeyY),
+    ("Z", Code::KeyZ),
+    ("0", Code::Digit0),
+    ("1", Code::Digit1),
+    ("2", Code::Digit2),
+    ("3", Code::Digit3),
+    ("4", Code::Digit4),
+    ("5", Code::Digit5),
+    ("6", Code::Digit6),
+    ("7", Code::Digit7),
+    ("8", Code::Digit8),
+    ("9", Code::Digit9),
+    ("F1", Code::F1),
+    ("F2", Code::F2),
+    ("F3", Code::F3),
+    ("F4", Code::F4),
+    ("F5", Code::F5),
+    ("F6", Code::F6),
+    ("F7", Code::F7),
+    ("F8", Code::F8),
+    ("F9", Code::F9),
+    ("F10", Code::F10),
+    ("F11", Code::F11),
+    ("F12", Code::F12),
+    ("F13", Code::F13),
+    ("F14", Code::F14),
+    ("F15", Code::F15),
+    ("F16", Code::F16),
+    ("F17", Code::F17),
+    ("F18", Code::F18),
+    ("F19", Code::F19),
+    ("F20", Code::F20),
+    ("F21", Code::F21),
+    ("F22", Code::F22),
+    ("F23", Code::F23),
+    ("F24", Code::F24),
+    (",", Code::Comma),
+    (".", Code::Period),
+    ("/", Code::Slash),
+    (";", Code::Semicolon),
+    ("'", Code::Quote),
+    ("[", Code::BracketLeft),
+    ("]", Code::BracketRight),
+    ("\\", Code::Backslash),
+    ("`", Code::Backquote),
+    ("-", Code::Minus),
+    ("=", Code::Equal),
+    ("Space", Code::Space),
+    ("Tab", Code::Tab),
+    ("Enter", Code::Enter),
+    ("Escape", Code::Escape),
+    ("Backspace", Code::Backspace),
+    ("Delete", Code::Delete),
+    ("Insert", Code::Insert),
+    ("Home", Code::Home),
+    ("End", Code::End),
+    ("PageUp", Code::PageUp),
+    ("PageDown", Code::PageDown),
+    ("Up", Code::ArrowUp),
+    ("Down", Code::ArrowDown),
+    ("Left", Code::ArrowLeft),
+    ("Right", Code::ArrowRight),
+];
+
+/// キー名の別名（別名 → 正規化した表記）
+const KEY_ALIASES: [(&str, &str); 17] = [
+    ("ArrowUp", "Up"),
+    ("ArrowDown", "Down"),
+    ("ArrowLeft", "Left"),
+    ("ArrowRight", "Right"),
+    ("Comma", ","),
+    ("Period", "."),
+    ("Slash", "/"),
+    ("Semicolon", ";"),
+    ("Quote", "'"),
+    ("BracketLeft", "["),
+    ("BracketRight", "]"),
+    ("Backslash", "\\"),
+    ("Backquote", "`"),
+    ("Minus", "-"),
+    ("Equal", "="),
+    ("Esc", "Escape"),
+    ("Return", "Enter"),
+];
+
+/// キー表記を解析（大文字小文字を区別しない）
+fn parse_key(name: &str) -> Option<(&'static str, Code)> {
+    let name = KEY_ALIASES
+        .iter()
+        .find(|(alias, _)| alias.eq_ignore_ascii_case(name))
+        .map_or(name, |(_, canonical)| *canonical);
+    KEYS.iter()
+        .find(|(key, _)| key.eq_ignore_ascii_case(name))
+        .copied()
+}
+
+/// グローバルショートカットのキーの組み合わせ（例: `CommandOrControl+Shift+K`）
+///
+/// 修飾キーは重複を除いて表記順に並べ、キー名は正規化した表記で保持します。
+/// `to_string()` の結果を `parse` すると同じ値になります。
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Accelerator {
+    modifiers: Vec<ModifierKey>,
+    key: &'static str,
+    code: Code,
+}
+
+impl Accelerator {
+    /// 文字列を解析
+    ///
+    /// ファンクションキー以外は、Shift以外の修飾キーを1つ以上含む必要があります
+    /// （文字入力を横取りしないため）。
+    pub fn parse(accelerator: &str) -> Result<Self> {
+        let invalid = |reason: String| {
+            WorkNoteError::ShortcutError(format!("Invalid shortcut {:?}: {}", accelerator, reason))
+        };
+
+        // `+` 自体は区切り文字のため、キーとしては `=`（Shift+`=`）などで指定する
+        let parts: Vec<&str> = accelerator.split('+').map(str::trim).collect();
+        if parts.iter().any(|part| part.is_empty()) {
+            return Err(invalid("empty key name".to_string()));
+        }
+        let Some((key_name, modifier_names)) = parts.split_last() else {
+            return Err(invalid("empty key name".to_string()));
+        };
+
+        let mut modifiers = Vec::new();
+        for name in modifier_names {
+            let modifier = ModifierKey::parse(name).ok_or_else(|| {
+                if parse_key(name).is_some() {
+                    invalid(format!("only one non-modifier key is allowed ({})", name))
+                } else {
+                    invalid(format!("unknown modifier {:?}", name))
+                }
+            })?;
+            modifiers.push(modifier);
+        }
+        modifiers.sort();
+        modifiers.dedup();
+
+        let (key, code) = parse_key(key_name).ok_or_else(|| {
+            if ModifierKey::parse(key_name).is_some() {
+                invalid("the last key must not be a modifier".to_string())
+            } else {
+                invalid(format!("unknown key {:?}", key_name))
+            }
+        })?;
+
+        let is_function_key = key.len() > 1 && key.starts_with('F');
+        if !is_function_key && modifiers.iter().all(|m| *m == ModifierKey::Shift) {
+            return Err(invalid(
+                "a modifier other than Shift is required (except for function keys)".to_string(),
+            ));
+        }
+
+        Ok(Accelerator {
+            modifiers,
+            key,
+            code,
+        })
+    }
+
+    pub fn modifiers(&self) -> &[ModifierKey] {
+        &self.modifiers
+    }
+
+    /// 正規化したキー名（例: `K`・`F5`・`/`）
+    pub fn key(&self) -> &str {
+        self.key
+    }
+
+    /// グローバルショートカットとして登録する値（`CommandOrControl` は実行環境に合わせて変換）
+    pub fn to_shortcut(&self) -> Shortcut {
+        let modifiers = self
+            .modifiers
+            .iter()
+            .fold(Modifiers::empty(), |acc, m| acc | m.modifiers());
+        Shortcut::new(Some(modifiers).filter(|m| !m.is_empty()), self.code)
+    }
+}
+
+impl fmt::Display for Accelerator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for modifier in &self.modifiers {
+            write!(f, "{}+", modifier.name())?;
+        }
+        f.write_str(self.key)
+    }
+}
+
+impl FromStr for Accelerator {
+    type Err = WorkNoteError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Accelerator::parse(s)
+    }
+}
+
+/// ショートカット設定を解析し、同じキーの組み合わせが複数の操作に割り当てられていないか検証
+///
+/// `CommandOrControl` と `Control` のように実行環境で同じキーになる組み合わせも重複とみなします。
+pub fn parse_bindings(shortcuts: &ShortcutsConfig) -> Result<Vec<(ShortcutAction, Accelerator)>> {
+    let mut bindings: Vec<(ShortcutAction, Accelerator)> = Vec::new();
+    for (action, value) in shortcuts.bindings() {
+        let accelerator = Accelerator::parse(value).map_err(|e| match e {
+            WorkNoteError::ShortcutError(msg) => {
+                WorkNoteError::ShortcutError(format!("{}: {}", action.label(), msg))
+            }
+            other => other,
+        })?;
+        let id = accelerator.to_shortcut().id();
+        if let Some((other, _)) = bindings
+            .iter()
+            .find(|(_, existing)| existing.to_shortcut().id() == id)
+        {
+            return Err(WorkNoteError::ShortcutError(format!(
+                "Shortcut conflict: {} is assigned to both {} and {}",
+                accelerator,
+                other.label(),
+                action.label()
+            )));
+        }
+        bindings.push((action, accelerator));
+    }
+    Ok(bindings)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_format() {
+        let accelerator = Accelerator::parse("shift + cmdorctrl+k").unwrap();
+        assert_eq!(
+            accelerator.modifiers(),
+            [ModifierKey::CommandOrControl, ModifierKey::Shift]
+        );
+        assert_eq!(accelerator.to_string(), "CommandOrControl+Shift+K");
+
+        // 正規化した表記は解析し直しても同じ値になる
+        for input in [
+            "Ctrl+Alt+Delete",
+            "Alt+F5",
+            "F13",
+            "Super+Space",
+            "Control+Shift+/",
+            "Alt+Comma",
+            "CommandOrControl+0",
+            "Alt+ArrowLeft",
+            "Ctrl+Esc",
+            "Alt+\\",
+        ] {
+            let parsed = Accelerator::parse(input).unwrap();
+            assert_eq!(Accelerator::parse(&parsed.to_string()).unwrap(), parsed);
+        }
+        assert_eq!(
+            Accelerator::parse("Alt+Comma").unwrap().to_string(),
+            "Alt+,"
+        );
+        assert_eq!(
+            Accelerator::parse("Alt+ArrowLeft").unwrap().to_string(),
+            "Alt+Left"
+        );
+        assert_eq!(
+            Accelerator::parse("Alt+K").unwrap().to_shortcut(),
+            Shortcut::new(Some(Modifiers::ALT), Code::KeyK)
+        );
+        assert_eq!(
+            Accelerator::parse("F13").unwrap().to_shortcut(),
+            Shortcut::new(None, Code::F13)
+        );
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        for input in [
+            "",
+            "J",
+            "Shift+J",
+            "Ctrl+",
+            "Ctrl++",
+            "Hyper+J",
+            "Ctrl+F25",
+            "Ctrl+J+K",
+            "Ctrl+Shift",
+        ] {
+            assert!(Accelerator::parse(input).is_err(), "{:?}", input);
+        }
+        let message = Accelerator::parse("Ctrl+J+K").unwrap_err().to_string();
+        assert!(message.contains("only one non-modifier key"));
+    }
+
+    #[test]
+    fn test_parse_bindings_conflict() {
+        let mut shortcuts = ShortcutsConfig {
+            search_runbooks: Some("Alt+Shift+F".to_string()),
+            ..Default::default()
+        };
+        let bindings = parse_bindings(&shortcuts).unwrap();
+        assert_eq!(bindings.len(), 2);
+        assert_eq!(bindings[1].0, ShortcutAction::SearchRunbooks);
+
+        // 表記が異なっても同じキーの組み合わせは重複
+        shortcuts.capture_clipboard = Some("shift+alt+f".to_string());
+        let message = parse_bindings(&shortcuts).unwrap_err().to_string();
+        assert!(message.contains("Shortcut conflict: Alt+Shift+F"));
+
+        shortcuts.capture_clipboard = Some(String::new());
+        assert_eq!(parse_bindings(&shortcuts).unwrap().len(), 2);
+
+        shortcuts.quick_input = "Hyper+J".to_string();
+        assert!(parse_bindings(&shortcuts).is_err());
+    }
+}
@@ -0,0 +1,245 @@
+use crate::models::taxonomy::LEGACY_CATEGORY_ALIASES;
+use crate::models::{
+    Category, CategoryMigrationReport, MigratedFile, Result, SkippedMigration, TaxonomyConfig,
+    WorkNoteError,
+};
+use crate::services::runbook_parser::RunbookParser;
+use crate::services::RunbookRepository;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// CategoryMigrator - 既存Runbookをカテゴリ定義に合わせて移行
+///
+/// 廃止済みカテゴリ（`maintenance` など）のフロントマターを移行先に書き換え、
+/// カテゴリ定義のディレクトリへファイルを移動します。コミットは行いません。
+pub struct CategoryMigrator {
+    repository: RunbookRepository,
+    taxonomy: TaxonomyConfig,
+}
+
+impl CategoryMigrator {
+    /// 新しいCategoryMigratorインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `save_dir` - Runbook保存先ディレクトリ（リポジトリパス + save_path）
+    /// * `taxonomy` - 移行先のカテゴリ定義
+    pub fn new(save_dir: impl Into<PathBuf>, taxonomy: TaxonomyConfig) -> Self {
+        CategoryMigrator {
+            repository: RunbookRepository::new(save_dir.into()),
+            taxonomy,
+        }
+    }
+
+    /// 移行を実行
+    ///
+    /// # Arguments
+    /// * `dry_run` - trueの場合はファイルを変更せず、移行内容のみ返す
+    pub fn migrate(&self, dry_run: bool) -> Result<CategoryMigrationReport> {
+        let mut report = CategoryMigrationReport {
+            dry_run,
+            ..Default::default()
+        };
+
+        for path in self.repository.runbook_files()? {
+            report.files_checked += 1;
+            let relative = self.repository.relative_path(&path);
+            let content = fs::read_to_string(&path).map_err(|e| {
+                WorkNoteError::FileError(format!("Failed to read {}: {}", relative, e))
+            })?;
+
+            let Some(category) = RunbookParser::parse(&content)
+                .ok()
+                .and_then(|document| document.get("category").map(str::to_string))
+            else {
+                continue;
+            };
+
+            let replacement = LEGACY_CATEGORY_ALIASES
+                .iter()
+                .find(|(legacy, _)| *legacy == category)
+                .map(|(_, replacement)| *replacement);
+            let target_category = replacement.unwrap_or(&category);
+            let Ok(parsed) = Category::try_from(target_category.to_string()) else {
+                continue;
+            };
+            if self.taxonomy.category(&parsed).is_none() {
+                report.skipped.push(SkippedMigration {
+                    path: relative,
+                    reason: format!("Unknown category: {}", category),
+                });
+                continue;
+            }
+
+            let file_name = path.file_name().unwrap_or_default();
+            let target = self
+                .repository
+                .save_dir()
+                .join(self.taxonomy.directory_for(&parsed))
+                .join(file_name);
+            if replacement.is_none() && target == path {
+                continue;
+            }
+            if target != path && target.exists() {
+                report.skipped.push(SkippedMigration {
+                    path: relative,
+                    reason: format!(
+                        "Destination already exists: {}",
+                        self.repository.relative_path(&target)
+                    ),
+                });
+                continue;
+            }
+
+            if !dry_run {
+                let content = match replacement {
+                    Some(replacement) => rewrite_category(&content, replacement),
+                    None => content,
+                };
+                Self::write(&path, &target, &content)?;
+            }
+
+            report.migrated.push(MigratedFile {
+                from: relative,
+                to: self.repository.relative_path(&target),
+                category_rewritten: replacement.is_some(),
+            });
+        }
+
+        if !dry_run {
+            self.remove_empty_dirs();
+        }
+        Ok(report)
+    }
+
+    /// 移行後の内容を書き込み、移動元を削除
+    fn write(path: &Path, target: &Path, content: &str) -> Result<()> {
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent).map_err(|e| {
+                WorkNoteError::FileError(format!("Failed to create directory: {}", e))
+            })?;
+        }
+        fs::write(target, content)
+            .map_err(|e| WorkNoteError::FileError(format!("Failed to write file: {}", e)))?;
+        if target != path {
+            fs::remove_file(path)
+                .map_err(|e| WorkNoteError::FileError(format!("Failed to remove file: {}", e)))?;
+        }
+        Ok(())
+    }
+
+    /// 移行で空になったカテゴリディレクトリを削除（インデックスのみ残る場合も含む）
+    fn remove_empty_dirs(&self) {
+        let Ok(dirs) = self.repository.category_dirs() else {
+            return;
+        };
+        for dir in dirs {
+            let name = dir.file_name().and_then(|s| s.to_str()).unwrap_or_default();
+            if self.taxonomy.category_by_directory(name).is_some() {
+                continue;
+            }
+            if fs::read_dir(&dir).is_ok_and(|mut entries| entries.next().is_none()) {
+                let _ = fs::remove_dir(&dir);
+            }
+        }
+    }
+}
+
+/// フロントマター内の `category:` 行を書き換え
+fn rewrite_category(content: &str, category: &str) -> String {
+    let mut in_frontmatter = false;
+    let mut done = false;
+    let mut lines = Vec::new();
+    for (index, line) in content.split_inclusive('\n').enumerate() {
+        let trimmed = line.trim_end();
+        if trimmed.trim_start_matches('\u{feff}') == "---" {
+            in_frontmatter = index == 0;
+        } else if in_frontmatter && !done && trimmed.starts_with("category:") {
+            let newline = &line[trimmed.len()..];
+            lines.push(format!("category: {}{}", category, newline));
+            done = true;
+            continue;
+        }
+        lines.push(line.to_string());
+    }
+    lines.concat()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::CategoryDefinition;
+    use std::env;
+
+    fn setup(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("worknote_migrator_{}", name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("maintenance")).unwrap();
+        fs::create_dir_all(dir.join("ops")).unwrap();
+        fs::create_dir_all(dir.join("alerts")).unwrap();
+        dir
+    }
+
+    fn runbook(category: &str) -> String {
+        format!(
+            "---\ntitle: 証明書更新\ncategory: {}\nseverity: low\n---\n\n# 証明書更新\n\ncategory: 本文はそのまま\n",
+            category
+        )
+    }
+
+    #[test]
+    fn test_migrate_legacy_category() {
+        let dir = setup("legacy");
+        fs::write(dir.join("maintenance/cert.md"), runbook("maintenance")).unwrap();
+        fs::write(dir.join("maintenance/dup.md"), runbook("maintenance")).unwrap();
+        fs::write(dir.join("ops/dup.md"), runbook("ops")).unwrap();
+        fs::write(dir.join("alerts/cpu.md"), runbook("alerts")).unwrap();
+
+        let migrator = CategoryMigrator::new(&dir, TaxonomyConfig::default());
+        let report = migrator.migrate(true).unwrap();
+        assert_eq!(report.files_checked, 4);
+        assert_eq!(report.migrated.len(), 1);
+        assert!(dir.join("maintenance/cert.md").exists());
+
+        let report = migrator.migrate(false).unwrap();
+        assert_eq!(
+            report.migrated,
+            vec![MigratedFile {
+                from: "maintenance/cert.md".to_string(),
+                to: "ops/cert.md".to_string(),
+                category_rewritten: true,
+            }]
+        );
+        assert_eq!(report.skipped.len(), 1);
+        assert_eq!(report.skipped[0].path, "maintenance/dup.md");
+
+        // 本文中の `category:` 行は書き換えない
+        let content = fs::read_to_string(dir.join("ops/cert.md")).unwrap();
+        assert_eq!(content, runbook("ops"));
+        assert!(!dir.join("maintenance/cert.md").exists());
+    }
+
+    #[test]
+    fn test_migrate_to_custom_directory() {
+        let dir = setup("directory");
+        fs::write(dir.join("ops/backup.md"), runbook("ops")).unwrap();
+
+        let mut taxonomy = TaxonomyConfig::default();
+        taxonomy.categories[1] = CategoryDefinition {
+            directory: "operations".to_string(),
+            ..taxonomy.categories[1].clone()
+        };
+        let report = CategoryMigrator::new(&dir, taxonomy)
+            .migrate(false)
+            .unwrap();
+        assert_eq!(report.migrated.len(), 1);
+        assert!(!report.migrated[0].category_rewritten);
+        assert_eq!(
+            fs::read_to_string(dir.join("operations/backup.md")).unwrap(),
+            runbook("ops")
+        );
+        // 空になった旧ディレクトリは削除、定義済みディレクトリは残す
+        assert!(!dir.join("ops").exists());
+        assert!(!dir.join("maintenance").exists());
+        assert!(dir.join("alerts").exists());
+    }
+}
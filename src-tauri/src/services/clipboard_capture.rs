@@ -0,0 +1,155 @@
+use crate::models::{Category, Draft, KnowledgeInput, Result, Severity, WorkNoteError};
+use crate::services::DraftManager;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// クリップボードを読み取るコマンド（先頭から順に試す）
+#[cfg(target_os = "macos")]
+const CLIPBOARD_COMMANDS: &[(&str, &[&str])] = &[("pbpaste", &[])];
+
+/// クリップボードを読み取るコマンド（先頭から順に試す）
+#[cfg(target_os = "windows")]
+const CLIPBOARD_COMMANDS: &[(&str, &[&str])] = &[(
+    "powershell",
+    &[
+        "-NoProfile",
+        "-NonInteractive",
+        "-Command",
+        "Get-Clipboard -Raw",
+    ],
+)];
+
+/// クリップボードを読み取るコマンド（先頭から順に試す。Wayland → X11）
+#[cfg(not(any(target_os = "macos", target_os = "windows")))]
+const CLIPBOARD_COMMANDS: &[(&str, &[&str])] = &[
+    ("wl-paste", &["--no-newline", "--type", "text/plain"]),
+    ("xclip", &["-selection", "clipboard", "-out"]),
+    ("xsel", &["--clipboard", "--output"]),
+];
+
+/// タイトルの最大文字数（先頭行から生成）
+const TITLE_MAX_CHARS: usize = 60;
+
+/// ClipboardCapture - クリップボードのテキストから下書きを作成
+///
+/// クリップボードはOS標準のコマンド（`pbpaste`・`wl-paste`・`xclip`・`xsel`・PowerShell）で読み取ります。
+pub struct ClipboardCapture;
+
+impl ClipboardCapture {
+    /// クリップボードのテキストを読み取って下書きを作成
+    ///
+    /// # Arguments
+    /// * `app_data_dir` - アプリケーションデータディレクトリ（下書きの保存先）
+    pub fn capture(app_data_dir: PathBuf) -> Result<Draft> {
+        let text = Self::read_text()?;
+        let input = Self::build_input(&text)?;
+        DraftManager::new(app_data_dir).create_draft(input)
+    }
+
+    /// クリップボードのテキストを読み取る
+    pub fn read_text() -> Result<String> {
+        Self::read_with(CLIPBOARD_COMMANDS)
+    }
+
+    /// 指定したコマンドを順に試し、最初に成功した出力を返す
+    fn read_with(commands: &[(&str, &[&str])]) -> Result<String> {
+        let mut failures = Vec::new();
+        for (program, args) in commands {
+            match Command::new(program).args(*args).output() {
+                Ok(output) if output.status.success() => {
+                    return Ok(String::from_utf8_lossy(&output.stdout).into_owned());
+                }
+                Ok(output) => failures.push(format!(
+                    "{}: {}",
+                    program,
+                    String::from_utf8_lossy(&output.stderr).trim()
+                )),
+                Err(e) if e.kind() == ErrorKind::NotFound => {
+                    failures.push(format!("{}: not installed", program))
+                }
+                Err(e) => failures.push(format!("{}: {}", program, e)),
+            }
+        }
+
+        Err(WorkNoteError::FileError(format!(
+            "Failed to read the clipboard ({})",
+            failures.join("; ")
+        )))
+    }
+
+    /// クリップボードのテキストから下書きの入力データを作成
+    ///
+    /// 先頭行をタイトル、全文を症状とします。カテゴリ・重要度は下書きを開いてから選び直す前提の既定値です。
+    pub fn build_input(text: &str) -> Result<KnowledgeInput> {
+        let text = text.trim();
+        let Some(first_line) = text.lines().map(str::trim).find(|l| !l.is_empty()) else {
+            return Err(WorkNoteError::ValidationError(
+                "Clipboard does not contain text".to_string(),
+            ));
+        };
+
+        let title = if first_line.chars().count() > TITLE_MAX_CHARS {
+            format!(
+                "{}…",
+                first_line.chars().take(TITLE_MAX_CHARS).collect::<String>()
+            )
+        } else {
+            first_line.to_string()
+        };
+
+        Ok(KnowledgeInput {
+            id: None,
+            title,
+            category: Category::Troubleshooting,
+            severity: Severity::Medium,
+            symptoms: text.to_string(),
+            procedure: String::new(),
+            notes: None,
+            related_links: None,
+            judgment: None,
+            slug: None,
+            related_alerts: Vec::new(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_build_input() {
+        let input = ClipboardCapture::build_input(
+            "\n  502 Bad Gateway が多発  \nnginx の error.log に upstream timed out\n",
+        )
+        .unwrap();
+        assert_eq!(input.title, "502 Bad Gateway が多発");
+        assert!(input.symptoms.starts_with("502 Bad Gateway"));
+        assert!(input.symptoms.ends_with("upstream timed out"));
+
+        let long = "あ".repeat(TITLE_MAX_CHARS + 5);
+        let input = ClipboardCapture::build_input(&long).unwrap();
+        assert_eq!(input.title.chars().count(), TITLE_MAX_CHARS + 1);
+
+        assert!(ClipboardCapture::build_input(" \n\t").is_err());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_read_with_fallback() {
+        // 見つからないコマンド・失敗したコマンドは飛ばして次を試す
+        let text = ClipboardCapture::read_with(&[
+            ("worknote-missing-clipboard-tool", &[]),
+            ("false", &[]),
+            ("printf", &["コピーした内容"]),
+        ])
+        .unwrap();
+        assert_eq!(text, "コピーした内容");
+
+        let error = ClipboardCapture::read_with(&[("worknote-missing-clipboard-tool", &[])])
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("worknote-missing-clipboard-tool: not installed"));
+    }
+}
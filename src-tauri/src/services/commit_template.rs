@@ -0,0 +1,342 @@
+use crate::models::{CommitTrailers, Result, WorkNoteError};
+use crate::services::file_naming::FileNamer;
+use chrono::{DateTime, Local};
+
+/// コミットメッセージ・ブランチ名の書式で使える変数
+const TEMPLATE_VARIABLES: &[&str] = &[
+    "title",
+    "category",
+    "severity",
+    "author",
+    "date",
+    "id",
+    "profile",
+    "timestamp",
+];
+
+/// タイトルからslugを生成できない場合のブランチ名のslug
+const FALLBACK_TITLE_SLUG: &str = "knowledge";
+
+/// 書式の変数に埋め込む値
+#[derive(Debug, Clone)]
+pub struct CommitContext {
+    pub title: String,
+    pub category: String,
+    pub severity: String,
+    /// Author名
+    pub author: String,
+    /// RunbookのID（未設定時は空文字列）
+    pub id: Option<String>,
+    /// 保存先プロファイル名
+    pub profile: String,
+    /// 保存日時（`{date}`・`{timestamp}` に使用）
+    pub time: DateTime<Local>,
+}
+
+impl CommitContext {
+    /// 変数の値を取得（ブランチ名の場合はref名に使える形式に変換）
+    fn value(&self, name: &str, for_branch: bool) -> Option<String> {
+        let value = match name {
+            "date" => return Some(self.time.format("%Y-%m-%d").to_string()),
+            "timestamp" => return Some(self.time.format("%Y%m%d-%H%M%S").to_string()),
+            "title" => &self.title,
+            "category" => &self.category,
+            "severity" => &self.severity,
+            "author" => &self.author,
+            "id" => self.id.as_deref().unwrap_or_default(),
+            "profile" => &self.profile,
+            _ => return None,
+        };
+        if !for_branch {
+            return Some(value.to_string());
+        }
+
+        // 日本語のタイトル・Author名はローマ字に変換する
+        let slug = FileNamer::romaji_slug(value);
+        if slug.is_empty() && name == "title" {
+            Some(FALLBACK_TITLE_SLUG.to_string())
+        } else {
+            Some(slug)
+        }
+    }
+}
+
+/// CommitTemplate - コミットメッセージ・ブランチ名の書式を展開・検証
+///
+/// 書式では `{title}`・`{category}`・`{severity}`・`{author}`・`{date}`・`{id}`・`{profile}`・
+/// `{timestamp}` を使えます。
+pub struct CommitTemplate;
+
+impl CommitTemplate {
+    /// コミットメッセージを生成
+    ///
+    /// trailer（課題キー・共同作成者）は末尾に追加します。書式の最終段落がtrailerの場合はその続きに、
+    /// そうでない場合は空行を挟んで追加します。
+    pub fn render_message(
+        template: &str,
+        context: &CommitContext,
+        trailers: &CommitTrailers,
+    ) -> String {
+        let message = Self::render(template, |name| context.value(name, false));
+        let lines = trailers.lines();
+        if lines.is_empty() {
+            return message;
+        }
+
+        let message = message.trim_end();
+        let separator = match message.rsplit_once("\n\n") {
+            Some((_, last)) if last.lines().all(Self::is_trailer_line) => "\n",
+            _ => "\n\n",
+        };
+        format!("{}{}{}", message, separator, lines.join("\n"))
+    }
+
+    /// ブランチ名を生成（変数の値はslugに変換し、生成結果をref名の規則で検証）
+    pub fn render_branch_name(template: &str, context: &CommitContext) -> Result<String> {
+        let name = Self::render(template, |name| context.value(name, true));
+        Self::validate_ref_name(&name).map_err(|e| match e {
+            WorkNoteError::ValidationError(msg) => WorkNoteError::ValidationError(format!(
+                "Branch name template {:?} produced an invalid name: {}",
+                template, msg
+            )),
+            other => other,
+        })?;
+        Ok(name)
+    }
+
+    /// コミットメッセージの書式を検証
+    pub fn validate_message(template: &str) -> Result<()> {
+        if template.trim().is_empty() {
+            return Err(WorkNoteError::ValidationError(
+                "Commit message format is empty".to_string(),
+            ));
+        }
+        Self::validate_variables(template, "commit message format")
+    }
+
+    /// ブランチ名の書式を検証（サンプルの値で展開した結果がref名として有効か）
+    ///
+    /// 複数のナレッジをまとめて公開する場合は `{id}` が空になるため、IDなしでも検証します。
+    pub fn validate_branch_name(template: &str) -> Result<()> {
+        if template.trim().is_empty() {
+            return Err(WorkNoteError::ValidationError(
+                "Branch name format is empty".to_string(),
+            ));
+        }
+        Self::validate_variables(template, "branch name format")?;
+
+        let mut sample = CommitContext {
+            title: "CPU高騰対応".to_string(),
+            category: "alerts".to_string(),
+            severity: "high".to_string(),
+            author: "Alice".to_string(),
+            id: Some("rb-0001".to_string()),
+            profile: "default".to_string(),
+            time: Local::now(),
+        };
+        Self::render_branch_name(template, &sample)?;
+        sample.id = None;
+        Self::render_branch_name(template, &sample).map(|_| ())
+    }
+
+    /// ref名（ブランチ名）を `git check-ref-format` の規則で検証
+    pub fn validate_ref_name(name: &str) -> Result<()> {
+        let invalid = |reason: &str| {
+            Err(WorkNoteError::ValidationError(format!(
+                "Invalid branch name {:?}: {}",
+                name, reason
+            )))
+        };
+
+        if name.is_empty() || name == "@" {
+            return invalid("must not be empty or '@'");
+        }
+        if let Some(c) = name
+            .chars()
+            .find(|c| c.is_ascii_control() || " ~^:?*[\\".contains(*c))
+        {
+            return invalid(&format!("must not contain {:?}", c));
+        }
+        if name.contains("..") || name.contains("@{") {
+            return invalid("must not contain '..' or '@{'");
+        }
+        if name.starts_with('-') {
+            return invalid("must not start with '-'");
+        }
+        if name.ends_with('.') {
+            return invalid("must not end with '.'");
+        }
+        for component in name.split('/') {
+            if component.is_empty() {
+                return invalid("must not start or end with '/' or contain '//'");
+            }
+            if component.starts_with('.') || component.ends_with(".lock") {
+                return invalid("path components must not start with '.' or end with '.lock'");
+            }
+        }
+
+        Ok(())
+    }
+
+    /// 書式の変数を検証（未定義の変数・閉じていない `{`）
+    fn validate_variables(template: &str, kind: &str) -> Result<()> {
+        let mut rest = template;
+        while let Some(start) = rest.find('{') {
+            let end = rest[start..].find('}').ok_or_else(|| {
+                WorkNoteError::ValidationError(format!("Unclosed '{{' in {}: {}", kind, template))
+            })?;
+            let name = &rest[start + 1..start + end];
+            if !TEMPLATE_VARIABLES.contains(&name) {
+                return Err(WorkNoteError::ValidationError(format!(
+                    "Unknown variable '{{{}}}' in {}. Available: {}",
+                    name,
+                    kind,
+                    TEMPLATE_VARIABLES
+                        .iter()
+                        .map(|v| format!("{{{}}}", v))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )));
+            }
+            rest = &rest[start + end + 1..];
+        }
+        Ok(())
+    }
+
+    /// 書式の変数を展開（値に含まれる `{...}` は展開しない）
+    fn render(template: &str, value: impl Fn(&str) -> Option<String>) -> String {
+        let mut result = String::new();
+        let mut rest = template;
+        while let Some(start) = rest.find('{') {
+            result.push_str(&rest[..start]);
+            let Some(end) = rest[start..].find('}') else {
+                rest = &rest[start..];
+                break;
+            };
+            let token = &rest[start..start + end + 1];
+            match value(&token[1..token.len() - 1]) {
+                Some(v) => result.push_str(&v),
+                None => result.push_str(token),
+            }
+            rest = &rest[start + end + 1..];
+        }
+        result.push_str(rest);
+        result
+    }
+
+    /// trailerの行（`Key: value`）かどうか
+    fn is_trailer_line(line: &str) -> bool {
+        line.split_once(": ").is_some_and(|(key, _)| {
+            !key.is_empty() && key.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn context() -> CommitContext {
+        CommitContext {
+            title: "DB接続エラー対応".to_string(),
+            category: "troubleshooting".to_string(),
+            severity: "high".to_string(),
+            author: "Taro Yamada".to_string(),
+            id: None,
+            profile: "platform".to_string(),
+            time: Local.with_ymd_and_hms(2026, 1, 31, 9, 5, 0).unwrap(),
+        }
+    }
+
+    #[test]
+    fn test_render_message() {
+        let template = "docs(worknote): add {title}\n\nCategory: {category}\nSeverity: {severity}";
+        let trailers = CommitTrailers {
+            issue_key: Some("OPS-42".to_string()),
+            co_authors: vec!["Bob <bob@example.com>".to_string()],
+        };
+        assert_eq!(
+            CommitTemplate::render_message(template, &context(), &trailers),
+            "docs(worknote): add DB接続エラー対応\n\nCategory: troubleshooting\nSeverity: high\nIssue: OPS-42\nCo-authored-by: Bob <bob@example.com>"
+        );
+
+        // 最終段落がtrailerでなければ空行を挟む。値に含まれる変数は展開しない
+        let mut context = context();
+        context.title = "{severity} の誤検知".to_string();
+        assert_eq!(
+            CommitTemplate::render_message(
+                "{profile}: {title} by {author} ({date})",
+                &context,
+                &trailers
+            ),
+            "platform: {severity} の誤検知 by Taro Yamada (2026-01-31)\n\nIssue: OPS-42\nCo-authored-by: Bob <bob@example.com>"
+        );
+        assert_eq!(
+            CommitTemplate::render_message("{title}", &context, &CommitTrailers::default()),
+            "{severity} の誤検知"
+        );
+        assert_eq!(
+            CommitTemplate::render_message("{title", &context, &CommitTrailers::default()),
+            "{title"
+        );
+    }
+
+    #[test]
+    fn test_render_branch_name() {
+        assert_eq!(
+            CommitTemplate::render_branch_name("feature/worknote-{title}-{timestamp}", &context())
+                .unwrap(),
+            "feature/worknote-db-setsuzoku-era-taiou-20260131-090500"
+        );
+        assert_eq!(
+            CommitTemplate::render_branch_name("runbook/{author}/{category}-{date}", &context())
+                .unwrap(),
+            "runbook/taro-yamada/troubleshooting-2026-01-31"
+        );
+
+        // slugにできないタイトルは既定のslug、空になった変数でref名が壊れる場合はエラー
+        let mut context = context();
+        context.title = "！！".to_string();
+        assert_eq!(
+            CommitTemplate::render_branch_name("wn/{title}", &context).unwrap(),
+            "wn/knowledge"
+        );
+        assert!(CommitTemplate::render_branch_name("wn/{id}/{title}", &context).is_err());
+    }
+
+    #[test]
+    fn test_validate_templates() {
+        assert!(CommitTemplate::validate_message("{title}\n\nRefs: {id}").is_ok());
+        assert!(CommitTemplate::validate_message(" ").is_err());
+        assert!(CommitTemplate::validate_message("{ticket}: {title}").is_err());
+        assert!(CommitTemplate::validate_message("{title").is_err());
+
+        assert!(
+            CommitTemplate::validate_branch_name("feature/worknote-{title}-{timestamp}").is_ok()
+        );
+        assert!(CommitTemplate::validate_branch_name("{profile}/{date}/{title}").is_ok());
+        for template in [
+            "feature/{title} draft",
+            "feature/.{title}",
+            "feature//{title}",
+            "{title}.lock",
+            "feature/{title}/",
+            "feature..{title}",
+            "-{title}",
+            "feature/{branch}",
+            "wn/{id}",
+        ] {
+            assert!(
+                CommitTemplate::validate_branch_name(template).is_err(),
+                "{}",
+                template
+            );
+        }
+
+        assert!(CommitTemplate::validate_ref_name("feature/worknote-abc").is_ok());
+        assert!(CommitTemplate::validate_ref_name("@").is_err());
+        assert!(CommitTemplate::validate_ref_name("a@{1}").is_err());
+        assert!(CommitTemplate::validate_ref_name("a:b").is_err());
+    }
+}
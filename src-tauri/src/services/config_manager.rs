@@ -1,6 +1,19 @@
-use crate::models::{Config, WorkNoteError, Result};
+use crate::models::config::DEFAULT_PROFILE_NAME;
+use crate::models::knowledge::OPTIONAL_FIELDS;
+use crate::models::repo_config::REPO_CONFIG_FILE_NAME;
+use crate::models::{
+    AuthorConfig, Config, EffectiveConfig, GitConfig, NamingConfig, NamingScheme, RepoConfig,
+    Result, WorkNoteError,
+};
+use crate::services::accelerator;
+use crate::services::commit_template::CommitTemplate;
+use crate::services::config_migration;
+use crate::services::file_naming::FileNamer;
+use serde_json::Value;
+use std::collections::HashSet;
 use std::fs;
 use std::path::{Path, PathBuf};
+use tracing::info;
 
 /// 設定ファイル名
 const CONFIG_FILE_NAME: &str = "config.json";
@@ -28,7 +41,8 @@ impl ConfigManager {
 
     /// 設定を読み込む
     ///
-    /// ファイルが存在しない場合はデフォルト値を返す
+    /// ファイルが存在しない場合はデフォルト値を返す。
+    /// 古いバージョンの設定は現在のバージョンまで移行し、移行前のファイルをバックアップしてから書き換える。
     pub fn load_config(&self) -> Result<Config> {
         let path = self.config_path();
 
@@ -41,10 +55,141 @@ impl ConfigManager {
             WorkNoteError::ConfigError(format!("Failed to read config file: {}", e))
         })?;
 
-        let config: Config = serde_json::from_str(&content).map_err(|e| {
+        let mut value: Value = serde_json::from_str(&content).map_err(|e| {
             WorkNoteError::ConfigError(format!("Failed to parse config file: {}", e))
         })?;
+        let migrated_from = config_migration::migrate(&mut value)?;
 
+        let config: Config = serde_json::from_value(value).map_err(|e| {
+            WorkNoteError::ConfigError(format!("Failed to parse config file: {}", e))
+        })?;
+
+        if let Some(version) = migrated_from {
+            let backup = self.backup_config(version)?;
+            self.write_config(&config)?;
+            info!(
+                "Migrated config from version {} to {} (backup: {})",
+                version,
+                config.version,
+                backup.display()
+            );
+        }
+
+        Ok(config)
+    }
+
+    /// 移行前の設定ファイルをバックアップ（`config.v1.json.bak` など）
+    ///
+    /// 同じバージョンのバックアップが既にある場合は上書きせず、日時付きの名前にする。
+    fn backup_config(&self, version: u32) -> Result<PathBuf> {
+        let mut backup = self
+            .config_dir
+            .join(format!("config.v{}.json.bak", version));
+        if backup.exists() {
+            backup = self.config_dir.join(format!(
+                "config.v{}.{}.json.bak",
+                version,
+                chrono::Local::now().format("%Y%m%d%H%M%S")
+            ));
+        }
+        fs::copy(self.config_path(), &backup).map_err(|e| {
+            WorkNoteError::ConfigError(format!("Failed to back up config file: {}", e))
+        })?;
+        Ok(backup)
+    }
+
+    /// リポジトリ共有設定（`.worknote.toml`）を重ねた設定を読み込む
+    ///
+    /// 保存・添削などの処理ではこちらを使います。設定画面で編集するのは
+    /// `load_config` のローカル設定のみで、共有設定の値は保存しません。
+    pub fn load_effective_config(&self) -> Result<EffectiveConfig> {
+        self.load_profile_config(None)
+    }
+
+    /// プロファイルを指定して、共有設定を重ねた設定を読み込む
+    ///
+    /// `profile` が `None` の場合は使用中のプロファイルを使います。
+    /// `.worknote.toml` はプロファイルのリポジトリから読み込みます。
+    pub fn load_profile_config(&self, profile: Option<&str>) -> Result<EffectiveConfig> {
+        let config = self.load_config()?.resolve_profile(profile)?;
+        if config.git.repository_path.is_empty() {
+            return Ok(EffectiveConfig {
+                config,
+                repo_config_path: None,
+                managed_keys: Vec::new(),
+            });
+        }
+
+        let repository = PathBuf::from(&config.git.repository_path);
+        Self::apply_repo_config(config, &repository)
+    }
+
+    /// 指定したパスを含むリポジトリのルートを探す
+    ///
+    /// `path` から親ディレクトリをたどり、`.worknote.toml` または `.git` のある
+    /// 最初のディレクトリを返します。
+    pub fn find_repository_root(path: &Path) -> Option<PathBuf> {
+        let path = path.canonicalize().ok()?;
+        path.ancestors()
+            .find(|dir| dir.join(REPO_CONFIG_FILE_NAME).is_file() || dir.join(".git").exists())
+            .map(Path::to_path_buf)
+    }
+
+    /// リポジトリの `.worknote.toml` を設定に重ねる（存在しない場合はそのまま）
+    pub fn apply_repo_config(mut config: Config, repository: &Path) -> Result<EffectiveConfig> {
+        let path = repository.join(REPO_CONFIG_FILE_NAME);
+        let Some(repo_config) = Self::load_repo_config(&path)? else {
+            return Ok(EffectiveConfig {
+                config,
+                repo_config_path: None,
+                managed_keys: Vec::new(),
+            });
+        };
+
+        repo_config.apply(&mut config);
+        if config.naming.scheme == NamingScheme::Pattern {
+            FileNamer::validate_pattern(&config.naming.pattern)?;
+        }
+        CommitTemplate::validate_message(&config.git.commit_message)
+            .and_then(|_| CommitTemplate::validate_branch_name(&config.git.branch_name))
+            .map_err(|e| WorkNoteError::ConfigError(format!("{}: {}", REPO_CONFIG_FILE_NAME, e)))?;
+        config
+            .taxonomy
+            .validate()
+            .map_err(|e| WorkNoteError::ConfigError(format!("{}: {}", REPO_CONFIG_FILE_NAME, e)))?;
+
+        Ok(EffectiveConfig {
+            config,
+            repo_config_path: Some(path.to_string_lossy().to_string()),
+            managed_keys: repo_config.managed_keys(),
+        })
+    }
+
+    /// `.worknote.toml` を読み込む（存在しない場合は `None`）
+    fn load_repo_config(path: &Path) -> Result<Option<RepoConfig>> {
+        if !path.is_file() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(path).map_err(|e| {
+            WorkNoteError::ConfigError(format!("Failed to read {}: {}", REPO_CONFIG_FILE_NAME, e))
+        })?;
+        RepoConfig::parse(&content).map(Some)
+    }
+
+    /// 使用中のプロファイルを切り替えて保存する
+    ///
+    /// # Arguments
+    /// * `name` - プロファイル名（`default` でトップレベルの設定に戻す）
+    pub fn set_active_profile(&self, name: &str) -> Result<Config> {
+        let mut config = self.load_config()?;
+        if name != DEFAULT_PROFILE_NAME && !config.profiles.iter().any(|p| p.name == name) {
+            return Err(WorkNoteError::ConfigError(format!(
+                "Unknown profile: {}",
+                name
+            )));
+        }
+        config.active_profile = (name != DEFAULT_PROFILE_NAME).then(|| name.to_string());
+        self.save_config(&config)?;
         Ok(config)
     }
 
@@ -63,6 +208,11 @@ impl ConfigManager {
             })?;
         }
 
+        self.write_config(config)
+    }
+
+    /// 設定ファイルを書き込む（バリデーションなし）
+    fn write_config(&self, config: &Config) -> Result<()> {
         let path = self.config_path();
         let content = serde_json::to_string_pretty(config).map_err(|e| {
             WorkNoteError::ConfigError(format!("Failed to serialize config: {}", e))
@@ -80,38 +230,167 @@ impl ConfigManager {
     /// # Arguments
     /// * `config` - バリデーションする設定
     fn validate_config(&self, config: &Config) -> Result<()> {
+        Self::validate_repository(&config.git.repository_path)?;
+        Self::validate_profile_settings(&config.naming, &config.git, config.author.as_ref())?;
+
+        // プロファイル名の重複・予約名と各プロファイルの設定を検証
+        let mut names = HashSet::new();
+        for profile in &config.profiles {
+            let name = profile.name.as_str();
+            if name.trim() != name
+                || name.is_empty()
+                || name == DEFAULT_PROFILE_NAME
+                || !names.insert(name)
+            {
+                return Err(WorkNoteError::ValidationError(format!(
+                    "Invalid or duplicate profile name: {:?}",
+                    profile.name
+                )));
+            }
+            Self::validate_repository(&profile.git.repository_path).map_err(|e| match e {
+                WorkNoteError::ValidationError(msg) => {
+                    WorkNoteError::ValidationError(format!("Profile {}: {}", profile.name, msg))
+                }
+                other => other,
+            })?;
+            Self::validate_profile_settings(
+                profile.naming.as_ref().unwrap_or(&config.naming),
+                &profile.git,
+                profile.author.as_ref(),
+            )
+            .map_err(|e| match e {
+                WorkNoteError::ValidationError(msg) => {
+                    WorkNoteError::ValidationError(format!("Profile {}: {}", profile.name, msg))
+                }
+                other => other,
+            })?;
+        }
+        if let Some(active) = &config.active_profile {
+            if active != DEFAULT_PROFILE_NAME && !names.contains(active.as_str()) {
+                return Err(WorkNoteError::ValidationError(format!(
+                    "Unknown active profile: {}",
+                    active
+                )));
+            }
+        }
+
+        // カテゴリ・重要度の定義を検証
+        config.taxonomy.validate()?;
+
+        // 入力必須にできるのは任意項目のみ
+        if let Some(field) = config
+            .required_fields
+            .iter()
+            .find(|f| !OPTIONAL_FIELDS.contains(&f.as_str()))
+        {
+            return Err(WorkNoteError::ValidationError(format!(
+                "Unknown required field: {}",
+                field
+            )));
+        }
+
+        // ショートカットの表記と重複を検証（登録は設定の反映時に行う）
+        accelerator::parse_bindings(&config.shortcuts)?;
+
+        // Webhook受信を有効にする場合はポート番号と共有トークンを検証
+        if config.webhook.enabled && config.webhook.port == 0 {
+            return Err(WorkNoteError::ValidationError(
+                "Webhook port must be between 1 and 65535".to_string(),
+            ));
+        }
+        if config.webhook.enabled && config.webhook.token.trim().is_empty() {
+            return Err(WorkNoteError::ValidationError(
+                "Webhook token is required".to_string(),
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// リポジトリパスを検証（空でない・存在する・Gitリポジトリである）
+    fn validate_repository(repository_path: &str) -> Result<()> {
         // リポジトリパスが空でないことを確認
-        if config.git.repository_path.is_empty() {
+        if repository_path.is_empty() {
             return Err(WorkNoteError::ValidationError(
                 "Repository path is empty".to_string(),
             ));
         }
 
         // リポジトリパスが存在することを確認
-        let repo_path = Path::new(&config.git.repository_path);
+        let repo_path = Path::new(repository_path);
         if !repo_path.exists() {
             return Err(WorkNoteError::ValidationError(format!(
                 "Repository path does not exist: {}",
-                config.git.repository_path
+                repository_path
             )));
         }
 
         // .gitディレクトリが存在することを確認（Gitリポジトリであることを検証）
-        let git_dir = repo_path.join(".git");
-        if !git_dir.exists() {
+        if !repo_path.join(".git").exists() {
             return Err(WorkNoteError::ValidationError(format!(
                 "Not a Git repository: {}",
-                config.git.repository_path
+                repository_path
             )));
         }
 
         Ok(())
     }
+
+    /// プロファイルごとに切り替わる命名パターン・コミットメッセージとブランチ名の書式・Authorを検証
+    fn validate_profile_settings(
+        naming: &NamingConfig,
+        git: &GitConfig,
+        author: Option<&AuthorConfig>,
+    ) -> Result<()> {
+        // パターン命名方式の場合はパターンを検証
+        if naming.scheme == NamingScheme::Pattern {
+            FileNamer::validate_pattern(&naming.pattern)?;
+        }
+
+        CommitTemplate::validate_message(&git.commit_message)?;
+        CommitTemplate::validate_branch_name(&git.branch_name)?;
+        CommitTemplate::validate_ref_name(&git.default_branch)?;
+        // Push先が空の場合は `remote` を使う
+        let push_remote = git.push_remote.iter().filter(|r| !r.trim().is_empty());
+        for remote in std::iter::once(&git.remote).chain(push_remote) {
+            if remote.trim().is_empty() || CommitTemplate::validate_ref_name(remote).is_err() {
+                return Err(WorkNoteError::ValidationError(format!(
+                    "Invalid remote name: {:?}",
+                    remote
+                )));
+            }
+        }
+
+        // gitはauthorの `<`・`>`・改行を受け付けない（空の項目はgit configから補う）
+        if let Some(author) = author {
+            if author.name.contains(['<', '>', '\n']) {
+                return Err(WorkNoteError::ValidationError(format!(
+                    "Invalid author name: {:?}",
+                    author.name
+                )));
+            }
+            let email = author.email.as_str();
+            if !email.is_empty()
+                && (email.contains(['<', '>'])
+                    || email.contains(char::is_whitespace)
+                    || !email.contains('@'))
+            {
+                return Err(WorkNoteError::ValidationError(format!(
+                    "Invalid author email: {:?}",
+                    author.email
+                )));
+            }
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::config::CURRENT_CONFIG_VERSION;
+    use crate::models::Profile;
     use std::env;
 
     fn create_test_config_manager() -> ConfigManager {
@@ -131,7 +410,7 @@ mod tests {
 
         // 設定ファイルが存在しない場合、デフォルト値が返される
         let config = manager.load_config().unwrap();
-        assert_eq!(config.version, 1);
+        assert_eq!(config.version, CURRENT_CONFIG_VERSION);
         assert_eq!(config.git.default_branch, "main");
     }
 
@@ -152,13 +431,204 @@ mod tests {
         // 読み込み
         let loaded_config = manager.load_config().unwrap();
         assert_eq!(loaded_config.version, config.version);
-        assert_eq!(loaded_config.git.repository_path, config.git.repository_path);
+        assert_eq!(
+            loaded_config.git.repository_path,
+            config.git.repository_path
+        );
 
         // クリーンアップ
         let _ = fs::remove_dir_all(&test_repo);
         let _ = fs::remove_file(manager.config_path());
     }
 
+    #[test]
+    fn test_load_config_versions() {
+        let app_data = env::temp_dir().join("worknote_test_config_migration");
+        let _ = fs::remove_dir_all(&app_data);
+        fs::create_dir_all(&app_data).unwrap();
+        let manager = ConfigManager::new(app_data.clone());
+
+        // 後から追加した項目のない設定は既定値で読み込み、ファイルは書き換えない
+        let v1 = r#"{
+            "version": 1,
+            "git": {"repositoryPath": "/repo", "savePath": "docs/runbooks", "defaultBranch": "main", "commitMode": "direct"},
+            "shortcuts": {"quickInput": "CommandOrControl+J"},
+            "preferences": {"launchAtLogin": false, "showInMenuBar": true, "showNotifications": true}
+        }"#;
+        fs::write(manager.config_path(), v1).unwrap();
+
+        let config = manager.load_config().unwrap();
+        assert_eq!(config.version, CURRENT_CONFIG_VERSION);
+        assert_eq!(config.git.repository_path, "/repo");
+        assert_eq!(config.git.remote, "origin");
+        assert!(config.profiles.is_empty());
+        assert_eq!(fs::read_to_string(manager.config_path()).unwrap(), v1);
+        assert_eq!(fs::read_dir(&app_data).unwrap().count(), 1);
+
+        // 新しいバージョンの設定は読み込まず、ファイルも変更しない
+        let future = format!(r#"{{"version": {}}}"#, CURRENT_CONFIG_VERSION + 1);
+        fs::write(manager.config_path(), &future).unwrap();
+        let error = manager.load_config().unwrap_err();
+        assert!(error.to_string().contains("Please update WorkNote"));
+        assert_eq!(fs::read_to_string(manager.config_path()).unwrap(), future);
+
+        let _ = fs::remove_dir_all(&app_data);
+    }
+
+    #[test]
+    fn test_load_effective_config() {
+        let app_data = env::temp_dir().join("worknote_test_effective_config");
+        let test_repo = env::temp_dir().join("worknote_test_effective_repo");
+        let _ = fs::remove_dir_all(&test_repo);
+        create_test_git_repo(&test_repo).unwrap();
+        let manager = ConfigManager::new(app_data.clone());
+
+        let mut config = Config::default();
+        config.git.repository_path = test_repo.to_str().unwrap().to_string();
+        config.git.default_branch = "main".to_string();
+        manager.save_config(&config).unwrap();
+
+        // 共有設定がなければローカル設定のまま
+        let effective = manager.load_effective_config().unwrap();
+        assert!(effective.repo_config_path.is_none());
+        assert_eq!(effective.config.git.save_path, "docs/runbooks");
+
+        fs::write(
+            test_repo.join(REPO_CONFIG_FILE_NAME),
+            "save_path = \"kb\"\ndefault_branch = \"develop\"\n",
+        )
+        .unwrap();
+        let effective = manager.load_effective_config().unwrap();
+        assert_eq!(effective.config.git.save_path, "kb");
+        assert_eq!(effective.config.git.default_branch, "develop");
+        assert_eq!(
+            effective.managed_keys,
+            vec!["git.savePath", "git.defaultBranch"]
+        );
+        // ローカル設定ファイルには反映しない
+        assert_eq!(manager.load_config().unwrap().git.default_branch, "main");
+
+        fs::write(test_repo.join(REPO_CONFIG_FILE_NAME), "save_path = [").unwrap();
+        assert!(manager.load_effective_config().is_err());
+
+        let _ = fs::remove_dir_all(&test_repo);
+        let _ = fs::remove_dir_all(&app_data);
+    }
+
+    #[test]
+    fn test_find_repository_root() {
+        let test_repo = env::temp_dir().join("worknote_test_find_repo_root");
+        let _ = fs::remove_dir_all(&test_repo);
+        create_test_git_repo(&test_repo).unwrap();
+        let runbooks = test_repo.join("docs/runbooks");
+        fs::create_dir_all(&runbooks).unwrap();
+        fs::write(
+            test_repo.join(REPO_CONFIG_FILE_NAME),
+            "[[categories]]\nid = \"db\"\nlabel = \"Database\"\ndirectory = \"db\"\n",
+        )
+        .unwrap();
+
+        let root = ConfigManager::find_repository_root(&runbooks).unwrap();
+        assert_eq!(root, test_repo.canonicalize().unwrap());
+        let effective = ConfigManager::apply_repo_config(Config::default(), &root).unwrap();
+        assert!(effective
+            .config
+            .taxonomy
+            .categories
+            .iter()
+            .any(|c| c.id == "db"));
+
+        let _ = fs::remove_dir_all(&test_repo);
+    }
+
+    #[test]
+    fn test_profiles() {
+        let app_data = env::temp_dir().join("worknote_test_config_profiles");
+        let platform = env::temp_dir().join("worknote_test_profile_platform");
+        let notes = env::temp_dir().join("worknote_test_profile_notes");
+        let _ = fs::remove_dir_all(&app_data);
+        create_test_git_repo(&platform).unwrap();
+        create_test_git_repo(&notes).unwrap();
+        fs::write(notes.join(REPO_CONFIG_FILE_NAME), "save_path = \"memo\"\n").unwrap();
+        let manager = ConfigManager::new(app_data.clone());
+
+        let mut config = Config::default();
+        config.git.repository_path = platform.to_str().unwrap().to_string();
+        config.profiles.push(Profile {
+            name: "notes".to_string(),
+            label: "個人メモ".to_string(),
+            git: GitConfig {
+                repository_path: notes.to_str().unwrap().to_string(),
+                ..Default::default()
+            },
+            naming: None,
+            author: None,
+        });
+        manager.save_config(&config).unwrap();
+
+        // 共有設定はプロファイルのリポジトリから読み込む
+        let effective = manager.load_profile_config(Some("notes")).unwrap();
+        assert_eq!(effective.config.git.save_path, "memo");
+        let effective = manager.load_effective_config().unwrap();
+        assert_eq!(effective.config.git.save_path, "docs/runbooks");
+
+        manager.set_active_profile("notes").unwrap();
+        let effective = manager.load_effective_config().unwrap();
+        assert_eq!(
+            effective.config.git.repository_path,
+            notes.to_str().unwrap()
+        );
+        manager.set_active_profile(DEFAULT_PROFILE_NAME).unwrap();
+        assert!(manager.load_config().unwrap().active_profile.is_none());
+        assert!(manager.set_active_profile("unknown").is_err());
+        assert!(manager.load_profile_config(Some("unknown")).is_err());
+
+        // 予約名・重複名・存在しないリポジトリは保存できない
+        let mut invalid = config.clone();
+        invalid.profiles[0].name = DEFAULT_PROFILE_NAME.to_string();
+        assert!(manager.validate_config(&invalid).is_err());
+        let mut invalid = config.clone();
+        invalid.profiles.push(invalid.profiles[0].clone());
+        assert!(manager.validate_config(&invalid).is_err());
+        let mut invalid = config.clone();
+        invalid.profiles[0].git.repository_path = "/nonexistent/path".to_string();
+        match manager.validate_config(&invalid).unwrap_err() {
+            WorkNoteError::ValidationError(msg) => {
+                assert_eq!(
+                    msg,
+                    "Profile notes: Repository path does not exist: /nonexistent/path"
+                );
+            }
+            _ => panic!("Expected ValidationError"),
+        }
+
+        // Authorは空の項目を許可し、gitが受け付けない値は保存できない
+        let mut with_author = config.clone();
+        with_author.profiles[0].author = Some(AuthorConfig {
+            name: "Notes Bot".to_string(),
+            email: String::new(),
+        });
+        assert!(manager.validate_config(&with_author).is_ok());
+        with_author.profiles[0].author.as_mut().unwrap().email =
+            "bot <bot@example.com>".to_string();
+        match manager.validate_config(&with_author).unwrap_err() {
+            WorkNoteError::ValidationError(msg) => {
+                assert!(msg.starts_with("Profile notes: Invalid author email"));
+            }
+            _ => panic!("Expected ValidationError"),
+        }
+        with_author.profiles[0].author = None;
+        with_author.author = Some(AuthorConfig {
+            name: "Alice\nBob".to_string(),
+            email: "alice@example.com".to_string(),
+        });
+        assert!(manager.validate_config(&with_author).is_err());
+
+        let _ = fs::remove_dir_all(&platform);
+        let _ = fs::remove_dir_all(&notes);
+        let _ = fs::remove_dir_all(&app_data);
+    }
+
     #[test]
     fn test_validate_config_empty_repository_path() {
         let manager = create_test_config_manager();
@@ -190,6 +660,58 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_validate_config_invalid_naming_pattern() {
+        let manager = create_test_config_manager();
+        let test_repo = env::temp_dir().join("test_repo_naming_pattern");
+        create_test_git_repo(&test_repo).unwrap();
+
+        let mut config = Config::default();
+        config.git.repository_path = test_repo.to_str().unwrap().to_string();
+        config.naming.scheme = NamingScheme::Pattern;
+        config.naming.pattern = "{date}-{unknown}".to_string();
+
+        let result = manager.validate_config(&config);
+        match result.unwrap_err() {
+            WorkNoteError::ValidationError(msg) => {
+                assert!(msg.contains("Unknown variable"));
+            }
+            _ => panic!("Expected ValidationError"),
+        }
+
+        // クリーンアップ
+        let _ = fs::remove_dir_all(&test_repo);
+    }
+
+    #[test]
+    fn test_validate_config_invalid_webhook_port() {
+        let manager = create_test_config_manager();
+        let test_repo = env::temp_dir().join("test_repo_webhook_port");
+        create_test_git_repo(&test_repo).unwrap();
+
+        let mut config = Config::default();
+        config.git.repository_path = test_repo.to_str().unwrap().to_string();
+        config.webhook.enabled = true;
+        config.webhook.port = 0;
+
+        let result = manager.validate_config(&config);
+        assert!(matches!(result, Err(WorkNoteError::ValidationError(_))));
+
+        // 有効にする場合は共有トークンが必要
+        config.webhook.port = 9393;
+        assert!(manager.validate_config(&config).is_err());
+        config.webhook.token = "secret".to_string();
+        assert!(manager.validate_config(&config).is_ok());
+
+        // 無効の場合はポート番号を検証しない
+        config.webhook.enabled = false;
+        config.webhook.port = 0;
+        assert!(manager.validate_config(&config).is_ok());
+
+        // クリーンアップ
+        let _ = fs::remove_dir_all(&test_repo);
+    }
+
     #[test]
     fn test_validate_config_not_git_repository() {
         let manager = create_test_config_manager();
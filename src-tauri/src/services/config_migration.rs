@@ -0,0 +1,179 @@
+use crate::models::config::CURRENT_CONFIG_VERSION;
+use crate::models::{Result, WorkNoteError};
+use serde_json::{Map, Value};
+
+/// 1段階分の移行処理（`version` の更新は呼び出し側で行う）
+type MigrationStep = fn(&mut Map<String, Value>) -> Result<()>;
+
+/// 移行処理の一覧（移行元バージョンの昇順）
+///
+/// 既存の設定を読めなくなる変更（項目の削除・名前や型の変更）をした場合に、
+/// `CURRENT_CONFIG_VERSION` を上げて移行処理を追加します。
+/// serdeの既定値で読める項目の追加ではバージョンを上げません（設定ファイルを書き換えない）。
+const MIGRATIONS: &[(u32, MigrationStep)] = &[];
+
+/// 設定JSONのバージョンを取得（`version` がない設定は v1 とみなす）
+pub fn config_version(value: &Value) -> Result<u32> {
+    match value.get("version") {
+        None | Some(Value::Null) => Ok(1),
+        Some(version) => version
+            .as_u64()
+            .and_then(|v| u32::try_from(v).ok())
+            .filter(|v| *v >= 1)
+            .ok_or_else(|| {
+                WorkNoteError::ConfigError(format!("Invalid config version: {}", version))
+            }),
+    }
+}
+
+/// 設定JSONを現在のバージョンまで順に移行
+///
+/// 移行した場合は移行前のバージョンを返します。
+/// 現在より新しいバージョンの設定はエラーにします（新しいWorkNoteで保存された設定）。
+pub fn migrate(value: &mut Value) -> Result<Option<u32>> {
+    migrate_with(value, CURRENT_CONFIG_VERSION, MIGRATIONS)
+}
+
+/// 指定した移行処理で、設定JSONを `current` のバージョンまで順に移行
+fn migrate_with(
+    value: &mut Value,
+    current: u32,
+    migrations: &[(u32, MigrationStep)],
+) -> Result<Option<u32>> {
+    let original = config_version(value)?;
+    if original > current {
+        return Err(WorkNoteError::ConfigError(format!(
+            "Config version {} is newer than the supported version {}. Please update WorkNote.",
+            original, current
+        )));
+    }
+    if original == current {
+        // `version` のない設定も読み込めるように補う（移行ではないのでファイルは書き換えない）
+        if let Some(object) = value.as_object_mut() {
+            if object.get("version").is_none_or(Value::is_null) {
+                object.insert("version".to_string(), Value::from(original));
+            }
+        }
+        return Ok(None);
+    }
+
+    let object = value
+        .as_object_mut()
+        .ok_or_else(|| WorkNoteError::ConfigError("Config must be a JSON object".to_string()))?;
+
+    let mut version = original;
+    for (from, step) in migrations {
+        if *from < version {
+            continue;
+        }
+        step(object).map_err(|e| {
+            WorkNoteError::ConfigError(format!(
+                "Failed to migrate config from version {} to {}: {}",
+                from,
+                from + 1,
+                e
+            ))
+        })?;
+        version = from + 1;
+        object.insert("version".to_string(), Value::from(version));
+    }
+
+    debug_assert_eq!(version, current);
+    Ok(Some(original))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::Config;
+    use serde_json::json;
+
+    fn v1_config() -> Value {
+        json!({
+            "version": 1,
+            "git": {"repositoryPath": "/repo", "savePath": "docs/runbooks", "defaultBranch": "main", "commitMode": "direct"},
+            "shortcuts": {"quickInput": "CommandOrControl+J"},
+            "preferences": {"launchAtLogin": false, "showInMenuBar": true, "showNotifications": true}
+        })
+    }
+
+    /// テスト用の移行処理: v1 → v2 で `git.repositoryPath` を `git.repository` に変更
+    fn rename_repository(config: &mut Map<String, Value>) -> Result<()> {
+        let git = config
+            .get_mut("git")
+            .and_then(Value::as_object_mut)
+            .ok_or_else(|| WorkNoteError::ConfigError("git section is missing".to_string()))?;
+        if let Some(path) = git.remove("repositoryPath") {
+            git.insert("repository".to_string(), path);
+        }
+        Ok(())
+    }
+
+    /// テスト用の移行処理: v2 → v3 で `shortcuts` を削除
+    fn drop_shortcuts(config: &mut Map<String, Value>) -> Result<()> {
+        config.remove("shortcuts");
+        Ok(())
+    }
+
+    const TEST_MIGRATIONS: &[(u32, MigrationStep)] = &[(1, rename_repository), (2, drop_shortcuts)];
+
+    #[test]
+    fn test_migrate_steps() {
+        // 移行元のバージョンから順に全ての段階を適用する
+        let mut value = v1_config();
+        assert_eq!(
+            migrate_with(&mut value, 3, TEST_MIGRATIONS).unwrap(),
+            Some(1)
+        );
+        assert_eq!(value["version"], 3);
+        assert_eq!(value["git"]["repository"], "/repo");
+        assert!(value["git"].get("repositoryPath").is_none());
+        assert!(value.get("shortcuts").is_none());
+
+        // 途中のバージョンからは残りの段階だけを適用する
+        let mut value = v1_config();
+        value["version"] = json!(2);
+        assert_eq!(
+            migrate_with(&mut value, 3, TEST_MIGRATIONS).unwrap(),
+            Some(2)
+        );
+        assert_eq!(value["git"]["repositoryPath"], "/repo");
+        assert!(value.get("shortcuts").is_none());
+
+        // 失敗した段階のバージョンをエラーに含める
+        let mut value = json!({"version": 1});
+        let message = migrate_with(&mut value, 3, TEST_MIGRATIONS)
+            .unwrap_err()
+            .to_string();
+        assert!(message.contains("from version 1 to 2"));
+    }
+
+    #[test]
+    fn test_migrate_versions() {
+        // version のない設定は v1 とみなす
+        let mut value = v1_config();
+        value.as_object_mut().unwrap().remove("version");
+        assert_eq!(migrate(&mut value).unwrap(), None);
+        assert_eq!(value, v1_config());
+
+        // 現在のバージョンは変更しない（項目の追加だけでは書き換えない）
+        let mut value = v1_config();
+        let before = value.clone();
+        assert_eq!(migrate(&mut value).unwrap(), None);
+        assert_eq!(value, before);
+        let config: Config = serde_json::from_value(value).unwrap();
+        assert_eq!(config.version, CURRENT_CONFIG_VERSION);
+
+        let mut current = serde_json::to_value(Config::default()).unwrap();
+        let before = current.clone();
+        assert_eq!(migrate(&mut current).unwrap(), None);
+        assert_eq!(current, before);
+
+        let mut future = json!({"version": CURRENT_CONFIG_VERSION + 1});
+        let message = migrate(&mut future).unwrap_err().to_string();
+        assert!(message.contains("newer than the supported version"));
+
+        assert!(migrate(&mut json!({"version": "2"})).is_err());
+        assert!(migrate(&mut json!({"version": 0})).is_err());
+    }
+}
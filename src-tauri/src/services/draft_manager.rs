@@ -38,13 +38,11 @@ impl DraftManager {
 
         let file_path = self.drafts_dir.join(format!("{}.json", draft.id));
 
-        let json = serde_json::to_string_pretty(draft).map_err(|e| {
-            WorkNoteError::FileError(format!("Failed to serialize draft: {}", e))
-        })?;
+        let json = serde_json::to_string_pretty(draft)
+            .map_err(|e| WorkNoteError::FileError(format!("Failed to serialize draft: {}", e)))?;
 
-        fs::write(&file_path, json).map_err(|e| {
-            WorkNoteError::FileError(format!("Failed to write draft file: {}", e))
-        })?;
+        fs::write(&file_path, json)
+            .map_err(|e| WorkNoteError::FileError(format!("Failed to write draft file: {}", e)))?;
 
         info!(draft_id = %draft.id, "Draft saved successfully");
         Ok(())
@@ -74,19 +72,14 @@ impl DraftManager {
         let file_path = self.drafts_dir.join(format!("{}.json", id));
 
         if !file_path.exists() {
-            return Err(WorkNoteError::FileError(format!(
-                "Draft not found: {}",
-                id
-            )));
+            return Err(WorkNoteError::FileError(format!("Draft not found: {}", id)));
         }
 
-        let json = fs::read_to_string(&file_path).map_err(|e| {
-            WorkNoteError::FileError(format!("Failed to read draft file: {}", e))
-        })?;
+        let json = fs::read_to_string(&file_path)
+            .map_err(|e| WorkNoteError::FileError(format!("Failed to read draft file: {}", e)))?;
 
-        let draft: Draft = serde_json::from_str(&json).map_err(|e| {
-            WorkNoteError::FileError(format!("Failed to deserialize draft: {}", e))
-        })?;
+        let draft: Draft = serde_json::from_str(&json)
+            .map_err(|e| WorkNoteError::FileError(format!("Failed to deserialize draft: {}", e)))?;
 
         info!(draft_id = %id, "Draft loaded successfully");
         Ok(draft)
@@ -94,6 +87,26 @@ impl DraftManager {
 
     /// 下書き一覧を取得
     pub fn list_drafts(&self) -> Result<Vec<DraftSummary>> {
+        let mut summaries: Vec<DraftSummary> =
+            self.read_drafts()?.iter().map(DraftSummary::from).collect();
+
+        // 更新日時の降順でソート
+        summaries.sort_by_key(|s| std::cmp::Reverse(s.updated_at));
+
+        info!(count = summaries.len(), "Listed drafts");
+        Ok(summaries)
+    }
+
+    /// 指定したアラートのfingerprintから作成された下書きを探す
+    pub fn find_by_alert_fingerprint(&self, fingerprint: &str) -> Result<Option<Draft>> {
+        Ok(self
+            .read_drafts()?
+            .into_iter()
+            .find(|draft| draft.alert_fingerprint.as_deref() == Some(fingerprint)))
+    }
+
+    /// 保存されている下書きをすべて読み込む（読み込めないファイルはスキップ）
+    fn read_drafts(&self) -> Result<Vec<Draft>> {
         if !self.drafts_dir.exists() {
             return Ok(Vec::new());
         }
@@ -102,7 +115,7 @@ impl DraftManager {
             WorkNoteError::FileError(format!("Failed to read drafts directory: {}", e))
         })?;
 
-        let mut summaries = Vec::new();
+        let mut drafts = Vec::new();
 
         for entry in entries {
             let entry = entry.map_err(|e| {
@@ -113,9 +126,7 @@ impl DraftManager {
             if path.extension().and_then(|s| s.to_str()) == Some("json") {
                 match fs::read_to_string(&path) {
                     Ok(json) => match serde_json::from_str::<Draft>(&json) {
-                        Ok(draft) => {
-                            summaries.push(DraftSummary::from(&draft));
-                        }
+                        Ok(draft) => drafts.push(draft),
                         Err(e) => {
                             error!(file = ?path, error = %e, "Failed to deserialize draft");
                         }
@@ -127,11 +138,7 @@ impl DraftManager {
             }
         }
 
-        // 更新日時の降順でソート
-        summaries.sort_by(|a, b| b.updated_at.cmp(&a.updated_at));
-
-        info!(count = summaries.len(), "Listed drafts");
-        Ok(summaries)
+        Ok(drafts)
     }
 
     /// 下書きを削除
@@ -143,15 +150,11 @@ impl DraftManager {
         let file_path = self.drafts_dir.join(format!("{}.json", id));
 
         if !file_path.exists() {
-            return Err(WorkNoteError::FileError(format!(
-                "Draft not found: {}",
-                id
-            )));
+            return Err(WorkNoteError::FileError(format!("Draft not found: {}", id)));
         }
 
-        fs::remove_file(&file_path).map_err(|e| {
-            WorkNoteError::FileError(format!("Failed to delete draft file: {}", e))
-        })?;
+        fs::remove_file(&file_path)
+            .map_err(|e| WorkNoteError::FileError(format!("Failed to delete draft file: {}", e)))?;
 
         info!(draft_id = %id, "Draft deleted successfully");
         Ok(())
@@ -162,7 +165,37 @@ impl DraftManager {
     /// # Arguments
     /// * `data` - ナレッジ入力データ
     pub fn create_draft(&self, data: KnowledgeInput) -> Result<Draft> {
-        let draft = Draft::new(data);
+        self.create_draft_for_profile(data, None)
+    }
+
+    /// 保存先プロファイルを指定して新しい下書きを作成
+    ///
+    /// # Arguments
+    /// * `data` - ナレッジ入力データ
+    /// * `profile` - 保存先プロファイル名（`None` の場合は保存時に使用中のプロファイル）
+    pub fn create_draft_for_profile(
+        &self,
+        data: KnowledgeInput,
+        profile: Option<String>,
+    ) -> Result<Draft> {
+        let mut draft = Draft::new(data);
+        draft.profile = profile;
+        self.save_draft(&draft)?;
+        Ok(draft)
+    }
+
+    /// Webhookで受信したアラートから下書きを作成
+    ///
+    /// # Arguments
+    /// * `data` - ナレッジ入力データ
+    /// * `fingerprint` - アラートのfingerprint（重複作成の判定に使用）
+    pub fn create_alert_draft(
+        &self,
+        data: KnowledgeInput,
+        fingerprint: Option<String>,
+    ) -> Result<Draft> {
+        let mut draft = Draft::new(data);
+        draft.alert_fingerprint = fingerprint;
         self.save_draft(&draft)?;
         Ok(draft)
     }
@@ -172,9 +205,16 @@ impl DraftManager {
     /// # Arguments
     /// * `id` - 下書きID
     /// * `data` - ナレッジ入力データ
-    pub fn update_draft(&self, id: &str, data: KnowledgeInput) -> Result<Draft> {
+    /// * `profile` - 保存先プロファイル名
+    pub fn update_draft(
+        &self,
+        id: &str,
+        data: KnowledgeInput,
+        profile: Option<String>,
+    ) -> Result<Draft> {
         let mut draft = self.load_draft(id)?;
         draft.update(data);
+        draft.profile = profile;
         self.save_draft(&draft)?;
         Ok(draft)
     }
@@ -200,6 +240,9 @@ mod tests {
             notes: Some("Test notes".to_string()),
             related_links: None,
             judgment: None,
+            slug: None,
+            related_alerts: Vec::new(),
+            id: None,
         };
 
         // 下書き作成
@@ -211,6 +254,18 @@ mod tests {
         assert_eq!(loaded.id, draft.id);
         assert_eq!(loaded.data.title, "Test Draft");
 
+        // RunbookIDは作成時に採番され、IDなしで更新しても維持される
+        let runbook_id = loaded.data.id.clone().unwrap();
+        assert!(runbook_id.starts_with("rb-"));
+        let updated = manager
+            .update_draft(&draft.id, input, Some("notes".to_string()))
+            .unwrap();
+        assert_eq!(updated.data.id.as_deref(), Some(runbook_id.as_str()));
+        assert_eq!(
+            manager.load_draft(&draft.id).unwrap().profile.as_deref(),
+            Some("notes")
+        );
+
         // クリーンアップ
         let _ = fs::remove_dir_all(&temp_dir);
     }
@@ -229,6 +284,9 @@ mod tests {
             notes: None,
             related_links: None,
             judgment: None,
+            slug: None,
+            related_alerts: Vec::new(),
+            id: None,
         };
 
         let input2 = KnowledgeInput {
@@ -240,6 +298,9 @@ mod tests {
             notes: None,
             related_links: None,
             judgment: None,
+            slug: None,
+            related_alerts: Vec::new(),
+            id: None,
         };
 
         manager.create_draft(input1).unwrap();
@@ -266,6 +327,9 @@ mod tests {
             notes: None,
             related_links: None,
             judgment: None,
+            slug: None,
+            related_alerts: Vec::new(),
+            id: None,
         };
 
         let draft = manager.create_draft(input).unwrap();
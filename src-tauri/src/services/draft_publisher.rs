@@ -0,0 +1,138 @@
+use crate::models::{CommitTrailers, Draft, PublishDraftsResponse, Result, WorkNoteError};
+use crate::services::config_manager::ConfigManager;
+use crate::services::draft_manager::DraftManager;
+use crate::services::knowledge_service::KnowledgeService;
+use std::path::PathBuf;
+use tracing::warn;
+
+/// DraftPublisher - 複数の下書きをまとめて公開（保存・コミット・Push）する
+///
+/// 下書きはPushに成功した後にのみ削除します。
+pub struct DraftPublisher {
+    app_data_dir: PathBuf,
+}
+
+impl DraftPublisher {
+    /// 新しいDraftPublisherインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `app_data_dir` - アプリケーションデータディレクトリ（設定・下書きの保存先）
+    pub fn new(app_data_dir: PathBuf) -> Self {
+        DraftPublisher { app_data_dir }
+    }
+
+    /// 下書きをまとめて公開
+    ///
+    /// # Arguments
+    /// * `draft_ids` - 公開する下書きのID（この順にコミット）
+    /// * `profile` - 保存先プロファイル（未指定時は下書きのプロファイル。全下書きで一致している必要がある）
+    /// * `separate_commits` - 下書きごとにコミットするか（`false` の場合は1つのコミット）
+    /// * `trailers` - コミットメッセージに追加するtrailer
+    pub fn publish(
+        &self,
+        draft_ids: &[String],
+        profile: Option<&str>,
+        separate_commits: bool,
+        trailers: CommitTrailers,
+    ) -> Result<PublishDraftsResponse> {
+        let drafts = self.load_drafts(draft_ids)?;
+        let profile = match profile {
+            Some(profile) => Some(profile.to_string()),
+            None => Self::common_profile(&drafts)?,
+        };
+
+        let config = ConfigManager::new(self.app_data_dir.clone())
+            .load_profile_config(profile.as_deref())?
+            .config;
+        let inputs: Vec<_> = drafts.iter().map(|draft| draft.data.clone()).collect();
+        let mut response = KnowledgeService::new(config)
+            .with_trailers(trailers)
+            .save_all(&inputs, separate_commits)?;
+
+        // Pushに成功した下書きを削除（削除に失敗しても公開は完了しているため警告のみ）
+        let manager = DraftManager::new(self.app_data_dir.clone());
+        for draft in &drafts {
+            match manager.delete_draft(&draft.id) {
+                Ok(()) => response.published_drafts.push(draft.id.clone()),
+                Err(e) => {
+                    warn!(draft_id = %draft.id, error = %e, "Failed to delete published draft")
+                }
+            }
+        }
+
+        Ok(response)
+    }
+
+    /// 下書きを読み込む（IDの重複・存在しない下書きはエラー）
+    fn load_drafts(&self, draft_ids: &[String]) -> Result<Vec<Draft>> {
+        if draft_ids.is_empty() {
+            return Err(WorkNoteError::ValidationError(
+                "No drafts specified".to_string(),
+            ));
+        }
+
+        let manager = DraftManager::new(self.app_data_dir.clone());
+        let mut drafts: Vec<Draft> = Vec::with_capacity(draft_ids.len());
+        for id in draft_ids {
+            if drafts.iter().any(|draft| &draft.id == id) {
+                return Err(WorkNoteError::ValidationError(format!(
+                    "Draft specified more than once: {}",
+                    id
+                )));
+            }
+            drafts.push(manager.load_draft(id)?);
+        }
+        Ok(drafts)
+    }
+
+    /// 下書きの保存先プロファイル（異なるプロファイルの下書きはまとめて公開できない）
+    fn common_profile(drafts: &[Draft]) -> Result<Option<String>> {
+        let profile = drafts[0].profile.clone();
+        if let Some(other) = drafts.iter().find(|draft| draft.profile != profile) {
+            return Err(WorkNoteError::ValidationError(format!(
+                "Drafts belong to different profiles ({} and {}); publish them separately or specify a profile",
+                profile.as_deref().unwrap_or("(active)"),
+                other.profile.as_deref().unwrap_or("(active)")
+            )));
+        }
+        Ok(profile)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::clipboard_capture::ClipboardCapture;
+
+    #[test]
+    fn test_load_drafts_and_common_profile() {
+        let app_data_dir = std::env::temp_dir().join("worknote_test_draft_publisher");
+        let _ = std::fs::remove_dir_all(&app_data_dir);
+        let manager = DraftManager::new(app_data_dir.clone());
+        let input = ClipboardCapture::build_input("ディスク逼迫").unwrap();
+        let a = manager.create_draft(input.clone()).unwrap();
+        let b = manager
+            .create_draft_for_profile(input, Some("platform".to_string()))
+            .unwrap();
+
+        let publisher = DraftPublisher::new(app_data_dir.clone());
+        let drafts = publisher
+            .load_drafts(&[a.id.clone(), b.id.clone()])
+            .unwrap();
+        assert_eq!(drafts.len(), 2);
+        assert!(publisher.load_drafts(&[]).is_err());
+        assert!(publisher
+            .load_drafts(&[a.id.clone(), a.id.clone()])
+            .is_err());
+        assert!(publisher.load_drafts(&["missing".to_string()]).is_err());
+
+        // プロファイルの異なる下書きはプロファイルを指定しない限りまとめて公開できない
+        assert!(DraftPublisher::common_profile(&drafts).is_err());
+        assert_eq!(
+            DraftPublisher::common_profile(&drafts[1..]).unwrap(),
+            Some("platform".to_string())
+        );
+
+        let _ = std::fs::remove_dir_all(&app_data_dir);
+    }
+}
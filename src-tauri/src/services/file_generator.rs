@@ -1,13 +1,21 @@
-use crate::models::{KnowledgeInput, Result, WorkNoteError};
+use crate::models::{
+    AuthorConfig, KnowledgeInput, NamingConfig, Result, RunbookEntry, TaxonomyConfig, WorkNoteError,
+};
+use crate::services::file_naming::FileNamer;
+use crate::services::runbook_parser::RunbookParser;
+use crate::services::runbook_repository::{RunbookRepository, INDEX_FILE_NAME};
 use chrono::Local;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
+use tracing::warn;
 
 /// FileGenerator - Markdownファイル生成を管理
 pub struct FileGenerator {
     repository_path: PathBuf,
     save_path: String,
-    author_name: String,
+    author: AuthorConfig,
+    naming: NamingConfig,
+    taxonomy: TaxonomyConfig,
 }
 
 impl FileGenerator {
@@ -16,15 +24,35 @@ impl FileGenerator {
     /// # Arguments
     /// * `repository_path` - Gitリポジトリパス
     /// * `save_path` - ナレッジ保存先パス（リポジトリルートからの相対パス）
-    /// * `author_name` - Author名
-    pub fn new(repository_path: PathBuf, save_path: String, author_name: String) -> Self {
+    /// * `author` - Author（名前・メールアドレス）
+    pub fn new(repository_path: PathBuf, save_path: String, author: AuthorConfig) -> Self {
         FileGenerator {
             repository_path,
             save_path,
-            author_name,
+            author,
+            naming: NamingConfig::default(),
+            taxonomy: TaxonomyConfig::default(),
         }
     }
 
+    /// ファイル命名設定を指定
+    ///
+    /// # Arguments
+    /// * `naming` - ファイル命名設定
+    pub fn with_naming(mut self, naming: NamingConfig) -> Self {
+        self.naming = naming;
+        self
+    }
+
+    /// カテゴリ・重要度の定義を指定（カテゴリの保存先ディレクトリに使用）
+    ///
+    /// # Arguments
+    /// * `taxonomy` - カテゴリ・重要度の定義
+    pub fn with_taxonomy(mut self, taxonomy: TaxonomyConfig) -> Self {
+        self.taxonomy = taxonomy;
+        self
+    }
+
     /// YAML文字列をエスケープ
     ///
     /// ダブルクォート、バックスラッシュ、改行をエスケープします。
@@ -34,69 +62,109 @@ impl FileGenerator {
     ///
     /// # Returns
     /// エスケープされた文字列
-    fn escape_yaml_string(s: &str) -> String {
+    pub(crate) fn escape_yaml_string(s: &str) -> String {
         s.replace('\\', "\\\\")
             .replace('"', "\\\"")
             .replace('\n', "\\n")
             .replace('\r', "\\r")
     }
 
-    /// タイトルをkebab-caseに変換
-    ///
-    /// ASCII文字（英数字）のみを残し、スペースと記号をハイフンに置換します。
-    /// 日本語文字は除去されます。
-    ///
-    /// # Arguments
-    /// * `title` - 変換するタイトル
-    ///
-    /// # Returns
-    /// kebab-case形式のファイル名
-    pub fn to_kebab_case(title: &str) -> String {
-        // 小文字に変換
-        let lowercase = title.to_lowercase();
-
-        // ASCII英数字のみを残し、その他はハイフンに置換
-        let replaced = lowercase
-            .chars()
-            .filter_map(|c| {
-                if c.is_ascii_alphanumeric() {
-                    Some(c)
-                } else if c.is_whitespace() || c.is_ascii_punctuation() {
-                    Some('-')
-                } else {
-                    // 非ASCII文字（日本語等）は除去
-                    None
-                }
-            })
-            .collect::<String>();
+    /// フロントマターに関連アラートの一覧を出力
+    fn push_related_alerts(content: &mut String, input: &KnowledgeInput) {
+        let alerts = input.normalized_related_alerts();
+        if alerts.is_empty() {
+            content.push_str("related_alerts: []\n");
+            return;
+        }
 
-        // 連続するハイフンを1つにまとめる
-        let mut result = String::new();
-        let mut prev_was_hyphen = false;
+        content.push_str("related_alerts:\n");
+        for alert in alerts {
+            content.push_str(&format!("  - \"{}\"\n", Self::escape_yaml_string(&alert)));
+        }
+    }
 
-        for c in replaced.chars() {
-            if c == '-' {
-                if !prev_was_hyphen {
-                    result.push(c);
-                }
-                prev_was_hyphen = true;
-            } else {
-                result.push(c);
-                prev_was_hyphen = false;
+    /// フロントマターに参照先RunbookIDの一覧を出力
+    fn push_references(content: &mut String, input: &KnowledgeInput) {
+        let references = input
+            .related_links
+            .as_deref()
+            .map(RunbookParser::wiki_links)
+            .unwrap_or_default();
+
+        if !references.is_empty() {
+            content.push_str("references:\n");
+            for id in references {
+                content.push_str(&format!("  - \"{}\"\n", Self::escape_yaml_string(&id)));
             }
         }
+    }
 
-        // 先頭と末尾のハイフンを削除
-        let trimmed = result.trim_matches('-').to_string();
+    /// 関連リンク中の `[[runbook-id]]` を保存先のRunbookへの相対リンクに置換
+    fn resolve_related_links(&self, links: &str) -> String {
+        if RunbookParser::wiki_links(links).is_empty() {
+            return links.to_string();
+        }
 
-        // 結果が空の場合はタイムスタンプを返す
-        if trimmed.is_empty() {
-            Local::now().format("%Y%m%d-%H%M%S").to_string()
-        } else {
-            trimmed
+        let repository = RunbookRepository::new(self.repository_path.join(&self.save_path));
+        match repository.list_entries() {
+            Ok(entries) => Self::resolve_wiki_links(links, &entries),
+            Err(e) => {
+                warn!(error = %e, "Failed to list runbooks for link resolution");
+                links.to_string()
+            }
         }
     }
 
+    /// Markdownのリンク `[text](target)` を生成
+    ///
+    /// リンクテキストの `[`・`]` と、リンク先の空白・括弧をエスケープします。
+    fn markdown_link(text: &str, target: &str) -> String {
+        let text = text
+            .replace('\\', "\\\\")
+            .replace('[', "\\[")
+            .replace(']', "\\]")
+            .replace(['\r', '\n'], " ");
+        let target = target
+            .replace(' ', "%20")
+            .replace('(', "%28")
+            .replace(')', "%29");
+        format!("[{}]({})", text, target)
+    }
+
+    /// `[[runbook-id]]` を `[タイトル](../<category>/<file>.md)` に置換
+    ///
+    /// 生成されるファイルは `save_path/<category>/` に配置されるため、
+    /// 保存先ディレクトリからの相対パスに `../` を付けます。
+    /// 見つからないIDはそのまま残します。
+    pub fn resolve_wiki_links(text: &str, entries: &[RunbookEntry]) -> String {
+        let mut result = String::new();
+        let mut rest = text;
+
+        while let Some(start) = rest.find("[[") {
+            let after = &rest[start + 2..];
+            let Some(end) = after.find("]]") else {
+                break;
+            };
+            let id = after[..end].trim();
+
+            result.push_str(&rest[..start]);
+            match entries.iter().find(|e| e.id.as_deref() == Some(id)) {
+                Some(entry) => result.push_str(&Self::markdown_link(
+                    &entry.title,
+                    &format!("../{}", entry.path),
+                )),
+                None => {
+                    warn!(runbook_id = %id, "Unresolved runbook reference");
+                    result.push_str(&rest[start..start + end + 4]);
+                }
+            }
+            rest = &after[end + 2..];
+        }
+
+        result.push_str(rest);
+        result
+    }
+
     /// Markdownファイルを生成（プレビュー用：author情報なし）
     ///
     /// # Arguments
@@ -111,12 +179,22 @@ impl FileGenerator {
 
         // Frontmatter
         content.push_str("---\n");
-        content.push_str(&format!("title: \"{}\"\n", Self::escape_yaml_string(&input.title)));
+        content.push_str(&format!(
+            "title: \"{}\"\n",
+            Self::escape_yaml_string(&input.title)
+        ));
+        if let Some(id) = &input.id {
+            content.push_str(&format!("id: {}\n", id));
+        }
         content.push_str(&format!("category: {}\n", input.category.as_str()));
         content.push_str(&format!("severity: {}\n", input.severity.as_str()));
         content.push_str("symptoms:\n");
-        content.push_str(&format!("  - \"{}\"\n", Self::escape_yaml_string(&input.symptoms)));
-        content.push_str("related_alerts: []\n");
+        content.push_str(&format!(
+            "  - \"{}\"\n",
+            Self::escape_yaml_string(&input.symptoms)
+        ));
+        Self::push_related_alerts(&mut content, input);
+        Self::push_references(&mut content, input);
         content.push_str(&format!("last_updated: {}\n", today));
         content.push_str("---\n\n");
 
@@ -140,7 +218,7 @@ impl FileGenerator {
         if let Some(notes) = &input.notes {
             content.push_str(&format!("{}\n\n", notes));
         } else {
-            content.push_str("\n");
+            content.push('\n');
         }
 
         // 関連リンク
@@ -148,7 +226,7 @@ impl FileGenerator {
         if let Some(links) = &input.related_links {
             content.push_str(&format!("{}\n\n", links));
         } else {
-            content.push_str("\n");
+            content.push('\n');
         }
 
         // 判断基準・判断軸（カテゴリ別）
@@ -170,20 +248,46 @@ impl FileGenerator {
     /// # Returns
     /// Markdown形式の文字列
     pub fn generate_markdown(&self, input: &KnowledgeInput) -> String {
+        self.generate_markdown_with_history(input, &[])
+    }
+
+    /// 保存済みの対応履歴を引き継いでMarkdownファイルを生成
+    ///
+    /// `history` が空の場合は初版作成、そうでない場合は履歴の末尾に更新の行を追加します。
+    fn generate_markdown_with_history(&self, input: &KnowledgeInput, history: &[String]) -> String {
         let today = Local::now().format("%Y-%m-%d").to_string();
 
         let mut content = String::new();
 
         // Frontmatter
         content.push_str("---\n");
-        content.push_str(&format!("title: \"{}\"\n", Self::escape_yaml_string(&input.title)));
+        content.push_str(&format!(
+            "title: \"{}\"\n",
+            Self::escape_yaml_string(&input.title)
+        ));
+        if let Some(id) = &input.id {
+            content.push_str(&format!("id: {}\n", id));
+        }
         content.push_str(&format!("category: {}\n", input.category.as_str()));
         content.push_str(&format!("severity: {}\n", input.severity.as_str()));
         content.push_str("symptoms:\n");
-        content.push_str(&format!("  - \"{}\"\n", Self::escape_yaml_string(&input.symptoms)));
-        content.push_str("related_alerts: []\n");
+        content.push_str(&format!(
+            "  - \"{}\"\n",
+            Self::escape_yaml_string(&input.symptoms)
+        ));
+        Self::push_related_alerts(&mut content, input);
+        Self::push_references(&mut content, input);
         content.push_str(&format!("last_updated: {}\n", today));
-        content.push_str(&format!("author: \"{}\"\n", Self::escape_yaml_string(&self.author_name)));
+        content.push_str(&format!(
+            "author: \"{}\"\n",
+            Self::escape_yaml_string(&self.author.name)
+        ));
+        if !self.author.email.is_empty() {
+            content.push_str(&format!(
+                "author_email: \"{}\"\n",
+                Self::escape_yaml_string(&self.author.email)
+            ));
+        }
         content.push_str("---\n\n");
 
         // タイトル
@@ -206,15 +310,15 @@ impl FileGenerator {
         if let Some(notes) = &input.notes {
             content.push_str(&format!("{}\n\n", notes));
         } else {
-            content.push_str("\n");
+            content.push('\n');
         }
 
-        // 関連リンク
+        // 関連リンク（`[[runbook-id]]` は相対リンクに解決）
         content.push_str("## 関連リンク\n\n");
         if let Some(links) = &input.related_links {
-            content.push_str(&format!("{}\n\n", links));
+            content.push_str(&format!("{}\n\n", self.resolve_related_links(links)));
         } else {
-            content.push_str("\n");
+            content.push('\n');
         }
 
         // 判断基準・判断軸（カテゴリ別）
@@ -229,11 +333,68 @@ impl FileGenerator {
         content.push_str("## 対応履歴\n\n");
         content.push_str("| 日付 | 対応者 | 備考 |\n");
         content.push_str("|------|--------|------|\n");
-        content.push_str(&format!("| {} | {} | 初版作成 |\n", today, self.author_name));
+        if history.is_empty() {
+            content.push_str(&format!("| {} | {} | 初版作成 |\n", today, self.author));
+        } else {
+            for row in history {
+                content.push_str(&format!("{}\n", row));
+            }
+            content.push_str(&format!("| {} | {} | 更新 |\n", today, self.author));
+        }
 
         content
     }
 
+    /// 保存済みのMarkdownから対応履歴の行（ヘッダー・区切り行を除く）を取り出す
+    fn history_rows(markdown: &str) -> Vec<String> {
+        let Some((_, section)) = markdown.split_once("\n## 対応履歴\n") else {
+            return Vec::new();
+        };
+        section
+            .lines()
+            .take_while(|line| !line.starts_with("## "))
+            .map(str::trim)
+            .filter(|line| line.starts_with('|'))
+            .skip(2)
+            .map(str::to_string)
+            .collect()
+    }
+
+    /// 同じRunbookIDで保存済みのファイルを探す
+    fn existing_file(&self, save_dir: &Path, id: Option<&str>) -> Option<RunbookEntry> {
+        let id = id?;
+        match RunbookRepository::new(save_dir.to_path_buf()).find_by_id(id) {
+            Ok(entry) => entry,
+            Err(e) => {
+                warn!(error = %e, runbook_id = %id, "Failed to look up existing runbook");
+                None
+            }
+        }
+    }
+
+    /// 新規保存時のファイルパスを決定
+    ///
+    /// 命名方式のファイル名が既存ファイル（自動生成のindex.mdを含む）と衝突する場合は、
+    /// RunbookIDを付けて区別します。
+    fn new_file_path(&self, category_dir: &Path, input: &KnowledgeInput) -> Result<PathBuf> {
+        let stem = FileNamer::new(self.naming.clone()).file_stem(input, Local::now().date_naive());
+        let filename = format!("{}.md", stem);
+        let file_path = category_dir.join(&filename);
+        if !file_path.exists() && filename != INDEX_FILE_NAME {
+            return Ok(file_path);
+        }
+
+        let id = input.id.as_deref().unwrap_or_default();
+        let file_path = category_dir.join(format!("{}-{}.md", stem, id));
+        if file_path.exists() {
+            return Err(WorkNoteError::FileError(format!(
+                "File already exists: {}",
+                file_path.display()
+            )));
+        }
+        Ok(file_path)
+    }
+
     /// ファイルを書き込む
     ///
     /// # Arguments
@@ -242,15 +403,16 @@ impl FileGenerator {
     /// # Returns
     /// 作成されたファイルのパス
     pub fn write_file(&self, input: &KnowledgeInput) -> Result<PathBuf> {
-        // ファイル名生成
-        let base_filename = Self::to_kebab_case(&input.title);
-        let filename = format!("{}.md", base_filename);
+        input.validate()?;
+
+        // RunbookIDが未採番なら採番（ファイル名の `{id}` にも使用）
+        let mut input = input.clone();
+        input.ensure_id();
+        let input = &input;
 
-        // カテゴリディレクトリパス
-        let category_dir = self
-            .repository_path
-            .join(&self.save_path)
-            .join(input.category.as_str());
+        // カテゴリディレクトリパス（定義のディレクトリ名を使用）
+        let directory = self.taxonomy.directory_for(&input.category);
+        let category_dir = self.repository_path.join(&self.save_path).join(&directory);
 
         // ディレクトリトラバーサル対策: repository_path 内に収まるか確認
         // まず必要なディレクトリを作成
@@ -278,7 +440,7 @@ impl FileGenerator {
             save_dir
                 .canonicalize()
                 .unwrap_or(save_dir.clone())
-                .join(input.category.as_str())
+                .join(&directory)
         });
 
         // リポジトリパス外への書き込みを防止
@@ -295,23 +457,33 @@ impl FileGenerator {
             })?;
         }
 
-        // ファイルパス
-        let mut file_path = category_dir.join(&filename);
-
-        // ファイル名衝突対応
-        if file_path.exists() {
-            let timestamp = Local::now().format("%Y%m%d-%H%M%S-%f").to_string();
-            let collision_filename = format!("{}-{}.md", base_filename, timestamp);
-            file_path = category_dir.join(&collision_filename);
-        }
+        // 同じRunbookIDのファイルが既にあれば、対応履歴を引き継いでそのファイルを上書き
+        // （カテゴリを変えると他のRunbookからの相対リンクが壊れるため、移動はしない）
+        let (file_path, history) = match self.existing_file(&save_dir, input.id.as_deref()) {
+            Some(entry) if entry.category != directory => {
+                return Err(WorkNoteError::ValidationError(format!(
+                    "Runbook {} is saved in category '{}' and cannot be moved to '{}'",
+                    input.id.as_deref().unwrap_or_default(),
+                    entry.category,
+                    directory
+                )));
+            }
+            Some(entry) => {
+                let path = save_dir.join(&entry.path);
+                let existing = fs::read_to_string(&path).map_err(|e| {
+                    WorkNoteError::FileError(format!("Failed to read existing runbook: {}", e))
+                })?;
+                (path, Self::history_rows(&existing))
+            }
+            None => (self.new_file_path(&category_dir, input)?, Vec::new()),
+        };
 
         // Markdown生成
-        let markdown = self.generate_markdown(input);
+        let markdown = self.generate_markdown_with_history(input, &history);
 
         // ファイル書き込み
-        fs::write(&file_path, markdown).map_err(|e| {
-            WorkNoteError::FileError(format!("Failed to write file: {}", e))
-        })?;
+        fs::write(&file_path, markdown)
+            .map_err(|e| WorkNoteError::FileError(format!("Failed to write file: {}", e)))?;
 
         Ok(file_path)
     }
@@ -323,33 +495,28 @@ mod tests {
     use crate::models::{Category, Severity};
     use std::env;
 
-    #[test]
-    fn test_to_kebab_case_english() {
-        assert_eq!(FileGenerator::to_kebab_case("CPU High Alert"), "cpu-high-alert");
-        assert_eq!(FileGenerator::to_kebab_case("Network Error"), "network-error");
-        assert_eq!(FileGenerator::to_kebab_case("Database Connection"), "database-connection");
-    }
-
-    #[test]
-    fn test_to_kebab_case_japanese() {
-        // 日本語文字は除去され、ASCII文字のみが残る
-        let result = FileGenerator::to_kebab_case("CPU高騰対応");
-        assert_eq!(result, "cpu");
-    }
-
-    #[test]
-    fn test_to_kebab_case_mixed() {
-        let result = FileGenerator::to_kebab_case("Test テスト 123");
-        // 英数字とハイフンのみ
-        assert!(result.chars().all(|c| c.is_alphanumeric() || c == '-'));
-        assert!(result.contains("test"));
-        assert!(result.contains("123"));
+    fn test_author() -> AuthorConfig {
+        AuthorConfig {
+            name: "Test User".to_string(),
+            email: "test@example.com".to_string(),
+        }
     }
 
     #[test]
-    fn test_to_kebab_case_special_chars() {
-        assert_eq!(FileGenerator::to_kebab_case("Test@#$%123"), "test-123");
-        assert_eq!(FileGenerator::to_kebab_case("Hello___World"), "hello-world");
+    fn test_resolve_wiki_links_escapes_title_and_path() {
+        let entries = vec![RunbookEntry {
+            path: "ops/a (old).md".to_string(),
+            id: Some("rb-1".to_string()),
+            category: "ops".to_string(),
+            title: "[重要] 手順](x)".to_string(),
+            severity: None,
+            last_updated: None,
+            author: None,
+        }];
+        assert_eq!(
+            FileGenerator::resolve_wiki_links("- [[rb-1]]", &entries),
+            "- [\\[重要\\] 手順\\](x)](../ops/a%20%28old%29.md)"
+        );
     }
 
     #[test]
@@ -357,7 +524,7 @@ mod tests {
         let generator = FileGenerator::new(
             PathBuf::from("/test/repo"),
             "docs/runbooks".to_string(),
-            "Test User".to_string(),
+            test_author(),
         );
 
         let input = KnowledgeInput {
@@ -365,10 +532,14 @@ mod tests {
             category: Category::Alerts,
             severity: Severity::High,
             symptoms: "CPU使用率が90%を超えている".to_string(),
-            procedure: "1. プロセス一覧を確認\n2. 原因プロセスを特定\n3. 必要に応じて再起動".to_string(),
+            procedure: "1. プロセス一覧を確認\n2. 原因プロセスを特定\n3. 必要に応じて再起動"
+                .to_string(),
             notes: Some("再起動前にログを確認すること".to_string()),
             related_links: Some("https://example.com/cpu-troubleshooting".to_string()),
             judgment: None,
+            slug: None,
+            related_alerts: Vec::new(),
+            id: None,
         };
 
         let markdown = generator.generate_markdown(&input);
@@ -378,7 +549,9 @@ mod tests {
         assert!(markdown.contains("title: \"CPU高騰対応\""));
         assert!(markdown.contains("category: alerts"));
         assert!(markdown.contains("severity: high"));
-        assert!(markdown.contains("author: \"Test User\""));
+        assert!(markdown.contains("author: \"Test User\"\nauthor_email: \"test@example.com\"\n"));
+        assert!(markdown.contains("| Test User <test@example.com> | 初版作成 |"));
+        assert!(markdown.contains("related_alerts: []"));
 
         // セクション確認
         assert!(markdown.contains("# CPU高騰対応"));
@@ -399,11 +572,8 @@ mod tests {
     #[test]
     fn test_write_file() {
         let temp_dir = env::temp_dir().join("worknote_test_filegen");
-        let generator = FileGenerator::new(
-            temp_dir.clone(),
-            "docs/runbooks".to_string(),
-            "Test User".to_string(),
-        );
+        let generator =
+            FileGenerator::new(temp_dir.clone(), "docs/runbooks".to_string(), test_author());
 
         let input = KnowledgeInput {
             title: "Test Knowledge".to_string(),
@@ -414,6 +584,9 @@ mod tests {
             notes: None,
             related_links: None,
             judgment: None,
+            slug: None,
+            related_alerts: Vec::new(),
+            id: None,
         };
 
         let file_path = generator.write_file(&input).unwrap();
@@ -431,24 +604,136 @@ mod tests {
         let _ = fs::remove_dir_all(&temp_dir);
     }
 
+    #[test]
+    fn test_write_file_japanese_title() {
+        let temp_dir = env::temp_dir().join("worknote_test_filegen_japanese");
+        let generator =
+            FileGenerator::new(temp_dir.clone(), "docs/runbooks".to_string(), test_author());
+
+        let input = KnowledgeInput {
+            title: "DB接続エラー対応".to_string(),
+            category: Category::Troubleshooting,
+            severity: Severity::High,
+            symptoms: "Test symptom".to_string(),
+            procedure: "Test procedure".to_string(),
+            notes: None,
+            related_links: None,
+            judgment: None,
+            slug: None,
+            related_alerts: Vec::new(),
+            id: None,
+        };
+
+        // デフォルトはローマ字変換
+        let file_path = generator.write_file(&input).unwrap();
+        assert!(file_path
+            .to_str()
+            .unwrap()
+            .ends_with("db-setsuzoku-era-taiou.md"));
+
+        // クリーンアップ
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_generate_markdown_related_alerts() {
+        let input = KnowledgeInput {
+            title: "CPU高騰対応".to_string(),
+            category: Category::Alerts,
+            severity: Severity::High,
+            symptoms: "CPU使用率が90%を超えている".to_string(),
+            procedure: "プロセス一覧を確認".to_string(),
+            notes: None,
+            related_links: None,
+            judgment: None,
+            slug: None,
+            id: None,
+            related_alerts: vec![
+                "HighCPUUsage".to_string(),
+                " highcpuusage ".to_string(),
+                "datadog:12345".to_string(),
+            ],
+        };
+
+        let markdown = FileGenerator::generate_markdown_for_preview(&input);
+        assert!(markdown.contains(
+            "related_alerts:\n  - \"HighCPUUsage\"\n  - \"datadog:12345\"\nlast_updated:"
+        ));
+
+        // 保存時は不正なアラート名をエラーにする
+        let temp_dir = env::temp_dir().join("worknote_test_filegen_related_alerts");
+        let generator =
+            FileGenerator::new(temp_dir.clone(), "docs/runbooks".to_string(), test_author());
+        let mut invalid = input.clone();
+        invalid.related_alerts = vec!["bad\"alert".to_string()];
+        assert!(matches!(
+            generator.write_file(&invalid),
+            Err(WorkNoteError::ValidationError(_))
+        ));
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_write_file_assigns_id_and_resolves_references() {
+        let temp_dir = env::temp_dir().join("worknote_test_filegen_references");
+        let _ = fs::remove_dir_all(&temp_dir);
+        let generator =
+            FileGenerator::new(temp_dir.clone(), "docs/runbooks".to_string(), test_author());
+
+        let mut target = KnowledgeInput {
+            title: "DB接続エラー対応".to_string(),
+            category: Category::Troubleshooting,
+            severity: Severity::High,
+            symptoms: "Test symptom".to_string(),
+            procedure: "Test procedure".to_string(),
+            notes: None,
+            related_links: None,
+            judgment: None,
+            slug: None,
+            related_alerts: Vec::new(),
+            id: Some("rb-0000abcd".to_string()),
+        };
+        generator.write_file(&target).unwrap();
+
+        target.title = "CPU高騰対応".to_string();
+        target.category = Category::Alerts;
+        target.id = None;
+        target.related_links = Some("- [[rb-0000abcd]]\n- [[rb-missing]]".to_string());
+        let file_path = generator.write_file(&target).unwrap();
+
+        let content = fs::read_to_string(&file_path).unwrap();
+        assert!(content.contains("\nid: rb-"));
+        assert!(!content.contains("id: rb-0000abcd"));
+        assert!(content.contains("references:\n  - \"rb-0000abcd\"\n  - \"rb-missing\"\n"));
+        assert!(
+            content.contains("- [DB接続エラー対応](../troubleshooting/db-setsuzoku-era-taiou.md)")
+        );
+        // 解決できない参照はそのまま残す
+        assert!(content.contains("- [[rb-missing]]"));
+
+        // クリーンアップ
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
     #[test]
     fn test_write_file_collision() {
         let temp_dir = env::temp_dir().join("worknote_test_collision");
-        let generator = FileGenerator::new(
-            temp_dir.clone(),
-            "docs/runbooks".to_string(),
-            "Test User".to_string(),
-        );
+        let generator =
+            FileGenerator::new(temp_dir.clone(), "docs/runbooks".to_string(), test_author());
 
         let input = KnowledgeInput {
             title: "Collision Test".to_string(),
-            category: Category::Maintenance,
+            category: Category::Ops,
             severity: Severity::Low,
             symptoms: "Test".to_string(),
             procedure: "Test".to_string(),
             notes: None,
             related_links: None,
             judgment: None,
+            slug: None,
+            related_alerts: Vec::new(),
+            id: None,
         };
 
         // 1回目: 通常のファイル名
@@ -456,7 +741,7 @@ mod tests {
         assert!(file_path1.to_str().unwrap().contains("collision"));
         assert_eq!(file_path1.extension().unwrap(), "md");
 
-        // 2回目: タイムスタンプ付きファイル名
+        // 2回目: 別のRunbookIDとして採番されるため、ID付きファイル名
         let file_path2 = generator.write_file(&input).unwrap();
         assert!(file_path2.to_str().unwrap().contains("collision-test-"));
         assert_eq!(file_path2.extension().unwrap(), "md");
@@ -469,4 +754,66 @@ mod tests {
         // クリーンアップ
         let _ = fs::remove_dir_all(&temp_dir);
     }
+
+    #[test]
+    fn test_write_file_overwrites_same_id() {
+        let temp_dir = env::temp_dir().join("worknote_test_overwrite_same_id");
+        let _ = fs::remove_dir_all(&temp_dir);
+        let generator =
+            FileGenerator::new(temp_dir.clone(), "docs/runbooks".to_string(), test_author());
+
+        let mut input = KnowledgeInput {
+            title: "Original Title".to_string(),
+            category: Category::Ops,
+            severity: Severity::Low,
+            symptoms: "Test".to_string(),
+            procedure: "Test".to_string(),
+            notes: None,
+            related_links: None,
+            judgment: None,
+            slug: None,
+            related_alerts: Vec::new(),
+            id: Some("rb-overwrite".to_string()),
+        };
+        let file_path1 = generator.write_file(&input).unwrap();
+
+        // タイトルを変えて保存し直しても、同じファイルを上書きする
+        input.title = "Renamed Title".to_string();
+        let file_path2 = generator.write_file(&input).unwrap();
+        assert_eq!(file_path1, file_path2);
+        assert!(fs::read_to_string(&file_path2)
+            .unwrap()
+            .contains("Renamed Title"));
+        assert_eq!(
+            fs::read_dir(file_path1.parent().unwrap()).unwrap().count(),
+            1
+        );
+
+        // 対応履歴は手動で追加した行も含めて引き継ぎ、更新の行を追加する
+        let content = fs::read_to_string(&file_path2).unwrap();
+        let rows: Vec<&str> = content
+            .lines()
+            .skip_while(|line| *line != "## 対応履歴")
+            .filter(|line| line.starts_with("| 20"))
+            .collect();
+        assert_eq!(rows.len(), 2);
+        assert!(rows[0].ends_with("| Test User <test@example.com> | 初版作成 |"));
+        assert!(rows[1].ends_with("| Test User <test@example.com> | 更新 |"));
+        fs::write(
+            &file_path2,
+            content.replace(rows[1], "| 2026-01-01 | 運用チーム | 手順を追記 |"),
+        )
+        .unwrap();
+        generator.write_file(&input).unwrap();
+        let content = fs::read_to_string(&file_path2).unwrap();
+        assert!(content.contains("| 2026-01-01 | 運用チーム | 手順を追記 |\n| "));
+        assert!(content.trim_end().ends_with("| 更新 |"));
+
+        // 保存済みのRunbookのカテゴリは変更できない
+        input.category = Category::Alerts;
+        assert!(generator.write_file(&input).is_err());
+        assert!(file_path2.exists());
+
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
 }
@@ -0,0 +1,291 @@
+use crate::models::{KnowledgeInput, NamingConfig, NamingScheme, Result, WorkNoteError};
+use crate::services::transliterator::Transliterator;
+use chrono::NaiveDate;
+
+/// ファイル名（拡張子なし）の最大文字数
+const MAX_STEM_LENGTH: usize = 80;
+
+/// パターンで使用できる変数
+const PATTERN_VARIABLES: &[&str] = &["date", "category", "slug", "id", "title"];
+
+/// FileNamer - 命名方式に従ってRunbookのファイル名を決定
+///
+/// ファイル名は新規保存時にのみ決定します（日付やタイトルを含むため、入力が変われば結果も変わります）。
+/// 保存済みのRunbookを更新する場合は、FileGeneratorが同じRunbookIDのファイルを上書きします。
+pub struct FileNamer {
+    config: NamingConfig,
+}
+
+impl FileNamer {
+    /// 新しいFileNamerインスタンスを作成
+    pub fn new(config: NamingConfig) -> Self {
+        FileNamer { config }
+    }
+
+    /// ファイル名（拡張子なし）を生成
+    ///
+    /// # Arguments
+    /// * `input` - ナレッジ入力データ
+    /// * `date` - ファイル名に使用する日付
+    pub fn file_stem(&self, input: &KnowledgeInput, date: NaiveDate) -> String {
+        let stem = match self.config.scheme {
+            NamingScheme::Ascii => Self::slugify(&input.title),
+            NamingScheme::Romaji => Self::romaji_slug(&input.title),
+            NamingScheme::DateId => String::new(),
+            NamingScheme::Slug => input
+                .slug
+                .as_deref()
+                .map(Self::slugify)
+                .filter(|s| !s.is_empty())
+                .unwrap_or_else(|| Self::romaji_slug(&input.title)),
+            NamingScheme::Pattern => self.expand_pattern(input, date),
+        };
+
+        // 変換結果が空の場合は日付 + IDにフォールバック
+        if stem.is_empty() {
+            Self::date_id(input, date)
+        } else {
+            stem
+        }
+    }
+
+    /// パターンを展開
+    fn expand_pattern(&self, input: &KnowledgeInput, date: NaiveDate) -> String {
+        let slug = input
+            .slug
+            .as_deref()
+            .map(Self::slugify)
+            .filter(|s| !s.is_empty())
+            .unwrap_or_else(|| Self::romaji_slug(&input.title));
+
+        let expanded = self
+            .config
+            .pattern
+            .replace("{date}", &date.format("%Y-%m-%d").to_string())
+            .replace("{category}", input.category.as_str())
+            .replace("{slug}", &slug)
+            .replace("{id}", &Self::short_id(input))
+            .replace("{title}", &Self::slugify(&input.title));
+
+        Self::slugify(&expanded)
+    }
+
+    /// パターンを検証
+    ///
+    /// 未知の変数や閉じていない `{` があればエラーを返します。
+    pub fn validate_pattern(pattern: &str) -> Result<()> {
+        if pattern.trim().is_empty() {
+            return Err(WorkNoteError::ValidationError(
+                "File name pattern is empty".to_string(),
+            ));
+        }
+
+        let mut rest = pattern;
+        while let Some(start) = rest.find('{') {
+            let end = rest[start..].find('}').ok_or_else(|| {
+                WorkNoteError::ValidationError(format!(
+                    "Unclosed '{{' in file name pattern: {}",
+                    pattern
+                ))
+            })?;
+            let name = &rest[start + 1..start + end];
+            if !PATTERN_VARIABLES.contains(&name) {
+                return Err(WorkNoteError::ValidationError(format!(
+                    "Unknown variable '{{{}}}' in file name pattern. Available: {}",
+                    name,
+                    PATTERN_VARIABLES
+                        .iter()
+                        .map(|v| format!("{{{}}}", v))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                )));
+            }
+            rest = &rest[start + end + 1..];
+        }
+
+        Ok(())
+    }
+
+    /// ローマ字に変換したslugを生成
+    pub fn romaji_slug(title: &str) -> String {
+        Self::slugify(&Transliterator::to_romaji(title))
+    }
+
+    /// 日付 + IDのファイル名を生成
+    fn date_id(input: &KnowledgeInput, date: NaiveDate) -> String {
+        format!("{}-{}", date.format("%Y%m%d"), Self::short_id(input))
+    }
+
+    /// ファイル名用の短いIDを取得
+    ///
+    /// RunbookIDがあればそれを使い、なければカテゴリとタイトルから
+    /// 8桁の16進IDを生成します（FNV-1a）。
+    fn short_id(input: &KnowledgeInput) -> String {
+        if let Some(id) = input.id.as_deref() {
+            let id = Self::slugify(id.strip_prefix("rb-").unwrap_or(id));
+            if !id.is_empty() {
+                return id;
+            }
+        }
+
+        let mut hash: u32 = 0x811c_9dc5;
+        for byte in input
+            .category
+            .as_str()
+            .bytes()
+            .chain(std::iter::once(0))
+            .chain(input.title.bytes())
+        {
+            hash ^= byte as u32;
+            hash = hash.wrapping_mul(0x0100_0193);
+        }
+        format!("{:08x}", hash)
+    }
+
+    /// ファイル名に使用できる形式に変換
+    ///
+    /// ASCII英数字のみを残して小文字化し、その他の文字はハイフンにまとめます。
+    pub fn slugify(text: &str) -> String {
+        let mut result = String::new();
+        let mut prev_was_hyphen = true;
+
+        for c in text.to_lowercase().chars() {
+            if c.is_ascii_alphanumeric() {
+                result.push(c);
+                prev_was_hyphen = false;
+            } else if !prev_was_hyphen && (c.is_whitespace() || c.is_ascii_punctuation()) {
+                result.push('-');
+                prev_was_hyphen = true;
+            }
+        }
+
+        let mut trimmed = result.trim_matches('-').to_string();
+        if trimmed.len() > MAX_STEM_LENGTH {
+            trimmed.truncate(MAX_STEM_LENGTH);
+            trimmed = trimmed.trim_end_matches('-').to_string();
+        }
+        trimmed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Category, Severity};
+
+    fn input(title: &str, slug: Option<&str>) -> KnowledgeInput {
+        KnowledgeInput {
+            title: title.to_string(),
+            category: Category::Alerts,
+            severity: Severity::High,
+            symptoms: "Test".to_string(),
+            procedure: "Test".to_string(),
+            notes: None,
+            related_links: None,
+            judgment: None,
+            slug: slug.map(str::to_string),
+            related_alerts: Vec::new(),
+            id: None,
+        }
+    }
+
+    fn namer(scheme: NamingScheme, pattern: &str) -> FileNamer {
+        FileNamer::new(NamingConfig {
+            scheme,
+            pattern: pattern.to_string(),
+        })
+    }
+
+    fn date() -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 1, 31).unwrap()
+    }
+
+    #[test]
+    fn test_romaji_scheme() {
+        let namer = namer(NamingScheme::Romaji, "");
+        assert_eq!(
+            namer.file_stem(&input("DB接続エラー対応", None), date()),
+            "db-setsuzoku-era-taiou"
+        );
+        assert_eq!(
+            namer.file_stem(&input("CPU High Alert", None), date()),
+            "cpu-high-alert"
+        );
+    }
+
+    #[test]
+    fn test_ascii_scheme_keeps_legacy_behavior() {
+        let namer = namer(NamingScheme::Ascii, "");
+        assert_eq!(
+            namer.file_stem(&input("DB接続エラー対応", None), date()),
+            "db"
+        );
+    }
+
+    #[test]
+    fn test_date_id_scheme_is_stable() {
+        let namer = namer(NamingScheme::DateId, "");
+        let first = namer.file_stem(&input("鯖落ち", None), date());
+        let second = namer.file_stem(&input("鯖落ち", None), date());
+
+        assert!(first.starts_with("20260131-"));
+        assert_eq!(first.len(), "20260131-".len() + 8);
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn test_slug_scheme() {
+        let namer = namer(NamingScheme::Slug, "");
+        assert_eq!(
+            namer.file_stem(
+                &input("DB接続エラー対応", Some("DB Connection Error")),
+                date()
+            ),
+            "db-connection-error"
+        );
+        // slug未指定時はローマ字
+        assert_eq!(
+            namer.file_stem(&input("障害対応", None), date()),
+            "shougai-taiou"
+        );
+    }
+
+    #[test]
+    fn test_pattern_scheme() {
+        let namer = namer(NamingScheme::Pattern, "{date}-{category}-{slug}");
+        assert_eq!(
+            namer.file_stem(&input("DB接続エラー対応", None), date()),
+            "2026-01-31-alerts-db-setsuzoku-era-taiou"
+        );
+    }
+
+    #[test]
+    fn test_fallback_to_date_id() {
+        let namer = namer(NamingScheme::Ascii, "");
+        assert!(namer
+            .file_stem(&input("鯖落ち", None), date())
+            .starts_with("20260131-"));
+    }
+
+    #[test]
+    fn test_date_id_scheme_uses_runbook_id() {
+        let namer = namer(NamingScheme::DateId, "");
+        let mut input = input("鯖落ち", None);
+        input.id = Some("rb-1a2b3c4d".to_string());
+        assert_eq!(namer.file_stem(&input, date()), "20260131-1a2b3c4d");
+    }
+
+    #[test]
+    fn test_validate_pattern() {
+        assert!(FileNamer::validate_pattern("{date}-{slug}").is_ok());
+        assert!(FileNamer::validate_pattern("{date}-{unknown}").is_err());
+        assert!(FileNamer::validate_pattern("{date").is_err());
+        assert!(FileNamer::validate_pattern("").is_err());
+    }
+
+    #[test]
+    fn test_slugify_truncates() {
+        let long = "a".repeat(200);
+        assert_eq!(FileNamer::slugify(&long).len(), MAX_STEM_LENGTH);
+    }
+}
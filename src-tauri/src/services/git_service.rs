@@ -1,11 +1,40 @@
-use crate::models::{Result, WorkNoteError};
-use std::path::{Path, PathBuf};
+use crate::models::{
+    AuthorConfig, CommitTrailers, GitConfig, GitHosting, Result, SigningConfig, SigningMode,
+    WorkNoteError,
+};
+use crate::services::commit_template::{CommitContext, CommitTemplate};
+use crate::services::remote_url::RemoteUrl;
+use std::path::PathBuf;
 use std::process::{Command, Stdio};
+use tracing::warn;
+
+/// 1つのコミットにまとめるファイルとコミットメッセージの変数の値
+#[derive(Debug, Clone)]
+pub struct CommitRequest {
+    pub files: Vec<PathBuf>,
+    pub context: CommitContext,
+}
+
+/// PR作成モードで公開するfeatureブランチ
+#[derive(Debug, Clone)]
+pub struct FeatureBranch {
+    /// ブランチ名
+    pub name: String,
+    /// PR（GitLabはMR）作成URL
+    pub pr_url: String,
+}
 
 /// GitService - Git操作を管理
 pub struct GitService {
     repository_path: PathBuf,
     default_branch: String,
+    commit_message: String,
+    branch_name: String,
+    remote: String,
+    push_remote: String,
+    hosting: Option<GitHosting>,
+    author: Option<AuthorConfig>,
+    signing: SigningConfig,
 }
 
 impl GitService {
@@ -14,61 +43,145 @@ impl GitService {
         GitService {
             repository_path,
             default_branch,
+            commit_message: GitConfig::default().commit_message,
+            branch_name: GitConfig::default().branch_name,
+            remote: GitConfig::default().remote,
+            push_remote: GitConfig::default().remote,
+            hosting: None,
+            author: None,
+            signing: SigningConfig::default(),
         }
     }
 
-    /// グローバルgit configからユーザー名を取得
-    pub fn get_global_user_name() -> Result<String> {
-        let output = Command::new("git")
-            .args(&["config", "--global", "user.name"])
-            .output()
-            .map_err(|e| WorkNoteError::GitError(format!("Failed to execute git config: {}", e)))?;
+    /// コミットメッセージの書式を指定（変数は `CommitTemplate` を参照）
+    ///
+    /// # Arguments
+    /// * `template` - コミットメッセージの書式
+    pub fn with_commit_message(mut self, template: String) -> Self {
+        self.commit_message = template;
+        self
+    }
 
-        if !output.status.success() {
-            return Err(WorkNoteError::ConfigError(
-                "Git user.name is not configured".to_string(),
-            ));
-        }
+    /// PR作成モードのブランチ名の書式を指定（変数は `CommitTemplate` を参照）
+    ///
+    /// # Arguments
+    /// * `template` - ブランチ名の書式
+    pub fn with_branch_name(mut self, template: String) -> Self {
+        self.branch_name = template;
+        self
+    }
 
-        let name = String::from_utf8_lossy(&output.stdout).trim().to_string();
-        if name.is_empty() {
-            return Err(WorkNoteError::ConfigError(
-                "Git user.name is empty".to_string(),
-            ));
-        }
+    /// コミットのauthor・committerを指定
+    ///
+    /// 指定しない場合はgitの既定（git configのユーザー）でコミットします。
+    ///
+    /// # Arguments
+    /// * `author` - 解決済みのAuthor（`resolve_author` の結果）
+    pub fn with_author(mut self, author: AuthorConfig) -> Self {
+        self.author = Some(author);
+        self
+    }
 
-        Ok(name)
+    /// Pull元・Push先のリモート名を指定（既定はどちらも `origin`）
+    ///
+    /// # Arguments
+    /// * `remote` - Pull・PR作成先のリモート名
+    /// * `push_remote` - Pushするリモート名
+    pub fn with_remotes(mut self, remote: String, push_remote: String) -> Self {
+        self.remote = remote;
+        self.push_remote = push_remote;
+        self
     }
 
-    /// グローバルgit configからユーザーメールを取得
-    pub fn get_global_user_email() -> Result<String> {
-        let output = Command::new("git")
-            .args(&["config", "--global", "user.email"])
+    /// PR作成URLの形式を指定（`None` の場合はリモートのホスト名から推定）
+    ///
+    /// # Arguments
+    /// * `hosting` - ホスティングサービス
+    pub fn with_hosting(mut self, hosting: Option<GitHosting>) -> Self {
+        self.hosting = hosting;
+        self
+    }
+
+    /// コミット署名の設定を指定
+    ///
+    /// # Arguments
+    /// * `signing` - 署名設定（既定はgit configに従う）
+    pub fn with_signing(mut self, signing: SigningConfig) -> Self {
+        self.signing = signing;
+        self
+    }
+
+    /// コミットに使うAuthorを解決
+    ///
+    /// 名前・メールアドレスそれぞれについて、設定のAuthor → リポジトリのgit config →
+    /// グローバルのgit config の順に、最初に見つかった空でない値を使います。
+    ///
+    /// # Arguments
+    /// * `configured` - 設定（プロファイル）のAuthor
+    pub fn resolve_author(&self, configured: Option<&AuthorConfig>) -> Result<AuthorConfig> {
+        let resolve = |value: Option<&String>, key: &str| -> Result<String> {
+            if let Some(value) = value.map(|v| v.trim()).filter(|v| !v.is_empty()) {
+                return Ok(value.to_string());
+            }
+            for scope in ["--local", "--global"] {
+                if let Some(value) = self.read_git_config(scope, key)? {
+                    return Ok(value);
+                }
+            }
+            Err(WorkNoteError::ConfigError(format!(
+                "Git {} is not configured. Set the author in WorkNote settings or run `git config {} <value>`",
+                key, key
+            )))
+        };
+
+        Ok(AuthorConfig {
+            name: resolve(configured.map(|a| &a.name), "user.name")?,
+            email: resolve(configured.map(|a| &a.email), "user.email")?,
+        })
+    }
+
+    /// git configの値を取得（未設定・空の場合は `None`）
+    ///
+    /// # Arguments
+    /// * `scope` - `--local` または `--global`
+    /// * `key` - 設定キー（例: `user.name`）
+    fn read_git_config(&self, scope: &str, key: &str) -> Result<Option<String>> {
+        let mut command = Command::new("git");
+        // リポジトリ外（パス未設定など）ではリポジトリの設定を読まない
+        if scope == "--local" {
+            if !self.repository_path.join(".git").exists() {
+                return Ok(None);
+            }
+            command.current_dir(&self.repository_path);
+        }
+
+        let output = command
+            .args(["config", scope, "--get", key])
+            .stdin(Stdio::null())
             .output()
             .map_err(|e| WorkNoteError::GitError(format!("Failed to execute git config: {}", e)))?;
 
+        // 未設定の場合は終了コード1
         if !output.status.success() {
-            return Err(WorkNoteError::ConfigError(
-                "Git user.email is not configured".to_string(),
-            ));
-        }
-
-        let email = String::from_utf8_lossy(&output.stdout).trim().to_string();
-        if email.is_empty() {
-            return Err(WorkNoteError::ConfigError(
-                "Git user.email is empty".to_string(),
-            ));
+            return Ok(None);
         }
 
-        Ok(email)
+        let value = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        Ok(Some(value).filter(|v| !v.is_empty()))
     }
 
     /// Gitコマンドを実行
-    fn execute_git(&self, args: &[&str]) -> Result<String> {
+    pub(crate) fn execute_git(&self, args: &[&str]) -> Result<String> {
+        self.execute_git_with_env(args, &[])
+    }
+
+    /// 環境変数を追加してGitコマンドを実行
+    fn execute_git_with_env(&self, args: &[&str], envs: &[(&str, &str)]) -> Result<String> {
         let output = Command::new("git")
             .current_dir(&self.repository_path)
             .args(args)
             .env("GIT_TERMINAL_PROMPT", "0")
+            .envs(envs.iter().copied())
             .stdin(Stdio::null())
             .output()
             .map_err(|e| WorkNoteError::GitError(format!("Failed to execute git: {}", e)))?;
@@ -85,7 +198,16 @@ impl GitService {
     fn classify_error(&self, stderr: &str) -> WorkNoteError {
         let lower = stderr.to_lowercase();
 
-        if lower.contains("authentication failed")
+        // "failed to write commit object" はディスク容量不足などでも出るため、署名の失敗とは判定しない
+        // （SSH署名は "failed to sign" を出さないため、gitのSSH署名固有のメッセージで判定）
+        if lower.contains("gpg failed to sign")
+            || lower.contains("failed to sign")
+            || lower.contains("signing failed")
+            || lower.contains("couldn't load public key")
+            || lower.contains("ssh-keygen -y sign")
+        {
+            WorkNoteError::SigningError(stderr.trim().to_string())
+        } else if lower.contains("authentication failed")
             || lower.contains("permission denied")
             || lower.contains("could not read from remote")
         {
@@ -111,173 +233,298 @@ impl GitService {
         // デフォルトブランチにチェックアウト
         self.execute_git(&["checkout", &self.default_branch])?;
         // Pull実行
-        self.execute_git(&["pull", "origin", &self.default_branch])?;
+        self.execute_git(&["pull", &self.remote, &self.default_branch])?;
+        Ok(())
+    }
+
+    /// ファイルをステージング
+    ///
+    /// # Arguments
+    /// * `files` - ステージングするファイルのパス（リポジトリ内の絶対パス）
+    fn stage_files(&self, files: &[PathBuf]) -> Result<()> {
+        for file_path in files {
+            // ファイル名を相対パスに変換
+            let relative_path = file_path
+                .strip_prefix(&self.repository_path)
+                .map_err(|e| WorkNoteError::FileError(format!("Invalid file path: {}", e)))?;
+
+            // 相対パスをUTF-8文字列に変換
+            let relative_path_str = relative_path
+                .to_str()
+                .ok_or_else(|| WorkNoteError::FileError("Invalid UTF-8 file path".to_string()))?;
+
+            // Git add
+            self.execute_git(&["add", relative_path_str])?;
+        }
         Ok(())
     }
 
     /// ファイルをコミット＆プッシュ
-    pub fn commit_and_push(&self, file_path: &Path, title: &str, category: &str, severity: &str) -> Result<String> {
-        // ファイル名を相対パスに変換
-        let relative_path = file_path.strip_prefix(&self.repository_path)
-            .map_err(|e| WorkNoteError::FileError(format!("Invalid file path: {}", e)))?;
+    ///
+    /// `files` の全ファイル（Runbook本体と再生成したインデックス等）を1つのコミットにまとめます。
+    ///
+    /// # Arguments
+    /// * `context` - コミットメッセージの変数の値
+    /// * `trailers` - コミットメッセージに追加するtrailer
+    pub fn commit_and_push(
+        &self,
+        files: &[PathBuf],
+        context: &CommitContext,
+        trailers: &CommitTrailers,
+    ) -> Result<String> {
+        let request = CommitRequest {
+            files: files.to_vec(),
+            context: context.clone(),
+        };
+        let hashes = self.commit_all_and_push(std::slice::from_ref(&request), trailers)?;
+        Ok(hashes.into_iter().last().unwrap_or_default())
+    }
 
-        // 相対パスをUTF-8文字列に変換
-        let relative_path_str = relative_path
-            .to_str()
-            .ok_or_else(|| WorkNoteError::FileError("Invalid UTF-8 file path".to_string()))?;
+    /// 複数のコミットを順に作成してまとめてプッシュ
+    ///
+    /// # Arguments
+    /// * `commits` - 作成するコミット（この順にコミット）
+    /// * `trailers` - 各コミットメッセージに追加するtrailer
+    ///
+    /// # Returns
+    /// 作成したコミットのハッシュ（コミット順）
+    pub fn commit_all_and_push(
+        &self,
+        commits: &[CommitRequest],
+        trailers: &CommitTrailers,
+    ) -> Result<Vec<String>> {
+        // 失敗した場合はコミット前のHEADに戻す（未コミットのリポジトリでは取得できない）
+        let original_head = self
+            .execute_git(&["rev-parse", "--verify", "-q", "HEAD"])
+            .ok();
+
+        let result = self.commit_requests(commits, trailers).and_then(|hashes| {
+            // Git push
+            self.execute_git(&["push", &self.push_remote, &self.default_branch])?;
+            Ok(hashes)
+        });
+        if result.is_err() {
+            let reset = match original_head.as_deref().map(str::trim) {
+                Some(head) => self.execute_git(&["reset", "-q", head]),
+                None => self
+                    .execute_git(&["update-ref", "-d", "HEAD"])
+                    .and_then(|_| self.execute_git(&["reset", "-q"])),
+            };
+            if let Err(e) = reset {
+                warn!(error = %e, "Failed to reset commits after publish failure");
+            }
+        }
+        result
+    }
 
-        // Git add
-        self.execute_git(&["add", relative_path_str])?;
+    /// 公開に失敗したファイルの変更を取り消す
+    ///
+    /// HEADにあるファイルはHEADの内容に戻し、新規ファイルは削除します。
+    /// 元のエラーを返すため、取り消しに失敗したファイルは警告のみ出力します。
+    ///
+    /// # Arguments
+    /// * `files` - 取り消すファイルのパス（リポジトリ内の絶対パス）
+    pub fn discard_files(&self, files: &[PathBuf]) {
+        for file_path in files {
+            let Some(relative_path) = file_path
+                .strip_prefix(&self.repository_path)
+                .ok()
+                .and_then(|path| path.to_str())
+            else {
+                continue;
+            };
+
+            let _ = self.execute_git(&["reset", "-q", "--", relative_path]);
+            let tracked = self
+                .execute_git(&["cat-file", "-e", &format!("HEAD:{}", relative_path)])
+                .is_ok();
+            let result = if tracked {
+                self.execute_git(&["checkout", "HEAD", "--", relative_path])
+                    .map(|_| ())
+            } else if file_path.exists() {
+                std::fs::remove_file(file_path).map_err(WorkNoteError::from)
+            } else {
+                Ok(())
+            };
+            if let Err(e) = result {
+                warn!(file = %relative_path, error = %e, "Failed to discard file");
+            }
+        }
+    }
 
-        // コミットメッセージ生成
-        let message = self.format_commit_message(title, category, severity);
+    /// 各コミットのファイルをステージしてコミットし、コミットハッシュを返す
+    fn commit_requests(
+        &self,
+        commits: &[CommitRequest],
+        trailers: &CommitTrailers,
+    ) -> Result<Vec<String>> {
+        if commits.is_empty() {
+            return Err(WorkNoteError::ValidationError(
+                "No files to commit".to_string(),
+            ));
+        }
 
-        // Git commit
-        self.execute_git(&["commit", "-m", &message])?;
+        let mut hashes = Vec::with_capacity(commits.len());
+        for commit in commits {
+            // Git add
+            self.stage_files(&commit.files)?;
 
-        // Git push
-        self.execute_git(&["push", "origin", &self.default_branch])?;
+            // コミットメッセージ生成
+            let message =
+                CommitTemplate::render_message(&self.commit_message, &commit.context, trailers);
 
-        // 最新のコミットハッシュを取得
-        let hash = self.execute_git(&["rev-parse", "HEAD"])?;
-        Ok(hash.trim().to_string())
-    }
+            // Git commit
+            self.commit_staged(&message)?;
 
-    /// コミットメッセージをフォーマット
-    fn format_commit_message(&self, title: &str, category: &str, severity: &str) -> String {
-        format!(
-            "docs(worknote): add {}\n\nCategory: {}\nSeverity: {}",
-            title, category, severity
-        )
+            // コミットハッシュを取得
+            let hash = self.execute_git(&["rev-parse", "HEAD"])?;
+            hashes.push(hash.trim().to_string());
+        }
+        Ok(hashes)
     }
 
-    /// リモートURLからowner/repoを取得
-    fn get_remote_info(&self) -> Result<(String, String)> {
-        let remote_url = self.execute_git(&["remote", "get-url", "origin"])?;
-        let remote_url = remote_url.trim();
-
-        // Parse GitHub URL
-        // Examples:
-        // - https://github.com/owner/repo.git
-        // - git@github.com:owner/repo.git
-        let parts: Vec<&str> = if remote_url.starts_with("https://") {
-            // HTTPS URL
-            remote_url
-                .trim_start_matches("https://github.com/")
-                .trim_end_matches(".git")
-                .split('/')
-                .collect()
-        } else if remote_url.starts_with("git@") {
-            // SSH URL
-            remote_url
-                .trim_start_matches("git@github.com:")
-                .trim_end_matches(".git")
-                .split('/')
-                .collect()
-        } else {
-            return Err(WorkNoteError::GitError(
-                "Unsupported remote URL format".to_string(),
-            ));
+    /// コミットコマンドの引数（署名設定を反映）
+    ///
+    /// GPG・SSHを指定した場合は `gpg.format`（鍵の指定があれば `user.signingkey` も）を
+    /// このコミットに限って上書きし、git configの `commit.gpgsign` に関わらず署名します。
+    fn commit_args(&self, message: &str) -> Vec<String> {
+        let mut args = Vec::new();
+        let format = match self.signing.mode {
+            SigningMode::Gpg => Some("openpgp"),
+            SigningMode::Ssh => Some("ssh"),
+            SigningMode::Inherit | SigningMode::Disabled => None,
         };
+        if let Some(format) = format {
+            args.push("-c".to_string());
+            args.push(format!("gpg.format={}", format));
+            if let Some(key) = self.signing.key.as_deref().map(str::trim) {
+                if !key.is_empty() {
+                    args.push("-c".to_string());
+                    args.push(format!("user.signingkey={}", key));
+                }
+            }
+        }
 
-        if parts.len() < 2 {
-            return Err(WorkNoteError::GitError(
-                "Invalid remote URL format".to_string(),
-            ));
+        args.push("commit".to_string());
+        match self.signing.mode {
+            SigningMode::Inherit => {}
+            SigningMode::Disabled => args.push("--no-gpg-sign".to_string()),
+            SigningMode::Gpg | SigningMode::Ssh => args.push("--gpg-sign".to_string()),
         }
+        args.push("-m".to_string());
+        args.push(message.to_string());
+        args
+    }
 
-        Ok((parts[0].to_string(), parts[1].to_string()))
+    /// ステージング済みの変更をコミット
+    ///
+    /// Authorが指定されている場合はauthor・committerの両方に明示的に設定します。
+    /// 署名に失敗した場合は `SigningError` を返します。
+    fn commit_staged(&self, message: &str) -> Result<()> {
+        let envs = match &self.author {
+            Some(author) => vec![
+                ("GIT_AUTHOR_NAME", author.name.as_str()),
+                ("GIT_AUTHOR_EMAIL", author.email.as_str()),
+                ("GIT_COMMITTER_NAME", author.name.as_str()),
+                ("GIT_COMMITTER_EMAIL", author.email.as_str()),
+            ],
+            None => Vec::new(),
+        };
+        let args = self.commit_args(message);
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        self.execute_git_with_env(&args, &envs)?;
+        Ok(())
     }
 
-    /// GitHub PR作成URLを生成
-    fn generate_pr_url(&self, branch: &str) -> Result<String> {
-        let (owner, repo) = self.get_remote_info()?;
-        Ok(format!(
-            "https://github.com/{}/{}/compare/{}",
-            owner, repo, branch
-        ))
+    /// リモートのURLを取得して解析
+    fn remote_url(&self, remote: &str) -> Result<RemoteUrl> {
+        let url = self.execute_git(&["remote", "get-url", remote])?;
+        RemoteUrl::parse(&url)
     }
 
-    /// タイトルをブランチ名に変換（kebab-case + タイムスタンプ）
-    fn sanitize_branch_name(&self, title: &str) -> String {
-        let timestamp = chrono::Utc::now().format("%Y%m%d-%H%M%S");
-        let sanitized = title
-            .to_lowercase()
-            .chars()
-            .filter_map(|c| {
-                if c.is_ascii_alphanumeric() {
-                    Some(c)
-                } else if c.is_whitespace() || c.is_ascii_punctuation() {
-                    Some('-')
-                } else {
-                    None
-                }
-            })
-            .collect::<String>()
-            .split('-')
-            .filter(|s| !s.is_empty())
-            .collect::<Vec<_>>()
-            .join("-");
-
-        // 日本語タイトルなど、ASCII文字がない場合は"knowledge"をデフォルトとして使用
-        let prefix = if sanitized.is_empty() {
-            "knowledge"
+    /// PR（GitLabはMR）作成URLを生成
+    ///
+    /// Push先とPull元のリモートが異なる場合（フォーク）は、Pull元のリポジトリへのPRにします。
+    fn generate_pr_url(&self, branch: &str) -> Result<String> {
+        let push_url = self.remote_url(&self.push_remote)?;
+        let base_url = if self.push_remote == self.remote {
+            None
         } else {
-            &sanitized
+            Some(self.remote_url(&self.remote)?)
         };
-
-        format!("feature/worknote-{}-{}", prefix, timestamp)
+        Ok(push_url.pull_request_url(
+            self.hosting,
+            branch,
+            &self.default_branch,
+            base_url.as_ref(),
+        ))
     }
 
-    /// PR作成モード: featureブランチにコミット＆プッシュ
-    pub fn commit_and_push_pr(
-        &self,
-        file_path: &Path,
-        title: &str,
-        category: &str,
-        severity: &str,
-    ) -> Result<(String, String)> {
+    /// PR作成モード: デフォルトブランチの最新を取得してfeatureブランチを作成・チェックアウト
+    ///
+    /// ファイル（Runbook・インデックス）は最新の内容をもとに生成するため、書き込む前に呼び出します。
+    /// ファイルの書き込みに失敗した場合は `abandon_feature_branch` でブランチを削除します。
+    ///
+    /// # Arguments
+    /// * `branch_context` - ブランチ名の変数の値
+    pub fn start_feature_branch(&self, branch_context: &CommitContext) -> Result<FeatureBranch> {
+        // featureブランチ名とPR作成URLを生成（失敗した場合はリポジトリを操作する前に中断）
+        let name = CommitTemplate::render_branch_name(&self.branch_name, branch_context)?;
+        let pr_url = self.generate_pr_url(&name)?;
+
         // デフォルトブランチから最新を取得
         self.pull_latest()?;
 
-        // featureブランチ名を生成
-        let branch_name = self.sanitize_branch_name(title);
-
         // featureブランチを作成してチェックアウト
-        self.execute_git(&["checkout", "-b", &branch_name])?;
-
-        // ファイル名を相対パスに変換
-        let relative_path = file_path
-            .strip_prefix(&self.repository_path)
-            .map_err(|e| WorkNoteError::FileError(format!("Invalid file path: {}", e)))?;
-
-        // 相対パスをUTF-8文字列に変換
-        let relative_path_str = relative_path
-            .to_str()
-            .ok_or_else(|| WorkNoteError::FileError("Invalid UTF-8 file path".to_string()))?;
+        self.execute_git(&["checkout", "-b", &name])?;
 
-        // Git add
-        self.execute_git(&["add", relative_path_str])?;
-
-        // コミットメッセージ生成
-        let message = self.format_commit_message(title, category, severity);
-
-        // Git commit
-        self.execute_git(&["commit", "-m", &message])?;
-
-        // Git push (featureブランチ)
-        self.execute_git(&["push", "origin", &branch_name])?;
+        Ok(FeatureBranch { name, pr_url })
+    }
 
-        // 最新のコミットハッシュを取得
-        let hash = self.execute_git(&["rev-parse", "HEAD"])?;
+    /// デフォルトブランチに戻し、作成したfeatureブランチ（とコミット）を削除
+    ///
+    /// 公開に失敗した場合に呼び出すため、削除に失敗した場合は警告のみ出力します。
+    pub fn abandon_feature_branch(&self, branch: &FeatureBranch) {
+        let rollback = self
+            .execute_git(&["checkout", &self.default_branch])
+            .and_then(|_| self.execute_git(&["branch", "-D", &branch.name]));
+        if let Err(e) = rollback {
+            warn!(
+                branch = %branch.name,
+                error = %e,
+                "Failed to remove feature branch after publish failure"
+            );
+        }
+    }
 
-        // PR URL生成
-        let pr_url = self.generate_pr_url(&branch_name)?;
+    /// PR作成モード: `start_feature_branch` で作成したブランチに複数のコミットを作成してプッシュ
+    ///
+    /// 失敗した場合はブランチを削除してデフォルトブランチに戻します。
+    ///
+    /// # Arguments
+    /// * `commits` - 作成するコミット（この順にコミット）
+    /// * `branch` - コミットするfeatureブランチ
+    /// * `trailers` - 各コミットメッセージに追加するtrailer
+    ///
+    /// # Returns
+    /// 作成したコミットのハッシュ（コミット順）
+    pub fn commit_all_and_push_pr(
+        &self,
+        commits: &[CommitRequest],
+        branch: &FeatureBranch,
+        trailers: &CommitTrailers,
+    ) -> Result<Vec<String>> {
+        let result = self.commit_requests(commits, trailers).and_then(|hashes| {
+            // Git push (featureブランチ)
+            self.execute_git(&["push", &self.push_remote, &branch.name])?;
+            Ok(hashes)
+        });
+        let hashes = result.inspect_err(|_| self.abandon_feature_branch(branch))?;
 
         // デフォルトブランチに戻す（次回のDirect modeで誤ったブランチに コミットしないため）
         self.execute_git(&["checkout", &self.default_branch])?;
 
-        Ok((hash.trim().to_string(), pr_url))
+        Ok(hashes)
     }
 }
 
@@ -308,12 +555,399 @@ mod tests {
     }
 
     #[test]
-    fn test_format_commit_message() {
+    fn test_classify_error_signing() {
+        let service = GitService::new(PathBuf::from("/test"), "main".to_string());
+        let error = service.classify_error(
+            "error: gpg failed to sign the data\nfatal: failed to write commit object\n",
+        );
+
+        match error {
+            WorkNoteError::SigningError(msg) => assert!(msg.starts_with("error: gpg failed")),
+            _ => panic!("Expected SigningError"),
+        }
+
+        // 署名以外の理由でコミットオブジェクトを書き込めない場合はGitError
+        let error = service.classify_error(
+            "error: insufficient permission for adding an object to repository database .git/objects\nfatal: failed to write commit object\n",
+        );
+        assert!(matches!(error, WorkNoteError::GitError(_)));
+    }
+
+    #[test]
+    fn test_commit_args() {
         let service = GitService::new(PathBuf::from("/test"), "main".to_string());
-        let message = service.format_commit_message("CPU高騰対応", "alerts", "high");
+        assert_eq!(service.commit_args("msg"), ["commit", "-m", "msg"]);
+
+        let service = service.with_signing(SigningConfig {
+            mode: SigningMode::Disabled,
+            key: Some("ABCD1234".to_string()),
+        });
+        assert_eq!(
+            service.commit_args("msg"),
+            ["commit", "--no-gpg-sign", "-m", "msg"]
+        );
+
+        let service = service.with_signing(SigningConfig {
+            mode: SigningMode::Gpg,
+            key: Some(" ABCD1234 ".to_string()),
+        });
+        assert_eq!(
+            service.commit_args("msg"),
+            [
+                "-c",
+                "gpg.format=openpgp",
+                "-c",
+                "user.signingkey=ABCD1234",
+                "commit",
+                "--gpg-sign",
+                "-m",
+                "msg"
+            ]
+        );
+
+        // 鍵の指定がなければgit configの user.signingkey を使う
+        let service = service.with_signing(SigningConfig {
+            mode: SigningMode::Ssh,
+            key: None,
+        });
+        assert_eq!(
+            service.commit_args("msg"),
+            ["-c", "gpg.format=ssh", "commit", "--gpg-sign", "-m", "msg"]
+        );
+    }
+
+    fn git(repo: &std::path::Path, args: &[&str]) -> String {
+        let output = Command::new("git")
+            .current_dir(repo)
+            .args(args)
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "git {:?} failed", args);
+        String::from_utf8_lossy(&output.stdout).trim().to_string()
+    }
+
+    #[test]
+    fn test_resolve_author_and_commit() {
+        let repo = std::env::temp_dir().join("worknote_test_git_author");
+        let _ = std::fs::remove_dir_all(&repo);
+        std::fs::create_dir_all(&repo).unwrap();
+        git(&repo, &["init", "--quiet"]);
+        git(&repo, &["config", "user.name", "Repo User"]);
+        git(&repo, &["config", "user.email", "repo@example.com"]);
+        let service = GitService::new(repo.clone(), "main".to_string());
+
+        // 設定のAuthorが優先、空の項目はリポジトリのgit configで補う
+        let configured = AuthorConfig {
+            name: "Config User".to_string(),
+            email: " ".to_string(),
+        };
+        let author = service.resolve_author(Some(&configured)).unwrap();
+        assert_eq!(author.name, "Config User");
+        assert_eq!(author.email, "repo@example.com");
+        assert_eq!(
+            service.resolve_author(None).unwrap().to_string(),
+            "Repo User <repo@example.com>"
+        );
+
+        // author・committerとも解決したAuthorでコミットする
+        std::fs::write(repo.join("note.md"), "# note\n").unwrap();
+        git(&repo, &["add", "note.md"]);
+        let service = service.with_author(author);
+        service.commit_staged("docs: add note").unwrap();
+        assert_eq!(
+            git(&repo, &["log", "-1", "--format=%an <%ae>|%cn <%ce>"]),
+            "Config User <repo@example.com>|Config User <repo@example.com>"
+        );
+
+        let _ = std::fs::remove_dir_all(&repo);
+    }
+
+    #[test]
+    fn test_commit_all_and_push() {
+        let base = std::env::temp_dir().join("worknote_test_git_batch");
+        let _ = std::fs::remove_dir_all(&base);
+        let (remote, repo) = (base.join("remote.git"), base.join("repo"));
+        std::fs::create_dir_all(&repo).unwrap();
+        git(&base, &["init", "--quiet", "--bare", "remote.git"]);
+        git(&repo, &["init", "--quiet", "--initial-branch=main"]);
+        git(&repo, &["config", "user.name", "Repo User"]);
+        git(&repo, &["config", "user.email", "repo@example.com"]);
+        git(
+            &repo,
+            &["remote", "add", "origin", remote.to_str().unwrap()],
+        );
+
+        let context = |title: &str| CommitContext {
+            title: title.to_string(),
+            category: "ops".to_string(),
+            severity: "low".to_string(),
+            author: "Repo User".to_string(),
+            id: None,
+            profile: "default".to_string(),
+            time: chrono::Local::now(),
+        };
+        let mut commits = Vec::new();
+        for name in ["a", "b"] {
+            std::fs::write(repo.join(format!("{}.md", name)), "# note\n").unwrap();
+            commits.push(CommitRequest {
+                files: vec![repo.join(format!("{}.md", name))],
+                context: context(name),
+            });
+        }
+
+        // コミットごとにハッシュを返し、最後にまとめてPushする
+        let service = GitService::new(repo.clone(), "main".to_string())
+            .with_commit_message("docs: add {title}".to_string());
+        let hashes = service
+            .commit_all_and_push(&commits, &CommitTrailers::default())
+            .unwrap();
+        assert_eq!(hashes.len(), 2);
+        assert_eq!(git(&remote, &["rev-parse", "main"]), hashes[1]);
+        assert_eq!(
+            git(&remote, &["log", "--format=%s", "main"]),
+            "docs: add b\ndocs: add a"
+        );
+        assert!(service
+            .commit_all_and_push(&[], &CommitTrailers::default())
+            .is_err());
+
+        let _ = std::fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn test_commit_all_and_push_rolls_back_on_failure() {
+        let repo = std::env::temp_dir().join("worknote_test_git_rollback");
+        let _ = std::fs::remove_dir_all(&repo);
+        std::fs::create_dir_all(&repo).unwrap();
+        git(&repo, &["init", "--quiet", "--initial-branch=main"]);
+        git(&repo, &["config", "user.name", "Repo User"]);
+        git(&repo, &["config", "user.email", "repo@example.com"]);
+        std::fs::write(repo.join("index.md"), "# index\n").unwrap();
+        git(&repo, &["add", "index.md"]);
+        git(&repo, &["commit", "--quiet", "-m", "init"]);
+        let head = git(&repo, &["rev-parse", "HEAD"]);
+
+        // 新規ファイルと既存ファイルの変更をコミットし、Push先がないためPushで失敗する
+        std::fs::write(repo.join("a.md"), "# a\n").unwrap();
+        std::fs::write(repo.join("index.md"), "# index\n- a\n").unwrap();
+        let files = vec![repo.join("a.md"), repo.join("index.md")];
+        let request = CommitRequest {
+            files: files.clone(),
+            context: CommitContext {
+                title: "a".to_string(),
+                category: "ops".to_string(),
+                severity: "low".to_string(),
+                author: "Repo User".to_string(),
+                id: None,
+                profile: "default".to_string(),
+                time: chrono::Local::now(),
+            },
+        };
+        let service = GitService::new(repo.clone(), "main".to_string());
+        assert!(service
+            .commit_all_and_push(&[request], &CommitTrailers::default())
+            .is_err());
+        assert_eq!(git(&repo, &["rev-parse", "HEAD"]), head);
+
+        // 書き込んだファイルを取り消すと、作業ツリーは公開前の状態に戻る
+        service.discard_files(&files);
+        assert!(!repo.join("a.md").exists());
+        assert_eq!(
+            std::fs::read_to_string(repo.join("index.md")).unwrap(),
+            "# index\n"
+        );
+        assert_eq!(git(&repo, &["status", "--porcelain"]), "");
+
+        let _ = std::fs::remove_dir_all(&repo);
+    }
+
+    #[test]
+    fn test_commit_all_and_push_pr() {
+        let base = std::env::temp_dir().join("worknote_test_git_feature_branch");
+        let _ = std::fs::remove_dir_all(&base);
+        let (remote, repo) = (base.join("remote.git"), base.join("repo"));
+        std::fs::create_dir_all(&repo).unwrap();
+        git(&base, &["init", "--quiet", "--bare", "remote.git"]);
+        git(&repo, &["init", "--quiet", "--initial-branch=main"]);
+        git(&repo, &["config", "user.name", "Repo User"]);
+        git(&repo, &["config", "user.email", "repo@example.com"]);
+        git(
+            &repo,
+            &["remote", "add", "origin", remote.to_str().unwrap()],
+        );
+        git(&repo, &["commit", "--quiet", "--allow-empty", "-m", "init"]);
+
+        let service = GitService::new(repo.clone(), "main".to_string())
+            .with_commit_message("docs: add {title}".to_string());
+        let request = |name: &str| {
+            std::fs::write(repo.join(format!("{}.md", name)), "# note\n").unwrap();
+            CommitRequest {
+                files: vec![repo.join(format!("{}.md", name))],
+                context: CommitContext {
+                    title: name.to_string(),
+                    category: "ops".to_string(),
+                    severity: "low".to_string(),
+                    author: "Repo User".to_string(),
+                    id: None,
+                    profile: "default".to_string(),
+                    time: chrono::Local::now(),
+                },
+            }
+        };
+
+        // ファイルは作成済みのfeatureブランチ上で書き込み、コミット後にデフォルトブランチへ戻る
+        let branch = FeatureBranch {
+            name: "wn/a".to_string(),
+            pr_url: String::new(),
+        };
+        git(&repo, &["checkout", "--quiet", "-b", "wn/a"]);
+        let hashes = service
+            .commit_all_and_push_pr(&[request("a")], &branch, &CommitTrailers::default())
+            .unwrap();
+        assert_eq!(git(&remote, &["rev-parse", "wn/a"]), hashes[0]);
+        assert_eq!(git(&repo, &["branch", "--show-current"]), "main");
+        assert!(!repo.join("a.md").exists());
+
+        // Pushに失敗した場合はブランチを削除してデフォルトブランチに戻る
+        let service = service.with_remotes("origin".to_string(), "missing".to_string());
+        let branch = FeatureBranch {
+            name: "wn/b".to_string(),
+            pr_url: String::new(),
+        };
+        git(&repo, &["checkout", "--quiet", "-b", "wn/b"]);
+        assert!(service
+            .commit_all_and_push_pr(&[request("b")], &branch, &CommitTrailers::default())
+            .is_err());
+        assert_eq!(git(&repo, &["branch", "--show-current"]), "main");
+        assert_eq!(git(&repo, &["branch", "--list", "wn/b"]), "");
+
+        let _ = std::fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn test_generate_pr_url() {
+        let repo = std::env::temp_dir().join("worknote_test_git_remotes");
+        let _ = std::fs::remove_dir_all(&repo);
+        std::fs::create_dir_all(&repo).unwrap();
+        git(&repo, &["init", "--quiet"]);
+        git(
+            &repo,
+            &[
+                "remote",
+                "add",
+                "upstream",
+                "ssh://git@gitlab.example.com:2222/sre/platform/runbooks.git",
+            ],
+        );
+        git(
+            &repo,
+            &["remote", "add", "fork", "git@github.com:alice/runbooks.git"],
+        );
+        git(
+            &repo,
+            &["remote", "add", "origin", "git@github.com:sre/runbooks.git"],
+        );
+
+        let service = GitService::new(repo.clone(), "main".to_string());
+        assert_eq!(
+            service.generate_pr_url("wn/a").unwrap(),
+            "https://github.com/sre/runbooks/compare/wn/a"
+        );
+
+        let service = service.with_remotes("upstream".to_string(), "upstream".to_string());
+        assert!(service
+            .generate_pr_url("wn/a")
+            .unwrap()
+            .starts_with("https://gitlab.example.com/sre/platform/runbooks/-/merge_requests/new?"));
+
+        // フォークにPushする場合はPull元のリポジトリと比較
+        let service = GitService::new(repo.clone(), "main".to_string())
+            .with_remotes("origin".to_string(), "fork".to_string());
+        assert_eq!(
+            service.generate_pr_url("wn/a").unwrap(),
+            "https://github.com/sre/runbooks/compare/main...alice:wn/a"
+        );
+
+        let service = service.with_remotes("missing".to_string(), "missing".to_string());
+        assert!(service.generate_pr_url("wn/a").is_err());
+
+        let _ = std::fs::remove_dir_all(&repo);
+    }
+
+    #[test]
+    fn test_ssh_signed_commit() {
+        let dir = std::env::temp_dir().join("worknote_test_git_signing");
+        let _ = std::fs::remove_dir_all(&dir);
+        let repo = dir.join("repo");
+        std::fs::create_dir_all(&repo).unwrap();
+
+        // 使い捨てのSSH鍵（ssh-keygenがない環境ではスキップ）
+        let key = dir.join("signing_key");
+        let keygen = Command::new("ssh-keygen")
+            .args(["-q", "-t", "ed25519", "-N", "", "-C", "worknote-test", "-f"])
+            .arg(&key)
+            .output();
+        if !keygen.is_ok_and(|o| o.status.success()) {
+            let _ = std::fs::remove_dir_all(&dir);
+            return;
+        }
+        let key = key.to_str().unwrap().to_string();
+
+        git(&repo, &["init", "--quiet"]);
+        let author = AuthorConfig {
+            name: "Signer".to_string(),
+            email: "signer@example.com".to_string(),
+        };
+        let commit = |signing: SigningConfig, message: &str| {
+            std::fs::write(repo.join("note.md"), message).unwrap();
+            git(&repo, &["add", "note.md"]);
+            GitService::new(repo.clone(), "main".to_string())
+                .with_author(author.clone())
+                .with_signing(signing)
+                .commit_staged(message)
+        };
+        let is_signed = || {
+            git(&repo, &["cat-file", "commit", "HEAD"]).contains("gpgsig -----BEGIN SSH SIGNATURE")
+        };
+
+        // 設定で鍵を指定して署名
+        commit(
+            SigningConfig {
+                mode: SigningMode::Ssh,
+                key: Some(key.clone()),
+            },
+            "ssh",
+        )
+        .unwrap();
+        assert!(is_signed());
+
+        // Inheritはリポジトリの commit.gpgsign・gpg.format に従い、Disabledは署名しない
+        git(&repo, &["config", "commit.gpgsign", "true"]);
+        git(&repo, &["config", "gpg.format", "ssh"]);
+        git(&repo, &["config", "user.signingkey", &key]);
+        commit(SigningConfig::default(), "inherit").unwrap();
+        assert!(is_signed());
+        commit(
+            SigningConfig {
+                mode: SigningMode::Disabled,
+                key: None,
+            },
+            "disabled",
+        )
+        .unwrap();
+        assert!(!is_signed());
+
+        // 鍵を読み込めない場合はSigningError
+        let error = commit(
+            SigningConfig {
+                mode: SigningMode::Ssh,
+                key: Some(dir.join("missing_key").to_str().unwrap().to_string()),
+            },
+            "missing",
+        )
+        .unwrap_err();
+        assert!(matches!(error, WorkNoteError::SigningError(_)));
 
-        assert!(message.contains("docs(worknote): add CPU高騰対応"));
-        assert!(message.contains("Category: alerts"));
-        assert!(message.contains("Severity: high"));
+        let _ = std::fs::remove_dir_all(&dir);
     }
 }
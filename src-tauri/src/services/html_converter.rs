@@ -0,0 +1,644 @@
+/// HTMLのトークン
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Start {
+        name: String,
+        attrs: Vec<(String, String)>,
+    },
+    End(String),
+    Text(String),
+}
+
+impl Token {
+    fn attr<'a>(attrs: &'a [(String, String)], name: &str) -> Option<&'a str> {
+        attrs
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// 内容を出力しない要素
+const SKIPPED_ELEMENTS: [&str; 6] = ["script", "style", "head", "noscript", "svg", "template"];
+
+/// 内容をタグとして解釈しない要素（終了タグまでをテキストとして読む）
+const RAW_TEXT_ELEMENTS: [&str; 3] = ["script", "style", "title"];
+
+/// 空要素（終了タグを持たない）
+const VOID_ELEMENTS: [&str; 8] = ["br", "hr", "img", "meta", "link", "input", "col", "wbr"];
+
+/// HtmlConverter - Confluence/NotionのエクスポートHTMLをMarkdownに変換
+///
+/// 外部クレートに依存しない簡易変換です。見出し・段落・リスト・表・コード・リンク・画像に対応し、
+/// それ以外の要素は内容のみを出力します。
+pub struct HtmlConverter;
+
+impl HtmlConverter {
+    /// HTMLをMarkdownに変換
+    ///
+    /// Confluenceの `#main-content`、Notionの `<article>` がある場合は本文部分のみを変換します。
+    pub fn to_markdown(html: &str) -> String {
+        let tokens = Self::tokenize(html);
+        let tokens = Self::main_content(&tokens);
+
+        let mut writer = MarkdownWriter::default();
+        for token in tokens {
+            writer.handle(token);
+        }
+        writer.finish()
+    }
+
+    /// `<title>` の内容を取得
+    pub fn title(html: &str) -> Option<String> {
+        let tokens = Self::tokenize(html);
+        let start = tokens
+            .iter()
+            .position(|t| matches!(t, Token::Start { name, .. } if name == "title"))?;
+
+        let mut title = String::new();
+        for token in &tokens[start + 1..] {
+            match token {
+                Token::Text(text) => title.push_str(text),
+                _ => break,
+            }
+        }
+        let title = collapse_whitespace(&title).trim().to_string();
+        (!title.is_empty()).then_some(title)
+    }
+
+    /// 本文部分のトークンを取得（見つからなければ全体）
+    fn main_content(tokens: &[Token]) -> &[Token] {
+        let start = tokens
+            .iter()
+            .position(|t| {
+                matches!(t, Token::Start { attrs, .. }
+                    if Token::attr(attrs, "id") == Some("main-content"))
+            })
+            .or_else(|| {
+                tokens
+                    .iter()
+                    .position(|t| matches!(t, Token::Start { name, .. } if name == "article"))
+            });
+
+        let Some(start) = start else {
+            return tokens;
+        };
+        let Token::Start { name, .. } = &tokens[start] else {
+            return tokens;
+        };
+
+        // 同名要素の入れ子を数えて対応する終了タグを探す
+        let mut depth = 0;
+        for (index, token) in tokens.iter().enumerate().skip(start) {
+            match token {
+                Token::Start { name: n, .. } if n == name => depth += 1,
+                Token::End(n) if n == name => {
+                    depth -= 1;
+                    if depth == 0 {
+                        return &tokens[start + 1..index];
+                    }
+                }
+                _ => {}
+            }
+        }
+        &tokens[start + 1..]
+    }
+
+    /// HTMLをトークン列に分割（コメント・DOCTYPEは除去）
+    fn tokenize(html: &str) -> Vec<Token> {
+        let mut tokens = Vec::new();
+        let mut rest = html;
+
+        while !rest.is_empty() {
+            let Some(lt) = rest.find('<') else {
+                tokens.push(Token::Text(decode_entities(rest)));
+                break;
+            };
+            if lt > 0 {
+                tokens.push(Token::Text(decode_entities(&rest[..lt])));
+                rest = &rest[lt..];
+            }
+
+            if let Some(after) = rest.strip_prefix("<!--") {
+                rest = after.find("-->").map(|end| &after[end + 3..]).unwrap_or("");
+                continue;
+            }
+            if rest.starts_with("<!") || rest.starts_with("<?") {
+                rest = rest.find('>').map(|end| &rest[end + 1..]).unwrap_or("");
+                continue;
+            }
+
+            let is_end = rest.starts_with("</");
+            let name_start = if is_end { 2 } else { 1 };
+            if !rest[name_start..].starts_with(|c: char| c.is_ascii_alphabetic()) {
+                tokens.push(Token::Text("<".to_string()));
+                rest = &rest[1..];
+                continue;
+            }
+
+            let Some(end) = find_tag_end(rest) else {
+                tokens.push(Token::Text(decode_entities(rest)));
+                break;
+            };
+            let inner = &rest[name_start..end];
+            rest = &rest[end + 1..];
+
+            let name_len = inner
+                .find(|c: char| c.is_whitespace() || c == '/')
+                .unwrap_or(inner.len());
+            let name = inner[..name_len].to_ascii_lowercase();
+
+            if is_end {
+                tokens.push(Token::End(name));
+                continue;
+            }
+
+            let self_closing = inner.trim_end().ends_with('/');
+            tokens.push(Token::Start {
+                name: name.clone(),
+                attrs: parse_attrs(&inner[name_len..]),
+            });
+
+            if RAW_TEXT_ELEMENTS.contains(&name.as_str()) {
+                let lower = rest.to_ascii_lowercase();
+                let close = format!("</{}", name);
+                let end = lower.find(&close).unwrap_or(rest.len());
+                if name == "title" {
+                    tokens.push(Token::Text(decode_entities(&rest[..end])));
+                }
+                rest = &rest[end..];
+            } else if self_closing && !VOID_ELEMENTS.contains(&name.as_str()) {
+                tokens.push(Token::End(name));
+            }
+        }
+
+        tokens
+    }
+}
+
+/// Markdownの出力状態
+#[derive(Default)]
+struct MarkdownWriter {
+    out: String,
+    /// リストの入れ子（`None` は箇条書き、`Some(n)` は番号付きの次の番号）
+    lists: Vec<Option<usize>>,
+    /// `<a>` のリンク先（空は通常テキストとして出力）
+    links: Vec<Option<String>>,
+    /// `<blockquote>` 開始時の出力位置
+    quotes: Vec<usize>,
+    table: Option<Vec<Vec<String>>>,
+    /// 表のセル開始時の出力位置
+    cell_start: Option<usize>,
+    /// 出力を抑止している要素の入れ子
+    skipping: Vec<String>,
+    in_pre: bool,
+}
+
+impl MarkdownWriter {
+    fn handle(&mut self, token: &Token) {
+        if let Some(skipped) = self.skipping.last() {
+            if matches!(token, Token::End(name) if name == skipped) {
+                self.skipping.pop();
+            } else if let Token::Start { name, .. } = token {
+                if SKIPPED_ELEMENTS.contains(&name.as_str()) {
+                    self.skipping.push(name.clone());
+                }
+            }
+            return;
+        }
+
+        match token {
+            Token::Text(text) => self.text(text),
+            Token::Start { name, attrs } => self.start(name, attrs),
+            Token::End(name) => self.end(name),
+        }
+    }
+
+    fn start(&mut self, name: &str, attrs: &[(String, String)]) {
+        match name {
+            _ if SKIPPED_ELEMENTS.contains(&name) || name == "title" => {
+                self.skipping.push(name.to_string())
+            }
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                self.block_break();
+                let level = name[1..].parse().unwrap_or(1);
+                self.out.push_str(&"#".repeat(level));
+                self.out.push(' ');
+            }
+            "p" | "div" | "section" | "header" | "figure" | "details" | "summary"
+                if self.lists.is_empty() && self.cell_start.is_none() =>
+            {
+                self.block_break()
+            }
+            "br" => {
+                if self.in_pre {
+                    self.out.push('\n');
+                } else if self.cell_start.is_some() {
+                    self.out.push(' ');
+                } else {
+                    self.out.push_str("  \n");
+                }
+            }
+            "hr" => {
+                self.block_break();
+                self.out.push_str("---");
+                self.block_break();
+            }
+            "strong" | "b" => self.out.push_str("**"),
+            "em" | "i" => self.out.push('*'),
+            "code" if !self.in_pre => self.out.push('`'),
+            "pre" => {
+                self.block_break();
+                self.out.push_str("```\n");
+                self.in_pre = true;
+            }
+            "ul" | "ol" => {
+                if self.lists.is_empty() {
+                    self.block_break();
+                } else {
+                    self.line_break();
+                }
+                let start = Token::attr(attrs, "start")
+                    .and_then(|s| s.parse().ok())
+                    .unwrap_or(1);
+                self.lists.push((name == "ol").then_some(start));
+            }
+            "li" => {
+                self.line_break();
+                let depth = self.lists.len().max(1);
+                self.out.push_str(&"  ".repeat(depth - 1));
+                match self.lists.last_mut() {
+                    Some(Some(number)) => {
+                        self.out.push_str(&format!("{}. ", number));
+                        *number += 1;
+                    }
+                    _ => self.out.push_str("- "),
+                }
+            }
+            "blockquote" => {
+                self.block_break();
+                self.quotes.push(self.out.len());
+            }
+            "a" => {
+                let href = Token::attr(attrs, "href")
+                    .filter(|href| !href.is_empty() && !href.starts_with('#'))
+                    .map(str::to_string);
+                if href.is_some() {
+                    self.out.push('[');
+                }
+                self.links.push(href);
+            }
+            "img" => {
+                if let Some(src) = Token::attr(attrs, "src").filter(|s| !s.is_empty()) {
+                    let alt = Token::attr(attrs, "alt").unwrap_or_default();
+                    self.out
+                        .push_str(&format!("![{}]({})", alt, encode_url(src)));
+                }
+            }
+            "table" => {
+                self.block_break();
+                self.table = Some(Vec::new());
+            }
+            "tr" => {
+                if let Some(rows) = self.table.as_mut() {
+                    rows.push(Vec::new());
+                }
+            }
+            "td" | "th" if self.table.is_some() => self.cell_start = Some(self.out.len()),
+            _ => {}
+        }
+    }
+
+    fn end(&mut self, name: &str) {
+        match name {
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => self.block_break(),
+            "p" | "div" | "section" | "header" | "figure" | "details" | "summary"
+                if self.lists.is_empty() && self.cell_start.is_none() =>
+            {
+                self.block_break()
+            }
+            "strong" | "b" => self.out.push_str("**"),
+            "em" | "i" => self.out.push('*'),
+            "code" if !self.in_pre => self.out.push('`'),
+            "pre" => {
+                self.in_pre = false;
+                self.line_break();
+                self.out.push_str("```");
+                self.block_break();
+            }
+            "ul" | "ol" => {
+                self.lists.pop();
+                if self.lists.is_empty() {
+                    self.block_break();
+                }
+            }
+            "li" => self.line_break(),
+            "blockquote" => {
+                if let Some(start) = self.quotes.pop() {
+                    let quoted = self.out.split_off(start);
+                    for line in quoted.trim().lines() {
+                        self.out
+                            .push_str(&format!("> {}\n", line).replace("> \n", ">\n"));
+                    }
+                    self.block_break();
+                }
+            }
+            "a" => {
+                if let Some(Some(href)) = self.links.pop() {
+                    self.out.push_str(&format!("]({})", encode_url(&href)));
+                }
+            }
+            "td" | "th" => {
+                if let (Some(start), Some(rows)) = (self.cell_start.take(), self.table.as_mut()) {
+                    let cell = self.out.split_off(start);
+                    let cell = collapse_whitespace(cell.trim()).replace('|', "\\|");
+                    if let Some(row) = rows.last_mut() {
+                        row.push(cell);
+                    }
+                }
+            }
+            "table" => {
+                if let Some(rows) = self.table.take() {
+                    self.write_table(rows);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        if self.in_pre {
+            self.out.push_str(text);
+            return;
+        }
+
+        let text = collapse_whitespace(text);
+        let at_line_start = self.out.is_empty()
+            || self.out.ends_with(['\n', ' '])
+            || self.out.ends_with("- ")
+            || self.out.ends_with('[');
+        let text = if at_line_start {
+            text.trim_start()
+        } else {
+            &text
+        };
+        self.out.push_str(text);
+    }
+
+    fn write_table(&mut self, rows: Vec<Vec<String>>) {
+        let rows: Vec<Vec<String>> = rows.into_iter().filter(|row| !row.is_empty()).collect();
+        let Some(columns) = rows.iter().map(Vec::len).max() else {
+            return;
+        };
+
+        for (index, row) in rows.iter().enumerate() {
+            let cells: Vec<&str> = (0..columns)
+                .map(|i| row.get(i).map(String::as_str).unwrap_or(""))
+                .collect();
+            self.out.push_str(&format!("| {} |\n", cells.join(" | ")));
+            if index == 0 {
+                self.out
+                    .push_str(&format!("|{}\n", " --- |".repeat(columns)));
+            }
+        }
+        self.block_break();
+    }
+
+    /// 行を終える（空行は入れない）
+    fn line_break(&mut self) {
+        trim_trailing_spaces(&mut self.out);
+        if !self.out.is_empty() && !self.out.ends_with('\n') {
+            self.out.push('\n');
+        }
+    }
+
+    /// ブロックを区切る（空行を1つ入れる）
+    fn block_break(&mut self) {
+        trim_trailing_spaces(&mut self.out);
+        if self.out.is_empty() || self.out.ends_with("\n\n") {
+            return;
+        }
+        self.out.push_str(if self.out.ends_with('\n') {
+            "\n"
+        } else {
+            "\n\n"
+        });
+    }
+
+    fn finish(self) -> String {
+        let mut result = String::new();
+        let mut blank_lines = 0;
+        for line in self.out.lines() {
+            if line.trim().is_empty() {
+                blank_lines += 1;
+                continue;
+            }
+            if !result.is_empty() && blank_lines > 0 {
+                result.push('\n');
+            }
+            blank_lines = 0;
+            // 行末の空白2つは改行（`<br>`）として残す
+            if line.ends_with("  ") {
+                result.push_str(line);
+            } else {
+                result.push_str(line.trim_end());
+            }
+            result.push('\n');
+        }
+        result
+    }
+}
+
+/// 行末の空白（改行を除く）を除去
+fn trim_trailing_spaces(out: &mut String) {
+    let trimmed = out.trim_end_matches([' ', '\t']).len();
+    out.truncate(trimmed);
+}
+
+/// 引用符を考慮してタグの終端 `>` を探す
+fn find_tag_end(tag: &str) -> Option<usize> {
+    let mut quote = None;
+    for (index, c) in tag.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (None, '"' | '\'') => quote = Some(c),
+            (None, '>') => return Some(index),
+            _ => {}
+        }
+    }
+    None
+}
+
+/// 属性を解析（名前は小文字化、値のエンティティは解除）
+fn parse_attrs(source: &str) -> Vec<(String, String)> {
+    let mut attrs = Vec::new();
+    let mut rest = source.trim_start_matches(|c: char| c.is_whitespace() || c == '/');
+
+    while !rest.is_empty() {
+        let name_end = rest
+            .find(|c: char| c.is_whitespace() || c == '=' || c == '/')
+            .unwrap_or(rest.len());
+        let name = rest[..name_end].to_ascii_lowercase();
+        rest = rest[name_end..].trim_start();
+
+        let mut value = String::new();
+        if let Some(after) = rest.strip_prefix('=') {
+            let after = after.trim_start();
+            if let Some(quote) = after.chars().next().filter(|c| *c == '"' || *c == '\'') {
+                let inner = &after[1..];
+                let end = inner.find(quote).unwrap_or(inner.len());
+                value = decode_entities(&inner[..end]);
+                rest = inner.get(end + 1..).unwrap_or("");
+            } else {
+                let end = after.find(char::is_whitespace).unwrap_or(after.len());
+                value = decode_entities(&after[..end]);
+                rest = &after[end..];
+            }
+        }
+
+        if !name.is_empty() {
+            attrs.push((name, value));
+        }
+        rest = rest.trim_start_matches(|c: char| c.is_whitespace() || c == '/');
+    }
+
+    attrs
+}
+
+/// HTMLエンティティを解除（主要な名前付き参照と数値参照に対応）
+pub fn decode_entities(text: &str) -> String {
+    if !text.contains('&') {
+        return text.to_string();
+    }
+
+    let mut result = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(amp) = rest.find('&') {
+        result.push_str(&rest[..amp]);
+        rest = &rest[amp..];
+
+        let decoded = rest.find(';').filter(|&end| end <= 10).and_then(|end| {
+            let entity = &rest[1..end];
+            let c = match entity {
+                "amp" => Some('&'),
+                "lt" => Some('<'),
+                "gt" => Some('>'),
+                "quot" => Some('"'),
+                "apos" | "#39" => Some('\''),
+                "nbsp" => Some(' '),
+                "ndash" => Some('–'),
+                "mdash" => Some('—'),
+                "hellip" => Some('…'),
+                "rarr" => Some('→'),
+                "larr" => Some('←'),
+                "copy" => Some('©'),
+                _ => entity
+                    .strip_prefix("#x")
+                    .or_else(|| entity.strip_prefix("#X"))
+                    .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+                    .or_else(|| entity.strip_prefix('#').and_then(|d| d.parse().ok()))
+                    .and_then(char::from_u32),
+            };
+            c.map(|c| (c, end))
+        });
+
+        match decoded {
+            Some((c, end)) => {
+                result.push(c);
+                rest = &rest[end + 1..];
+            }
+            None => {
+                result.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    result.push_str(rest);
+    result
+}
+
+/// 連続する空白を1つのスペースにまとめる
+fn collapse_whitespace(text: &str) -> String {
+    let mut result = String::with_capacity(text.len());
+    let mut last_space = false;
+    for c in text.chars() {
+        if c.is_whitespace() {
+            if !last_space {
+                result.push(' ');
+            }
+            last_space = true;
+        } else {
+            result.push(c);
+            last_space = false;
+        }
+    }
+    result
+}
+
+/// Markdownのリンク先として使えるよう空白と括弧をエスケープ
+fn encode_url(url: &str) -> String {
+    url.replace(' ', "%20")
+        .replace('(', "%28")
+        .replace(')', "%29")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_basic_elements() {
+        let html = r#"<html><head><title>CPU &amp; メモリ</title><style>p{}</style></head>
+<body>
+<h1>CPU高騰対応</h1>
+<p>CPU使用率が<strong>90%</strong>を超えた場合の<a href="https://example.com/doc">手順</a>です。</p>
+<h2>対応手順</h2>
+<ol><li>ログイン</li><li>確認<ul><li>top</li></ul></li></ol>
+<pre><code>top -o %CPU
+ps aux</code></pre>
+<blockquote><p>本番では注意</p></blockquote>
+<img src="images/graph 1.png" alt="グラフ">
+<!-- comment -->
+</body></html>"#;
+
+        assert_eq!(HtmlConverter::title(html).as_deref(), Some("CPU & メモリ"));
+        assert_eq!(
+            HtmlConverter::to_markdown(html),
+            "# CPU高騰対応\n\n\
+             CPU使用率が**90%**を超えた場合の[手順](https://example.com/doc)です。\n\n\
+             ## 対応手順\n\n\
+             1. ログイン\n\
+             2. 確認\n  \
+             - top\n\n\
+             ```\ntop -o %CPU\nps aux\n```\n\n\
+             > 本番では注意\n\n\
+             ![グラフ](images/graph%201.png)\n"
+        );
+    }
+
+    #[test]
+    fn test_table() {
+        let html = "<table><tr><th>項目</th><th>値</th></tr>\
+                    <tr><td>CPU</td><td>90% | 高</td></tr></table>";
+        assert_eq!(
+            HtmlConverter::to_markdown(html),
+            "| 項目 | 値 |\n| --- | --- |\n| CPU | 90% \\| 高 |\n"
+        );
+    }
+
+    #[test]
+    fn test_main_content_only() {
+        let html = r#"<body><div id="breadcrumbs">Home &gt; Ops</div>
+<div id="main-content" class="wiki-content"><div><p>本文</p></div></div>
+<div id="footer">Generated by Confluence</div></body>"#;
+        assert_eq!(HtmlConverter::to_markdown(html), "本文\n");
+    }
+
+    #[test]
+    fn test_decode_entities() {
+        assert_eq!(
+            decode_entities("a &lt;b&gt; &#12354;&#x3042; &unknown; & c"),
+            "a <b> ああ &unknown; & c"
+        );
+    }
+}
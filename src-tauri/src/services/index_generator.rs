@@ -0,0 +1,254 @@
+use crate::models::{Result, RunbookEntry, WorkNoteError};
+use crate::services::runbook_repository::{RunbookRepository, INDEX_FILE_NAME};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// 自動生成ファイルであることを示すコメント
+const GENERATED_NOTICE: &str =
+    "<!-- このファイルは WorkNote が自動生成します。手動で編集しないでください。 -->";
+
+/// IndexGenerator - カテゴリ別・全体のindex.mdを生成
+///
+/// 並び順はファイルパス順で固定し、タイトル変更や追加で差分が最小になるようにします。
+/// 生成日時などの可変情報は含めません。
+pub struct IndexGenerator {
+    repository: RunbookRepository,
+}
+
+impl IndexGenerator {
+    /// 新しいIndexGeneratorインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `save_dir` - Runbook保存先ディレクトリ（リポジトリパス + save_path）
+    pub fn new(save_dir: PathBuf) -> Self {
+        IndexGenerator {
+            repository: RunbookRepository::new(save_dir),
+        }
+    }
+
+    /// インデックスを再生成
+    ///
+    /// Runbookがなくなったカテゴリ（と全体）の自動生成インデックスは削除します。
+    ///
+    /// # Returns
+    /// 書き込んだ・削除したインデックスファイルのパス（内容に変更がないファイルは含まない）
+    pub fn regenerate(&self) -> Result<Vec<PathBuf>> {
+        let entries = self.repository.list_entries()?;
+
+        let mut by_category: BTreeMap<String, Vec<RunbookEntry>> = BTreeMap::new();
+        for entry in entries {
+            by_category
+                .entry(entry.category.clone())
+                .or_default()
+                .push(entry);
+        }
+
+        let mut written = Vec::new();
+        let save_dir = self.repository.save_dir();
+
+        for (category, entries) in &by_category {
+            let path = save_dir.join(category).join(INDEX_FILE_NAME);
+            if Self::write_if_changed(&path, &Self::render_category_index(category, entries))? {
+                written.push(path);
+            }
+        }
+
+        // Runbookがなくなったカテゴリのインデックスを削除（削除もコミットに含める）
+        for dir in self.repository.category_dirs()? {
+            let has_runbooks = dir
+                .file_name()
+                .and_then(|name| name.to_str())
+                .is_some_and(|name| by_category.contains_key(name));
+            if !has_runbooks && Self::remove_generated(&dir.join(INDEX_FILE_NAME))? {
+                written.push(dir.join(INDEX_FILE_NAME));
+            }
+        }
+
+        let path = save_dir.join(INDEX_FILE_NAME);
+        if by_category.is_empty() {
+            if Self::remove_generated(&path)? {
+                written.push(path);
+            }
+        } else if Self::write_if_changed(&path, &Self::render_root_index(&by_category))? {
+            written.push(path);
+        }
+
+        Ok(written)
+    }
+
+    /// 再生成で書き込み・削除する可能性のあるインデックスファイルのパス
+    ///
+    /// 再生成の途中で失敗した場合に、変更済みのインデックスを取り消すために使います。
+    pub fn index_files(&self) -> Vec<PathBuf> {
+        let save_dir = self.repository.save_dir();
+        let mut paths = vec![save_dir.join(INDEX_FILE_NAME)];
+        if let Ok(dirs) = self.repository.category_dirs() {
+            paths.extend(dirs.into_iter().map(|dir| dir.join(INDEX_FILE_NAME)));
+        }
+        paths
+    }
+
+    /// 自動生成したインデックスを削除（手動で作成したファイルは残す）
+    fn remove_generated(path: &Path) -> Result<bool> {
+        if !fs::read_to_string(path).is_ok_and(|content| content.contains(GENERATED_NOTICE)) {
+            return Ok(false);
+        }
+
+        fs::remove_file(path)
+            .map_err(|e| WorkNoteError::FileError(format!("Failed to remove index file: {}", e)))?;
+        Ok(true)
+    }
+
+    /// カテゴリ別インデックスを生成
+    fn render_category_index(category: &str, entries: &[RunbookEntry]) -> String {
+        let mut content = String::new();
+        content.push_str(&format!("# {}\n\n", category));
+        content.push_str(GENERATED_NOTICE);
+        content.push_str("\n\n");
+        Self::push_table(&mut content, entries, |entry| {
+            entry
+                .path
+                .rsplit('/')
+                .next()
+                .unwrap_or(&entry.path)
+                .to_string()
+        });
+        content
+    }
+
+    /// 全体インデックスを生成
+    fn render_root_index(by_category: &BTreeMap<String, Vec<RunbookEntry>>) -> String {
+        let mut content = String::new();
+        content.push_str("# Runbooks\n\n");
+        content.push_str(GENERATED_NOTICE);
+        content.push('\n');
+
+        for (category, entries) in by_category {
+            content.push_str(&format!(
+                "\n## [{}]({}/{}) ({})\n\n",
+                category,
+                category,
+                INDEX_FILE_NAME,
+                entries.len()
+            ));
+            Self::push_table(&mut content, entries, |entry| entry.path.clone());
+        }
+        content
+    }
+
+    /// Runbook一覧テーブルを出力
+    fn push_table(
+        content: &mut String,
+        entries: &[RunbookEntry],
+        link: impl Fn(&RunbookEntry) -> String,
+    ) {
+        content.push_str("| タイトル | 重要度 | 最終更新日 | 作成者 |\n");
+        content.push_str("|----------|--------|------------|--------|\n");
+        for entry in entries {
+            content.push_str(&format!(
+                "| [{}]({}) | {} | {} | {} |\n",
+                Self::escape_cell(&entry.title),
+                link(entry),
+                Self::escape_cell(entry.severity.as_deref().unwrap_or("-")),
+                Self::escape_cell(entry.last_updated.as_deref().unwrap_or("-")),
+                Self::escape_cell(entry.author.as_deref().unwrap_or("-")),
+            ));
+        }
+    }
+
+    /// テーブルセル用にエスケープ
+    fn escape_cell(value: &str) -> String {
+        value
+            .replace('\\', "\\\\")
+            .replace('|', "\\|")
+            .replace(['\r', '\n'], " ")
+    }
+
+    /// 内容が変わる場合のみファイルを書き込む
+    fn write_if_changed(path: &Path, content: &str) -> Result<bool> {
+        if fs::read_to_string(path).is_ok_and(|existing| existing == content) {
+            return Ok(false);
+        }
+
+        fs::write(path, content)
+            .map_err(|e| WorkNoteError::FileError(format!("Failed to write index file: {}", e)))?;
+        Ok(true)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn write_runbook(dir: &Path, relative: &str, title: &str, severity: &str) {
+        let path = dir.join(relative);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(
+            path,
+            format!(
+                "---\ntitle: \"{}\"\nseverity: {}\nlast_updated: 2026-01-31\nauthor: \"Test User\"\n---\n",
+                title, severity
+            ),
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_regenerate_index() {
+        let temp_dir = env::temp_dir().join("worknote_test_index_generator");
+        let _ = fs::remove_dir_all(&temp_dir);
+        write_runbook(&temp_dir, "alerts/z-disk.md", "ディスク逼迫", "medium");
+        write_runbook(&temp_dir, "alerts/a-cpu.md", "CPU | 高騰", "high");
+        write_runbook(&temp_dir, "ops/deploy.md", "デプロイ手順", "low");
+
+        let generator = IndexGenerator::new(temp_dir.clone());
+        let written = generator.regenerate().unwrap();
+        assert_eq!(written.len(), 3);
+
+        let alerts = fs::read_to_string(temp_dir.join("alerts/index.md")).unwrap();
+        assert!(alerts.starts_with("# alerts\n"));
+        assert!(alerts.contains("| [CPU \\| 高騰](a-cpu.md) | high | 2026-01-31 | Test User |"));
+        // ファイルパス順で並ぶ
+        assert!(alerts.find("a-cpu.md").unwrap() < alerts.find("z-disk.md").unwrap());
+
+        let root = fs::read_to_string(temp_dir.join("index.md")).unwrap();
+        assert!(root.contains("## [alerts](alerts/index.md) (2)"));
+        assert!(root.contains("## [ops](ops/index.md) (1)"));
+        assert!(root.contains("[デプロイ手順](ops/deploy.md)"));
+
+        // 内容が変わらなければ再書き込みしない
+        assert!(generator.regenerate().unwrap().is_empty());
+
+        // Runbookがなくなったカテゴリのインデックスは削除し、パスを返す
+        fs::remove_file(temp_dir.join("ops/deploy.md")).unwrap();
+        let written = generator.regenerate().unwrap();
+        assert!(written.contains(&temp_dir.join("ops/index.md")));
+        assert!(written.contains(&temp_dir.join("index.md")));
+        assert!(!temp_dir.join("ops/index.md").exists());
+        let root = fs::read_to_string(temp_dir.join("index.md")).unwrap();
+        assert!(!root.contains("ops/index.md"));
+
+        // 手動で作成したindex.mdは削除しない
+        fs::write(temp_dir.join("ops/index.md"), "# 手動\n").unwrap();
+        assert!(!generator
+            .regenerate()
+            .unwrap()
+            .contains(&temp_dir.join("ops/index.md")));
+        assert!(temp_dir.join("ops/index.md").exists());
+
+        // クリーンアップ
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_regenerate_index_empty() {
+        let temp_dir = env::temp_dir().join("worknote_test_index_generator_empty");
+        let _ = fs::remove_dir_all(&temp_dir);
+
+        let generator = IndexGenerator::new(temp_dir.clone());
+        assert!(generator.regenerate().unwrap().is_empty());
+        assert!(!temp_dir.join("index.md").exists());
+    }
+}
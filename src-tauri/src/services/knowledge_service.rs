@@ -0,0 +1,400 @@
+use crate::models::{
+    AuthorConfig, CommitMode, CommitTrailers, Config, KnowledgeInput, PostmortemInput,
+    PublishDraftsResponse, Result, SaveKnowledgeResponse, WorkNoteError,
+};
+use crate::services::commit_template::CommitContext;
+use crate::services::file_generator::FileGenerator;
+use crate::services::git_service::{CommitRequest, FeatureBranch, GitService};
+use crate::services::index_generator::IndexGenerator;
+use crate::services::postmortem_generator::PostmortemGenerator;
+use chrono::Local;
+use std::path::PathBuf;
+
+/// KnowledgeService - ナレッジの保存（ファイル生成 + インデックス更新 + Git操作）を管理
+///
+/// GUI（Tauriコマンド）とCLIの両方から同じ保存処理を使うためのサービスです。
+pub struct KnowledgeService {
+    config: Config,
+    trailers: CommitTrailers,
+}
+
+impl KnowledgeService {
+    /// 新しいKnowledgeServiceインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `config` - アプリケーション設定
+    pub fn new(config: Config) -> Self {
+        KnowledgeService {
+            config,
+            trailers: CommitTrailers::default(),
+        }
+    }
+
+    /// コミットメッセージに追加するtrailer（課題キー・共同作成者）を指定
+    ///
+    /// # Arguments
+    /// * `trailers` - 追加するtrailer
+    pub fn with_trailers(mut self, trailers: CommitTrailers) -> Self {
+        self.trailers = trailers;
+        self
+    }
+
+    /// ナレッジを保存してコミット・Pushする
+    ///
+    /// # Arguments
+    /// * `input` - ナレッジ入力データ
+    pub fn save(&self, input: &KnowledgeInput) -> Result<SaveKnowledgeResponse> {
+        self.config
+            .taxonomy
+            .validate_input(&input.category, &input.severity)?;
+        input.validate_required_fields(&self.config.required_fields)?;
+        input.validate()?;
+        self.trailers.validate()?;
+
+        // RunbookIDを採番し、ファイルとコミットメッセージ・ブランチ名に同じIDを使う
+        let mut input = input.clone();
+        input.ensure_id();
+
+        // Authorは設定 → git configの順に解決
+        let author = self
+            .git_service()
+            .resolve_author(self.config.author.as_ref())?;
+        let context = self.commit_context(
+            &input.title,
+            input.category.as_str(),
+            input.severity.as_str(),
+            input.id.clone(),
+            &author,
+        );
+        let git_service = self.git_service().with_author(author.clone());
+
+        // PR作成モードでは最新を取得してfeatureブランチを作成してから、ファイルを書き込む
+        let branch = self.start_branch(&git_service, &context)?;
+
+        // Markdownファイル生成（カテゴリ別・全体のindex.mdも再生成し、同じコミットに含める）
+        let (file_paths, index_files) = self
+            .write_runbooks(&git_service, &author, std::slice::from_ref(&input))
+            .inspect_err(|_| Self::abandon_branch(&git_service, branch.as_ref()))?;
+        let file_path = file_paths[0].clone();
+
+        let request = CommitRequest {
+            files: file_paths.into_iter().chain(index_files).collect(),
+            context,
+        };
+        let (commit_hashes, pr_url) = self.commit(&git_service, branch, &[request])?;
+
+        Ok(SaveKnowledgeResponse {
+            success: true,
+            commit_hash: commit_hashes.into_iter().last().unwrap_or_default(),
+            file_path: file_path.to_string_lossy().to_string(),
+            pr_url,
+        })
+    }
+
+    /// 複数のナレッジをまとめて保存し、1回のPush（PR作成モードでは1つのブランチ）で公開する
+    ///
+    /// 全ての入力を検証してからファイルを生成します。`separate_commits` が `false` の場合は
+    /// 全ファイルを1つのコミットに、`true` の場合はナレッジごとにコミットします
+    /// （再生成したインデックスは最後のコミットに含めます）。
+    ///
+    /// # Arguments
+    /// * `inputs` - ナレッジ入力データ（この順にコミット）
+    /// * `separate_commits` - ナレッジごとにコミットするか
+    pub fn save_all(
+        &self,
+        inputs: &[KnowledgeInput],
+        separate_commits: bool,
+    ) -> Result<PublishDraftsResponse> {
+        if inputs.is_empty() {
+            return Err(WorkNoteError::ValidationError(
+                "No knowledge to publish".to_string(),
+            ));
+        }
+        for input in inputs {
+            self.config
+                .taxonomy
+                .validate_input(&input.category, &input.severity)?;
+            input.validate_required_fields(&self.config.required_fields)?;
+            input.validate()?;
+        }
+        self.trailers.validate()?;
+
+        // RunbookIDを採番し、ファイルとコミットメッセージ・ブランチ名に同じIDを使う
+        let inputs: Vec<KnowledgeInput> = inputs
+            .iter()
+            .map(|input| {
+                let mut input = input.clone();
+                input.ensure_id();
+                input
+            })
+            .collect();
+
+        let author = self
+            .git_service()
+            .resolve_author(self.config.author.as_ref())?;
+        let contexts: Vec<CommitContext> = inputs
+            .iter()
+            .map(|input| {
+                self.commit_context(
+                    &input.title,
+                    input.category.as_str(),
+                    input.severity.as_str(),
+                    input.id.clone(),
+                    &author,
+                )
+            })
+            .collect();
+        let combined = Self::combined_context(&contexts);
+        let git_service = self.git_service().with_author(author.clone());
+
+        // PR作成モードでは最新を取得してfeatureブランチを作成してから、ファイルを書き込む
+        let branch = self.start_branch(&git_service, &combined)?;
+        let (file_paths, index_files) = self
+            .write_runbooks(&git_service, &author, &inputs)
+            .inspect_err(|_| Self::abandon_branch(&git_service, branch.as_ref()))?;
+
+        let commits = if separate_commits {
+            let mut commits: Vec<CommitRequest> = file_paths
+                .iter()
+                .zip(contexts)
+                .map(|(path, context)| CommitRequest {
+                    files: vec![path.clone()],
+                    context,
+                })
+                .collect();
+            if let Some(last) = commits.last_mut() {
+                last.files.extend(index_files);
+            }
+            commits
+        } else {
+            vec![CommitRequest {
+                files: file_paths.iter().cloned().chain(index_files).collect(),
+                context: combined,
+            }]
+        };
+        let (commit_hashes, pr_url) = self.commit(&git_service, branch, &commits)?;
+
+        Ok(PublishDraftsResponse {
+            success: true,
+            commit_hashes,
+            file_paths: file_paths
+                .iter()
+                .map(|path| path.to_string_lossy().to_string())
+                .collect(),
+            pr_url,
+            published_drafts: Vec::new(),
+        })
+    }
+
+    /// ポストモーテムを保存してコミット・Pushする
+    ///
+    /// Runbookと同じコミットモード（直接Push / PR作成）に従います。
+    ///
+    /// # Arguments
+    /// * `input` - ポストモーテム入力データ
+    pub fn save_postmortem(&self, input: &PostmortemInput) -> Result<SaveKnowledgeResponse> {
+        if self.config.taxonomy.severity(&input.severity).is_none() {
+            return Err(WorkNoteError::ValidationError(format!(
+                "Unknown severity: {}",
+                input.severity
+            )));
+        }
+        input.validate()?;
+        self.trailers.validate()?;
+        let author = self
+            .git_service()
+            .resolve_author(self.config.author.as_ref())?;
+
+        // IDを採番し、ファイルとコミットメッセージ・ブランチ名に同じIDを使う
+        let mut input = input.clone();
+        input.ensure_id();
+        let context = self.commit_context(
+            &input.title,
+            "postmortem",
+            input.severity.as_str(),
+            input.id.clone(),
+            &author,
+        );
+        let git_service = self.git_service().with_author(author.clone());
+        let generator = PostmortemGenerator::new(
+            PathBuf::from(&self.config.git.repository_path),
+            self.config.git.save_path.clone(),
+            self.config.git.postmortem_path.clone(),
+            author,
+        );
+
+        // PR作成モードでは最新を取得してfeatureブランチを作成してから、ファイルを書き込む
+        let branch = self.start_branch(&git_service, &context)?;
+        let file_path = generator
+            .write_file(&input)
+            .inspect_err(|_| Self::abandon_branch(&git_service, branch.as_ref()))?;
+
+        let request = CommitRequest {
+            files: vec![file_path.clone()],
+            context,
+        };
+        let (commit_hashes, pr_url) = self.commit(&git_service, branch, &[request])?;
+
+        Ok(SaveKnowledgeResponse {
+            success: true,
+            commit_hash: commit_hashes.into_iter().last().unwrap_or_default(),
+            file_path: file_path.to_string_lossy().to_string(),
+            pr_url,
+        })
+    }
+
+    /// 設定に従ったGitServiceを作成
+    fn git_service(&self) -> GitService {
+        GitService::new(
+            PathBuf::from(&self.config.git.repository_path),
+            self.config.git.default_branch.clone(),
+        )
+        .with_commit_message(self.config.git.commit_message.clone())
+        .with_branch_name(self.config.git.branch_name.clone())
+        .with_remotes(
+            self.config.git.remote.clone(),
+            self.config.git.push_remote().to_string(),
+        )
+        .with_hosting(self.config.git.hosting)
+        .with_signing(self.config.git.signing.clone())
+    }
+
+    /// コミットメッセージ・ブランチ名の変数の値を作成
+    fn commit_context(
+        &self,
+        title: &str,
+        category: &str,
+        severity: &str,
+        id: Option<String>,
+        author: &AuthorConfig,
+    ) -> CommitContext {
+        CommitContext {
+            title: title.to_string(),
+            category: category.to_string(),
+            severity: severity.to_string(),
+            author: author.name.clone(),
+            id,
+            profile: self.config.active_profile_name().to_string(),
+            time: Local::now(),
+        }
+    }
+
+    /// 複数のナレッジをまとめたコミットメッセージ・ブランチ名の変数の値を作成
+    ///
+    /// タイトルは「先頭のタイトル ほかN件」、カテゴリ・重要度は共通ならその値、
+    /// 異なる場合は重複を除いてカンマ区切りにします。IDは1件の場合のみ設定します。
+    fn combined_context(contexts: &[CommitContext]) -> CommitContext {
+        let mut combined = contexts[0].clone();
+        if contexts.len() == 1 {
+            return combined;
+        }
+
+        let join_unique = |values: Vec<&str>| {
+            let mut unique: Vec<&str> = Vec::new();
+            for value in values {
+                if !unique.contains(&value) {
+                    unique.push(value);
+                }
+            }
+            unique.join(",")
+        };
+        combined.title = format!("{} ほか{}件", combined.title, contexts.len() - 1);
+        combined.category = join_unique(contexts.iter().map(|c| c.category.as_str()).collect());
+        combined.severity = join_unique(contexts.iter().map(|c| c.severity.as_str()).collect());
+        combined.id = None;
+        combined
+    }
+
+    /// Runbookを書き込み、カテゴリ別・全体のインデックスを再生成
+    ///
+    /// 途中で失敗した場合は、書き込んだファイルを保存前の状態に戻します
+    /// （既存のRunbookを上書きしている場合もあるため、削除せずにHEADの内容に戻します）。
+    ///
+    /// # Returns
+    /// 書き込んだRunbookのパス（入力順）と、変更したインデックスファイルのパス
+    fn write_runbooks(
+        &self,
+        git_service: &GitService,
+        author: &AuthorConfig,
+        inputs: &[KnowledgeInput],
+    ) -> Result<(Vec<PathBuf>, Vec<PathBuf>)> {
+        let repository_path = PathBuf::from(&self.config.git.repository_path);
+        let file_generator = FileGenerator::new(
+            repository_path.clone(),
+            self.config.git.save_path.clone(),
+            author.clone(),
+        )
+        .with_naming(self.config.naming.clone())
+        .with_taxonomy(self.config.taxonomy.clone());
+
+        let mut file_paths = Vec::with_capacity(inputs.len());
+        for input in inputs {
+            match file_generator.write_file(input) {
+                Ok(path) => file_paths.push(path),
+                Err(e) => {
+                    git_service.discard_files(&file_paths);
+                    return Err(e);
+                }
+            }
+        }
+
+        let index_generator = IndexGenerator::new(repository_path.join(&self.config.git.save_path));
+        match index_generator.regenerate() {
+            Ok(index_files) => Ok((file_paths, index_files)),
+            Err(e) => {
+                git_service.discard_files(&file_paths);
+                git_service.discard_files(&index_generator.index_files());
+                Err(e)
+            }
+        }
+    }
+
+    /// PR作成モードの場合はデフォルトブランチの最新を取得してfeatureブランチを作成
+    ///
+    /// Direct modeの場合は `None` を返します。
+    fn start_branch(
+        &self,
+        git_service: &GitService,
+        branch_context: &CommitContext,
+    ) -> Result<Option<FeatureBranch>> {
+        match self.config.git.commit_mode {
+            CommitMode::Direct => Ok(None),
+            CommitMode::FeatureBranch => git_service.start_feature_branch(branch_context).map(Some),
+        }
+    }
+
+    /// ファイルの書き込みに失敗した場合に、作成したfeatureブランチを削除
+    fn abandon_branch(git_service: &GitService, branch: Option<&FeatureBranch>) {
+        if let Some(branch) = branch {
+            git_service.abandon_feature_branch(branch);
+        }
+    }
+
+    /// CommitModeに応じてコミット・Push（PR作成モードでは `start_branch` で作成したブランチ）
+    ///
+    /// 失敗した場合はコミットを取り消し、コミットするファイルを保存前の状態に戻します。
+    ///
+    /// # Returns
+    /// 作成したコミットのハッシュ（コミット順）とPR作成URL
+    fn commit(
+        &self,
+        git_service: &GitService,
+        branch: Option<FeatureBranch>,
+        commits: &[CommitRequest],
+    ) -> Result<(Vec<String>, Option<String>)> {
+        let result = match &branch {
+            None => git_service.commit_all_and_push(commits, &self.trailers),
+            Some(branch) => git_service.commit_all_and_push_pr(commits, branch, &self.trailers),
+        };
+        // コミット・Pushに失敗した場合は、書き込んだファイルを保存前の状態に戻す
+        let commit_hashes = result.inspect_err(|_| {
+            let files: Vec<PathBuf> = commits
+                .iter()
+                .flat_map(|commit| commit.files.iter().cloned())
+                .collect();
+            git_service.discard_files(&files);
+        })?;
+
+        Ok((commit_hashes, branch.map(|branch| branch.pr_url)))
+    }
+}
@@ -1,7 +1,7 @@
 #[allow(unused_imports)]
-use crate::models::{KnowledgeInput, Result, WorkNoteError};
-use crate::services::FileGenerator;
-use pulldown_cmark::{html, Parser};
+use crate::models::{KnowledgeInput, PostmortemInput, Result, WorkNoteError};
+use crate::services::{FileGenerator, PostmortemGenerator};
+use pulldown_cmark::{html, Options, Parser};
 
 pub struct MarkdownRenderer;
 
@@ -18,6 +18,19 @@ impl MarkdownRenderer {
 
         Ok(html_output)
     }
+
+    /// PostmortemInputからHTMLプレビューを生成
+    pub fn render_postmortem(input: &PostmortemInput) -> Result<String> {
+        let markdown = PostmortemGenerator::generate_markdown_for_preview(input);
+
+        let mut options = Options::empty();
+        options.insert(Options::ENABLE_TABLES);
+        let parser = Parser::new_ext(&markdown, options);
+        let mut html_output = String::new();
+        html::push_html(&mut html_output, parser);
+
+        Ok(html_output)
+    }
 }
 
 #[cfg(test)]
@@ -36,6 +49,9 @@ mod tests {
             notes: Some("Test notes".to_string()),
             related_links: Some("https://example.com".to_string()),
             judgment: None,
+            slug: None,
+            related_alerts: Vec::new(),
+            id: None,
         };
 
         let result = MarkdownRenderer::render_markdown(&input);
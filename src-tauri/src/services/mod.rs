@@ -1,16 +1,62 @@
+pub mod accelerator;
+pub mod category_migrator;
+pub mod clipboard_capture;
+pub mod commit_template;
 pub mod config_manager;
+pub mod config_migration;
 pub mod draft_manager;
+pub mod draft_publisher;
 pub mod file_generator;
+pub mod file_naming;
 pub mod git_service;
+pub mod html_converter;
+pub mod index_generator;
+pub mod knowledge_service;
 pub mod markdown_renderer;
+pub mod pdf_exporter;
+pub mod postmortem_generator;
 pub mod proofreader_service;
+pub mod remote_url;
+pub mod runbook_history;
+pub mod runbook_importer;
+pub mod runbook_parser;
+pub mod runbook_repository;
+pub mod runbook_validator;
+pub mod session_capture;
 pub mod shortcut_manager;
+pub mod timeline_manager;
+pub mod transliterator;
+pub mod webhook_parser;
+pub mod webhook_server;
+pub mod zip_reader;
 
 // Re-export commonly used types
+pub use accelerator::Accelerator;
+pub use category_migrator::CategoryMigrator;
+pub use clipboard_capture::ClipboardCapture;
+pub use commit_template::{CommitContext, CommitTemplate};
 pub use config_manager::ConfigManager;
 pub use draft_manager::DraftManager;
+pub use draft_publisher::DraftPublisher;
 pub use file_generator::FileGenerator;
-pub use git_service::GitService;
+pub use file_naming::FileNamer;
+pub use git_service::{CommitRequest, FeatureBranch, GitService};
+pub use html_converter::HtmlConverter;
+pub use index_generator::IndexGenerator;
+pub use knowledge_service::KnowledgeService;
 pub use markdown_renderer::MarkdownRenderer;
+pub use pdf_exporter::PdfExporter;
+pub use postmortem_generator::PostmortemGenerator;
 pub use proofreader_service::ProofreadService;
+pub use remote_url::RemoteUrl;
+pub use runbook_history::RunbookHistory;
+pub use runbook_importer::RunbookImporter;
+pub use runbook_parser::{RunbookDocument, RunbookParser};
+pub use runbook_repository::RunbookRepository;
+pub use runbook_validator::RunbookValidator;
+pub use session_capture::SessionCapture;
 pub use shortcut_manager::ShortcutManager;
+pub use timeline_manager::TimelineManager;
+pub use transliterator::Transliterator;
+pub use webhook_parser::WebhookParser;
+pub use webhook_server::WebhookServer;
@@ -0,0 +1,708 @@
+use crate::models::{Result, WorkNoteError};
+use crate::services::runbook_parser::{RunbookDocument, RunbookParser};
+use std::fs;
+use std::path::Path;
+
+/// A4サイズ（pt）
+const PAGE_WIDTH: f32 = 595.0;
+const PAGE_HEIGHT: f32 = 842.0;
+const MARGIN: f32 = 50.0;
+const FOOTER_HEIGHT: f32 = 24.0;
+
+const TITLE_FONT_SIZE: f32 = 18.0;
+const HEADING_FONT_SIZE: f32 = 13.0;
+const BODY_FONT_SIZE: f32 = 10.0;
+const FOOTER_FONT_SIZE: f32 = 8.0;
+const LINE_SPACING: f32 = 1.5;
+const CELL_PADDING: f32 = 4.0;
+
+/// 対応履歴セクションの見出し
+const HISTORY_HEADING: &str = "対応履歴";
+
+/// PdfExporter - RunbookをPDFに変換
+///
+/// フォントは埋め込まず、PDF仕様で定義済みの日本語フォント（HeiseiKakuGo-W5）と
+/// `UniJIS-UCS2-HW-H` CMapを参照します。外部コマンドやネットワークには依存しません。
+///
+/// フォントを埋め込まないため、表示は閲覧側の代替フォントに依存します。Adobe Acrobat等の
+/// 日本語フォントを持つビューアでは正しく表示されますが、日本語フォントのない環境や一部の
+/// ブラウザ内蔵ビューアでは文字化け・字形の違いが起こり得ます。また、PDF/A等の
+/// フォント埋め込みを必須とする形式の要件は満たしません。
+pub struct PdfExporter;
+
+impl PdfExporter {
+    /// Markdown文字列をPDFに変換してファイルに書き込む
+    ///
+    /// # Arguments
+    /// * `markdown` - FileGeneratorが出力した形式のMarkdown
+    /// * `output_path` - 出力先PDFファイルパス
+    pub fn export_markdown(markdown: &str, output_path: &Path) -> Result<()> {
+        let document = RunbookParser::parse(markdown)?;
+        let pdf = Self::render(&document);
+
+        if let Some(parent) = output_path.parent() {
+            if !parent.as_os_str().is_empty() && !parent.exists() {
+                return Err(WorkNoteError::FileError(format!(
+                    "Output directory does not exist: {}",
+                    parent.display()
+                )));
+            }
+        }
+
+        fs::write(output_path, pdf)
+            .map_err(|e| WorkNoteError::FileError(format!("Failed to write PDF file: {}", e)))?;
+
+        Ok(())
+    }
+
+    /// パース済みRunbookをPDFバイト列に変換
+    pub fn render(document: &RunbookDocument) -> Vec<u8> {
+        let mut layout = PageLayout::new();
+        let title = document.display_title().unwrap_or("Runbook").to_string();
+
+        // タイトル
+        layout.paragraph(&title, TITLE_FONT_SIZE, 0.0);
+        layout.rule(1.0);
+        layout.gap(BODY_FONT_SIZE);
+
+        // メタデータヘッダー
+        let metadata = Self::metadata_rows(document);
+        if !metadata.is_empty() {
+            layout.table(&metadata, false);
+            layout.gap(BODY_FONT_SIZE);
+        }
+
+        if !document.preamble.is_empty() {
+            Self::render_body(&mut layout, &document.preamble);
+        }
+
+        // セクション（対応履歴は最後に表として出力）
+        for section in document
+            .sections
+            .iter()
+            .filter(|s| s.heading != HISTORY_HEADING)
+        {
+            layout.heading(&section.heading);
+            Self::render_body(&mut layout, &section.body);
+        }
+
+        if let Some(history) = document.section(HISTORY_HEADING) {
+            layout.heading(HISTORY_HEADING);
+            let rows = RunbookParser::parse_table(&history.body);
+            if rows.is_empty() {
+                Self::render_body(&mut layout, &history.body);
+            } else {
+                layout.table(&rows, true);
+            }
+        }
+
+        build_pdf(&layout.finish(), &title)
+    }
+
+    /// メタデータヘッダーの行を生成
+    fn metadata_rows(document: &RunbookDocument) -> Vec<Vec<String>> {
+        let fields = [
+            ("カテゴリ", "category"),
+            ("重要度", "severity"),
+            ("最終更新日", "last_updated"),
+            ("作成者", "author"),
+        ];
+
+        let mut rows: Vec<Vec<String>> = fields
+            .iter()
+            .filter_map(|(label, key)| {
+                document
+                    .get(key)
+                    .filter(|v| !v.is_empty())
+                    .map(|v| vec![label.to_string(), v.to_string()])
+            })
+            .collect();
+
+        let alerts = document.get_list("related_alerts");
+        if !alerts.is_empty() {
+            rows.push(vec!["関連アラート".to_string(), alerts.join(", ")]);
+        }
+
+        rows
+    }
+
+    /// セクション本文を描画（表・コードブロック・箇条書きに対応）
+    fn render_body(layout: &mut PageLayout, body: &str) {
+        let mut in_code_block = false;
+        let mut table_lines: Vec<&str> = Vec::new();
+
+        for line in body.lines() {
+            let trimmed = line.trim();
+
+            if !in_code_block && trimmed.starts_with('|') {
+                table_lines.push(trimmed);
+                continue;
+            }
+            if !table_lines.is_empty() {
+                layout.table(&RunbookParser::parse_table(&table_lines.join("\n")), true);
+                table_lines.clear();
+            }
+
+            if trimmed.starts_with("```") {
+                in_code_block = !in_code_block;
+                continue;
+            }
+
+            if in_code_block {
+                layout.paragraph(&line.replace('\t', "    "), BODY_FONT_SIZE, 16.0);
+            } else if trimmed.is_empty() {
+                layout.gap(BODY_FONT_SIZE * 0.5);
+            } else if let Some(item) = trimmed
+                .strip_prefix("- ")
+                .or_else(|| trimmed.strip_prefix("* "))
+            {
+                layout.paragraph(
+                    &format!("・{}", strip_inline_markdown(item)),
+                    BODY_FONT_SIZE,
+                    8.0,
+                );
+            } else if let Some(heading) = trimmed.strip_prefix("### ") {
+                layout.paragraph(&strip_inline_markdown(heading), BODY_FONT_SIZE + 1.0, 0.0);
+            } else {
+                layout.paragraph(&strip_inline_markdown(trimmed), BODY_FONT_SIZE, 0.0);
+            }
+        }
+
+        if !table_lines.is_empty() {
+            layout.table(&RunbookParser::parse_table(&table_lines.join("\n")), true);
+        }
+        layout.gap(BODY_FONT_SIZE * 0.5);
+    }
+}
+
+/// 強調・インラインコード・リンク記法を除去
+fn strip_inline_markdown(text: &str) -> String {
+    let mut result = String::new();
+    let mut rest = text;
+
+    // [label](url) → label (url)
+    while let Some(start) = rest.find('[') {
+        let Some(mid) = rest[start..].find("](").map(|i| start + i) else {
+            break;
+        };
+        let Some(end) = rest[mid..].find(')').map(|i| mid + i) else {
+            break;
+        };
+        result.push_str(&rest[..start]);
+        result.push_str(&rest[start + 1..mid]);
+        result.push_str(" (");
+        result.push_str(&rest[mid + 2..end]);
+        result.push(')');
+        rest = &rest[end + 1..];
+    }
+    result.push_str(rest);
+
+    result.replace("**", "").replace('`', "")
+}
+
+/// 文字幅（em単位）を取得
+fn char_width(c: char) -> f32 {
+    if c.is_ascii() || ('\u{ff61}'..='\u{ff9f}').contains(&c) {
+        0.5
+    } else {
+        1.0
+    }
+}
+
+/// 文字列の描画幅（pt）を取得
+fn text_width(text: &str, font_size: f32) -> f32 {
+    text.chars().map(char_width).sum::<f32>() * font_size
+}
+
+/// 指定幅に収まるよう文字列を折り返す
+///
+/// 日本語は任意の文字位置で、英単語は可能な限り空白位置で改行します。
+fn wrap_text(text: &str, font_size: f32, max_width: f32) -> Vec<String> {
+    let mut lines = Vec::new();
+    let mut current: Vec<char> = Vec::new();
+    let mut width = 0.0;
+
+    for c in text.chars() {
+        let w = char_width(c) * font_size;
+        if width + w > max_width && !current.is_empty() {
+            // ASCII単語の途中であれば直前の空白で改行
+            let break_at = if c.is_ascii_alphanumeric() {
+                current
+                    .iter()
+                    .rposition(|ch| *ch == ' ')
+                    .filter(|pos| *pos > 0)
+                    .map(|pos| pos + 1)
+            } else {
+                None
+            };
+
+            match break_at {
+                Some(pos) => {
+                    let carry = current.split_off(pos);
+                    lines.push(current.iter().collect::<String>().trim_end().to_string());
+                    current = carry;
+                }
+                None => {
+                    lines.push(current.iter().collect());
+                    current.clear();
+                }
+            }
+            width = current.iter().map(|ch| char_width(*ch) * font_size).sum();
+        }
+        current.push(c);
+        width += w;
+    }
+
+    if !current.is_empty() || lines.is_empty() {
+        lines.push(current.iter().collect());
+    }
+    lines
+}
+
+/// PDF文字列（UTF-16BEの16進表記）にエンコード
+///
+/// UniJIS-UCS2 CMapはBMPのみ対応のため、それ以外の文字は `?` に置き換えます。
+fn encode_text(text: &str) -> String {
+    text.chars()
+        .map(|c| {
+            let code = if (c as u32) <= 0xffff {
+                c as u32
+            } else {
+                '?' as u32
+            };
+            format!("{:04X}", code)
+        })
+        .collect()
+}
+
+/// ページレイアウト（描画位置の管理と改ページ）
+struct PageLayout {
+    pages: Vec<String>,
+    current: String,
+    y: f32,
+}
+
+impl PageLayout {
+    fn new() -> Self {
+        Self {
+            pages: Vec::new(),
+            current: String::new(),
+            y: PAGE_HEIGHT - MARGIN,
+        }
+    }
+
+    fn content_width() -> f32 {
+        PAGE_WIDTH - MARGIN * 2.0
+    }
+
+    fn new_page(&mut self) {
+        self.pages.push(std::mem::take(&mut self.current));
+        self.y = PAGE_HEIGHT - MARGIN;
+    }
+
+    /// 指定の高さが残っていなければ改ページ
+    fn ensure_space(&mut self, height: f32) {
+        if self.y - height < MARGIN + FOOTER_HEIGHT {
+            self.new_page();
+        }
+    }
+
+    fn gap(&mut self, height: f32) {
+        self.y -= height;
+    }
+
+    fn text_at(&mut self, text: &str, font_size: f32, x: f32, baseline: f32) {
+        self.current.push_str(&format!(
+            "BT /F1 {:.1} Tf {:.2} {:.2} Td <{}> Tj ET\n",
+            font_size,
+            x,
+            baseline,
+            encode_text(text)
+        ));
+    }
+
+    /// 折り返し付きで段落を描画
+    fn paragraph(&mut self, text: &str, font_size: f32, indent: f32) {
+        let line_height = font_size * LINE_SPACING;
+        for line in wrap_text(text, font_size, Self::content_width() - indent) {
+            self.ensure_space(line_height);
+            self.y -= line_height;
+            self.text_at(&line, font_size, MARGIN + indent, self.y + font_size * 0.3);
+        }
+    }
+
+    /// セクション見出しを描画
+    fn heading(&mut self, text: &str) {
+        // 見出しだけがページ末尾に残らないよう本文1行分の余白も確保
+        self.ensure_space(HEADING_FONT_SIZE * LINE_SPACING + BODY_FONT_SIZE * LINE_SPACING * 2.0);
+        self.gap(BODY_FONT_SIZE * 0.5);
+        self.paragraph(text, HEADING_FONT_SIZE, 0.0);
+        self.rule(0.5);
+        self.gap(BODY_FONT_SIZE * 0.3);
+    }
+
+    /// 水平線を描画
+    fn rule(&mut self, line_width: f32) {
+        self.y -= 2.0;
+        self.current.push_str(&format!(
+            "{:.1} w 0.6 G {:.2} {:.2} m {:.2} {:.2} l S 0 G\n",
+            line_width,
+            MARGIN,
+            self.y,
+            PAGE_WIDTH - MARGIN,
+            self.y
+        ));
+    }
+
+    /// 罫線付きの表を描画
+    ///
+    /// 列幅は各列の最大文字幅に比例して割り当てます。ページの残りに収まらない行は
+    /// 収まる行数で分割し、次のページに続けて描画します。
+    /// `header` が真の場合、先頭行を改ページ後にも繰り返します。
+    fn table(&mut self, rows: &[Vec<String>], header: bool) {
+        let columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+        if columns == 0 {
+            return;
+        }
+
+        let total_width = Self::content_width();
+        let min_width = total_width / (columns as f32 * 4.0);
+        let natural: Vec<f32> = (0..columns)
+            .map(|col| {
+                rows.iter()
+                    .filter_map(|row| row.get(col))
+                    .map(|cell| text_width(cell, BODY_FONT_SIZE) + CELL_PADDING * 2.0)
+                    .fold(min_width, f32::max)
+            })
+            .collect();
+        let natural_total: f32 = natural.iter().sum();
+        let widths: Vec<f32> = natural
+            .iter()
+            .map(|w| w / natural_total * total_width)
+            .collect();
+
+        let line_height = BODY_FONT_SIZE * LINE_SPACING;
+        for (index, row) in rows.iter().enumerate() {
+            let mut wrapped: Vec<Vec<String>> = (0..columns)
+                .map(|col| {
+                    let cell = row.get(col).map(String::as_str).unwrap_or("");
+                    wrap_text(cell, BODY_FONT_SIZE, widths[col] - CELL_PADDING * 2.0)
+                })
+                .collect();
+            let mut fresh_page = false;
+
+            // 残りの高さに収まらない行は、収まる行数で分割して次のページに続ける
+            loop {
+                let lines = wrapped.iter().map(Vec::len).max().unwrap_or(0).max(1);
+                let mut available =
+                    ((self.y - MARGIN - FOOTER_HEIGHT - CELL_PADDING) / line_height) as usize;
+                if fresh_page {
+                    // 改ページ直後は1行も入らなくても描画して進める
+                    available = available.max(1);
+                }
+                if lines <= available {
+                    self.table_row_wrapped(
+                        &wrapped,
+                        &widths,
+                        lines as f32 * line_height + CELL_PADDING,
+                    );
+                    break;
+                }
+                if available > 0 {
+                    let rest = wrapped
+                        .iter_mut()
+                        .map(|cell| cell.split_off(available.min(cell.len())))
+                        .collect();
+                    self.table_row_wrapped(
+                        &wrapped,
+                        &widths,
+                        available as f32 * line_height + CELL_PADDING,
+                    );
+                    wrapped = rest;
+                }
+
+                self.new_page();
+                if header && index > 0 {
+                    self.table_row(&rows[0], &widths);
+                }
+                fresh_page = true;
+            }
+        }
+    }
+
+    fn table_row(&mut self, row: &[String], widths: &[f32]) {
+        let line_height = BODY_FONT_SIZE * LINE_SPACING;
+        let wrapped: Vec<Vec<String>> = widths
+            .iter()
+            .enumerate()
+            .map(|(col, width)| {
+                let cell = row.get(col).map(String::as_str).unwrap_or("");
+                wrap_text(cell, BODY_FONT_SIZE, width - CELL_PADDING * 2.0)
+            })
+            .collect();
+        let row_height =
+            wrapped.iter().map(Vec::len).max().unwrap_or(1) as f32 * line_height + CELL_PADDING;
+        self.table_row_wrapped(&wrapped, widths, row_height);
+    }
+
+    fn table_row_wrapped(&mut self, cells: &[Vec<String>], widths: &[f32], row_height: f32) {
+        let top = self.y;
+        let mut x = MARGIN;
+        let line_height = BODY_FONT_SIZE * LINE_SPACING;
+
+        for (lines, width) in cells.iter().zip(widths) {
+            self.current.push_str(&format!(
+                "0.5 w 0.6 G {:.2} {:.2} {:.2} {:.2} re S 0 G\n",
+                x,
+                top - row_height,
+                width,
+                row_height
+            ));
+            for (i, line) in lines.iter().enumerate() {
+                let baseline = top - CELL_PADDING / 2.0 - line_height * (i as f32 + 1.0)
+                    + BODY_FONT_SIZE * 0.3;
+                self.text_at(line, BODY_FONT_SIZE, x + CELL_PADDING, baseline);
+            }
+            x += width;
+        }
+
+        self.y -= row_height;
+    }
+
+    /// 全ページにページ番号を付与してコンテンツストリームを返す
+    fn finish(mut self) -> Vec<String> {
+        if !self.current.is_empty() || self.pages.is_empty() {
+            self.pages.push(std::mem::take(&mut self.current));
+        }
+
+        let total = self.pages.len();
+        self.pages
+            .into_iter()
+            .enumerate()
+            .map(|(index, mut content)| {
+                let footer = format!("{} / {}", index + 1, total);
+                let x = (PAGE_WIDTH - text_width(&footer, FOOTER_FONT_SIZE)) / 2.0;
+                content.push_str(&format!(
+                    "BT /F1 {:.1} Tf {:.2} {:.2} Td <{}> Tj ET\n",
+                    FOOTER_FONT_SIZE,
+                    x,
+                    MARGIN / 2.0,
+                    encode_text(&footer)
+                ));
+                content
+            })
+            .collect()
+    }
+}
+
+/// ページのコンテンツストリームからPDFドキュメントを組み立てる
+fn build_pdf(pages: &[String], title: &str) -> Vec<u8> {
+    // オブジェクト番号: 1=Catalog, 2=Pages, 3=Type0フォント, 4=CIDフォント,
+    // 5=FontDescriptor, 6=Info, 7以降=各ページとコンテンツ
+    let page_ids: Vec<usize> = (0..pages.len()).map(|i| 7 + i * 2).collect();
+
+    let mut objects: Vec<String> = vec![
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        format!(
+            "<< /Type /Pages /Kids [{}] /Count {} >>",
+            page_ids
+                .iter()
+                .map(|id| format!("{} 0 R", id))
+                .collect::<Vec<_>>()
+                .join(" "),
+            pages.len()
+        ),
+        "<< /Type /Font /Subtype /Type0 /BaseFont /HeiseiKakuGo-W5 \
+         /Encoding /UniJIS-UCS2-HW-H /DescendantFonts [4 0 R] >>"
+            .to_string(),
+        "<< /Type /Font /Subtype /CIDFontType0 /BaseFont /HeiseiKakuGo-W5 \
+         /CIDSystemInfo << /Registry (Adobe) /Ordering (Japan1) /Supplement 2 >> \
+         /FontDescriptor 5 0 R /DW 1000 /W [231 325 500] >>"
+            .to_string(),
+        "<< /Type /FontDescriptor /FontName /HeiseiKakuGo-W5 /Flags 4 \
+         /FontBBox [-92 -250 1010 922] /ItalicAngle 0 /Ascent 752 /Descent -221 \
+         /CapHeight 737 /StemV 114 >>"
+            .to_string(),
+        format!(
+            "<< /Title <FEFF{}> /Producer (WorkNote) >>",
+            encode_text(title)
+        ),
+    ];
+
+    for (index, content) in pages.iter().enumerate() {
+        let content_id = page_ids[index] + 1;
+        objects.push(format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {} {}] \
+             /Resources << /Font << /F1 3 0 R >> >> /Contents {} 0 R >>",
+            PAGE_WIDTH, PAGE_HEIGHT, content_id
+        ));
+        objects.push(format!(
+            "<< /Length {} >>\nstream\n{}endstream",
+            content.len(),
+            content
+        ));
+    }
+
+    let mut pdf = b"%PDF-1.4\n%\xe2\xe3\xcf\xd3\n".to_vec();
+    let mut offsets = Vec::with_capacity(objects.len());
+    for (index, object) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        pdf.extend_from_slice(format!("{} 0 obj\n{}\nendobj\n", index + 1, object).as_bytes());
+    }
+
+    let xref_offset = pdf.len();
+    let mut trailer = format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1);
+    for offset in offsets {
+        trailer.push_str(&format!("{:010} 00000 n \n", offset));
+    }
+    trailer.push_str(&format!(
+        "trailer\n<< /Size {} /Root 1 0 R /Info 6 0 R >>\nstartxref\n{}\n%%EOF\n",
+        objects.len() + 1,
+        xref_offset
+    ));
+    pdf.extend_from_slice(trailer.as_bytes());
+
+    pdf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{AuthorConfig, Category, KnowledgeInput, Severity};
+    use crate::services::FileGenerator;
+    use std::env;
+    use std::path::PathBuf;
+
+    fn sample_markdown(procedure: &str) -> String {
+        let generator = FileGenerator::new(
+            PathBuf::from("/test/repo"),
+            "docs/runbooks".to_string(),
+            AuthorConfig {
+                name: "Test User".to_string(),
+                email: String::new(),
+            },
+        );
+        let input = KnowledgeInput {
+            title: "CPU高騰対応".to_string(),
+            category: Category::Alerts,
+            severity: Severity::High,
+            symptoms: "CPU使用率が90%を超えている".to_string(),
+            procedure: procedure.to_string(),
+            notes: Some("再起動前にログを確認すること".to_string()),
+            related_links: None,
+            judgment: None,
+            slug: None,
+            related_alerts: Vec::new(),
+            id: None,
+        };
+        generator.generate_markdown(&input)
+    }
+
+    fn page_count(pdf: &[u8]) -> usize {
+        let text = String::from_utf8_lossy(pdf);
+        text.matches("/Type /Page ").count()
+    }
+
+    #[test]
+    fn test_render_pdf_structure() {
+        let document = RunbookParser::parse(&sample_markdown("1. top を確認")).unwrap();
+        let pdf = PdfExporter::render(&document);
+        let text = String::from_utf8_lossy(&pdf);
+
+        assert!(pdf.starts_with(b"%PDF-1.4"));
+        assert!(text.trim_end().ends_with("%%EOF"));
+        assert!(text.contains("/HeiseiKakuGo-W5"));
+        assert!(text.contains(&encode_text("CPU高騰対応")));
+        assert!(text.contains(&encode_text("対応履歴")));
+        assert!(text.contains(&encode_text("Test User")));
+        assert_eq!(page_count(&pdf), 1);
+    }
+
+    #[test]
+    fn test_render_pdf_paginates_long_content() {
+        let procedure = (1..=200)
+            .map(|i| format!("{}. 手順 {}", i, i))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let document = RunbookParser::parse(&sample_markdown(&procedure)).unwrap();
+        let pdf = PdfExporter::render(&document);
+
+        let pages = page_count(&pdf);
+        assert!(pages > 1);
+        let text = String::from_utf8_lossy(&pdf);
+        assert!(text.contains(&encode_text(&format!("{} / {}", pages, pages))));
+    }
+
+    #[test]
+    fn test_xref_offsets_point_to_objects() {
+        let document = RunbookParser::parse(&sample_markdown("test")).unwrap();
+        let pdf = PdfExporter::render(&document);
+        let text = String::from_utf8_lossy(&pdf).to_string();
+
+        let xref_start = text.find("xref\n").unwrap();
+        let first_entry = text[xref_start..].lines().nth(3).unwrap();
+        let offset: usize = first_entry[..10].parse().unwrap();
+        // ヘッダーのバイナリコメントを含むためバイト列で比較
+        assert!(pdf[offset..].starts_with(b"1 0 obj"));
+    }
+
+    #[test]
+    fn test_table_splits_tall_rows() {
+        let mut layout = PageLayout::new();
+        layout.gap(PAGE_HEIGHT / 2.0);
+        let long_cell = "ログを確認する。".repeat(400);
+        let rows = vec![
+            vec!["日付".to_string(), "内容".to_string()],
+            vec!["2026-01-31".to_string(), long_cell.clone()],
+        ];
+        layout.table(&rows, true);
+        let pages = layout.finish();
+        assert!(pages.len() > 2);
+
+        // 本文はすべて余白より上に描画され、改ページ後は見出し行を繰り返す
+        let header = encode_text("内容");
+        for page in &pages[1..] {
+            assert!(page.contains(&header));
+        }
+        let mut lines = 0;
+        for page in &pages {
+            for op in page.lines().filter(|l| l.starts_with("BT /F1 10.0 Tf")) {
+                let baseline: f32 = op.split(' ').nth(5).unwrap().parse().unwrap();
+                assert!(baseline > MARGIN + FOOTER_HEIGHT, "{}", op);
+                lines += 1;
+            }
+        }
+        let wrapped = wrap_text(&long_cell, BODY_FONT_SIZE, PAGE_WIDTH);
+        assert!(lines > wrapped.len());
+    }
+
+    #[test]
+    fn test_wrap_text() {
+        let lines = wrap_text("あいうえおかきくけこ", 10.0, 50.0);
+        assert_eq!(lines, vec!["あいうえお", "かきくけこ"]);
+
+        let lines = wrap_text("hello world again", 10.0, 40.0);
+        assert_eq!(lines, vec!["hello", "world", "again"]);
+    }
+
+    #[test]
+    fn test_strip_inline_markdown() {
+        assert_eq!(
+            strip_inline_markdown("**重要** [手順書](https://example.com) の `kubectl`"),
+            "重要 手順書 (https://example.com) の kubectl"
+        );
+    }
+
+    #[test]
+    fn test_export_markdown_to_file() {
+        let temp_dir = env::temp_dir().join("worknote_test_pdf_export");
+        fs::create_dir_all(&temp_dir).unwrap();
+        let output = temp_dir.join("runbook.pdf");
+
+        PdfExporter::export_markdown(&sample_markdown("test"), &output).unwrap();
+        assert!(fs::read(&output).unwrap().starts_with(b"%PDF"));
+
+        // クリーンアップ
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+}
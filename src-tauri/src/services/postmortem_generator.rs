@@ -0,0 +1,386 @@
+use crate::models::{AuthorConfig, PostmortemInput, Result, RunbookEntry, WorkNoteError};
+use crate::services::file_generator::FileGenerator;
+use crate::services::file_naming::FileNamer;
+use crate::services::runbook_repository::RunbookRepository;
+use chrono::Local;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+use tracing::warn;
+
+/// PostmortemGenerator - ポストモーテムのMarkdownファイル生成を管理
+///
+/// Runbookとは別の保存先（`git.postmortemPath`）に `<発生日>-<slug>.md` として保存し、
+/// 関連RunbookのIDはRunbookへの相対リンクに解決します。
+pub struct PostmortemGenerator {
+    repository_path: PathBuf,
+    save_path: String,
+    postmortem_path: String,
+    author: AuthorConfig,
+}
+
+impl PostmortemGenerator {
+    /// 新しいPostmortemGeneratorインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `repository_path` - Gitリポジトリパス
+    /// * `save_path` - Runbookの保存先パス（関連リンクの解決に使用）
+    /// * `postmortem_path` - ポストモーテムの保存先パス
+    /// * `author` - Author（名前・メールアドレス）
+    pub fn new(
+        repository_path: PathBuf,
+        save_path: String,
+        postmortem_path: String,
+        author: AuthorConfig,
+    ) -> Self {
+        PostmortemGenerator {
+            repository_path,
+            save_path,
+            postmortem_path,
+            author,
+        }
+    }
+
+    /// Markdownを生成（プレビュー用：author情報なし、関連RunbookはIDのまま）
+    pub fn generate_markdown_for_preview(input: &PostmortemInput) -> String {
+        Self::render(input, None, &[], "")
+    }
+
+    /// Markdownを生成
+    pub fn generate_markdown(&self, input: &PostmortemInput) -> String {
+        let entries = if input.related_runbooks.is_empty() {
+            Vec::new()
+        } else {
+            let repository = RunbookRepository::new(self.repository_path.join(&self.save_path));
+            repository.list_entries().unwrap_or_else(|e| {
+                warn!(error = %e, "Failed to list runbooks for link resolution");
+                Vec::new()
+            })
+        };
+        let runbook_dir = relative_dir(&self.postmortem_path, &self.save_path);
+
+        Self::render(input, Some(&self.author), &entries, &runbook_dir)
+    }
+
+    /// ファイルを書き込む
+    ///
+    /// # Returns
+    /// 作成されたファイルのパス
+    pub fn write_file(&self, input: &PostmortemInput) -> Result<PathBuf> {
+        input.validate()?;
+
+        let mut input = input.clone();
+        input.ensure_id();
+        let input = &input;
+
+        // リポジトリパス外への書き込みを防止（作成前に `..` と絶対パスを拒否）
+        let relative = Path::new(&self.postmortem_path);
+        if relative.is_absolute()
+            || relative
+                .components()
+                .any(|c| matches!(c, Component::ParentDir))
+        {
+            return Err(WorkNoteError::FileError(
+                "Invalid postmortem path: directory traversal detected".to_string(),
+            ));
+        }
+
+        let postmortem_dir = self.repository_path.join(relative);
+        if !self.repository_path.exists() {
+            return Err(WorkNoteError::FileError(format!(
+                "Repository path does not exist: {}",
+                self.repository_path.display()
+            )));
+        }
+        fs::create_dir_all(&postmortem_dir).map_err(|e| {
+            WorkNoteError::FileError(format!("Failed to create postmortem directory: {}", e))
+        })?;
+
+        // シンボリックリンク経由の書き込みも防止
+        let canonical_repo = self.repository_path.canonicalize().map_err(|e| {
+            WorkNoteError::FileError(format!("Failed to canonicalize repository path: {}", e))
+        })?;
+        let canonical_dir = postmortem_dir.canonicalize().map_err(|e| {
+            WorkNoteError::FileError(format!("Failed to canonicalize postmortem path: {}", e))
+        })?;
+        if !canonical_dir.starts_with(&canonical_repo) {
+            return Err(WorkNoteError::FileError(
+                "Invalid postmortem path: directory traversal detected".to_string(),
+            ));
+        }
+
+        let slug = match FileNamer::romaji_slug(&input.title) {
+            slug if slug.is_empty() => input.id.clone().unwrap_or_default(),
+            slug => slug,
+        };
+        let base_filename = format!("{}-{}", input.incident_date()?, slug);
+        let mut file_path = postmortem_dir.join(format!("{}.md", base_filename));
+
+        // ファイル名衝突対応
+        if file_path.exists() {
+            let timestamp = Local::now().format("%Y%m%d-%H%M%S-%f").to_string();
+            file_path = postmortem_dir.join(format!("{}-{}.md", base_filename, timestamp));
+        }
+
+        fs::write(&file_path, self.generate_markdown(input))
+            .map_err(|e| WorkNoteError::FileError(format!("Failed to write file: {}", e)))?;
+
+        Ok(file_path)
+    }
+
+    /// Markdownを組み立てる
+    ///
+    /// # Arguments
+    /// * `author` - Author（プレビュー時は `None`）
+    /// * `entries` - 関連Runbookの解決に使うRunbook一覧
+    /// * `runbook_dir` - ポストモーテムの保存先からRunbookの保存先への相対パス
+    fn render(
+        input: &PostmortemInput,
+        author: Option<&AuthorConfig>,
+        entries: &[RunbookEntry],
+        runbook_dir: &str,
+    ) -> String {
+        let escape = FileGenerator::escape_yaml_string;
+        let today = Local::now().format("%Y-%m-%d").to_string();
+        let mut content = String::new();
+
+        // Frontmatter
+        content.push_str("---\n");
+        content.push_str("type: postmortem\n");
+        content.push_str(&format!("title: \"{}\"\n", escape(&input.title)));
+        if let Some(id) = &input.id {
+            content.push_str(&format!("id: {}\n", id));
+        }
+        content.push_str(&format!("incident_date: {}\n", input.incident_date.trim()));
+        content.push_str(&format!("severity: {}\n", input.severity.as_str()));
+        if input.related_runbooks.is_empty() {
+            content.push_str("related_runbooks: []\n");
+        } else {
+            content.push_str("related_runbooks:\n");
+            for id in &input.related_runbooks {
+                content.push_str(&format!("  - \"{}\"\n", escape(id.trim())));
+            }
+        }
+        content.push_str(&format!("last_updated: {}\n", today));
+        if let Some(author) = author {
+            content.push_str(&format!("author: \"{}\"\n", escape(&author.name)));
+            if !author.email.is_empty() {
+                content.push_str(&format!("author_email: \"{}\"\n", escape(&author.email)));
+            }
+        }
+        content.push_str("---\n\n");
+
+        content.push_str(&format!("# {}\n\n", input.title));
+
+        content.push_str("## 概要\n\n");
+        content.push_str(&format!("{}\n\n", input.summary.trim()));
+
+        content.push_str("## 影響\n\n");
+        content.push_str(&format!("{}\n\n", input.impact.trim()));
+
+        // タイムライン
+        content.push_str("## タイムライン\n\n");
+        if !input.timeline.is_empty() {
+            content.push_str("| 時刻 | 出来事 |\n");
+            content.push_str("|------|--------|\n");
+            for entry in &input.timeline {
+                content.push_str(&format!(
+                    "| {} | {} |\n",
+                    table_cell(&entry.time),
+                    table_cell(&entry.description)
+                ));
+            }
+        }
+        content.push('\n');
+
+        content.push_str("## 根本原因\n\n");
+        content.push_str(&format!("{}\n\n", input.root_cause.trim()));
+
+        // なぜなぜ分析
+        content.push_str("## なぜなぜ分析\n\n");
+        let whys: Vec<&String> = input
+            .five_whys
+            .iter()
+            .filter(|why| !why.trim().is_empty())
+            .collect();
+        for (i, why) in whys.iter().enumerate() {
+            content.push_str(&format!("{}. **なぜ{}**: {}\n", i + 1, i + 1, why.trim()));
+        }
+        content.push('\n');
+
+        // アクションアイテム
+        content.push_str("## アクションアイテム\n\n");
+        if !input.action_items.is_empty() {
+            content.push_str("| 内容 | 担当 | 期限 | 状態 |\n");
+            content.push_str("|------|------|------|------|\n");
+            for item in &input.action_items {
+                content.push_str(&format!(
+                    "| {} | {} | {} | {} |\n",
+                    table_cell(&item.description),
+                    table_cell(&item.owner),
+                    item.due_date.as_deref().unwrap_or("-"),
+                    item.status.label()
+                ));
+            }
+        }
+        content.push('\n');
+
+        content.push_str("## 教訓\n\n");
+        if let Some(lessons) = input.lessons_learned.as_deref().map(str::trim) {
+            if !lessons.is_empty() {
+                content.push_str(&format!("{}\n\n", lessons));
+            }
+        }
+
+        // 関連Runbook（見つからないIDは `[[id]]` のまま残す）
+        content.push_str("## 関連Runbook\n\n");
+        for id in &input.related_runbooks {
+            let id = id.trim();
+            match entries.iter().find(|e| e.id.as_deref() == Some(id)) {
+                Some(entry) => content.push_str(&format!(
+                    "- [{}]({}{})\n",
+                    entry.title, runbook_dir, entry.path
+                )),
+                None => content.push_str(&format!("- [[{}]]\n", id)),
+            }
+        }
+
+        content.trim_end().to_string() + "\n"
+    }
+}
+
+/// 表のセルに入れられるように `|` と改行をエスケープ
+fn table_cell(text: &str) -> String {
+    text.trim().replace('|', "\\|").replace('\n', "<br>")
+}
+
+/// `from` ディレクトリから `to` ディレクトリへの相対パス（末尾 `/` 付き）
+///
+/// どちらもリポジトリルートからの相対パスです（例: `docs/postmortems` → `docs/runbooks` は `../runbooks/`）。
+fn relative_dir(from: &str, to: &str) -> String {
+    let components = |path: &str| -> Vec<String> {
+        path.split(['/', '\\'])
+            .filter(|c| !c.is_empty() && *c != ".")
+            .map(str::to_string)
+            .collect()
+    };
+    let from = components(from);
+    let to = components(to);
+
+    let common = from.iter().zip(&to).take_while(|(a, b)| a == b).count();
+    let mut result = "../".repeat(from.len() - common);
+    for component in &to[common..] {
+        result.push_str(component);
+        result.push('/');
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{ActionItem, ActionItemStatus, PostmortemTimelineEntry, Severity};
+    use std::env;
+
+    fn input() -> PostmortemInput {
+        PostmortemInput {
+            id: Some("pm-0001".to_string()),
+            title: "決済APIの障害".to_string(),
+            incident_date: "2024-04-01".to_string(),
+            severity: Severity::Critical,
+            summary: "決済APIが30分停止した".to_string(),
+            impact: "決済の失敗 1,200件".to_string(),
+            timeline: vec![PostmortemTimelineEntry {
+                time: "10:05".to_string(),
+                description: "5xx | アラート発報".to_string(),
+            }],
+            root_cause: "コネクションプールの枯渇".to_string(),
+            five_whys: vec!["リクエストが失敗した".to_string(), "".to_string()],
+            action_items: vec![ActionItem {
+                description: "プール上限の監視を追加".to_string(),
+                owner: "alice".to_string(),
+                due_date: Some("2024-04-15".to_string()),
+                status: ActionItemStatus::InProgress,
+            }],
+            lessons_learned: None,
+            related_runbooks: vec!["rb-db01".to_string(), "rb-missing".to_string()],
+        }
+    }
+
+    #[test]
+    fn test_relative_dir() {
+        assert_eq!(
+            relative_dir("docs/postmortems", "docs/runbooks"),
+            "../runbooks/"
+        );
+        assert_eq!(
+            relative_dir("postmortems", "docs/runbooks/"),
+            "../docs/runbooks/"
+        );
+        assert_eq!(relative_dir("./docs", "docs/runbooks"), "runbooks/");
+    }
+
+    #[test]
+    fn test_render() {
+        let entries = vec![RunbookEntry {
+            path: "alerts/db.md".to_string(),
+            id: Some("rb-db01".to_string()),
+            category: "alerts".to_string(),
+            title: "DB接続エラー".to_string(),
+            severity: None,
+            last_updated: None,
+            author: None,
+        }];
+        let author = AuthorConfig {
+            name: "bob".to_string(),
+            email: "bob@example.com".to_string(),
+        };
+        let markdown =
+            PostmortemGenerator::render(&input(), Some(&author), &entries, "../runbooks/");
+
+        assert!(
+            markdown.starts_with("---\ntype: postmortem\ntitle: \"決済APIの障害\"\nid: pm-0001\n")
+        );
+        assert!(markdown.contains("incident_date: 2024-04-01\nseverity: critical\n"));
+        assert!(markdown.contains("related_runbooks:\n  - \"rb-db01\"\n  - \"rb-missing\"\n"));
+        assert!(markdown.contains("author: \"bob\"\nauthor_email: \"bob@example.com\"\n"));
+        assert!(markdown.contains("| 10:05 | 5xx \\| アラート発報 |\n"));
+        assert!(markdown.contains("1. **なぜ1**: リクエストが失敗した\n\n## アクションアイテム"));
+        assert!(markdown.contains("| プール上限の監視を追加 | alice | 2024-04-15 | 対応中 |\n"));
+        assert!(markdown.contains("- [DB接続エラー](../runbooks/alerts/db.md)\n- [[rb-missing]]\n"));
+
+        let preview = PostmortemGenerator::generate_markdown_for_preview(&input());
+        assert!(!preview.contains("author:"));
+        assert!(preview.contains("- [[rb-db01]]"));
+    }
+
+    #[test]
+    fn test_write_file() {
+        let repo = env::temp_dir().join("worknote_postmortem_write");
+        let _ = fs::remove_dir_all(&repo);
+        fs::create_dir_all(&repo).unwrap();
+
+        let generator = PostmortemGenerator::new(
+            repo.clone(),
+            "docs/runbooks".to_string(),
+            "docs/postmortems".to_string(),
+            AuthorConfig::default(),
+        );
+        let path = generator.write_file(&input()).unwrap();
+        assert_eq!(
+            path,
+            repo.join("docs/postmortems/2024-04-01-api-no-shougai.md")
+        );
+
+        // 同名ファイルがあれば別名で保存
+        let second = generator.write_file(&input()).unwrap();
+        assert_ne!(path, second);
+
+        let generator = PostmortemGenerator::new(
+            repo,
+            "docs/runbooks".to_string(),
+            "../outside".to_string(),
+            AuthorConfig::default(),
+        );
+        assert!(generator.write_file(&input()).is_err());
+    }
+}
@@ -22,6 +22,12 @@ pub struct ProofreadService {
     custom_prompt: Option<String>,
 }
 
+impl Default for ProofreadService {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl ProofreadService {
     pub fn new() -> Self {
         Self {
@@ -171,17 +177,13 @@ impl ProofreadService {
         let symptoms = self
             .extract_xml_content(response, "symptoms")
             .ok_or_else(|| {
-                WorkNoteError::ProofreadError(
-                    "Failed to parse symptoms from response".to_string(),
-                )
+                WorkNoteError::ProofreadError("Failed to parse symptoms from response".to_string())
             })?;
 
         let procedure = self
             .extract_xml_content(response, "procedure")
             .ok_or_else(|| {
-                WorkNoteError::ProofreadError(
-                    "Failed to parse procedure from response".to_string(),
-                )
+                WorkNoteError::ProofreadError("Failed to parse procedure from response".to_string())
             })?;
 
         let notes = self.extract_xml_content(response, "notes");
@@ -217,8 +219,7 @@ impl ProofreadService {
 {}
 </user_input>
 "#,
-            system_prompt,
-            content
+            system_prompt, content
         )
     }
 
@@ -240,21 +241,20 @@ impl ProofreadService {
                             .to_string(),
                     )
                 } else {
-                    WorkNoteError::ProofreadError(format!(
-                        "Failed to execute claude: {}",
-                        e
-                    ))
+                    WorkNoteError::ProofreadError(format!("Failed to execute claude: {}", e))
                 }
             })?;
 
         // wait_timeout() を呼び出す前にパイプを取得
         // （wait_timeout() がプロセスをreapするため、その後 wait_with_output() は ECHILD で失敗する）
-        let mut stdout = child.stdout.take().ok_or_else(|| {
-            WorkNoteError::ProofreadError("Failed to capture stdout".to_string())
-        })?;
-        let mut stderr = child.stderr.take().ok_or_else(|| {
-            WorkNoteError::ProofreadError("Failed to capture stderr".to_string())
-        })?;
+        let mut stdout = child
+            .stdout
+            .take()
+            .ok_or_else(|| WorkNoteError::ProofreadError("Failed to capture stdout".to_string()))?;
+        let mut stderr = child
+            .stderr
+            .take()
+            .ok_or_else(|| WorkNoteError::ProofreadError("Failed to capture stderr".to_string()))?;
 
         // タイムアウト付きでプロセスの完了を待つ
         match child.wait_timeout(self.timeout).map_err(|e| {
@@ -290,7 +290,10 @@ impl ProofreadService {
             None => {
                 // タイムアウト発生時はプロセスを強制終了
                 child.kill().map_err(|e| {
-                    WorkNoteError::ProofreadError(format!("Failed to kill timed-out process: {}", e))
+                    WorkNoteError::ProofreadError(format!(
+                        "Failed to kill timed-out process: {}",
+                        e
+                    ))
                 })?;
                 child.wait().ok(); // クリーンアップ
 
@@ -0,0 +1,547 @@
+use crate::models::{
+    BlameLine, DiffHunk, DiffLine, DiffLineKind, Result, RunbookCommit, RunbookDiff,
+    RunbookRevision, WorkNoteError,
+};
+use crate::services::git_service::GitService;
+use chrono::{DateTime, FixedOffset};
+use std::collections::{HashMap, HashSet};
+use std::path::{Component, Path};
+
+/// `git log` の出力形式（レコード区切り `\x1e`・項目区切り `\x1f`）
+const LOG_FORMAT: &str = "--format=%x1e%H%x1f%h%x1f%an%x1f%ae%x1f%aI%x1f%s";
+
+/// RunbookHistory - Runbookのコミット履歴・差分・過去の内容・blameを取得
+///
+/// パスは保存先ディレクトリ（save_path）からの相対パス（`RunbookEntry::path`）で指定します。
+/// カテゴリの移行などによる移動・リネームは `git log --follow` で追跡します。
+pub struct RunbookHistory {
+    git: GitService,
+    save_path: String,
+}
+
+impl RunbookHistory {
+    /// 新しいRunbookHistoryインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `git` - Runbookリポジトリを操作するGitService
+    /// * `save_path` - リポジトリ内の保存先ディレクトリ
+    pub fn new(git: GitService, save_path: String) -> Self {
+        RunbookHistory { git, save_path }
+    }
+
+    /// Runbookのコミット履歴を新しい順に取得
+    ///
+    /// # Arguments
+    /// * `path` - Runbookのパス（保存先ディレクトリからの相対パス）
+    /// * `limit` - 取得する最大件数（未指定時は全件）
+    pub fn log(&self, path: &str, limit: Option<usize>) -> Result<Vec<RunbookCommit>> {
+        let mut commits = self.commits(&self.repository_path(path)?, limit)?;
+        for commit in &mut commits {
+            commit.path = self.save_relative_path(&commit.path);
+        }
+        Ok(commits)
+    }
+
+    /// 指定したリビジョンのRunbookの内容を取得
+    ///
+    /// # Arguments
+    /// * `path` - Runbookの現在のパス（保存先ディレクトリからの相対パス）
+    /// * `revision` - コミットハッシュ・ブランチ名など
+    pub fn show(&self, path: &str, revision: &str) -> Result<RunbookRevision> {
+        let commits = self.commits(&self.repository_path(path)?, None)?;
+        let revision = self.resolve_revision(revision)?;
+        let file_path = self.path_at(&commits, path, &revision)?;
+        let content = self.content_at(&revision, &file_path)?;
+
+        Ok(RunbookRevision {
+            revision,
+            path: self.save_relative_path(&file_path),
+            content,
+        })
+    }
+
+    /// 2つのリビジョン間のRunbookの差分を取得
+    ///
+    /// # Arguments
+    /// * `path` - Runbookの現在のパス（保存先ディレクトリからの相対パス）
+    /// * `from` - 変更前のリビジョン（未指定時は作成前＝空の内容からの差分）
+    /// * `to` - 変更後のリビジョン（未指定時は `HEAD`）
+    pub fn diff(&self, path: &str, from: Option<&str>, to: Option<&str>) -> Result<RunbookDiff> {
+        let commits = self.commits(&self.repository_path(path)?, None)?;
+        let to_revision = self.resolve_revision(to.unwrap_or("HEAD"))?;
+        let new_path = self.path_at(&commits, path, &to_revision)?;
+        let modified = self.content_at(&to_revision, &new_path)?;
+
+        let (from_revision, old_path, original, hunks) = match from {
+            Some(from) => {
+                let from_revision = self.resolve_revision(from)?;
+                let old_path = self.path_at(&commits, path, &from_revision)?;
+                let original = self.content_at(&from_revision, &old_path)?;
+                let output = self.git.execute_git(&[
+                    "diff",
+                    "--no-color",
+                    "--no-ext-diff",
+                    "--unified=3",
+                    &format!("{}:{}", from_revision, old_path),
+                    &format!("{}:{}", to_revision, new_path),
+                ])?;
+                (
+                    Some(from_revision),
+                    Some(self.save_relative_path(&old_path)),
+                    original,
+                    parse_diff(&output),
+                )
+            }
+            None => (None, None, String::new(), added_hunks(&modified)),
+        };
+
+        let count = |kind| {
+            hunks
+                .iter()
+                .flat_map(|hunk| &hunk.lines)
+                .filter(|line| line.kind == kind)
+                .count()
+        };
+        let (additions, deletions) = (count(DiffLineKind::Added), count(DiffLineKind::Removed));
+
+        Ok(RunbookDiff {
+            from_revision,
+            to_revision,
+            old_path,
+            new_path: self.save_relative_path(&new_path),
+            original,
+            modified,
+            hunks,
+            additions,
+            deletions,
+        })
+    }
+
+    /// 各行を最後に変更したコミットを取得（blame）
+    ///
+    /// # Arguments
+    /// * `path` - Runbookの現在のパス（保存先ディレクトリからの相対パス）
+    /// * `revision` - 対象のリビジョン（未指定時は `HEAD`）
+    pub fn blame(&self, path: &str, revision: Option<&str>) -> Result<Vec<BlameLine>> {
+        let commits = self.commits(&self.repository_path(path)?, None)?;
+        let revision = self.resolve_revision(revision.unwrap_or("HEAD"))?;
+        let file_path = self.path_at(&commits, path, &revision)?;
+        let output =
+            self.git
+                .execute_git(&["blame", "--porcelain", &revision, "--", &file_path])?;
+        Ok(parse_blame(&output))
+    }
+
+    /// リポジトリからの相対パスのコミット履歴（パスはリポジトリからの相対パス）
+    fn commits(&self, repository_path: &str, limit: Option<usize>) -> Result<Vec<RunbookCommit>> {
+        let max_count = limit.map(|limit| format!("--max-count={}", limit));
+        let mut args = vec!["log", "--follow", "--no-color", LOG_FORMAT, "--name-only"];
+        if let Some(max_count) = &max_count {
+            args.push(max_count);
+        }
+        args.extend(["--", repository_path]);
+
+        let output = self.git.execute_git(&args)?;
+        Ok(parse_log(&output))
+    }
+
+    /// 指定したリビジョン時点のファイルのパス（リポジトリからの相対パス）
+    ///
+    /// リビジョンから辿れる最新のコミットでのパスを使います（以降の移動・リネームを遡る）。
+    fn path_at(&self, commits: &[RunbookCommit], path: &str, revision: &str) -> Result<String> {
+        let ancestors: HashSet<String> = self
+            .git
+            .execute_git(&["rev-list", revision])?
+            .lines()
+            .map(str::to_string)
+            .collect();
+
+        commits
+            .iter()
+            .find(|commit| ancestors.contains(&commit.hash))
+            .map(|commit| commit.path.clone())
+            .ok_or_else(|| {
+                WorkNoteError::ValidationError(format!(
+                    "Runbook {} does not exist at revision {}",
+                    path, revision
+                ))
+            })
+    }
+
+    /// 指定したリビジョンのファイルの内容
+    fn content_at(&self, revision: &str, repository_path: &str) -> Result<String> {
+        self.git
+            .execute_git(&["show", &format!("{}:{}", revision, repository_path)])
+    }
+
+    /// リビジョンをコミットハッシュに解決
+    fn resolve_revision(&self, revision: &str) -> Result<String> {
+        let revision = revision.trim();
+        let invalid = || WorkNoteError::ValidationError(format!("Unknown revision: {}", revision));
+        // オプションとして解釈されないよう `-` で始まる指定は受け付けない
+        if revision.is_empty() || revision.starts_with('-') {
+            return Err(invalid());
+        }
+
+        let hash = self
+            .git
+            .execute_git(&[
+                "rev-parse",
+                "--verify",
+                "--quiet",
+                &format!("{}^{{commit}}", revision),
+            ])
+            .map_err(|_| invalid())?;
+        Ok(hash.trim().to_string())
+    }
+
+    /// 保存先ディレクトリからの相対パスをリポジトリからの相対パスに変換
+    fn repository_path(&self, path: &str) -> Result<String> {
+        let valid = !path.is_empty()
+            && Path::new(path)
+                .components()
+                .all(|c| matches!(c, Component::Normal(_)));
+        if !valid {
+            return Err(WorkNoteError::ValidationError(format!(
+                "Invalid runbook path: {}",
+                path
+            )));
+        }
+
+        let path = path.replace('\\', "/");
+        match self.save_prefix() {
+            Some(prefix) => Ok(format!("{}/{}", prefix, path)),
+            None => Ok(path),
+        }
+    }
+
+    /// リポジトリからの相対パスを保存先ディレクトリからの相対パスに変換
+    ///
+    /// 保存先ディレクトリの外（保存先を変更する前のパスなど）はリポジトリからの相対パスのまま返します。
+    fn save_relative_path(&self, repository_path: &str) -> String {
+        match self.save_prefix() {
+            Some(prefix) => repository_path
+                .strip_prefix(&format!("{}/", prefix))
+                .unwrap_or(repository_path)
+                .to_string(),
+            None => repository_path.to_string(),
+        }
+    }
+
+    /// 保存先ディレクトリ（`/` 区切り。リポジトリ直下の場合は `None`）
+    fn save_prefix(&self) -> Option<String> {
+        let prefix = self.save_path.replace('\\', "/");
+        let prefix = prefix.trim_matches('/').trim_start_matches("./");
+        (!prefix.is_empty() && prefix != ".").then(|| prefix.to_string())
+    }
+}
+
+/// `git log`（`LOG_FORMAT` と `--name-only`）の出力を解析
+fn parse_log(output: &str) -> Vec<RunbookCommit> {
+    output
+        .split('\x1e')
+        .filter_map(|record| {
+            let mut lines = record.lines();
+            let fields: Vec<&str> = lines.next()?.split('\x1f').collect();
+            let [hash, short_hash, author_name, author_email, date, subject] = fields[..] else {
+                return None;
+            };
+            let path = lines.map(str::trim).rfind(|line| !line.is_empty())?;
+
+            Some(RunbookCommit {
+                hash: hash.to_string(),
+                short_hash: short_hash.to_string(),
+                author_name: author_name.to_string(),
+                author_email: author_email.to_string(),
+                date: DateTime::parse_from_rfc3339(date).ok()?,
+                subject: subject.to_string(),
+                path: path.to_string(),
+            })
+        })
+        .collect()
+}
+
+/// unified形式の差分を解析（ハンクの前のヘッダー行は読み飛ばす）
+fn parse_diff(output: &str) -> Vec<DiffHunk> {
+    let mut hunks: Vec<DiffHunk> = Vec::new();
+    let (mut old_line, mut new_line) = (0, 0);
+
+    for line in output.lines() {
+        if let Some(hunk) = parse_hunk_header(line) {
+            old_line = hunk.old_start;
+            new_line = hunk.new_start;
+            hunks.push(hunk);
+            continue;
+        }
+        let Some(hunk) = hunks.last_mut() else {
+            continue;
+        };
+
+        let (kind, content) = match line.split_at_checked(1) {
+            Some((" ", content)) => (DiffLineKind::Context, content),
+            Some(("+", content)) => (DiffLineKind::Added, content),
+            Some(("-", content)) => (DiffLineKind::Removed, content),
+            // 空のコンテキスト行（末尾の空白を除去する設定の場合）
+            None => (DiffLineKind::Context, ""),
+            // `\ No newline at end of file` など
+            _ => continue,
+        };
+        let (old, new) = match kind {
+            DiffLineKind::Context => (Some(old_line), Some(new_line)),
+            DiffLineKind::Added => (None, Some(new_line)),
+            DiffLineKind::Removed => (Some(old_line), None),
+        };
+        old_line += old.is_some() as usize;
+        new_line += new.is_some() as usize;
+        hunk.lines.push(DiffLine {
+            kind,
+            content: content.to_string(),
+            old_line: old,
+            new_line: new,
+        });
+    }
+
+    hunks
+}
+
+/// ハンクのヘッダー（`@@ -a,b +c,d @@ section`）を解析
+fn parse_hunk_header(line: &str) -> Option<DiffHunk> {
+    let rest = line.strip_prefix("@@ -")?;
+    let (ranges, section) = rest.split_once(" @@")?;
+    let (old, new) = ranges.split_once(" +")?;
+    let range = |range: &str| -> Option<(usize, usize)> {
+        match range.split_once(',') {
+            Some((start, count)) => Some((start.parse().ok()?, count.parse().ok()?)),
+            None => Some((range.parse().ok()?, 1)),
+        }
+    };
+    let (old_start, old_lines) = range(old)?;
+    let (new_start, new_lines) = range(new)?;
+
+    Some(DiffHunk {
+        old_start,
+        old_lines,
+        new_start,
+        new_lines,
+        section: section.trim().to_string(),
+        lines: Vec::new(),
+    })
+}
+
+/// 空の内容からの差分（全行を追加）
+fn added_hunks(content: &str) -> Vec<DiffHunk> {
+    let lines: Vec<DiffLine> = content
+        .lines()
+        .enumerate()
+        .map(|(i, line)| DiffLine {
+            kind: DiffLineKind::Added,
+            content: line.to_string(),
+            old_line: None,
+            new_line: Some(i + 1),
+        })
+        .collect();
+    if lines.is_empty() {
+        return Vec::new();
+    }
+
+    vec![DiffHunk {
+        old_start: 0,
+        old_lines: 0,
+        new_start: 1,
+        new_lines: lines.len(),
+        section: String::new(),
+        lines,
+    }]
+}
+
+/// `git blame --porcelain` の出力を解析
+///
+/// コミットの情報（author等）は各コミットの初出の行にのみ出力されるため、コミットごとに保持します。
+fn parse_blame(output: &str) -> Vec<BlameLine> {
+    let mut commits: HashMap<String, HashMap<String, String>> = HashMap::new();
+    let mut current: Option<(String, usize)> = None;
+    let mut lines = Vec::new();
+
+    for line in output.lines() {
+        if let Some(content) = line.strip_prefix('\t') {
+            let Some((hash, line_number)) = current.take() else {
+                continue;
+            };
+            let info = commits.get(&hash);
+            let field = |key: &str| {
+                info.and_then(|info| info.get(key))
+                    .cloned()
+                    .unwrap_or_default()
+            };
+            let date = blame_date(&field("author-time"), &field("author-tz"));
+            lines.push(BlameLine {
+                line_number,
+                hash,
+                author_name: field("author"),
+                date,
+                subject: field("summary"),
+                content: content.to_string(),
+            });
+            continue;
+        }
+
+        let (key, value) = line.split_once(' ').unwrap_or((line, ""));
+        if key.len() >= 40 && key.chars().all(|c| c.is_ascii_hexdigit()) {
+            // `<hash> <元の行番号> <現在の行番号> [<行数>]`
+            let line_number = value
+                .split_whitespace()
+                .nth(1)
+                .and_then(|n| n.parse().ok())
+                .unwrap_or_default();
+            commits.entry(key.to_string()).or_default();
+            current = Some((key.to_string(), line_number));
+        } else if let Some((hash, _)) = &current {
+            if let Some(info) = commits.get_mut(hash) {
+                info.insert(key.to_string(), value.to_string());
+            }
+        }
+    }
+
+    lines
+}
+
+/// blameのauthor-time（UNIX時刻）とauthor-tz（`+0900`）から日時を作成
+fn blame_date(time: &str, tz: &str) -> DateTime<FixedOffset> {
+    let offset = tz
+        .split_at_checked(1)
+        .and_then(|(sign, hhmm)| {
+            let minutes =
+                hhmm.get(..2)?.parse::<i32>().ok()? * 60 + hhmm.get(2..)?.parse::<i32>().ok()?;
+            let seconds = if sign == "-" { -minutes } else { minutes } * 60;
+            FixedOffset::east_opt(seconds)
+        })
+        .unwrap_or_else(|| FixedOffset::east_opt(0).unwrap());
+    let time = time.parse::<i64>().unwrap_or_default();
+    DateTime::from_timestamp(time, 0)
+        .unwrap_or_default()
+        .with_timezone(&offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+
+    #[test]
+    fn test_parse_diff() {
+        let output = "diff --git a/a.md b/a.md\n--- a/a.md\n+++ b/a.md\n@@ -1,3 +1,4 @@ # タイトル\n ## 症状\n-旧\n+新\n+追加\n \n\\ No newline at end of file\n@@ -10 +11,0 @@\n-末尾\n";
+        let hunks = parse_diff(output);
+        assert_eq!(hunks.len(), 2);
+        assert_eq!(hunks[0].section, "# タイトル");
+        assert_eq!(
+            hunks[0]
+                .lines
+                .iter()
+                .map(|l| (l.kind, l.old_line, l.new_line))
+                .collect::<Vec<_>>(),
+            [
+                (DiffLineKind::Context, Some(1), Some(1)),
+                (DiffLineKind::Removed, Some(2), None),
+                (DiffLineKind::Added, None, Some(2)),
+                (DiffLineKind::Added, None, Some(3)),
+                (DiffLineKind::Context, Some(3), Some(4)),
+            ]
+        );
+        assert_eq!((hunks[1].old_start, hunks[1].old_lines), (10, 1));
+        assert_eq!((hunks[1].new_start, hunks[1].new_lines), (11, 0));
+        assert_eq!(hunks[1].lines[0].content, "末尾");
+
+        assert_eq!(added_hunks("a\nb\n")[0].new_lines, 2);
+        assert!(added_hunks("").is_empty());
+        assert_eq!(
+            blame_date("1769817900", "+0900").to_rfc3339(),
+            "2026-01-31T09:05:00+09:00"
+        );
+    }
+
+    fn git(repo: &Path, args: &[&str]) {
+        let status = Command::new("git")
+            .current_dir(repo)
+            .args(args)
+            .env("GIT_AUTHOR_DATE", "2026-01-31T09:05:00+09:00")
+            .output()
+            .unwrap()
+            .status;
+        assert!(status.success(), "git {:?} failed", args);
+    }
+
+    #[test]
+    fn test_runbook_history() {
+        let repo = std::env::temp_dir().join("worknote_test_runbook_history");
+        let _ = std::fs::remove_dir_all(&repo);
+        std::fs::create_dir_all(repo.join("runbooks/alerts")).unwrap();
+        git(&repo, &["init", "--quiet"]);
+        git(&repo, &["config", "user.name", "Repo User"]);
+        git(&repo, &["config", "user.email", "repo@example.com"]);
+
+        // 作成 → 編集 → カテゴリ移行による移動
+        let old_file = repo.join("runbooks/alerts/disk.md");
+        std::fs::write(&old_file, "# ディスク逼迫\n\n手順A\n").unwrap();
+        git(&repo, &["add", "."]);
+        git(&repo, &["commit", "--quiet", "-m", "docs: add disk"]);
+        std::fs::write(&old_file, "# ディスク逼迫\n\n手順B\n確認\n").unwrap();
+        git(&repo, &["commit", "--quiet", "-am", "docs: update disk"]);
+        std::fs::create_dir_all(repo.join("runbooks/ops")).unwrap();
+        git(
+            &repo,
+            &["mv", "runbooks/alerts/disk.md", "runbooks/ops/disk.md"],
+        );
+        git(&repo, &["commit", "--quiet", "-m", "chore: move disk"]);
+
+        let history = RunbookHistory::new(
+            GitService::new(repo.clone(), "main".to_string()),
+            "./runbooks/".to_string(),
+        );
+        let commits = history.log("ops/disk.md", None).unwrap();
+        assert_eq!(
+            commits
+                .iter()
+                .map(|c| (c.subject.as_str(), c.path.as_str()))
+                .collect::<Vec<_>>(),
+            [
+                ("chore: move disk", "ops/disk.md"),
+                ("docs: update disk", "alerts/disk.md"),
+                ("docs: add disk", "alerts/disk.md"),
+            ]
+        );
+        assert_eq!(commits[0].author_name, "Repo User");
+        assert_eq!(commits[0].date.to_rfc3339(), "2026-01-31T09:05:00+09:00");
+        assert_eq!(history.log("ops/disk.md", Some(1)).unwrap().len(), 1);
+
+        // 移動前のリビジョンは当時のパスから読む
+        let first = history.show("ops/disk.md", &commits[2].short_hash).unwrap();
+        assert_eq!(first.revision, commits[2].hash);
+        assert_eq!(first.path, "alerts/disk.md");
+        assert_eq!(first.content, "# ディスク逼迫\n\n手順A\n");
+
+        let diff = history
+            .diff("ops/disk.md", Some(&commits[2].hash), None)
+            .unwrap();
+        assert_eq!((diff.additions, diff.deletions), (2, 1));
+        assert_eq!(diff.old_path.as_deref(), Some("alerts/disk.md"));
+        assert_eq!(diff.new_path, "ops/disk.md");
+        assert_eq!(diff.modified, "# ディスク逼迫\n\n手順B\n確認\n");
+        let created = history
+            .diff("ops/disk.md", None, Some(&commits[2].hash))
+            .unwrap();
+        assert_eq!((created.additions, created.deletions), (3, 0));
+        assert_eq!(created.original, "");
+
+        let blame = history.blame("ops/disk.md", None).unwrap();
+        assert_eq!(blame.len(), 4);
+        assert_eq!(blame[0].hash, commits[2].hash);
+        assert_eq!(blame[3].subject, "docs: update disk");
+        assert_eq!(blame[3].content, "確認");
+
+        assert!(history.show("ops/disk.md", "--all").is_err());
+        assert!(history.show("ops/disk.md", "no-such-branch").is_err());
+        assert!(history.log("../secret.md", None).is_err());
+
+        let _ = std::fs::remove_dir_all(&repo);
+    }
+}
@@ -0,0 +1,680 @@
+use crate::models::{
+    Category, ImportOptions, ImportReport, ImportedDocument, KnowledgeInput, Result, Severity,
+    SkippedDocument, WorkNoteError,
+};
+use crate::services::html_converter::HtmlConverter;
+use crate::services::zip_reader::ZipArchive;
+use crate::services::DraftManager;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// タイトルの最大文字数（KnowledgeInputの検証と同じ）
+const TITLE_MAX_CHARS: usize = 100;
+
+/// 取り込み先の項目
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Field {
+    Symptoms,
+    Procedure,
+    Notes,
+    Links,
+}
+
+/// 見出しのキーワードと取り込み先の対応（上から順に判定）
+const HEADING_RULES: [(Field, &[&str]); 4] = [
+    (
+        Field::Notes,
+        &[
+            "注意",
+            "落とし穴",
+            "備考",
+            "補足",
+            "留意",
+            "caveat",
+            "warning",
+            "note",
+            "gotcha",
+            "tip",
+        ],
+    ),
+    (
+        Field::Links,
+        &[
+            "リンク",
+            "参考",
+            "関連",
+            "参照",
+            "link",
+            "reference",
+            "see also",
+            "related",
+        ],
+    ),
+    (
+        Field::Procedure,
+        &[
+            "手順",
+            "対応",
+            "対処",
+            "復旧",
+            "解決",
+            "作業",
+            "procedure",
+            "step",
+            "remediation",
+            "resolution",
+            "mitigation",
+            "how to",
+            "runbook",
+            "action",
+            "fix",
+        ],
+    ),
+    (
+        Field::Symptoms,
+        &[
+            "症状",
+            "検知",
+            "現象",
+            "事象",
+            "概要",
+            "条件",
+            "原因",
+            "symptom",
+            "detection",
+            "overview",
+            "summary",
+            "description",
+            "background",
+            "trigger",
+            "cause",
+        ],
+    ),
+];
+
+/// カテゴリ推定のキーワード
+const CATEGORY_RULES: [(Category, &[&str]); 3] = [
+    (
+        Category::Alerts,
+        &["alert", "アラート", "監視", "monitor", "alarm"],
+    ),
+    (
+        Category::Troubleshooting,
+        &[
+            "troubleshoot",
+            "障害",
+            "トラブル",
+            "incident",
+            "エラー",
+            "error",
+            "failure",
+        ],
+    ),
+    (
+        Category::Inquiry,
+        &["faq", "問い合わせ", "問合せ", "inquiry", "質問", "question"],
+    ),
+];
+
+/// 重要度推定のキーワード
+const SEVERITY_RULES: [(Severity, &[&str]); 2] = [
+    (
+        Severity::Critical,
+        &["critical", "sev1", "sev-1", "緊急", "致命"],
+    ),
+    (Severity::High, &["sev2", "sev-2", "重大", "重要度: 高"]),
+];
+
+/// 変換済みのドキュメント
+#[derive(Debug, Clone)]
+pub struct ConvertedDocument {
+    pub input: KnowledgeInput,
+    pub unmapped_sections: Vec<String>,
+    pub warnings: Vec<String>,
+}
+
+/// RunbookImporter - Confluence/NotionのエクスポートからRunbookの下書きを作成
+///
+/// HTML・Markdownファイル、それらを含むディレクトリ、ZIPアーカイブを受け付けます。
+/// 見出しをキーワードで各項目に対応付け、結果はコミットせず下書きとして保存します。
+pub struct RunbookImporter {
+    draft_manager: DraftManager,
+    options: ImportOptions,
+}
+
+impl RunbookImporter {
+    /// 新しいRunbookImporterインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `app_data_dir` - アプリケーションデータディレクトリ（下書きの保存先）
+    /// * `options` - インポートのオプション
+    pub fn new(app_data_dir: PathBuf, options: ImportOptions) -> Self {
+        RunbookImporter {
+            draft_manager: DraftManager::new(app_data_dir),
+            options,
+        }
+    }
+
+    /// ファイル・ディレクトリ・ZIPアーカイブを取り込む
+    pub fn import(&self, path: &Path) -> Result<ImportReport> {
+        let mut report = ImportReport::default();
+
+        if path.is_dir() {
+            for file in Self::walk(path)? {
+                let source = file.to_string_lossy().to_string();
+                let name = file
+                    .strip_prefix(path)
+                    .unwrap_or(&file)
+                    .to_string_lossy()
+                    .replace('\\', "/");
+                match fs::read(&file) {
+                    Ok(bytes) => self.import_file(&source, &name, bytes, &mut report)?,
+                    Err(e) => report.skipped.push(SkippedDocument {
+                        source,
+                        reason: format!("読み込めません: {}", e),
+                    }),
+                }
+            }
+        } else {
+            let bytes = fs::read(path).map_err(|e| {
+                WorkNoteError::FileError(format!("Failed to read {}: {}", path.display(), e))
+            })?;
+            let name = path
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_default();
+            self.import_file(&path.to_string_lossy(), &name, bytes, &mut report)?;
+        }
+
+        Ok(report)
+    }
+
+    /// 1ファイルを取り込む（ZIPは中のファイルを再帰的に処理）
+    fn import_file(
+        &self,
+        source: &str,
+        name: &str,
+        bytes: Vec<u8>,
+        report: &mut ImportReport,
+    ) -> Result<()> {
+        let file_name = name.rsplit('/').next().unwrap_or(name);
+        if file_name.starts_with('.') || name.starts_with("__MACOSX/") {
+            return Ok(());
+        }
+
+        let extension = file_name
+            .rsplit_once('.')
+            .map(|(_, ext)| ext.to_ascii_lowercase())
+            .unwrap_or_default();
+        let is_html = match extension.as_str() {
+            "zip" => {
+                let mut archive = match ZipArchive::new(bytes) {
+                    Ok(archive) => archive,
+                    Err(e) => {
+                        report.skipped.push(SkippedDocument {
+                            source: source.to_string(),
+                            reason: e.to_string(),
+                        });
+                        return Ok(());
+                    }
+                };
+                let entries = archive.entries().to_vec();
+                for entry in entries.iter().filter(|e| !e.is_dir()) {
+                    let entry_source = format!("{}!/{}", source, entry.name);
+                    match archive.read(entry) {
+                        Ok(bytes) => self.import_file(&entry_source, &entry.name, bytes, report)?,
+                        Err(e) => report.skipped.push(SkippedDocument {
+                            source: entry_source,
+                            reason: e.to_string(),
+                        }),
+                    }
+                }
+                return Ok(());
+            }
+            "html" | "htm" => true,
+            "md" | "markdown" => false,
+            // 添付ファイル・スタイルシート等は対象外
+            _ => return Ok(()),
+        };
+
+        // Confluenceのスペース一覧ページは本文を持たない
+        if is_html && file_name.eq_ignore_ascii_case("index.html") {
+            report.skipped.push(SkippedDocument {
+                source: source.to_string(),
+                reason: "インデックスページのため対象外です".to_string(),
+            });
+            return Ok(());
+        }
+
+        let Ok(content) = String::from_utf8(bytes) else {
+            report.skipped.push(SkippedDocument {
+                source: source.to_string(),
+                reason: "UTF-8のテキストではありません".to_string(),
+            });
+            return Ok(());
+        };
+
+        let Some(converted) = Self::convert(name, &content, is_html, &self.options) else {
+            report.skipped.push(SkippedDocument {
+                source: source.to_string(),
+                reason: "本文がありません".to_string(),
+            });
+            return Ok(());
+        };
+
+        let title = converted.input.title.clone();
+        let draft = self.draft_manager.create_draft(converted.input)?;
+        report.imported.push(ImportedDocument {
+            source: source.to_string(),
+            draft_id: draft.id,
+            title,
+            unmapped_sections: converted.unmapped_sections,
+            warnings: converted.warnings,
+        });
+        Ok(())
+    }
+
+    /// ドキュメントをナレッジ入力データに変換（本文が空の場合は `None`）
+    ///
+    /// # Arguments
+    /// * `name` - ファイル名（タイトルが見つからない場合に使用）
+    /// * `content` - ファイルの内容
+    /// * `is_html` - HTMLかどうか（falseの場合はMarkdown）
+    /// * `options` - インポートのオプション
+    pub fn convert(
+        name: &str,
+        content: &str,
+        is_html: bool,
+        options: &ImportOptions,
+    ) -> Option<ConvertedDocument> {
+        let (markdown, document_title) = if is_html {
+            (
+                HtmlConverter::to_markdown(content),
+                HtmlConverter::title(content).map(|t| Self::strip_space_name(&t)),
+            )
+        } else {
+            (content.replace("\r\n", "\n"), None)
+        };
+        if markdown.trim().is_empty() {
+            return None;
+        }
+
+        let mut outline = Outline::parse(&markdown);
+        let mut warnings = Vec::new();
+
+        let mut title = outline
+            .title
+            .take()
+            .or(document_title)
+            .unwrap_or_else(|| Self::title_from_file_name(name));
+        if title.chars().count() > TITLE_MAX_CHARS {
+            title = title.chars().take(TITLE_MAX_CHARS).collect();
+            warnings.push(format!("タイトルを{}文字に切り詰めました", TITLE_MAX_CHARS));
+        }
+
+        let mut fields: Vec<(Field, Vec<String>)> = [
+            Field::Symptoms,
+            Field::Procedure,
+            Field::Notes,
+            Field::Links,
+        ]
+        .into_iter()
+        .map(|field| (field, Vec::new()))
+        .collect();
+        let mut push = |field: Field, heading: Option<&str>, body: &str| {
+            if body.trim().is_empty() {
+                return;
+            }
+            let parts = &mut fields.iter_mut().find(|(f, _)| *f == field).unwrap().1;
+            match heading {
+                // 同じ項目に複数のセクションが対応する場合は見出しを残す
+                Some(heading) if !parts.is_empty() => {
+                    parts.push(format!("### {}\n\n{}", heading, body.trim()))
+                }
+                _ => parts.push(body.trim().to_string()),
+            }
+        };
+
+        push(Field::Symptoms, None, &outline.preamble);
+
+        let mut unmapped_sections = Vec::new();
+        for (heading, body) in &outline.sections {
+            match Self::classify(heading) {
+                Some(field) => push(field, Some(heading), body),
+                None => {
+                    // 内容を失わないよう見出し付きで注意点に退避する
+                    unmapped_sections.push(heading.clone());
+                    if !body.trim().is_empty() {
+                        push(
+                            Field::Notes,
+                            None,
+                            &format!("### {}\n\n{}", heading, body.trim()),
+                        );
+                    }
+                }
+            }
+        }
+
+        let mut take = |field: Field| {
+            let parts = std::mem::take(&mut fields.iter_mut().find(|(f, _)| *f == field)?.1);
+            (!parts.is_empty()).then(|| parts.join("\n\n"))
+        };
+        let symptoms = take(Field::Symptoms).unwrap_or_default();
+        let procedure = take(Field::Procedure).unwrap_or_default();
+        let notes = take(Field::Notes);
+        let related_links = take(Field::Links);
+
+        if symptoms.is_empty() {
+            warnings.push("症状・検知条件に対応する内容が見つかりません".to_string());
+        }
+        if procedure.is_empty() {
+            warnings.push("対応手順に対応する内容が見つかりません".to_string());
+        }
+        for image in Self::local_images(&markdown) {
+            warnings.push(format!("画像は取り込まれません: {}", image));
+        }
+
+        let classified_text = format!("{} {}", name, title).to_lowercase();
+        let category = options.category.clone().unwrap_or_else(|| {
+            Self::guess(&CATEGORY_RULES, &classified_text).unwrap_or(Category::Ops)
+        });
+        let severity = options.severity.clone().unwrap_or_else(|| {
+            Self::guess(&SEVERITY_RULES, &markdown.to_lowercase()).unwrap_or(Severity::Medium)
+        });
+
+        Some(ConvertedDocument {
+            input: KnowledgeInput {
+                id: None,
+                title,
+                category,
+                severity,
+                symptoms,
+                procedure,
+                notes,
+                related_links,
+                judgment: None,
+                slug: None,
+                related_alerts: Vec::new(),
+            },
+            unmapped_sections,
+            warnings,
+        })
+    }
+
+    /// 見出しから取り込み先の項目を判定
+    fn classify(heading: &str) -> Option<Field> {
+        let heading = heading.to_lowercase();
+        HEADING_RULES
+            .iter()
+            .find(|(_, keywords)| keywords.iter().any(|k| heading.contains(k)))
+            .map(|(field, _)| *field)
+    }
+
+    fn guess<T: Clone>(rules: &[(T, &[&str])], text: &str) -> Option<T> {
+        rules
+            .iter()
+            .find(|(_, keywords)| keywords.iter().any(|k| text.contains(k)))
+            .map(|(value, _)| value.clone())
+    }
+
+    /// Confluenceの `<title>`（`スペース名 : ページ名`）からページ名を取得
+    fn strip_space_name(title: &str) -> String {
+        title
+            .rsplit_once(" : ")
+            .map(|(_, page)| page)
+            .unwrap_or(title)
+            .trim()
+            .to_string()
+    }
+
+    /// ファイル名からタイトルを生成（Notionが付与する32桁のIDは除去）
+    fn title_from_file_name(name: &str) -> String {
+        let file_name = name.rsplit('/').next().unwrap_or(name);
+        let stem = file_name
+            .rsplit_once('.')
+            .map(|(stem, _)| stem)
+            .unwrap_or(file_name);
+
+        match stem.rsplit_once(' ') {
+            Some((title, id)) if id.len() == 32 && id.chars().all(|c| c.is_ascii_hexdigit()) => {
+                title.trim().to_string()
+            }
+            _ => stem.replace(['_', '+'], " ").trim().to_string(),
+        }
+    }
+
+    /// ローカルの画像参照（`![alt](path)`）を抽出
+    fn local_images(markdown: &str) -> Vec<String> {
+        let mut images = Vec::new();
+        let mut rest = markdown;
+        while let Some(start) = rest.find("![") {
+            rest = &rest[start + 2..];
+            let Some(target) = rest
+                .find("](")
+                .map(|i| &rest[i + 2..])
+                .and_then(|t| t.find(')').map(|end| &t[..end]))
+            else {
+                continue;
+            };
+            if !target.contains("://") && !target.starts_with("data:") {
+                images.push(target.to_string());
+            }
+        }
+        images
+    }
+
+    /// ディレクトリ内のファイルをパス順に再帰的に列挙
+    fn walk(dir: &Path) -> Result<Vec<PathBuf>> {
+        let mut files = Vec::new();
+        let entries = fs::read_dir(dir).map_err(|e| {
+            WorkNoteError::FileError(format!("Failed to read {}: {}", dir.display(), e))
+        })?;
+
+        for entry in entries.filter_map(|e| e.ok()) {
+            let path = entry.path();
+            if path.is_dir() {
+                files.extend(Self::walk(&path)?);
+            } else {
+                files.push(path);
+            }
+        }
+
+        files.sort();
+        Ok(files)
+    }
+}
+
+/// Markdownの見出し構造
+#[derive(Debug, Default)]
+struct Outline {
+    /// 先頭の `#` 見出し
+    title: Option<String>,
+    /// 最初のセクションより前の本文
+    preamble: String,
+    /// 最上位レベルの見出しとその本文（下位の見出しは本文に含む）
+    sections: Vec<(String, String)>,
+}
+
+impl Outline {
+    fn parse(markdown: &str) -> Outline {
+        let mut headings = Vec::new();
+        let mut in_code_block = false;
+        let lines: Vec<&str> = markdown.lines().collect();
+
+        for (index, line) in lines.iter().enumerate() {
+            if line.trim_start().starts_with("```") || line.trim_start().starts_with("~~~") {
+                in_code_block = !in_code_block;
+            }
+            if in_code_block {
+                continue;
+            }
+            let level = line.chars().take_while(|&c| c == '#').count();
+            if (1..=6).contains(&level) && line[level..].starts_with(' ') {
+                headings.push((index, level, line[level..].trim().to_string()));
+            }
+        }
+
+        let mut outline = Outline::default();
+        let mut skip_line = None;
+        if let Some((index, 1, title)) = headings.first().cloned() {
+            // タイトルより前に本文がない場合のみタイトルとして扱う
+            if lines[..index].iter().all(|l| l.trim().is_empty()) {
+                outline.title = Some(title);
+                skip_line = Some(index);
+                headings.remove(0);
+            }
+        }
+
+        let Some(section_level) = headings.iter().map(|(_, level, _)| *level).min() else {
+            outline.preamble = Self::join(&lines, skip_line.map_or(0, |i| i + 1), lines.len());
+            return outline;
+        };
+        let top: Vec<&(usize, usize, String)> = headings
+            .iter()
+            .filter(|(_, level, _)| *level == section_level)
+            .collect();
+
+        outline.preamble = Self::join(&lines, skip_line.map_or(0, |i| i + 1), top[0].0);
+        for (i, (index, _, heading)) in top.iter().enumerate() {
+            let end = top.get(i + 1).map_or(lines.len(), |next| next.0);
+            outline
+                .sections
+                .push((heading.clone(), Self::join(&lines, index + 1, end)));
+        }
+        outline
+    }
+
+    fn join(lines: &[&str], start: usize, end: usize) -> String {
+        lines[start.min(end)..end].join("\n").trim().to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::zip_reader::tests::build_zip;
+    use std::env;
+
+    const CONFLUENCE_PAGE: &str = r#"<html><head><title>SRE : DB接続エラー対応</title></head>
+<body><div id="main-content">
+<h2>概要</h2><p>アプリからDBに接続できない</p>
+<h2>対応手順</h2><ol><li>接続数を確認</li><li>再起動</li></ol>
+<h2>注意事項</h2><p>本番は承認が必要</p>
+<h2>エスカレーション先</h2><p>DBチーム</p>
+<h2>参考リンク</h2><ul><li><a href="https://example.com">手順書</a></li></ul>
+<p><img src="attachments/1/graph.png"></p>
+</div></body></html>"#;
+
+    #[test]
+    fn test_convert_confluence_html() {
+        let converted = RunbookImporter::convert(
+            "SRE/DB-Error_123.html",
+            CONFLUENCE_PAGE,
+            true,
+            &ImportOptions::default(),
+        )
+        .unwrap();
+        let input = &converted.input;
+
+        assert_eq!(input.title, "DB接続エラー対応");
+        assert_eq!(input.category, Category::Troubleshooting);
+        assert_eq!(input.severity, Severity::Medium);
+        assert_eq!(input.symptoms, "アプリからDBに接続できない");
+        assert_eq!(input.procedure, "1. 接続数を確認\n2. 再起動");
+        assert_eq!(
+            input.notes.as_deref(),
+            Some("本番は承認が必要\n\n### エスカレーション先\n\nDBチーム")
+        );
+        assert_eq!(
+            input.related_links.as_deref(),
+            Some("- [手順書](https://example.com)\n\n![](attachments/1/graph.png)")
+        );
+        assert_eq!(converted.unmapped_sections, vec!["エスカレーション先"]);
+        assert_eq!(
+            converted.warnings,
+            vec!["画像は取り込まれません: attachments/1/graph.png"]
+        );
+    }
+
+    #[test]
+    fn test_convert_notion_markdown() {
+        let markdown = "# CPUアラート\n\nTags: sre\n\n## 検知条件\n\nCPU > 90%\n\n\
+                        ### 補足\n\n5分継続\n\n## Steps\n\n```\n# not a heading\ntop\n```\n";
+        let converted = RunbookImporter::convert(
+            "Export/CPUアラート 0123456789abcdef0123456789abcdef.md",
+            markdown,
+            false,
+            &ImportOptions {
+                category: None,
+                severity: Some(Severity::High),
+            },
+        )
+        .unwrap();
+        let input = &converted.input;
+
+        assert_eq!(input.title, "CPUアラート");
+        assert_eq!(input.category, Category::Alerts);
+        assert_eq!(input.severity, Severity::High);
+        assert_eq!(
+            input.symptoms,
+            "Tags: sre\n\n### 検知条件\n\nCPU > 90%\n\n### 補足\n\n5分継続"
+        );
+        assert_eq!(input.procedure, "```\n# not a heading\ntop\n```");
+        assert!(converted.unmapped_sections.is_empty());
+
+        // 見出しがない場合はファイル名をタイトルにする
+        let converted = RunbookImporter::convert(
+            "Notes 0123456789abcdef0123456789abcdef.md",
+            "本文のみ",
+            false,
+            &ImportOptions::default(),
+        )
+        .unwrap();
+        assert_eq!(converted.input.title, "Notes");
+        assert_eq!(
+            converted.warnings,
+            vec!["対応手順に対応する内容が見つかりません"]
+        );
+
+        assert!(RunbookImporter::convert(
+            "empty.html",
+            "<p> </p>",
+            true,
+            &ImportOptions::default()
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn test_import_zip_creates_drafts() {
+        let base = env::temp_dir().join("worknote_import_zip");
+        let _ = fs::remove_dir_all(&base);
+        fs::create_dir_all(&base).unwrap();
+
+        let inner = build_zip(&[("page.md", "# 内側\n\n## 手順\n\n確認".as_bytes(), true)]);
+        let archive = build_zip(&[
+            ("SRE/index.html", b"<p>index</p>", false),
+            ("SRE/db.html", CONFLUENCE_PAGE.as_bytes(), true),
+            ("SRE/styles/site.css", b"body{}", false),
+            ("SRE/empty.md", b"", false),
+            ("Part-1.zip", &inner, false),
+        ]);
+        let zip_path = base.join("export.zip");
+        fs::write(&zip_path, archive).unwrap();
+
+        let data_dir = base.join("data");
+        let report = RunbookImporter::new(data_dir.clone(), ImportOptions::default())
+            .import(&zip_path)
+            .unwrap();
+
+        let titles: Vec<&str> = report.imported.iter().map(|d| d.title.as_str()).collect();
+        assert_eq!(titles, vec!["DB接続エラー対応", "内側"]);
+        assert!(report.imported[1]
+            .source
+            .ends_with("export.zip!/Part-1.zip!/page.md"));
+        assert_eq!(report.skipped.len(), 2);
+
+        let drafts = DraftManager::new(data_dir).list_drafts().unwrap();
+        assert_eq!(drafts.len(), 2);
+    }
+}
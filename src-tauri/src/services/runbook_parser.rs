@@ -0,0 +1,414 @@
+use crate::models::{Result, WorkNoteError};
+use std::collections::BTreeMap;
+
+/// フロントマターの値
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FrontmatterValue {
+    /// スカラー値（`key: value`）
+    Scalar(String),
+    /// リスト値（`key: [a, b]` または `- item` 形式）
+    List(Vec<String>),
+}
+
+/// Markdownの `##` セクション
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RunbookSection {
+    pub heading: String,
+    pub body: String,
+}
+
+/// パース済みのRunbook
+#[derive(Debug, Clone, Default)]
+pub struct RunbookDocument {
+    pub frontmatter: BTreeMap<String, FrontmatterValue>,
+    /// `#` 見出しのタイトル
+    pub title: Option<String>,
+    /// `#` 見出しと最初の `##` の間の本文
+    pub preamble: String,
+    pub sections: Vec<RunbookSection>,
+}
+
+impl RunbookDocument {
+    /// フロントマターのスカラー値を取得
+    pub fn get(&self, key: &str) -> Option<&str> {
+        match self.frontmatter.get(key) {
+            Some(FrontmatterValue::Scalar(value)) => Some(value.as_str()),
+            _ => None,
+        }
+    }
+
+    /// フロントマターのリスト値を取得（スカラー値は1要素のリストとして扱う）
+    pub fn get_list(&self, key: &str) -> Vec<String> {
+        match self.frontmatter.get(key) {
+            Some(FrontmatterValue::List(values)) => values.clone(),
+            Some(FrontmatterValue::Scalar(value)) if !value.is_empty() => vec![value.clone()],
+            _ => Vec::new(),
+        }
+    }
+
+    /// 表示用タイトルを取得（フロントマター優先）
+    pub fn display_title(&self) -> Option<&str> {
+        self.get("title")
+            .filter(|t| !t.is_empty())
+            .or(self.title.as_deref())
+    }
+
+    /// 見出しでセクションを検索
+    pub fn section(&self, heading: &str) -> Option<&RunbookSection> {
+        self.sections.iter().find(|s| s.heading == heading)
+    }
+}
+
+/// RunbookParser - FileGeneratorが出力したMarkdownを構造化データに変換
+pub struct RunbookParser;
+
+impl RunbookParser {
+    /// Markdown文字列をパース
+    ///
+    /// フロントマターは任意です。開始の `---` があるのに終了の `---` がない場合はエラーを返します。
+    ///
+    /// # Arguments
+    /// * `content` - Markdown文字列
+    pub fn parse(content: &str) -> Result<RunbookDocument> {
+        let content = content.strip_prefix('\u{feff}').unwrap_or(content);
+        let (frontmatter, body) = Self::split_frontmatter(content)?;
+
+        let mut document = RunbookDocument {
+            frontmatter: match frontmatter {
+                Some(fm) => Self::parse_frontmatter(fm)?,
+                None => BTreeMap::new(),
+            },
+            ..Default::default()
+        };
+
+        let mut current: Option<RunbookSection> = None;
+        let mut in_code_block = false;
+
+        for line in body.lines() {
+            if line.trim_start().starts_with("```") {
+                in_code_block = !in_code_block;
+            }
+
+            if !in_code_block {
+                if let Some(heading) = line.strip_prefix("## ") {
+                    if let Some(section) = current.take() {
+                        document.sections.push(Self::finish_section(section));
+                    }
+                    current = Some(RunbookSection {
+                        heading: heading.trim().to_string(),
+                        body: String::new(),
+                    });
+                    continue;
+                }
+
+                if document.title.is_none() && current.is_none() {
+                    if let Some(title) = line.strip_prefix("# ") {
+                        document.title = Some(title.trim().to_string());
+                        continue;
+                    }
+                }
+            }
+
+            let target = match current.as_mut() {
+                Some(section) => &mut section.body,
+                None => &mut document.preamble,
+            };
+            target.push_str(line);
+            target.push('\n');
+        }
+
+        if let Some(section) = current.take() {
+            document.sections.push(Self::finish_section(section));
+        }
+        document.preamble = document.preamble.trim().to_string();
+
+        Ok(document)
+    }
+
+    /// フロントマター部分と本文を分割
+    fn split_frontmatter(content: &str) -> Result<(Option<&str>, &str)> {
+        let rest = match content
+            .strip_prefix("---\n")
+            .or_else(|| content.strip_prefix("---\r\n"))
+        {
+            Some(rest) => rest,
+            None => return Ok((None, content)),
+        };
+
+        let mut offset = 0;
+        for line in rest.split_inclusive('\n') {
+            if line.trim_end() == "---" {
+                let frontmatter = &rest[..offset];
+                let body = &rest[offset + line.len()..];
+                return Ok((Some(frontmatter), body));
+            }
+            offset += line.len();
+        }
+
+        Err(WorkNoteError::ValidationError(
+            "Frontmatter is not terminated with '---'".to_string(),
+        ))
+    }
+
+    /// フロントマターをパース（FileGeneratorが出力するYAMLのサブセットに対応）
+    fn parse_frontmatter(frontmatter: &str) -> Result<BTreeMap<String, FrontmatterValue>> {
+        let mut values = BTreeMap::new();
+        let mut list_key: Option<String> = None;
+
+        for (index, line) in frontmatter.lines().enumerate() {
+            if line.trim().is_empty() || line.trim_start().starts_with('#') {
+                continue;
+            }
+
+            let trimmed = line.trim_start();
+            if let Some(item) = trimmed
+                .strip_prefix("- ")
+                .or_else(|| (trimmed == "-").then_some(""))
+            {
+                let key = list_key.as_ref().ok_or_else(|| {
+                    WorkNoteError::ValidationError(format!(
+                        "Frontmatter line {}: list item without a key",
+                        index + 1
+                    ))
+                })?;
+                if let Some(FrontmatterValue::List(items)) = values.get_mut(key) {
+                    items.push(Self::parse_scalar(item));
+                }
+                continue;
+            }
+
+            let (key, value) = line.split_once(':').ok_or_else(|| {
+                WorkNoteError::ValidationError(format!(
+                    "Frontmatter line {}: expected 'key: value'",
+                    index + 1
+                ))
+            })?;
+            let key = key.trim().to_string();
+            let value = value.trim();
+
+            if value.is_empty() {
+                values.insert(key.clone(), FrontmatterValue::List(Vec::new()));
+                list_key = Some(key);
+            } else if let Some(inner) = value.strip_prefix('[').and_then(|v| v.strip_suffix(']')) {
+                let items = Self::split_inline_list(inner)
+                    .iter()
+                    .map(|item| Self::parse_scalar(item))
+                    .collect();
+                values.insert(key, FrontmatterValue::List(items));
+                list_key = None;
+            } else {
+                values.insert(key, FrontmatterValue::Scalar(Self::parse_scalar(value)));
+                list_key = None;
+            }
+        }
+
+        Ok(values)
+    }
+
+    /// インラインリスト（`a, "b, c"`）を要素に分割
+    fn split_inline_list(inner: &str) -> Vec<String> {
+        let mut items = Vec::new();
+        let mut current = String::new();
+        let mut in_quotes = false;
+        let mut escaped = false;
+
+        for c in inner.chars() {
+            match c {
+                _ if escaped => {
+                    current.push(c);
+                    escaped = false;
+                }
+                '\\' if in_quotes => {
+                    current.push(c);
+                    escaped = true;
+                }
+                '"' => {
+                    current.push(c);
+                    in_quotes = !in_quotes;
+                }
+                ',' if !in_quotes => {
+                    items.push(current.trim().to_string());
+                    current.clear();
+                }
+                _ => current.push(c),
+            }
+        }
+
+        if !current.trim().is_empty() {
+            items.push(current.trim().to_string());
+        }
+        items
+    }
+
+    /// スカラー値をパース（ダブルクォート文字列のエスケープを解除）
+    fn parse_scalar(value: &str) -> String {
+        let value = value.trim();
+        if let Some(inner) = value.strip_prefix('"').and_then(|v| v.strip_suffix('"')) {
+            let mut result = String::new();
+            let mut chars = inner.chars();
+            while let Some(c) = chars.next() {
+                if c == '\\' {
+                    match chars.next() {
+                        Some('n') => result.push('\n'),
+                        Some('r') => result.push('\r'),
+                        Some('t') => result.push('\t'),
+                        Some(other) => result.push(other),
+                        None => result.push('\\'),
+                    }
+                } else {
+                    result.push(c);
+                }
+            }
+            result
+        } else if let Some(inner) = value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')) {
+            inner.replace("''", "'")
+        } else {
+            value.to_string()
+        }
+    }
+
+    /// セクション本文の前後の空行を除去
+    fn finish_section(mut section: RunbookSection) -> RunbookSection {
+        section.body = section.body.trim_matches('\n').trim_end().to_string();
+        section
+    }
+
+    /// Markdownテーブルを行ごとのセルに分解（区切り行は除外）
+    ///
+    /// # Arguments
+    /// * `body` - テーブルを含むセクション本文
+    pub fn parse_table(body: &str) -> Vec<Vec<String>> {
+        body.lines()
+            .map(str::trim)
+            .filter(|line| line.starts_with('|'))
+            .filter(|line| !line.chars().all(|c| matches!(c, '|' | '-' | ':' | ' ')))
+            .map(|line| {
+                line.trim_matches('|')
+                    .split('|')
+                    .map(|cell| cell.trim().to_string())
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// `[[runbook-id]]` 形式の参照を出現順に抽出（重複は除外）
+    pub fn wiki_links(text: &str) -> Vec<String> {
+        let mut links: Vec<String> = Vec::new();
+        let mut rest = text;
+
+        while let Some(start) = rest.find("[[") {
+            let after = &rest[start + 2..];
+            let Some(end) = after.find("]]") else {
+                break;
+            };
+            let id = after[..end].trim();
+            if !id.is_empty() && !id.contains('[') && !links.iter().any(|l| l == id) {
+                links.push(id.to_string());
+            }
+            rest = &after[end + 2..];
+        }
+
+        links
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"---
+title: "CPU高騰対応 \"緊急\""
+category: alerts
+severity: high
+symptoms:
+  - "CPU使用率が90%を超えている"
+related_alerts: []
+last_updated: 2026-01-31
+author: "Test User"
+---
+
+# CPU高騰対応
+
+## 概要
+
+CPU使用率が90%を超えている
+
+## 対応手順
+
+1. プロセス一覧を確認
+
+```
+## not a heading
+```
+
+## 対応履歴
+
+| 日付 | 対応者 | 備考 |
+|------|--------|------|
+| 2026-01-31 | Test User | 初版作成 |
+"#;
+
+    #[test]
+    fn test_parse_frontmatter() {
+        let doc = RunbookParser::parse(SAMPLE).unwrap();
+
+        assert_eq!(doc.get("title"), Some("CPU高騰対応 \"緊急\""));
+        assert_eq!(doc.get("category"), Some("alerts"));
+        assert_eq!(doc.get("last_updated"), Some("2026-01-31"));
+        assert_eq!(doc.get_list("symptoms"), vec!["CPU使用率が90%を超えている"]);
+        assert!(doc.get_list("related_alerts").is_empty());
+    }
+
+    #[test]
+    fn test_parse_sections() {
+        let doc = RunbookParser::parse(SAMPLE).unwrap();
+
+        assert_eq!(doc.title.as_deref(), Some("CPU高騰対応"));
+        let headings: Vec<&str> = doc.sections.iter().map(|s| s.heading.as_str()).collect();
+        assert_eq!(headings, vec!["概要", "対応手順", "対応履歴"]);
+
+        // コードブロック内の `##` は見出しとして扱わない
+        let procedure = doc.section("対応手順").unwrap();
+        assert!(procedure.body.contains("## not a heading"));
+    }
+
+    #[test]
+    fn test_parse_table() {
+        let doc = RunbookParser::parse(SAMPLE).unwrap();
+        let rows = RunbookParser::parse_table(&doc.section("対応履歴").unwrap().body);
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0], vec!["日付", "対応者", "備考"]);
+        assert_eq!(rows[1], vec!["2026-01-31", "Test User", "初版作成"]);
+    }
+
+    #[test]
+    fn test_parse_without_frontmatter() {
+        let doc = RunbookParser::parse("# Title\n\nIntro\n\n## Section\n\nBody\n").unwrap();
+
+        assert!(doc.frontmatter.is_empty());
+        assert_eq!(doc.display_title(), Some("Title"));
+        assert_eq!(doc.preamble, "Intro");
+        assert_eq!(doc.sections[0].body, "Body");
+    }
+
+    #[test]
+    fn test_parse_unterminated_frontmatter() {
+        let result = RunbookParser::parse("---\ntitle: test\n# Title\n");
+        assert!(matches!(result, Err(WorkNoteError::ValidationError(_))));
+    }
+
+    #[test]
+    fn test_wiki_links() {
+        let links = RunbookParser::wiki_links(
+            "- [[rb-1a2b3c4d]]\n- [[ rb-deadbeef ]] と [[rb-1a2b3c4d]]\n- [[]] [[broken",
+        );
+        assert_eq!(links, vec!["rb-1a2b3c4d", "rb-deadbeef"]);
+    }
+
+    #[test]
+    fn test_parse_inline_list() {
+        let doc = RunbookParser::parse("---\ntags: [a, \"b, c\", d]\n---\n").unwrap();
+        assert_eq!(doc.get_list("tags"), vec!["a", "b, c", "d"]);
+    }
+}
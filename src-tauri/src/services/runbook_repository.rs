@@ -0,0 +1,447 @@
+use crate::models::{Result, RunbookEntry, SearchResult, WorkNoteError};
+use crate::services::runbook_parser::RunbookParser;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tracing::warn;
+
+/// 自動生成されるインデックスファイル名
+pub const INDEX_FILE_NAME: &str = "index.md";
+
+/// 検索結果のスニペットの最大文字数
+const SNIPPET_MAX_CHARS: usize = 120;
+
+/// RunbookRepository - 保存先ディレクトリ内のRunbookを走査
+///
+/// Runbookは `save_path/<category>/<name>.md` に配置されている前提です。
+pub struct RunbookRepository {
+    save_dir: PathBuf,
+}
+
+impl RunbookRepository {
+    /// 新しいRunbookRepositoryインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `save_dir` - Runbook保存先ディレクトリ（リポジトリパス + save_path）
+    pub fn new(save_dir: PathBuf) -> Self {
+        RunbookRepository { save_dir }
+    }
+
+    /// 保存先ディレクトリを取得
+    pub fn save_dir(&self) -> &Path {
+        &self.save_dir
+    }
+
+    /// カテゴリディレクトリ一覧を名前順で取得
+    pub fn category_dirs(&self) -> Result<Vec<PathBuf>> {
+        if !self.save_dir.exists() {
+            return Ok(Vec::new());
+        }
+
+        let entries = fs::read_dir(&self.save_dir).map_err(|e| {
+            WorkNoteError::FileError(format!("Failed to read save directory: {}", e))
+        })?;
+
+        let mut dirs: Vec<PathBuf> = entries
+            .filter_map(|entry| entry.ok())
+            .map(|entry| entry.path())
+            .filter(|path| path.is_dir() && !Self::is_hidden(path))
+            .collect();
+        dirs.sort();
+
+        Ok(dirs)
+    }
+
+    /// Runbookファイル一覧をパス順で取得（インデックスファイルは除外）
+    pub fn runbook_files(&self) -> Result<Vec<PathBuf>> {
+        let mut files = Vec::new();
+
+        for dir in self.category_dirs()? {
+            let entries = fs::read_dir(&dir).map_err(|e| {
+                WorkNoteError::FileError(format!("Failed to read category directory: {}", e))
+            })?;
+
+            files.extend(
+                entries
+                    .filter_map(|entry| entry.ok())
+                    .map(|entry| entry.path())
+                    .filter(|path| {
+                        path.is_file()
+                            && path.extension().and_then(|s| s.to_str()) == Some("md")
+                            && path.file_name().and_then(|s| s.to_str()) != Some(INDEX_FILE_NAME)
+                            && !Self::is_hidden(path)
+                    }),
+            );
+        }
+
+        files.sort();
+        Ok(files)
+    }
+
+    /// Runbookエントリ一覧を取得
+    ///
+    /// フロントマターが読めないファイルはファイル名をタイトルとして扱います。
+    pub fn list_entries(&self) -> Result<Vec<RunbookEntry>> {
+        Ok(self
+            .read_runbooks()?
+            .into_iter()
+            .map(|(path, content)| self.entry_from_content(&path, &content))
+            .collect())
+    }
+
+    /// RunbookIDからエントリを検索
+    pub fn find_by_id(&self, id: &str) -> Result<Option<RunbookEntry>> {
+        Ok(self
+            .list_entries()?
+            .into_iter()
+            .find(|entry| entry.id.as_deref() == Some(id)))
+    }
+
+    /// Runbookを全文検索
+    ///
+    /// 空白区切りのすべての語を含むRunbookを、一致度の高い順に返します（大文字小文字は区別しない）。
+    ///
+    /// # Arguments
+    /// * `query` - 検索語
+    /// * `limit` - 最大件数
+    pub fn search(&self, query: &str, limit: usize) -> Result<Vec<SearchResult>> {
+        let terms: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+        if terms.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let mut results = Vec::new();
+        for (path, content) in self.read_runbooks()? {
+            let entry = self.entry_from_content(&path, &content);
+            let title = entry.title.to_lowercase();
+            let lower = content.to_lowercase();
+
+            if !terms
+                .iter()
+                .all(|term| lower.contains(term.as_str()) || title.contains(term.as_str()))
+            {
+                continue;
+            }
+
+            let score = terms
+                .iter()
+                .map(|term| {
+                    let title_score = if title.contains(term.as_str()) { 10 } else { 0 };
+                    title_score + lower.matches(term.as_str()).count()
+                })
+                .sum();
+            let snippet = Self::snippet(&content, &terms);
+
+            results.push(SearchResult {
+                entry,
+                snippet,
+                score,
+            });
+        }
+
+        results.sort_by(|a, b| {
+            b.score
+                .cmp(&a.score)
+                .then_with(|| a.entry.path.cmp(&b.entry.path))
+        });
+        results.truncate(limit);
+        Ok(results)
+    }
+
+    /// 検索語を含む最初の本文行を取得（フロントマターは除く）
+    fn snippet(content: &str, terms: &[String]) -> String {
+        let body = RunbookParser::parse(content)
+            .map(|document| {
+                let mut body = document.preamble.clone();
+                for section in &document.sections {
+                    body.push_str(&format!("\n{}\n{}", section.heading, section.body));
+                }
+                body
+            })
+            .unwrap_or_else(|_| content.to_string());
+
+        let line = body
+            .lines()
+            .map(str::trim)
+            .find(|line| {
+                let lower = line.to_lowercase();
+                terms.iter().any(|term| lower.contains(term.as_str()))
+            })
+            .unwrap_or_default();
+
+        if line.chars().count() > SNIPPET_MAX_CHARS {
+            let truncated: String = line.chars().take(SNIPPET_MAX_CHARS).collect();
+            format!("{}…", truncated)
+        } else {
+            line.to_string()
+        }
+    }
+
+    /// 指定したアラートに対応するRunbook一覧を取得
+    ///
+    /// フロントマターの `related_alerts` を大文字小文字を区別せずに照合します。
+    pub fn find_by_alert(&self, alert_name: &str) -> Result<Vec<RunbookEntry>> {
+        let alert_name = alert_name.trim();
+        let mut entries = Vec::new();
+
+        for (path, content) in self.read_runbooks()? {
+            let Ok(document) = RunbookParser::parse(&content) else {
+                continue;
+            };
+            if document
+                .get_list("related_alerts")
+                .iter()
+                .any(|alert| alert.trim().eq_ignore_ascii_case(alert_name))
+            {
+                entries.push(self.entry_from_content(&path, &content));
+            }
+        }
+
+        Ok(entries)
+    }
+
+    /// 指定したRunbookIDを参照しているRunbook一覧を取得
+    ///
+    /// フロントマターの `references`、または本文中の `[[id]]` で参照しているものが対象です。
+    pub fn backlinks(&self, id: &str) -> Result<Vec<RunbookEntry>> {
+        let mut entries = Vec::new();
+
+        for (path, content) in self.read_runbooks()? {
+            let Ok(document) = RunbookParser::parse(&content) else {
+                continue;
+            };
+            if document.get("id") == Some(id) {
+                continue;
+            }
+
+            let referenced = document.get_list("references").iter().any(|r| r == id)
+                || RunbookParser::wiki_links(&content).iter().any(|r| r == id);
+            if referenced {
+                entries.push(self.entry_from_content(&path, &content));
+            }
+        }
+
+        Ok(entries)
+    }
+
+    /// Runbookファイルをパス順に読み込む（読めないファイルはスキップ）
+    fn read_runbooks(&self) -> Result<Vec<(PathBuf, String)>> {
+        let mut runbooks = Vec::new();
+
+        for path in self.runbook_files()? {
+            match fs::read_to_string(&path) {
+                Ok(content) => runbooks.push((path, content)),
+                Err(e) => warn!(file = ?path, error = %e, "Failed to read runbook"),
+            }
+        }
+
+        Ok(runbooks)
+    }
+
+    /// ファイル内容からRunbookエントリを生成
+    fn entry_from_content(&self, path: &Path, content: &str) -> RunbookEntry {
+        let relative = self.relative_path(path);
+        let category = path
+            .parent()
+            .and_then(|p| p.file_name())
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default();
+        let stem = path
+            .file_stem()
+            .map(|s| s.to_string_lossy().to_string())
+            .unwrap_or_default();
+
+        match RunbookParser::parse(content) {
+            Ok(document) => RunbookEntry {
+                path: relative,
+                id: document.get("id").map(str::to_string),
+                category,
+                title: document.display_title().unwrap_or(&stem).to_string(),
+                severity: document.get("severity").map(str::to_string),
+                last_updated: document.get("last_updated").map(str::to_string),
+                author: document.get("author").map(str::to_string),
+            },
+            Err(e) => {
+                warn!(file = ?path, error = %e, "Failed to parse runbook frontmatter");
+                RunbookEntry {
+                    path: relative,
+                    id: None,
+                    category,
+                    title: stem,
+                    severity: None,
+                    last_updated: None,
+                    author: None,
+                }
+            }
+        }
+    }
+
+    /// 保存先ディレクトリからの相対パスを `/` 区切りで取得
+    pub fn relative_path(&self, path: &Path) -> String {
+        path.strip_prefix(&self.save_dir)
+            .unwrap_or(path)
+            .components()
+            .map(|c| c.as_os_str().to_string_lossy())
+            .collect::<Vec<_>>()
+            .join("/")
+    }
+
+    fn is_hidden(path: &Path) -> bool {
+        path.file_name()
+            .and_then(|s| s.to_str())
+            .is_some_and(|name| name.starts_with('.'))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_list_entries() {
+        let temp_dir = env::temp_dir().join("worknote_test_runbook_repository");
+        let _ = fs::remove_dir_all(&temp_dir);
+        fs::create_dir_all(temp_dir.join("alerts")).unwrap();
+        fs::create_dir_all(temp_dir.join(".git")).unwrap();
+
+        fs::write(
+            temp_dir.join("alerts/cpu.md"),
+            "---\ntitle: \"CPU高騰対応\"\nseverity: high\nlast_updated: 2026-01-31\nauthor: \"Test User\"\n---\n",
+        )
+        .unwrap();
+        fs::write(temp_dir.join("alerts/broken.md"), "---\ntitle: broken\n").unwrap();
+        fs::write(temp_dir.join("alerts/index.md"), "# index\n").unwrap();
+        fs::write(temp_dir.join("alerts/notes.txt"), "ignored").unwrap();
+
+        let repository = RunbookRepository::new(temp_dir.clone());
+        let entries = repository.list_entries().unwrap();
+
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].path, "alerts/broken.md");
+        assert_eq!(entries[0].title, "broken");
+        assert_eq!(entries[1].path, "alerts/cpu.md");
+        assert_eq!(entries[1].title, "CPU高騰対応");
+        assert_eq!(entries[1].severity.as_deref(), Some("high"));
+        assert_eq!(entries[1].category, "alerts");
+
+        // クリーンアップ
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_find_by_id_and_backlinks() {
+        let temp_dir = env::temp_dir().join("worknote_test_runbook_backlinks");
+        let _ = fs::remove_dir_all(&temp_dir);
+        fs::create_dir_all(temp_dir.join("alerts")).unwrap();
+        fs::create_dir_all(temp_dir.join("ops")).unwrap();
+
+        fs::write(
+            temp_dir.join("alerts/db.md"),
+            "---\ntitle: \"DB接続エラー\"\nid: rb-00000001\n---\n\n## 関連リンク\n\n- [[rb-00000001]]\n",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.join("alerts/cpu.md"),
+            "---\ntitle: \"CPU高騰\"\nid: rb-00000002\nreferences: [rb-00000001]\n---\n",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.join("ops/failover.md"),
+            "---\ntitle: \"フェイルオーバー\"\nid: rb-00000003\n---\n\n- [[rb-00000001]]\n",
+        )
+        .unwrap();
+
+        let repository = RunbookRepository::new(temp_dir.clone());
+
+        let found = repository.find_by_id("rb-00000002").unwrap().unwrap();
+        assert_eq!(found.path, "alerts/cpu.md");
+        assert!(repository.find_by_id("rb-ffffffff").unwrap().is_none());
+
+        // 自己参照は除外される
+        let backlinks = repository.backlinks("rb-00000001").unwrap();
+        let paths: Vec<&str> = backlinks.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(paths, vec!["alerts/cpu.md", "ops/failover.md"]);
+
+        // クリーンアップ
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_find_by_alert() {
+        let temp_dir = env::temp_dir().join("worknote_test_runbook_alerts");
+        let _ = fs::remove_dir_all(&temp_dir);
+        fs::create_dir_all(temp_dir.join("alerts")).unwrap();
+
+        fs::write(
+            temp_dir.join("alerts/cpu.md"),
+            "---\ntitle: \"CPU高騰\"\nrelated_alerts:\n  - \"HighCPUUsage\"\n  - \"datadog:12345\"\n---\n",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.join("alerts/load.md"),
+            "---\ntitle: \"ロードアベレージ\"\nrelated_alerts: [highcpuusage]\n---\n",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.join("alerts/disk.md"),
+            "---\ntitle: \"ディスク\"\nrelated_alerts: []\n---\n",
+        )
+        .unwrap();
+
+        let repository = RunbookRepository::new(temp_dir.clone());
+
+        let found = repository.find_by_alert("HIGHCPUUSAGE").unwrap();
+        let paths: Vec<&str> = found.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(paths, vec!["alerts/cpu.md", "alerts/load.md"]);
+
+        assert_eq!(repository.find_by_alert("datadog:12345").unwrap().len(), 1);
+        assert!(repository.find_by_alert("Unknown").unwrap().is_empty());
+
+        // クリーンアップ
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_search() {
+        let temp_dir = env::temp_dir().join("worknote_test_runbook_search");
+        let _ = fs::remove_dir_all(&temp_dir);
+        fs::create_dir_all(temp_dir.join("alerts")).unwrap();
+        fs::create_dir_all(temp_dir.join("ops")).unwrap();
+
+        fs::write(
+            temp_dir.join("alerts/db.md"),
+            "---\ntitle: \"DB接続エラー対応\"\n---\n\n# DB接続エラー対応\n\n## 対応手順\n\n1. コネクションプールを確認\n",
+        )
+        .unwrap();
+        fs::write(
+            temp_dir.join("ops/restart.md"),
+            "---\ntitle: \"再起動手順\"\n---\n\n## 対応手順\n\n1. DB を停止してから再起動\n",
+        )
+        .unwrap();
+
+        let repository = RunbookRepository::new(temp_dir.clone());
+
+        // タイトル一致が優先される
+        let results = repository.search("db", 10).unwrap();
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].entry.path, "alerts/db.md");
+        assert_eq!(results[1].snippet, "1. DB を停止してから再起動");
+
+        // すべての語を含むもののみ
+        let results = repository.search("DB 再起動", 10).unwrap();
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].entry.path, "ops/restart.md");
+
+        assert_eq!(repository.search("db", 1).unwrap().len(), 1);
+        assert!(repository.search("  ", 10).unwrap().is_empty());
+
+        // クリーンアップ
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_list_entries_missing_dir() {
+        let repository = RunbookRepository::new(PathBuf::from("/nonexistent/worknote"));
+        assert!(repository.list_entries().unwrap().is_empty());
+    }
+}
@@ -0,0 +1,749 @@
+use crate::models::knowledge::is_valid_runbook_id;
+use crate::models::taxonomy::LEGACY_CATEGORY_ALIASES;
+use crate::models::{
+    IssueLevel, Result, TaxonomyConfig, ValidationIssue, ValidationReport, WorkNoteError,
+};
+use crate::services::runbook_parser::{RunbookDocument, RunbookParser};
+use crate::services::RunbookRepository;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::path::Path;
+
+/// 必須セクション（FileGeneratorが必ず出力する `##` 見出し）
+pub const REQUIRED_SECTIONS: [&str; 5] = [
+    "概要",
+    "症状・検知条件",
+    "対応手順",
+    "注意点・落とし穴",
+    "関連リンク",
+];
+
+/// 本文が空であってはならないセクション
+const NON_EMPTY_SECTIONS: [&str; 2] = ["症状・検知条件", "対応手順"];
+
+/// 必須のフロントマター項目
+const REQUIRED_FRONTMATTER: [&str; 3] = ["title", "category", "severity"];
+
+/// RunbookValidator - 保存先ディレクトリ内のRunbookがFileGeneratorの形式に沿っているかを検証
+///
+/// 手作業で編集されたファイルのフロントマター・セクション構成・リポジトリ内リンクを確認します。
+pub struct RunbookValidator {
+    repository: RunbookRepository,
+    taxonomy: TaxonomyConfig,
+}
+
+impl RunbookValidator {
+    /// 新しいRunbookValidatorインスタンスを作成
+    ///
+    /// # Arguments
+    /// * `save_dir` - Runbook保存先ディレクトリ（リポジトリパス + save_path）
+    pub fn new(save_dir: impl Into<std::path::PathBuf>) -> Self {
+        RunbookValidator {
+            repository: RunbookRepository::new(save_dir.into()),
+            taxonomy: TaxonomyConfig::default(),
+        }
+    }
+
+    /// カテゴリ・重要度の定義を指定（未指定時は組み込みの定義で検証）
+    ///
+    /// # Arguments
+    /// * `taxonomy` - カテゴリ・重要度の定義
+    pub fn with_taxonomy(mut self, taxonomy: TaxonomyConfig) -> Self {
+        self.taxonomy = taxonomy;
+        self
+    }
+
+    /// すべてのRunbookを検証
+    pub fn validate_all(&self) -> Result<ValidationReport> {
+        if !self.repository.save_dir().is_dir() {
+            return Err(WorkNoteError::FileError(format!(
+                "Save directory does not exist: {}",
+                self.repository.save_dir().display()
+            )));
+        }
+
+        let mut report = ValidationReport::default();
+        let mut documents = Vec::new();
+
+        for path in self.repository.runbook_files()? {
+            report.files_checked += 1;
+            let relative = self.repository.relative_path(&path);
+            let content = match fs::read_to_string(&path) {
+                Ok(content) => content,
+                Err(e) => {
+                    report.issues.push(issue(
+                        &relative,
+                        None,
+                        IssueLevel::Error,
+                        "unreadable",
+                        format!("Failed to read file: {}", e),
+                    ));
+                    continue;
+                }
+            };
+
+            let document = match RunbookParser::parse(&content) {
+                Ok(document) => document,
+                Err(e) => {
+                    report.issues.push(issue(
+                        &relative,
+                        Some(1),
+                        IssueLevel::Error,
+                        "frontmatter-parse",
+                        e.to_string(),
+                    ));
+                    // 構造は検証できないため、リンクのみ検証する
+                    report
+                        .issues
+                        .extend(Self::check_links(&path, &relative, &content));
+                    continue;
+                }
+            };
+
+            report
+                .issues
+                .extend(self.check_frontmatter(&path, &relative, &content, &document));
+            report
+                .issues
+                .extend(Self::check_sections(&relative, &content, &document));
+            report
+                .issues
+                .extend(Self::check_links(&path, &relative, &content));
+            documents.push((relative, content, document));
+        }
+
+        report.issues.extend(Self::check_ids(&documents));
+        report
+            .issues
+            .sort_by(|a, b| a.path.cmp(&b.path).then_with(|| a.line.cmp(&b.line)));
+        Ok(report)
+    }
+
+    /// フロントマターの必須項目・値・配置ディレクトリを検証
+    fn check_frontmatter(
+        &self,
+        path: &Path,
+        relative: &str,
+        content: &str,
+        document: &RunbookDocument,
+    ) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        if !content
+            .strip_prefix('\u{feff}')
+            .unwrap_or(content)
+            .starts_with("---")
+        {
+            issues.push(issue(
+                relative,
+                Some(1),
+                IssueLevel::Error,
+                "frontmatter-missing",
+                "Frontmatter is missing".to_string(),
+            ));
+            return issues;
+        }
+        for key in REQUIRED_FRONTMATTER {
+            if document.get(key).is_none_or(|v| v.trim().is_empty()) {
+                issues.push(issue(
+                    relative,
+                    Some(1),
+                    IssueLevel::Error,
+                    "frontmatter-required",
+                    format!("Frontmatter field '{}' is required", key),
+                ));
+            }
+        }
+
+        if let Some(category) = document.get("category").filter(|c| !c.is_empty()) {
+            let line = frontmatter_line(content, "category");
+            let definition = self.taxonomy.categories.iter().find(|c| c.id == category);
+            if definition.is_none() {
+                let message = match LEGACY_CATEGORY_ALIASES
+                    .iter()
+                    .find(|(legacy, _)| *legacy == category)
+                {
+                    Some((_, replacement)) => format!(
+                        "Category '{}' is deprecated; use '{}' (run `worknote migrate-categories`)",
+                        category, replacement
+                    ),
+                    None => format!("Unknown category: {}", category),
+                };
+                issues.push(issue(
+                    relative,
+                    line,
+                    IssueLevel::Error,
+                    "invalid-category",
+                    message,
+                ));
+            }
+            let directory = path
+                .parent()
+                .and_then(|p| p.file_name())
+                .map(|s| s.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let expected = definition.map_or(category, |d| d.directory.as_str());
+            if expected != directory {
+                issues.push(issue(
+                    relative,
+                    line,
+                    IssueLevel::Error,
+                    "category-mismatch",
+                    format!(
+                        "Category '{}' does not match directory '{}'",
+                        category, directory
+                    ),
+                ));
+            }
+        }
+
+        if let Some(severity) = document.get("severity").filter(|s| !s.is_empty()) {
+            if !self.taxonomy.severities.iter().any(|s| s.id == severity) {
+                issues.push(issue(
+                    relative,
+                    frontmatter_line(content, "severity"),
+                    IssueLevel::Error,
+                    "invalid-severity",
+                    format!("Unknown severity: {}", severity),
+                ));
+            }
+        }
+
+        if let Some(id) = document.get("id") {
+            if !is_valid_runbook_id(id) {
+                issues.push(issue(
+                    relative,
+                    frontmatter_line(content, "id"),
+                    IssueLevel::Error,
+                    "invalid-id",
+                    format!("Invalid runbook ID: {}", id),
+                ));
+            }
+        }
+
+        issues
+    }
+
+    /// 必須セクションの有無を検証
+    fn check_sections(
+        relative: &str,
+        content: &str,
+        document: &RunbookDocument,
+    ) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+
+        if document.title.is_none() {
+            issues.push(issue(
+                relative,
+                None,
+                IssueLevel::Warning,
+                "title-heading-missing",
+                "Missing '# ' title heading".to_string(),
+            ));
+        }
+
+        for heading in REQUIRED_SECTIONS {
+            match document.section(heading) {
+                None => issues.push(issue(
+                    relative,
+                    None,
+                    IssueLevel::Error,
+                    "section-missing",
+                    format!("Required section '## {}' is missing", heading),
+                )),
+                Some(section)
+                    if section.body.trim().is_empty() && NON_EMPTY_SECTIONS.contains(&heading) =>
+                {
+                    issues.push(issue(
+                        relative,
+                        heading_line(content, heading),
+                        IssueLevel::Warning,
+                        "section-empty",
+                        format!("Section '## {}' is empty", heading),
+                    ))
+                }
+                Some(_) => {}
+            }
+        }
+
+        issues
+    }
+
+    /// リポジトリ内リンク・画像の参照先が存在するかを検証
+    fn check_links(path: &Path, relative: &str, content: &str) -> Vec<ValidationIssue> {
+        let base_dir = path.parent().unwrap_or(Path::new("."));
+        let mut issues = Vec::new();
+
+        for (line, link) in Self::local_links(content) {
+            let Some(target) = local_target(&link.target) else {
+                continue;
+            };
+            if base_dir.join(&target).exists() {
+                continue;
+            }
+
+            let (rule, kind) = if link.image {
+                ("broken-image", "Image")
+            } else {
+                ("broken-link", "Link")
+            };
+            issues.push(issue(
+                relative,
+                Some(line),
+                IssueLevel::Error,
+                rule,
+                format!("{} target not found: {}", kind, link.target),
+            ));
+        }
+
+        issues
+    }
+
+    /// RunbookIDの重複と、`references`・`[[id]]` の参照先を検証
+    fn check_ids(documents: &[(String, String, RunbookDocument)]) -> Vec<ValidationIssue> {
+        let mut issues = Vec::new();
+        let mut owners: BTreeMap<&str, &str> = BTreeMap::new();
+
+        for (relative, content, document) in documents {
+            let Some(id) = document.get("id") else {
+                continue;
+            };
+            if let Some(owner) = owners.get(id) {
+                issues.push(issue(
+                    relative,
+                    frontmatter_line(content, "id"),
+                    IssueLevel::Error,
+                    "duplicate-id",
+                    format!("Runbook ID '{}' is already used by {}", id, owner),
+                ));
+            } else {
+                owners.insert(id, relative);
+            }
+        }
+
+        for (relative, content, document) in documents {
+            let mut references: BTreeSet<String> =
+                document.get_list("references").into_iter().collect();
+            references.extend(RunbookParser::wiki_links(content));
+
+            for reference in references {
+                if !owners.contains_key(reference.as_str()) {
+                    issues.push(issue(
+                        relative,
+                        None,
+                        IssueLevel::Warning,
+                        "unresolved-reference",
+                        format!("Referenced runbook not found: {}", reference),
+                    ));
+                }
+            }
+        }
+
+        issues
+    }
+
+    /// 本文中のMarkdownリンク・画像を行番号付きで抽出
+    ///
+    /// フロントマター・コードブロック・インラインコードは対象外です。
+    fn local_links(content: &str) -> Vec<(usize, MarkdownLink)> {
+        let mut links = Vec::new();
+        let mut in_frontmatter = false;
+        let mut in_code_block = false;
+
+        for (index, line) in content.lines().enumerate() {
+            let trimmed = line.trim();
+            if index == 0 && trimmed.trim_start_matches('\u{feff}') == "---" {
+                in_frontmatter = true;
+                continue;
+            }
+            if in_frontmatter {
+                in_frontmatter = trimmed != "---";
+                continue;
+            }
+            if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+                in_code_block = !in_code_block;
+                continue;
+            }
+            if in_code_block {
+                continue;
+            }
+
+            let line = strip_inline_code(line);
+            links.extend(parse_links(&line).into_iter().map(|link| (index + 1, link)));
+        }
+
+        links
+    }
+}
+
+/// Markdownのリンク
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct MarkdownLink {
+    target: String,
+    image: bool,
+}
+
+/// 1行からインラインリンク `[text](target)`・画像 `![alt](src)`・参照定義 `[id]: target` を抽出
+fn parse_links(line: &str) -> Vec<MarkdownLink> {
+    let mut links = Vec::new();
+
+    // 参照定義
+    if let Some(rest) = line.trim_start().strip_prefix('[') {
+        if let Some((label, target)) = rest.split_once("]:") {
+            if !label.is_empty() && !label.starts_with('[') {
+                if let Some(target) = target.split_whitespace().next() {
+                    links.push(MarkdownLink {
+                        target: target.trim_matches(['<', '>']).to_string(),
+                        image: false,
+                    });
+                }
+            }
+            return links;
+        }
+    }
+
+    let mut offset = 0;
+    while let Some(position) = line[offset..].find("](") {
+        let start = offset + position;
+        let image = line[..start]
+            .rfind('[')
+            .is_some_and(|open| line[..open].ends_with('!'));
+
+        let rest = &line[start + 2..];
+        let target = if let Some(inner) = rest.strip_prefix('<') {
+            inner.split('>').next().unwrap_or_default().to_string()
+        } else {
+            let mut depth = 0;
+            let end = rest
+                .char_indices()
+                .find(|&(_, c)| match c {
+                    '(' => {
+                        depth += 1;
+                        false
+                    }
+                    ')' if depth == 0 => true,
+                    ')' => {
+                        depth -= 1;
+                        false
+                    }
+                    c => c.is_whitespace(),
+                })
+                .map(|(i, _)| i)
+                .unwrap_or(rest.len());
+            rest[..end].to_string()
+        };
+
+        links.push(MarkdownLink { target, image });
+        offset = start + 2;
+    }
+
+    links
+}
+
+/// リポジトリ内の相対パスを指すリンクであれば、アンカー等を除いたパスを返す
+///
+/// 外部URL・ページ内アンカー・ルート相対パス（`/` 始まり）は検証対象外です。
+fn local_target(target: &str) -> Option<String> {
+    let target = target.trim();
+    if target.is_empty()
+        || target.starts_with('#')
+        || target.starts_with('/')
+        || target.contains("://")
+        || target.starts_with("mailto:")
+        || target.starts_with("tel:")
+        || target.starts_with("data:")
+    {
+        return None;
+    }
+
+    let path = target.split(['#', '?']).next().unwrap_or_default();
+    if path.is_empty() {
+        return None;
+    }
+    Some(percent_decode(path))
+}
+
+/// `%XX` 形式のエスケープを解除（不正なシーケンスはそのまま残す）
+fn percent_decode(value: &str) -> String {
+    let bytes = value.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+
+    while i < bytes.len() {
+        if bytes[i] == b'%' && i + 2 < bytes.len() {
+            if let Some(byte) = std::str::from_utf8(&bytes[i + 1..i + 3])
+                .ok()
+                .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+            {
+                decoded.push(byte);
+                i += 3;
+                continue;
+            }
+        }
+        decoded.push(bytes[i]);
+        i += 1;
+    }
+
+    String::from_utf8(decoded).unwrap_or_else(|_| value.to_string())
+}
+
+/// インラインコード（`` `...` ``）を除去
+fn strip_inline_code(line: &str) -> String {
+    let mut result = String::new();
+    let mut in_code = false;
+    for c in line.chars() {
+        if c == '`' {
+            in_code = !in_code;
+        } else if !in_code {
+            result.push(c);
+        }
+    }
+    result
+}
+
+/// フロントマター内のキーの行番号を取得
+fn frontmatter_line(content: &str, key: &str) -> Option<usize> {
+    content
+        .lines()
+        .enumerate()
+        .skip(1)
+        .take_while(|(_, line)| line.trim() != "---")
+        .find(|(_, line)| {
+            line.split_once(':')
+                .is_some_and(|(k, _)| !k.starts_with(' ') && k.trim() == key)
+        })
+        .map(|(index, _)| index + 1)
+}
+
+/// `## ` 見出しの行番号を取得
+fn heading_line(content: &str, heading: &str) -> Option<usize> {
+    content
+        .lines()
+        .position(|line| line.strip_prefix("## ").map(str::trim) == Some(heading))
+        .map(|index| index + 1)
+}
+
+fn issue(
+    path: &str,
+    line: Option<usize>,
+    level: IssueLevel,
+    rule: &str,
+    message: String,
+) -> ValidationIssue {
+    ValidationIssue {
+        path: path.to_string(),
+        line,
+        level,
+        rule: rule.to_string(),
+        message,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Category, CategoryDefinition, KnowledgeInput, Severity};
+    use crate::services::FileGenerator;
+    use std::env;
+    use std::path::PathBuf;
+
+    fn setup(name: &str) -> PathBuf {
+        let dir = env::temp_dir().join(format!("worknote_validator_{}", name));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("alerts")).unwrap();
+        fs::create_dir_all(dir.join("ops")).unwrap();
+        dir
+    }
+
+    fn generated(id: &str) -> String {
+        let input = KnowledgeInput {
+            id: Some(id.to_string()),
+            title: "CPU高騰対応".to_string(),
+            category: Category::Alerts,
+            severity: Severity::High,
+            symptoms: "CPU使用率が90%を超える".to_string(),
+            procedure: "1. topで確認".to_string(),
+            notes: None,
+            related_links: None,
+            judgment: None,
+            slug: None,
+            related_alerts: Vec::new(),
+        };
+        FileGenerator::generate_markdown_for_preview(&input)
+    }
+
+    fn rules(report: &ValidationReport, path: &str) -> Vec<String> {
+        report
+            .issues
+            .iter()
+            .filter(|i| i.path == path)
+            .map(|i| i.rule.clone())
+            .collect()
+    }
+
+    #[test]
+    fn test_generated_runbook_is_valid() {
+        let dir = setup("valid");
+        fs::write(dir.join("alerts/cpu.md"), generated("rb-00000001")).unwrap();
+
+        let report = RunbookValidator::new(&dir).validate_all().unwrap();
+        assert_eq!(report.files_checked, 1);
+        assert!(report.issues.is_empty(), "{:?}", report.issues);
+    }
+
+    #[test]
+    fn test_frontmatter_and_sections() {
+        let dir = setup("frontmatter");
+        fs::write(
+            dir.join("ops/wrong.md"),
+            generated("rb-00000002").replace("severity: high", "severity: urgent"),
+        )
+        .unwrap();
+        fs::write(dir.join("alerts/no-frontmatter.md"), "# タイトル\n\n本文\n").unwrap();
+        fs::write(dir.join("alerts/broken.md"), "---\ntitle: x\n").unwrap();
+
+        let report = RunbookValidator::new(&dir).validate_all().unwrap();
+        let wrong = rules(&report, "ops/wrong.md");
+        assert!(wrong.contains(&"category-mismatch".to_string()));
+        assert!(wrong.contains(&"invalid-severity".to_string()));
+
+        let missing = rules(&report, "alerts/no-frontmatter.md");
+        assert!(missing.contains(&"frontmatter-missing".to_string()));
+        assert!(missing.contains(&"section-missing".to_string()));
+
+        assert!(rules(&report, "alerts/broken.md").contains(&"frontmatter-parse".to_string()));
+        assert!(report.has_errors());
+
+        // カテゴリ不一致は行番号付きで報告される
+        let issue = report
+            .issues
+            .iter()
+            .find(|i| i.rule == "category-mismatch")
+            .unwrap();
+        assert_eq!(issue.line, Some(4));
+    }
+
+    #[test]
+    fn test_taxonomy() {
+        let dir = setup("taxonomy");
+        fs::create_dir_all(dir.join("sec")).unwrap();
+        fs::create_dir_all(dir.join("maintenance")).unwrap();
+        let security = generated("rb-00000003").replace("category: alerts", "category: security");
+        fs::write(dir.join("sec/leak.md"), &security).unwrap();
+        fs::write(
+            dir.join("maintenance/old.md"),
+            generated("rb-00000004").replace("category: alerts", "category: maintenance"),
+        )
+        .unwrap();
+
+        // 組み込みの定義では security は未定義
+        let report = RunbookValidator::new(&dir).validate_all().unwrap();
+        assert!(rules(&report, "sec/leak.md").contains(&"invalid-category".to_string()));
+        let legacy = report
+            .issues
+            .iter()
+            .find(|i| i.path == "maintenance/old.md" && i.rule == "invalid-category")
+            .unwrap();
+        assert!(legacy.message.contains("'ops'"));
+
+        let mut taxonomy = TaxonomyConfig::default();
+        taxonomy.categories.push(CategoryDefinition {
+            id: "security".to_string(),
+            label: "セキュリティ".to_string(),
+            directory: "sec".to_string(),
+            icon: String::new(),
+            judgment_fields: Vec::new(),
+            template: None,
+        });
+        let report = RunbookValidator::new(&dir)
+            .with_taxonomy(taxonomy)
+            .validate_all()
+            .unwrap();
+        assert!(rules(&report, "sec/leak.md").is_empty());
+    }
+
+    #[test]
+    fn test_links_and_references() {
+        let dir = setup("links");
+        fs::create_dir_all(dir.join("alerts/images")).unwrap();
+        fs::write(dir.join("alerts/images/graph.png"), "png").unwrap();
+        fs::write(
+            dir.join("ops/restart.md"),
+            generated("rb-00000003").replace("alerts", "ops"),
+        )
+        .unwrap();
+
+        let content = generated("rb-00000004").replace(
+            "## 関連リンク\n\n",
+            "## 関連リンク\n\n\
+             - [再起動](../ops/restart.md#手順)\n\
+             - [存在しない](../ops/missing.md)\n\
+             - ![グラフ](images/graph.png) ![なし](images/none%20file.png)\n\
+             - [外部](https://example.com) [アンカー](#概要) `[コード](nope.md)`\n\
+             - [[rb-00000003]] [[rb-deadbeef]]\n\n\
+             ```\n[コードブロック](nope.md)\n```\n",
+        );
+        fs::write(dir.join("alerts/cpu.md"), content).unwrap();
+
+        let report = RunbookValidator::new(&dir).validate_all().unwrap();
+        let issues: Vec<&ValidationIssue> = report
+            .issues
+            .iter()
+            .filter(|i| i.path == "alerts/cpu.md")
+            .collect();
+        let messages: Vec<&str> = issues.iter().map(|i| i.message.as_str()).collect();
+
+        assert_eq!(issues.len(), 3, "{:?}", messages);
+        assert!(issues
+            .iter()
+            .any(|i| i.rule == "broken-link" && i.message.contains("missing.md")));
+        assert!(issues
+            .iter()
+            .any(|i| i.rule == "broken-image" && i.message.contains("none%20file.png")));
+        assert!(issues.iter().any(|i| i.rule == "unresolved-reference"
+            && i.message.contains("rb-deadbeef")
+            && i.level == IssueLevel::Warning));
+    }
+
+    #[test]
+    fn test_duplicate_id() {
+        let dir = setup("duplicate");
+        fs::write(dir.join("alerts/a.md"), generated("rb-00000005")).unwrap();
+        fs::write(dir.join("alerts/b.md"), generated("rb-00000005")).unwrap();
+
+        let report = RunbookValidator::new(&dir).validate_all().unwrap();
+        assert_eq!(rules(&report, "alerts/b.md"), vec!["duplicate-id"]);
+    }
+
+    #[test]
+    fn test_report_formats() {
+        let report = ValidationReport {
+            files_checked: 2,
+            issues: vec![issue(
+                "alerts/cpu.md",
+                Some(12),
+                IssueLevel::Error,
+                "broken-link",
+                "Link target not found: x.md".to_string(),
+            )],
+        };
+
+        assert_eq!(
+            report.to_text(),
+            "alerts/cpu.md:12: error[broken-link] Link target not found: x.md\n\
+             2 files checked, 1 errors, 0 warnings\n"
+        );
+
+        let sarif = report.to_sarif("docs/runbooks/");
+        assert_eq!(sarif["version"], "2.1.0");
+        let result = &sarif["runs"][0]["results"][0];
+        assert_eq!(result["ruleId"], "broken-link");
+        assert_eq!(
+            result["locations"][0]["physicalLocation"]["artifactLocation"]["uri"],
+            "docs/runbooks/alerts/cpu.md"
+        );
+        assert_eq!(
+            result["locations"][0]["physicalLocation"]["region"]["startLine"],
+            12
+        );
+    }
+}
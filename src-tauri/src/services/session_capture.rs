@@ -0,0 +1,1085 @@
+use crate::models::{
+    CaptureOptions, CaptureSource, Category, Draft, KnowledgeInput, Result, Severity, WorkNoteError,
+};
+use crate::services::html_converter::decode_entities;
+use crate::services::DraftManager;
+use chrono::{DateTime, Local, NaiveDateTime, TimeZone, Utc};
+use serde_json::Value;
+use std::path::PathBuf;
+
+/// 単独では記録する価値のないコマンド
+const NOISE_COMMANDS: [&str; 12] = [
+    "ls", "ll", "la", "l", "pwd", "clear", "history", "exit", "logout", "reset", "cd", "cd ..",
+];
+
+/// 相槌のみのメッセージ（小文字で比較）
+const ACK_MESSAGES: [&str; 16] = [
+    "ok",
+    "okay",
+    "thx",
+    "thanks",
+    "ty",
+    "+1",
+    "lgtm",
+    "ありがとう",
+    "ありがとうございます",
+    "了解",
+    "了解です",
+    "承知しました",
+    "見ます",
+    "確認します",
+    "👍",
+    "🙏",
+];
+
+/// 記録しないSlackのメッセージ種別
+const NOISE_SUBTYPES: [&str; 8] = [
+    "channel_join",
+    "channel_leave",
+    "channel_topic",
+    "channel_purpose",
+    "channel_name",
+    "bot_add",
+    "pinned_item",
+    "reminder_add",
+];
+
+/// 値を伏せるオプション名・変数名に含まれる語
+const SECRET_KEYWORDS: [&str; 6] = ["password", "passwd", "token", "secret", "apikey", "api_key"];
+
+/// `Authorization` ヘッダーの認証方式（値の前に置かれるため伏せない）
+const AUTH_SCHEMES: [&str; 4] = ["bearer", "token", "basic", "digest"];
+
+/// `-p<password>` の形式でパスワードを受け取るコマンド
+const PASSWORD_FLAG_COMMANDS: [&str; 5] = [
+    "mysql",
+    "mysqldump",
+    "mysqladmin",
+    "mariadb",
+    "mariadb-dump",
+];
+
+/// タイトルの最大文字数（自動生成時）
+const GENERATED_TITLE_MAX_CHARS: usize = 60;
+
+/// 取り込んだ記録の1項目
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CaptureEntry {
+    /// チャットのメッセージ
+    Message {
+        time: Option<DateTime<Utc>>,
+        author: String,
+        text: String,
+    },
+    /// 実行されたコマンド（Slackのコードブロックは複数行）
+    Command {
+        time: Option<DateTime<Utc>>,
+        command: String,
+    },
+}
+
+impl CaptureEntry {
+    fn time(&self) -> Option<DateTime<Utc>> {
+        match self {
+            CaptureEntry::Message { time, .. } | CaptureEntry::Command { time, .. } => *time,
+        }
+    }
+}
+
+/// 取り込んだ記録
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CapturedSession {
+    /// 録画ファイル等に記録されたタイトル
+    pub title: Option<String>,
+    /// 記録の開始時刻（各項目の時刻が不明な場合に使用）
+    pub started_at: Option<DateTime<Utc>>,
+    pub entries: Vec<CaptureEntry>,
+    /// ノイズとして除外した項目数
+    pub filtered: usize,
+}
+
+impl CapturedSession {
+    fn push_command(&mut self, time: Option<DateTime<Utc>>, command: &str) {
+        let command = command.trim();
+        if command.is_empty() {
+            return;
+        }
+        let is_repeat = matches!(self.entries.last(),
+            Some(CaptureEntry::Command { command: last, .. }) if last == command);
+        if is_repeat || NOISE_COMMANDS.contains(&command) {
+            self.filtered += 1;
+            return;
+        }
+        self.entries.push(CaptureEntry::Command {
+            time,
+            command: redact_secrets(command),
+        });
+    }
+}
+
+/// SessionCapture - Slackスレッドや端末操作の記録からRunbookの下書きを作成
+///
+/// コマンドは対応手順のコードブロックに、時刻はそのまま残し、相槌や `ls` などのノイズは除外します。
+pub struct SessionCapture;
+
+impl SessionCapture {
+    /// 記録を解析して下書きを作成
+    ///
+    /// # Arguments
+    /// * `app_data_dir` - アプリケーションデータディレクトリ（下書きの保存先）
+    /// * `content` - 取り込むファイルの内容
+    /// * `options` - 取り込みのオプション
+    pub fn capture(
+        app_data_dir: PathBuf,
+        content: &str,
+        options: &CaptureOptions,
+    ) -> Result<Draft> {
+        let session = Self::parse(options.source, content)?;
+        if session.entries.is_empty() {
+            return Err(WorkNoteError::ValidationError(format!(
+                "No messages or commands found in the {}",
+                options.source.label()
+            )));
+        }
+
+        let input = Self::build_input(&session, options);
+        DraftManager::new(app_data_dir).create_draft(input)
+    }
+
+    /// 取り込み元の形式に応じて解析
+    pub fn parse(source: CaptureSource, content: &str) -> Result<CapturedSession> {
+        match source {
+            CaptureSource::Slack => Self::parse_slack(content),
+            CaptureSource::Asciinema => Self::parse_asciinema(content),
+            CaptureSource::Script => Ok(Self::parse_script(content)),
+            CaptureSource::History => Ok(Self::parse_history(content)),
+        }
+    }
+
+    /// Slackのエクスポート（メッセージ配列、または `messages` を持つオブジェクト）を解析
+    pub fn parse_slack(content: &str) -> Result<CapturedSession> {
+        let value: Value = serde_json::from_str(content).map_err(|e| {
+            WorkNoteError::ValidationError(format!("Invalid Slack export JSON: {}", e))
+        })?;
+        let messages = value
+            .as_array()
+            .or_else(|| value.get("messages").and_then(Value::as_array))
+            .ok_or_else(|| {
+                WorkNoteError::ValidationError(
+                    "Slack export must be an array of messages".to_string(),
+                )
+            })?;
+
+        let mut messages: Vec<&Value> = messages.iter().collect();
+        messages.sort_by(|a, b| {
+            let ts = |m: &Value| m.get("ts").and_then(Value::as_str).and_then(parse_slack_ts);
+            ts(a).cmp(&ts(b))
+        });
+
+        let mut session = CapturedSession::default();
+        for message in messages {
+            let subtype = message.get("subtype").and_then(Value::as_str);
+            if subtype.is_some_and(|s| NOISE_SUBTYPES.contains(&s)) {
+                session.filtered += 1;
+                continue;
+            }
+
+            let time = message
+                .get("ts")
+                .and_then(Value::as_str)
+                .and_then(parse_slack_ts);
+            let author = slack_author(message);
+            let text = clean_slack_text(message.get("text").and_then(Value::as_str).unwrap_or(""));
+
+            let (text, code_blocks) = split_code_blocks(&text);
+            if is_noise_message(&text) && code_blocks.is_empty() {
+                session.filtered += 1;
+                continue;
+            }
+
+            if session.started_at.is_none() {
+                session.started_at = time;
+            }
+            if !text.trim().is_empty() {
+                session.entries.push(CaptureEntry::Message {
+                    time,
+                    author,
+                    text: redact_secrets(text.trim()),
+                });
+            }
+            for block in code_blocks {
+                let command = block
+                    .lines()
+                    .map(|line| line.strip_prefix("$ ").unwrap_or(line))
+                    .collect::<Vec<_>>()
+                    .join("\n");
+                session.push_command(time, &command);
+            }
+        }
+
+        Ok(session)
+    }
+
+    /// asciinemaの録画（v2: 絶対時刻、v3: 前イベントからの経過時間）を解析
+    pub fn parse_asciinema(content: &str) -> Result<CapturedSession> {
+        let mut lines = content.lines().filter(|line| !line.trim().is_empty());
+        let header: Value = lines
+            .next()
+            .and_then(|line| serde_json::from_str(line).ok())
+            .filter(|header: &Value| header.get("version").is_some())
+            .ok_or_else(|| {
+                WorkNoteError::ValidationError("Invalid asciinema header".to_string())
+            })?;
+
+        let version = header.get("version").and_then(Value::as_u64).unwrap_or(2);
+        let started_at = header
+            .get("timestamp")
+            .and_then(Value::as_i64)
+            .and_then(|ts| Utc.timestamp_opt(ts, 0).single());
+
+        let mut terminal = TerminalBuffer::default();
+        let mut elapsed = 0.0;
+        for line in lines {
+            let Ok(Value::Array(event)) = serde_json::from_str::<Value>(line) else {
+                continue;
+            };
+            let (Some(time), Some(kind), Some(data)) = (
+                event.first().and_then(Value::as_f64),
+                event.get(1).and_then(Value::as_str),
+                event.get(2).and_then(Value::as_str),
+            ) else {
+                continue;
+            };
+
+            elapsed = if version >= 3 { elapsed + time } else { time };
+            if kind == "o" {
+                let time = started_at
+                    .map(|start| start + chrono::Duration::milliseconds((elapsed * 1000.0) as i64));
+                terminal.feed(data, time);
+            }
+        }
+
+        let mut session = Self::session_from_terminal(terminal.finish());
+        session.started_at = started_at;
+        session.title = header
+            .get("title")
+            .and_then(Value::as_str)
+            .map(str::to_string);
+        Ok(session)
+    }
+
+    /// `script` コマンドの記録を解析（時刻は開始時刻のみ）
+    pub fn parse_script(content: &str) -> CapturedSession {
+        let mut started_at = None;
+        let mut body = content;
+
+        if let Some(first) = content.lines().next() {
+            if let Some(header) = first.strip_prefix("Script started on ") {
+                started_at = parse_script_time(header);
+                body = &content[first.len()..];
+            }
+        }
+
+        let mut terminal = TerminalBuffer::default();
+        terminal.feed(body, None);
+        let lines: Vec<(String, Option<DateTime<Utc>>)> = terminal
+            .finish()
+            .into_iter()
+            .filter(|(line, _)| !line.starts_with("Script done on "))
+            .collect();
+
+        let mut session = Self::session_from_terminal(lines);
+        session.started_at = started_at;
+        session
+    }
+
+    /// シェル履歴を解析
+    ///
+    /// zshの拡張形式（`: 時刻:0;コマンド`）、bashの `#時刻` 行、fishの履歴ファイル、
+    /// `history` コマンドの出力（番号・HISTTIMEFORMAT付き）、単純なコマンドの列に対応します。
+    pub fn parse_history(content: &str) -> CapturedSession {
+        let mut session = CapturedSession::default();
+        let mut pending_time: Option<DateTime<Utc>> = None;
+        let mut lines = content.lines().peekable();
+
+        while let Some(line) = lines.next() {
+            let trimmed = line.trim();
+            if trimmed.is_empty() {
+                continue;
+            }
+
+            // bash: HISTTIMEFORMAT設定時の時刻行
+            if let Some(ts) = trimmed
+                .strip_prefix('#')
+                .filter(|ts| !ts.is_empty() && ts.chars().all(|c| c.is_ascii_digit()))
+            {
+                pending_time = ts
+                    .parse()
+                    .ok()
+                    .and_then(|ts| Utc.timestamp_opt(ts, 0).single());
+                continue;
+            }
+
+            // zsh: 拡張履歴形式
+            if let Some((meta, command)) = trimmed
+                .strip_prefix(": ")
+                .and_then(|rest| rest.split_once(';'))
+            {
+                let time = meta
+                    .split(':')
+                    .next()
+                    .and_then(|ts| ts.trim().parse().ok())
+                    .and_then(|ts| Utc.timestamp_opt(ts, 0).single());
+                session.push_command(time, command);
+                continue;
+            }
+
+            // fish: `- cmd: ...` と `  when: ...`
+            if let Some(command) = trimmed.strip_prefix("- cmd: ") {
+                let time = lines
+                    .peek()
+                    .and_then(|next| next.trim().strip_prefix("when: "))
+                    .and_then(|ts| ts.trim().parse().ok())
+                    .and_then(|ts| Utc.timestamp_opt(ts, 0).single());
+                session.push_command(time, &command.replace("\\n", "\n"));
+                continue;
+            }
+            if trimmed.starts_with("when: ") || trimmed.starts_with("paths:") {
+                continue;
+            }
+
+            // `history` の出力: 番号（と時刻）付き
+            let (time, command) = match split_history_number(trimmed) {
+                Some(rest) => parse_history_time(rest),
+                None => (None, trimmed),
+            };
+            session.push_command(time.or(pending_time.take()), command);
+        }
+
+        session.started_at = session.entries.first().and_then(CaptureEntry::time);
+        session
+    }
+
+    /// 記録からナレッジ入力データを生成
+    pub fn build_input(session: &CapturedSession, options: &CaptureOptions) -> KnowledgeInput {
+        let first_message = session.entries.iter().find_map(|entry| match entry {
+            CaptureEntry::Message { text, .. } => Some(text.clone()),
+            _ => None,
+        });
+
+        let title = options
+            .title
+            .clone()
+            .filter(|t| !t.trim().is_empty())
+            .or_else(|| session.title.clone())
+            .or_else(|| {
+                first_message
+                    .as_deref()
+                    .and_then(|m| m.lines().next())
+                    .map(|line| truncate(line.trim(), GENERATED_TITLE_MAX_CHARS))
+            })
+            .unwrap_or_else(|| match session.started_at {
+                Some(start) => format!(
+                    "{} {}",
+                    options.source.label(),
+                    start.with_timezone(&Local).format("%Y-%m-%d %H:%M")
+                ),
+                None => options.source.label().to_string(),
+            });
+
+        KnowledgeInput {
+            id: None,
+            title,
+            category: options
+                .category
+                .clone()
+                .unwrap_or(Category::Troubleshooting),
+            severity: options.severity.clone().unwrap_or(Severity::Medium),
+            symptoms: first_message.unwrap_or_default(),
+            procedure: Self::render_procedure(session, options.source),
+            notes: None,
+            related_links: None,
+            judgment: None,
+            slug: None,
+            related_alerts: Vec::new(),
+        }
+    }
+
+    /// 対応手順を時系列で生成（連続するコマンドは1つのコードブロックにまとめる）
+    fn render_procedure(session: &CapturedSession, source: CaptureSource) -> String {
+        let mut out = String::new();
+
+        let times: Vec<DateTime<Utc>> = session
+            .entries
+            .iter()
+            .filter_map(CaptureEntry::time)
+            .collect();
+        let first = times.first().copied().or(session.started_at);
+        if let Some(first) = first {
+            let first = first.with_timezone(&Local);
+            let last = times.last().map(|t| t.with_timezone(&Local));
+            out.push_str(&format!("記録: {}", first.format("%Y-%m-%d %H:%M:%S")));
+            if let Some(last) = last.filter(|last| *last != first) {
+                out.push_str(&format!(" 〜 {}", last.format("%H:%M:%S")));
+            }
+            out.push_str(&format!("（{}）\n\n", source.label()));
+        }
+
+        // コマンドに含まれるバッククォートの連続より長いフェンスで囲む
+        let longest_run = session
+            .entries
+            .iter()
+            .filter_map(|entry| match entry {
+                CaptureEntry::Command { command, .. } => Some(longest_backtick_run(command)),
+                CaptureEntry::Message { .. } => None,
+            })
+            .max()
+            .unwrap_or(0);
+        let fence = "`".repeat(longest_run.max(2) + 1);
+
+        let mut in_block = false;
+        for entry in &session.entries {
+            match entry {
+                CaptureEntry::Message { time, author, text } => {
+                    if in_block {
+                        out.push_str(&format!("{}\n\n", fence));
+                        in_block = false;
+                    }
+                    let text = text.replace('\n', "\n  ");
+                    match time {
+                        Some(time) => out.push_str(&format!(
+                            "- **{}** {}: {}\n",
+                            time.with_timezone(&Local).format("%H:%M:%S"),
+                            author,
+                            text
+                        )),
+                        None => out.push_str(&format!("- {}: {}\n", author, text)),
+                    }
+                }
+                CaptureEntry::Command { time, command } => {
+                    if !in_block {
+                        if !out.is_empty() && !out.ends_with("\n\n") {
+                            out.push('\n');
+                        }
+                        out.push_str(&format!("{}sh\n", fence));
+                        in_block = true;
+                    }
+                    if let Some(time) = time {
+                        out.push_str(&format!(
+                            "# {}\n",
+                            time.with_timezone(&Local).format("%H:%M:%S")
+                        ));
+                    }
+                    out.push_str(command);
+                    out.push('\n');
+                }
+            }
+        }
+        if in_block {
+            out.push_str(&format!("{}\n", fence));
+        }
+
+        out.trim_end().to_string()
+    }
+
+    /// 端末出力の行からプロンプト付きの行をコマンドとして抽出
+    fn session_from_terminal(lines: Vec<(String, Option<DateTime<Utc>>)>) -> CapturedSession {
+        let mut session = CapturedSession::default();
+        for (line, time) in lines {
+            if let Some(command) = prompt_command(&line) {
+                session.push_command(time, command);
+            }
+        }
+        session
+    }
+}
+
+/// 端末出力を行に変換（制御シーケンス・復帰・バックスペースを処理）
+#[derive(Default)]
+struct TerminalBuffer {
+    lines: Vec<(String, Option<DateTime<Utc>>)>,
+    current: Vec<char>,
+    cursor: usize,
+    escape: EscapeState,
+    pending_cr: bool,
+}
+
+#[derive(Default, PartialEq, Eq)]
+enum EscapeState {
+    #[default]
+    None,
+    Escape,
+    Csi,
+    Osc,
+    OscEscape,
+}
+
+impl TerminalBuffer {
+    /// 出力を追加（`time` は行が確定した時刻として記録）
+    fn feed(&mut self, data: &str, time: Option<DateTime<Utc>>) {
+        for c in data.chars() {
+            match self.escape {
+                EscapeState::Escape => {
+                    self.escape = match c {
+                        '[' => EscapeState::Csi,
+                        ']' => EscapeState::Osc,
+                        _ => EscapeState::None,
+                    };
+                    continue;
+                }
+                EscapeState::Csi => {
+                    if ('@'..='~').contains(&c) {
+                        self.escape = EscapeState::None;
+                        // 行末までの消去
+                        if c == 'K' {
+                            self.current.truncate(self.cursor);
+                        }
+                    }
+                    continue;
+                }
+                EscapeState::Osc => {
+                    match c {
+                        '\u{7}' => self.escape = EscapeState::None,
+                        '\u{1b}' => self.escape = EscapeState::OscEscape,
+                        _ => {}
+                    }
+                    continue;
+                }
+                EscapeState::OscEscape => {
+                    self.escape = EscapeState::None;
+                    continue;
+                }
+                EscapeState::None => {}
+            }
+
+            if self.pending_cr && c != '\n' {
+                // 単独の復帰は行頭から上書き
+                self.cursor = 0;
+            }
+            self.pending_cr = false;
+
+            match c {
+                '\u{1b}' => self.escape = EscapeState::Escape,
+                '\n' => {
+                    let line: String = self.current.iter().collect();
+                    self.lines.push((line.trim_end().to_string(), time));
+                    self.current.clear();
+                    self.cursor = 0;
+                }
+                '\r' => self.pending_cr = true,
+                '\u{8}' => self.cursor = self.cursor.saturating_sub(1),
+                c if c.is_control() => {}
+                c => {
+                    if self.cursor < self.current.len() {
+                        self.current[self.cursor] = c;
+                    } else {
+                        self.current.push(c);
+                    }
+                    self.cursor += 1;
+                }
+            }
+        }
+    }
+
+    fn finish(mut self) -> Vec<(String, Option<DateTime<Utc>>)> {
+        if !self.current.is_empty() {
+            let time = self.lines.last().and_then(|(_, time)| *time);
+            let line: String = self.current.iter().collect();
+            self.lines.push((line.trim_end().to_string(), time));
+        }
+        self.lines
+    }
+}
+
+/// プロンプト付きの行からコマンド部分を取得
+///
+/// `user@host:~$ cmd`、`[user@host dir]# cmd`、`$ cmd`、`% cmd`、`❯ cmd` などの形式に対応します。
+fn prompt_command(line: &str) -> Option<&str> {
+    ["$ ", "# ", "% ", "❯ "]
+        .iter()
+        .filter_map(|marker| line.find(marker).map(|pos| (pos, *marker)))
+        .min_by_key(|(pos, _)| *pos)
+        .filter(|(pos, _)| {
+            let prefix = &line[..*pos];
+            prefix.is_empty()
+                || (prefix.len() <= 120
+                    && !prefix.starts_with(' ')
+                    && (prefix.contains('@')
+                        || prefix.contains(':')
+                        || prefix.ends_with([']', ')', '~', '/'])))
+        })
+        .map(|(pos, marker)| line[pos + marker.len()..].trim())
+        .filter(|command| !command.is_empty())
+}
+
+/// `history` 出力の行番号を除去（`  123  cmd`、`123* cmd`）
+fn split_history_number(line: &str) -> Option<&str> {
+    let digits = line.chars().take_while(char::is_ascii_digit).count();
+    if digits == 0 {
+        return None;
+    }
+    let rest = line[digits..].strip_prefix('*').unwrap_or(&line[digits..]);
+    rest.starts_with(char::is_whitespace)
+        .then(|| rest.trim_start())
+}
+
+/// HISTTIMEFORMAT（`%F %T `）付きの行から時刻を取得
+fn parse_history_time(rest: &str) -> (Option<DateTime<Utc>>, &str) {
+    if rest.len() > 20 && rest.is_char_boundary(19) {
+        if let Ok(time) = NaiveDateTime::parse_from_str(&rest[..19], "%Y-%m-%d %H:%M:%S") {
+            let time = Local
+                .from_local_datetime(&time)
+                .single()
+                .map(|t| t.with_timezone(&Utc));
+            return (time, rest[19..].trim_start());
+        }
+    }
+    (None, rest)
+}
+
+/// `script` のヘッダー（`2024-04-01 10:00:00+09:00 [...]`）から開始時刻を取得
+fn parse_script_time(header: &str) -> Option<DateTime<Utc>> {
+    let value = header.split(" [").next()?.trim();
+    DateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S%:z")
+        .or_else(|_| DateTime::parse_from_str(value, "%Y-%m-%d %H:%M:%S%z"))
+        .ok()
+        .map(|t| t.with_timezone(&Utc))
+}
+
+/// Slackのタイムスタンプ（`1712345678.000100`）を時刻に変換
+fn parse_slack_ts(ts: &str) -> Option<DateTime<Utc>> {
+    let (secs, micros) = ts.split_once('.').unwrap_or((ts, "0"));
+    let nanos = format!("{:0<9}", micros).get(..9)?.parse().ok()?;
+    Utc.timestamp_opt(secs.parse().ok()?, nanos).single()
+}
+
+/// メッセージの投稿者名を取得
+fn slack_author(message: &Value) -> String {
+    let profile = message.get("user_profile");
+    [
+        profile.and_then(|p| p.get("display_name")),
+        profile.and_then(|p| p.get("real_name")),
+        message.get("user_name"),
+        message.get("username"),
+        message.get("user"),
+        message.get("bot_id"),
+    ]
+    .into_iter()
+    .flatten()
+    .filter_map(Value::as_str)
+    .find(|name| !name.is_empty())
+    .unwrap_or("unknown")
+    .to_string()
+}
+
+/// Slackの書式（`<@U123|name>`、`<url|label>` など）をMarkdownに変換
+fn clean_slack_text(text: &str) -> String {
+    let mut result = String::new();
+    let mut rest = text;
+
+    while let Some(start) = rest.find('<') {
+        result.push_str(&rest[..start]);
+        let Some(end) = rest[start..].find('>') else {
+            rest = &rest[start..];
+            break;
+        };
+        let inner = &rest[start + 1..start + end];
+        let (target, label) = inner.split_once('|').unwrap_or((inner, ""));
+
+        let converted = if let Some(id) = target.strip_prefix('@') {
+            format!("@{}", if label.is_empty() { id } else { label })
+        } else if let Some(id) = target.strip_prefix('#') {
+            format!("#{}", if label.is_empty() { id } else { label })
+        } else if let Some(special) = target.strip_prefix('!') {
+            format!("@{}", special.split('^').next().unwrap_or(special))
+        } else if label.is_empty() {
+            target.to_string()
+        } else {
+            format!("[{}]({})", label, target)
+        };
+        result.push_str(&converted);
+        rest = &rest[start + end + 1..];
+    }
+    result.push_str(rest);
+
+    decode_entities(&result)
+}
+
+/// ``` で囲まれたコードブロックを本文から分離
+fn split_code_blocks(text: &str) -> (String, Vec<String>) {
+    let mut body = String::new();
+    let mut blocks = Vec::new();
+
+    for (index, part) in text.split("```").enumerate() {
+        if index % 2 == 1 {
+            let code = part.trim_matches('\n').trim();
+            if !code.is_empty() {
+                blocks.push(code.to_string());
+            }
+        } else {
+            body.push_str(part);
+        }
+    }
+
+    let body = body
+        .lines()
+        .map(str::trim_end)
+        .filter(|line| !line.trim().is_empty())
+        .collect::<Vec<_>>()
+        .join("\n");
+    (body, blocks)
+}
+
+/// 相槌・絵文字のみのメッセージか
+fn is_noise_message(text: &str) -> bool {
+    let text = text.trim();
+    if text.is_empty() || ACK_MESSAGES.contains(&text.to_lowercase().as_str()) {
+        return true;
+    }
+    // `:+1: :eyes:` のような絵文字のみ
+    text.split_whitespace().all(|word| {
+        word.len() > 2
+            && word.starts_with(':')
+            && word.ends_with(':')
+            && word[1..word.len() - 1]
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || "_+-".contains(c))
+    })
+}
+
+/// パスワード・トークン等の値を伏せる
+///
+/// `NAME=value`、`--name=value`、`--name value`、`Bearer value`、
+/// `Authorization: <scheme> value` などのヘッダー、MySQLクライアントの `-p<password>` が対象です。
+/// 複数行のコマンドやメッセージは行ごとに判定し、次の行まで伏せないようにします。
+fn redact_secrets(text: &str) -> String {
+    text.split('\n')
+        .map(redact_line)
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// 1行分のパスワード・トークン等の値を伏せる
+fn redact_line(command: &str) -> String {
+    let is_secret = |name: &str| {
+        let name = name.trim_start_matches('-').to_lowercase();
+        SECRET_KEYWORDS.iter().any(|k| name.contains(k))
+    };
+    // 閉じ引用符は残す（`-H "Authorization: Bearer xyz"` の `"`）
+    let mask = |word: &str| {
+        let value = word.trim_end_matches(['"', '\'']);
+        format!("****{}", &word[value.len()..])
+    };
+    let takes_password_flag = command
+        .split_whitespace()
+        .take_while(|word| !word.starts_with('-'))
+        .filter_map(|word| word.rsplit('/').next())
+        .any(|program| PASSWORD_FLAG_COMMANDS.contains(&program));
+
+    let mut result = Vec::new();
+    let mut mask_next = false;
+    let mut in_authorization = false;
+    for word in command.split(' ') {
+        let bare = word.trim_matches(['"', '\'']);
+        if mask_next && !word.is_empty() {
+            // `Authorization: Bearer value` の認証方式は残して、その次の値を伏せる
+            if in_authorization && AUTH_SCHEMES.contains(&bare.to_lowercase().as_str()) {
+                in_authorization = false;
+                result.push(word.to_string());
+                continue;
+            }
+            result.push(mask(word));
+            mask_next = false;
+            in_authorization = false;
+            continue;
+        }
+        match word.split_once('=') {
+            Some((name, value)) if !value.is_empty() && is_secret(name) => {
+                result.push(format!("{}=****", name));
+            }
+            _ if takes_password_flag && bare.len() > 2 && bare.starts_with("-p") => {
+                result.push(word.replacen(bare, "-p****", 1));
+            }
+            _ => {
+                let header = bare.strip_suffix(':');
+                in_authorization = header.is_some_and(|name| {
+                    name.eq_ignore_ascii_case("authorization")
+                        || name.eq_ignore_ascii_case("proxy-authorization")
+                });
+                mask_next = (bare.starts_with("--") && is_secret(bare))
+                    || bare == "Bearer"
+                    || in_authorization
+                    || header.is_some_and(is_secret);
+                result.push(word.to_string());
+            }
+        }
+    }
+    result.join(" ")
+}
+
+/// 連続するバッククォートの最大数
+fn longest_backtick_run(text: &str) -> usize {
+    text.split(|c| c != '`').map(str::len).max().unwrap_or(0)
+}
+
+fn truncate(text: &str, max_chars: usize) -> String {
+    if text.chars().count() > max_chars {
+        format!("{}…", text.chars().take(max_chars).collect::<String>())
+    } else {
+        text.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    fn local_time(ts: i64) -> String {
+        Utc.timestamp_opt(ts, 0)
+            .unwrap()
+            .with_timezone(&Local)
+            .format("%H:%M:%S")
+            .to_string()
+    }
+
+    fn commands(session: &CapturedSession) -> Vec<&str> {
+        session
+            .entries
+            .iter()
+            .filter_map(|entry| match entry {
+                CaptureEntry::Command { command, .. } => Some(command.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    const SLACK_THREAD: &str = r#"[
+        {"type": "message", "user": "U2", "user_profile": {"display_name": "bob"},
+         "text": "接続数を確認します\n```$ psql -c 'select count(*) from pg_stat_activity'```",
+         "ts": "1712000060.000200", "thread_ts": "1712000000.000100"},
+        {"type": "message", "user": "U1", "user_profile": {"display_name": "alice"},
+         "text": "&lt;!here&gt; 本番でDB接続エラーが多発 <@U2|bob> <https://grafana.example.com/d/1|ダッシュボード>",
+         "ts": "1712000000.000100"},
+        {"type": "message", "subtype": "channel_join", "user": "U3", "text": "<@U3> has joined", "ts": "1712000010.000000"},
+        {"type": "message", "user": "U3", "text": ":eyes: :+1:", "ts": "1712000020.000000"},
+        {"type": "message", "user": "U1", "user_profile": {"display_name": "alice"}, "text": "了解", "ts": "1712000070.000000"}
+    ]"#;
+
+    #[test]
+    fn test_parse_slack() {
+        let session = SessionCapture::parse_slack(SLACK_THREAD).unwrap();
+
+        assert_eq!(session.filtered, 3);
+        assert_eq!(session.entries.len(), 3);
+        assert_eq!(
+            session.entries[0],
+            CaptureEntry::Message {
+                time: parse_slack_ts("1712000000.000100"),
+                author: "alice".to_string(),
+                text: "<!here> 本番でDB接続エラーが多発 @bob [ダッシュボード](https://grafana.example.com/d/1)"
+                    .to_string(),
+            }
+        );
+        assert_eq!(
+            commands(&session),
+            vec!["psql -c 'select count(*) from pg_stat_activity'"]
+        );
+
+        // `{ "messages": [...] }` 形式も受け付ける
+        let wrapped = format!(r#"{{"ok": true, "messages": {}}}"#, SLACK_THREAD);
+        assert_eq!(SessionCapture::parse_slack(&wrapped).unwrap(), session);
+        assert!(SessionCapture::parse_slack("{}").is_err());
+
+        // メッセージ本文のパスワード・トークンも伏せる
+        let session = SessionCapture::parse_slack(
+            r#"[{"user": "U1", "text": "API_TOKEN=xoxb-123 で再実行\npassword: hunter2", "ts": "1712000000.000000"}]"#,
+        )
+        .unwrap();
+        assert!(matches!(
+            &session.entries[0],
+            CaptureEntry::Message { text, .. } if text == "API_TOKEN=**** で再実行\npassword: ****"
+        ));
+    }
+
+    #[test]
+    fn test_parse_asciinema() {
+        let cast = concat!(
+            r#"{"version": 2, "width": 80, "height": 24, "timestamp": 1712000000, "title": "DB復旧"}"#,
+            "\n",
+            r#"[0.5, "o", "\u001b]0;user@host: ~\u0007\u001b[01;32muser@host\u001b[00m:~$ "]"#,
+            "\n",
+            r#"[1.0, "o", "sudo systemctl restart app\r\n"]"#,
+            "\n",
+            r#"[1.2, "o", "Restarted.\r\nuser@host:~$ ls\r\nfile\r\n"]"#,
+            "\n",
+            r#"[5.0, "o", "user@host:~$ export DB_PASSWORD=hunter2\r\n"]"#,
+            "\n",
+            r#"[6.0, "i", "exit\r"]"#,
+            "\n",
+            r#"[6.5, "o", "user@host:~$ exit\r\n"]"#,
+            "\n"
+        );
+        let session = SessionCapture::parse_asciinema(cast).unwrap();
+
+        assert_eq!(session.title.as_deref(), Some("DB復旧"));
+        assert_eq!(
+            commands(&session),
+            vec!["sudo systemctl restart app", "export DB_PASSWORD=****"]
+        );
+        assert_eq!(session.filtered, 2);
+        assert_eq!(
+            session.entries[0].time(),
+            Utc.timestamp_opt(1712000001, 0).single()
+        );
+
+        // v3は前イベントからの経過時間
+        let cast = concat!(
+            r#"{"version": 3, "timestamp": 1712000000}"#,
+            "\n",
+            r#"[1.5, "o", "$ df -h\n"]"#,
+            "\n",
+            r#"[2.0, "o", "$ du -sh /var\n"]"#,
+            "\n"
+        );
+        let session = SessionCapture::parse_asciinema(cast).unwrap();
+        assert_eq!(
+            session.entries[1].time(),
+            Some(Utc.timestamp_opt(1712000003, 500_000_000).unwrap())
+        );
+
+        assert!(SessionCapture::parse_asciinema("not a cast").is_err());
+    }
+
+    #[test]
+    fn test_parse_script() {
+        let typescript = "Script started on 2024-04-01 10:00:00+09:00 [TERM=\"xterm\"]\n\
+                          [root@db01 ~]# journalctl -u postgresql --since to\u{8}\u{8}\"1 hour ago\"\r\n\
+                          -- Logs begin --\r\n\
+                          [root@db01 ~]# # comment only\r\n\
+                          [root@db01 ~]# \r\n\
+                          Script done on 2024-04-01 10:05:00+09:00 [COMMAND_EXIT_CODE=\"0\"]\n";
+        let session = SessionCapture::parse_script(typescript);
+
+        assert_eq!(
+            session.started_at,
+            Some(Utc.with_ymd_and_hms(2024, 4, 1, 1, 0, 0).unwrap())
+        );
+        assert_eq!(
+            commands(&session),
+            vec![
+                "journalctl -u postgresql --since \"1 hour ago\"",
+                "# comment only"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_history() {
+        let zsh = ": 1712000000:0;kubectl get pods -n app\n\
+                   : 1712000030:0;kubectl get pods -n app\n\
+                   : 1712000060:0;clear\n\
+                   : 1712000090:0;kubectl rollout restart deploy/app --token abc123\n";
+        let session = SessionCapture::parse_history(zsh);
+        assert_eq!(
+            commands(&session),
+            vec![
+                "kubectl get pods -n app",
+                "kubectl rollout restart deploy/app --token ****"
+            ]
+        );
+        assert_eq!(session.filtered, 2);
+        assert_eq!(
+            session.started_at,
+            Utc.timestamp_opt(1712000000, 0).single()
+        );
+
+        let bash =
+            "#1712000000\ncurl -H \"Authorization: Bearer xyz\" https://api\n#1712000100\ndf -h\n";
+        let session = SessionCapture::parse_history(bash);
+        assert_eq!(
+            commands(&session),
+            vec![
+                "curl -H \"Authorization: Bearer ****\" https://api",
+                "df -h"
+            ]
+        );
+        assert_eq!(
+            session.entries[1].time(),
+            Utc.timestamp_opt(1712000100, 0).single()
+        );
+
+        let fish =
+            "- cmd: systemctl status nginx\n  when: 1712000000\n- cmd: pwd\n  when: 1712000001\n";
+        let session = SessionCapture::parse_history(fish);
+        assert_eq!(commands(&session), vec!["systemctl status nginx"]);
+
+        let numbered = "  501  top\n  502* free -m\n  503  2024-04-01 10:00:00 vmstat 1\n";
+        let session = SessionCapture::parse_history(numbered);
+        assert_eq!(commands(&session), vec!["top", "free -m", "vmstat 1"]);
+        assert!(session.entries[2].time().is_some());
+    }
+
+    #[test]
+    fn test_redact_secrets() {
+        assert_eq!(
+            redact_secrets("mysql -u root -pS3cret -h db"),
+            "mysql -u root -p**** -h db"
+        );
+        // MySQLクライアント以外の `-p` は伏せない
+        assert_eq!(redact_secrets("mkdir -pv /tmp/a"), "mkdir -pv /tmp/a");
+        assert_eq!(
+            redact_secrets("curl -H \"Authorization: token ghp_abc\" https://api"),
+            "curl -H \"Authorization: token ****\" https://api"
+        );
+        assert_eq!(
+            redact_secrets("curl -H 'X-Api-Token: abc' https://api"),
+            "curl -H 'X-Api-Token: ****' https://api"
+        );
+        // 複数行は行ごとに伏せる（次の行の内容は残す）
+        assert_eq!(
+            redact_secrets("export DB_PASSWORD=s3cret\npsql -h db\nmysql -pS3cret"),
+            "export DB_PASSWORD=****\npsql -h db\nmysql -p****"
+        );
+    }
+
+    #[test]
+    fn test_render_procedure_fence() {
+        let session = SessionCapture::parse_history("echo '```' > note.md\n");
+        let procedure = SessionCapture::render_procedure(&session, CaptureSource::History);
+        assert!(procedure.starts_with("````sh\necho '```' > note.md\n````"));
+    }
+
+    #[test]
+    fn test_capture_creates_draft() {
+        let data_dir = env::temp_dir().join("worknote_capture_draft");
+        let _ = std::fs::remove_dir_all(&data_dir);
+
+        let mut options = CaptureOptions::new(CaptureSource::Slack);
+        options.severity = Some(Severity::High);
+        let draft = SessionCapture::capture(data_dir.clone(), SLACK_THREAD, &options).unwrap();
+        let input = &draft.data;
+
+        assert!(input.title.starts_with("<!here> 本番でDB接続エラーが多発"));
+        assert_eq!(input.category, Category::Troubleshooting);
+        assert_eq!(input.severity, Severity::High);
+        assert!(input.symptoms.contains("本番でDB接続エラーが多発"));
+        assert!(input.procedure.contains(&format!(
+            "- **{}** bob: 接続数を確認します\n\n```sh\n# {}\npsql -c",
+            local_time(1712000060),
+            local_time(1712000060)
+        )));
+        assert!(input.procedure.ends_with("```"));
+        assert_eq!(
+            DraftManager::new(data_dir.clone())
+                .list_drafts()
+                .unwrap()
+                .len(),
+            1
+        );
+
+        // コマンドもメッセージもない場合はエラー
+        let options = CaptureOptions::new(CaptureSource::History);
+        assert!(SessionCapture::capture(data_dir, "ls\nclear\n", &options).is_err());
+    }
+}
@@ -1,6 +1,25 @@
-use tauri::{AppHandle, Manager};
-use tauri_plugin_global_shortcut::{Code, GlobalShortcutExt, Modifiers, Shortcut};
 use crate::models::error::{Result, WorkNoteError};
+use crate::models::ShortcutAction;
+use crate::services::accelerator::Accelerator;
+use crate::services::ClipboardCapture;
+use serde::Serialize;
+use tauri::{AppHandle, Emitter, Manager};
+use tauri_plugin_global_shortcut::{GlobalShortcutExt, Shortcut, ShortcutState};
+
+/// Event sent to the main window when a shortcut action needs the frontend
+pub const SHORTCUT_ACTION_EVENT: &str = "shortcut-action";
+
+/// Payload of [`SHORTCUT_ACTION_EVENT`]
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ShortcutActionEvent {
+    pub action: ShortcutAction,
+    /// Draft created by `capture-clipboard`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub draft_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
 
 pub struct ShortcutManager {
     app: AppHandle,
@@ -12,64 +31,95 @@ impl ShortcutManager {
     }
 
     /// Parse shortcut string into Shortcut struct
-    /// Supports formats like "CommandOrControl+J", "Super+J", etc.
-    fn parse_shortcut(shortcut_str: &str) -> Result<Shortcut> {
-        let parts: Vec<&str> = shortcut_str.split('+').collect();
-        if parts.len() != 2 {
-            return Err(WorkNoteError::ShortcutError(
-                format!("Invalid shortcut format: {}. Expected format: 'Modifier+Key'", shortcut_str)
-            ));
-        }
-
-        let modifier = match parts[0].to_lowercase().as_str() {
-            "commandorcontrol" => {
-                // macOSではCommand (META)、Windows/LinuxではControl
-                #[cfg(target_os = "macos")]
-                { Some(Modifiers::META) }
-                #[cfg(not(target_os = "macos"))]
-                { Some(Modifiers::CONTROL) }
-            },
-            "cmd" => Some(Modifiers::META),  // macOS Command key
-            "super" => Some(Modifiers::SUPER),
-            "ctrl" | "control" => Some(Modifiers::CONTROL),
-            "alt" | "option" => Some(Modifiers::ALT),
-            "shift" => Some(Modifiers::SHIFT),
-            _ => return Err(WorkNoteError::ShortcutError(
-                format!("Unknown modifier: {}", parts[0])
-            )),
-        };
-
-        let code = match parts[1].to_uppercase().as_str() {
-            "J" => Code::KeyJ,
-            "K" => Code::KeyK,
-            "L" => Code::KeyL,
-            "N" => Code::KeyN,
-            "M" => Code::KeyM,
-            // Add more keys as needed
-            _ => return Err(WorkNoteError::ShortcutError(
-                format!("Unknown key code: {}", parts[1])
-            )),
-        };
-
-        Ok(Shortcut::new(modifier, code))
+    /// Supports formats like "CommandOrControl+Shift+K", "Alt+F5", "Super+/", etc.
+    pub fn parse_shortcut(shortcut_str: &str) -> Result<Shortcut> {
+        Ok(Accelerator::parse(shortcut_str)?.to_shortcut())
     }
 
-    /// Register a global shortcut and set up the event handler
-    pub fn register_shortcut(&self, shortcut_str: &str) -> Result<()> {
-        // Parse shortcut string (e.g., "CommandOrControl+J")
-        let shortcut = Self::parse_shortcut(shortcut_str)?;
-
-        // Register the shortcut
+    /// Register a global shortcut that runs the given action
+    pub fn register_binding(
+        &self,
+        action: ShortcutAction,
+        accelerator: &Accelerator,
+    ) -> Result<()> {
+        // on_shortcut registers the shortcut itself; calling register() first fails as already registered
         self.app
             .global_shortcut()
-            .register(shortcut)
-            .map_err(|e| WorkNoteError::ShortcutError(e.to_string()))?;
+            .on_shortcut(accelerator.to_shortcut(), move |app, _shortcut, event| {
+                // Run once per key press, not again on release
+                if event.state == ShortcutState::Pressed {
+                    Self::run_action(app, action);
+                }
+            })
+            .map_err(|e| {
+                WorkNoteError::ShortcutError(format!(
+                    "Failed to register {} for {}: {} (it may already be used by another application)",
+                    accelerator,
+                    action.label(),
+                    e
+                ))
+            })
+    }
 
-        // Set up event listener
-        let app = self.app.clone();
+    /// Unregister a global shortcut
+    pub fn unregister_binding(&self, accelerator: &Accelerator) -> Result<()> {
         self.app
             .global_shortcut()
-            .on_shortcut(shortcut, move |_app, _shortcut, _event| {
+            .unregister(accelerator.to_shortcut())
+            .map_err(|e| WorkNoteError::ShortcutError(e.to_string()))
+    }
+
+    /// Register each binding independently (used at startup, when nothing is registered yet)
+    ///
+    /// A shortcut taken by another app does not prevent the others from being registered.
+    /// Returns the bindings that were registered and the errors for the ones that were not.
+    pub fn register_each(
+        &self,
+        bindings: &[(ShortcutAction, Accelerator)],
+    ) -> (Vec<(ShortcutAction, Accelerator)>, Vec<WorkNoteError>) {
+        let mut registered = Vec::with_capacity(bindings.len());
+        let mut errors = Vec::new();
+        for (action, accelerator) in bindings {
+            match self.register_binding(*action, accelerator) {
+                Ok(()) => registered.push((*action, accelerator.clone())),
+                Err(e) => errors.push(e),
+            }
+        }
+        (registered, errors)
+    }
+
+    /// Replace the registered bindings with new ones (used when the settings change)
+    ///
+    /// If any of the new shortcuts cannot be registered (e.g. it is taken by another app),
+    /// the new ones registered so far are removed and the old bindings are registered again.
+    pub fn replace_bindings(
+        &self,
+        old: &[(ShortcutAction, Accelerator)],
+        new: &[(ShortcutAction, Accelerator)],
+    ) -> Result<()> {
+        for (_, accelerator) in old {
+            self.unregister_binding(accelerator)?;
+        }
+
+        for (index, (action, accelerator)) in new.iter().enumerate() {
+            if let Err(e) = self.register_binding(*action, accelerator) {
+                for (_, registered) in &new[..index] {
+                    let _ = self.unregister_binding(registered);
+                }
+                for (action, accelerator) in old {
+                    let _ = self.register_binding(*action, accelerator);
+                }
+                return Err(e);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Run the action bound to a shortcut
+    fn run_action(app: &AppHandle, action: ShortcutAction) {
+        match action {
+            ShortcutAction::QuickInput => {
                 // Toggle quick-input window visibility
                 if let Some(window) = app.get_webview_window("quick-input") {
                     let is_visible = window.is_visible().unwrap_or(false);
@@ -80,31 +130,82 @@ impl ShortcutManager {
                         let _ = window.set_focus();
                     }
                 }
-            })
-            .map_err(|e| WorkNoteError::ShortcutError(e.to_string()))?;
-
-        Ok(())
+            }
+            ShortcutAction::DetailWindow => Self::show_main_window(app),
+            ShortcutAction::SearchRunbooks => {
+                Self::show_main_window(app);
+                Self::emit_action(app, action, None, None);
+            }
+            ShortcutAction::CaptureClipboard => {
+                // Clipboard tools are external processes; keep them off the event loop
+                let app = app.clone();
+                std::thread::spawn(move || {
+                    let result = app
+                        .path()
+                        .app_data_dir()
+                        .map_err(|e| {
+                            WorkNoteError::FileError(format!("Failed to get app data dir: {}", e))
+                        })
+                        .and_then(ClipboardCapture::capture);
+                    Self::show_main_window(&app);
+                    match result {
+                        Ok(draft) => Self::emit_action(&app, action, Some(draft.id), None),
+                        Err(e) => Self::emit_action(&app, action, None, Some(e.to_string())),
+                    }
+                });
+            }
+        }
     }
 
-    /// Unregister the current shortcut
-    pub fn unregister_shortcut(&self, shortcut_str: &str) -> Result<()> {
-        let shortcut = Self::parse_shortcut(shortcut_str)?;
-
-        self.app
-            .global_shortcut()
-            .unregister(shortcut)
-            .map_err(|e| WorkNoteError::ShortcutError(e.to_string()))?;
+    fn show_main_window(app: &AppHandle) {
+        if let Some(window) = app.get_webview_window("main") {
+            let _ = window.unminimize();
+            let _ = window.show();
+            let _ = window.set_focus();
+        }
+    }
 
-        Ok(())
+    fn emit_action(
+        app: &AppHandle,
+        action: ShortcutAction,
+        draft_id: Option<String>,
+        error: Option<String>,
+    ) {
+        let event = ShortcutActionEvent {
+            action,
+            draft_id,
+            error,
+        };
+        if let Err(e) = app.emit_to("main", SHORTCUT_ACTION_EVENT, event) {
+            tracing::warn!(error = %e, "Failed to emit shortcut action event");
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use tauri_plugin_global_shortcut::{Code, Modifiers};
+
     #[test]
     fn test_shortcut_manager_creation() {
         // ShortcutManager creation test
         // Note: Actual shortcut registration requires a running Tauri app,
         // so this is a placeholder for integration tests
     }
+
+    #[test]
+    fn test_parse_shortcut() {
+        let shortcut = ShortcutManager::parse_shortcut("Alt+K").unwrap();
+        assert_eq!(shortcut, Shortcut::new(Some(Modifiers::ALT), Code::KeyK));
+        // Modifier and key names are case-insensitive
+        assert_eq!(
+            ShortcutManager::parse_shortcut("alt+k").unwrap().id(),
+            shortcut.id()
+        );
+
+        assert!(ShortcutManager::parse_shortcut("J").is_err());
+        assert!(ShortcutManager::parse_shortcut("Hyper+J").is_err());
+        assert!(ShortcutManager::parse_shortcut("Alt+F25").is_err());
+    }
 }
@@ -0,0 +1,374 @@
+/// 漢字語の読み辞書（運用ドキュメントで頻出する語彙）
+///
+/// 最長一致で引くため、長い語から順に並べる必要はありません。
+const KANJI_READINGS: &[(&str, &str)] = &[
+    ("再起動", "saikidou"),
+    ("使用率", "shiyouritsu"),
+    ("証明書", "shoumeisho"),
+    ("問い合わせ", "toiawase"),
+    ("問合せ", "toiawase"),
+    ("監視", "kanshi"),
+    ("接続", "setsuzoku"),
+    ("切断", "setsudan"),
+    ("対応", "taiou"),
+    ("障害", "shougai"),
+    ("調査", "chousa"),
+    ("確認", "kakunin"),
+    ("手順", "tejun"),
+    ("復旧", "fukkyuu"),
+    ("高騰", "koutou"),
+    ("上昇", "joushou"),
+    ("低下", "teika"),
+    ("遅延", "chien"),
+    ("停止", "teishi"),
+    ("起動", "kidou"),
+    ("設定", "settei"),
+    ("変更", "henkou"),
+    ("追加", "tsuika"),
+    ("削除", "sakujo"),
+    ("更新", "koushin"),
+    ("容量", "youryou"),
+    ("不足", "fusoku"),
+    ("枯渇", "koketsu"),
+    ("逼迫", "hippaku"),
+    ("期限", "kigen"),
+    ("切れ", "kire"),
+    ("失敗", "shippai"),
+    ("成功", "seikou"),
+    ("発生", "hassei"),
+    ("応答", "outou"),
+    ("時間", "jikan"),
+    ("通知", "tsuuchi"),
+    ("権限", "kengen"),
+    ("認証", "ninshou"),
+    ("負荷", "fuka"),
+    ("性能", "seinou"),
+    ("劣化", "rekka"),
+    ("本番", "honban"),
+    ("環境", "kankyou"),
+    ("移行", "ikou"),
+    ("構築", "kouchiku"),
+    ("作業", "sagyou"),
+    ("定期", "teiki"),
+    ("緊急", "kinkyuu"),
+    ("保守", "hoshu"),
+    ("運用", "unyou"),
+    ("手動", "shudou"),
+    ("自動", "jidou"),
+    ("切替", "kirikae"),
+    ("切り替え", "kirikae"),
+    ("原因", "genin"),
+    ("特定", "tokutei"),
+    ("解消", "kaishou"),
+    ("回避", "kaihi"),
+    ("方法", "houhou"),
+    ("手順書", "tejunsho"),
+    ("報告", "houkoku"),
+    ("連絡", "renraku"),
+    ("共有", "kyouyuu"),
+    ("記録", "kiroku"),
+    ("履歴", "rireki"),
+    ("状態", "joutai"),
+    ("異常", "ijou"),
+    ("正常", "seijou"),
+    ("警告", "keikoku"),
+    ("重大", "juudai"),
+    ("閾値", "shikiichi"),
+    ("検知", "kenchi"),
+    ("検出", "kenshutsu"),
+    ("収集", "shuushuu"),
+    ("取得", "shutoku"),
+    ("送信", "soushin"),
+    ("受信", "jushin"),
+    ("処理", "shori"),
+    ("実行", "jikkou"),
+    ("待ち", "machi"),
+    ("詰まり", "tsumari"),
+    ("漏れ", "more"),
+    ("不可", "fuka"),
+    ("不能", "funou"),
+    ("不具合", "fuguai"),
+    ("一時", "ichiji"),
+    ("全体", "zentai"),
+    ("一部", "ichibu"),
+    ("顧客", "kokyaku"),
+    ("利用者", "riyousha"),
+    ("社内", "shanai"),
+    ("外部", "gaibu"),
+    ("内部", "naibu"),
+    ("通信", "tsuushin"),
+    ("回線", "kaisen"),
+    ("帯域", "taiiki"),
+    ("転送", "tensou"),
+    ("同期", "douki"),
+    ("非同期", "hidouki"),
+    ("複製", "fukusei"),
+    ("冗長", "jouchou"),
+    ("切り戻し", "kirimodoshi"),
+    ("切戻し", "kirimodoshi"),
+    ("反映", "hanei"),
+    ("適用", "tekiyou"),
+    ("導入", "dounyuu"),
+    ("廃止", "haishi"),
+    ("参照", "sanshou"),
+    ("書き込み", "kakikomi"),
+    ("読み込み", "yomikomi"),
+    ("満杯", "manpai"),
+    ("空き", "aki"),
+    ("領域", "ryouiki"),
+    ("記憶", "kioku"),
+    ("演算", "enzan"),
+    ("資源", "shigen"),
+    ("料金", "ryoukin"),
+    ("請求", "seikyuu"),
+    ("契約", "keiyaku"),
+    ("申請", "shinsei"),
+    ("承認", "shounin"),
+    ("依頼", "irai"),
+    ("対象", "taishou"),
+    ("範囲", "han-i"),
+    ("影響", "eikyou"),
+    ("優先度", "yuusendo"),
+    ("重要度", "juuyoudo"),
+    ("判断", "handan"),
+    ("基準", "kijun"),
+    ("注意", "chuui"),
+    ("点検", "tenken"),
+    ("計画", "keikaku"),
+    ("予定", "yotei"),
+    ("夜間", "yakan"),
+    ("休日", "kyuujitsu"),
+    ("日次", "nichiji"),
+    ("月次", "getsuji"),
+    ("週次", "shuuji"),
+    ("年次", "nenji"),
+    ("新規", "shinki"),
+    ("既存", "kizon"),
+    ("旧", "kyuu"),
+    ("新", "shin"),
+];
+
+/// ひらがなのローマ字表（ヘボン式、長音記号なし）
+#[rustfmt::skip]
+const HIRAGANA: &[(&str, &str)] = &[
+    ("きゃ", "kya"), ("きゅ", "kyu"), ("きょ", "kyo"),
+    ("しゃ", "sha"), ("しゅ", "shu"), ("しょ", "sho"), ("しぇ", "she"),
+    ("ちゃ", "cha"), ("ちゅ", "chu"), ("ちょ", "cho"), ("ちぇ", "che"),
+    ("にゃ", "nya"), ("にゅ", "nyu"), ("にょ", "nyo"),
+    ("ひゃ", "hya"), ("ひゅ", "hyu"), ("ひょ", "hyo"),
+    ("みゃ", "mya"), ("みゅ", "myu"), ("みょ", "myo"),
+    ("りゃ", "rya"), ("りゅ", "ryu"), ("りょ", "ryo"),
+    ("ぎゃ", "gya"), ("ぎゅ", "gyu"), ("ぎょ", "gyo"),
+    ("じゃ", "ja"), ("じゅ", "ju"), ("じょ", "jo"), ("じぇ", "je"),
+    ("びゃ", "bya"), ("びゅ", "byu"), ("びょ", "byo"),
+    ("ぴゃ", "pya"), ("ぴゅ", "pyu"), ("ぴょ", "pyo"),
+    ("てぃ", "ti"), ("でぃ", "di"), ("とぅ", "tu"), ("どぅ", "du"),
+    ("ふぁ", "fa"), ("ふぃ", "fi"), ("ふぇ", "fe"), ("ふぉ", "fo"),
+    ("うぃ", "wi"), ("うぇ", "we"), ("うぉ", "wo"),
+    ("ゔぁ", "va"), ("ゔぃ", "vi"), ("ゔぇ", "ve"), ("ゔぉ", "vo"),
+    ("あ", "a"), ("い", "i"), ("う", "u"), ("え", "e"), ("お", "o"),
+    ("か", "ka"), ("き", "ki"), ("く", "ku"), ("け", "ke"), ("こ", "ko"),
+    ("さ", "sa"), ("し", "shi"), ("す", "su"), ("せ", "se"), ("そ", "so"),
+    ("た", "ta"), ("ち", "chi"), ("つ", "tsu"), ("て", "te"), ("と", "to"),
+    ("な", "na"), ("に", "ni"), ("ぬ", "nu"), ("ね", "ne"), ("の", "no"),
+    ("は", "ha"), ("ひ", "hi"), ("ふ", "fu"), ("へ", "he"), ("ほ", "ho"),
+    ("ま", "ma"), ("み", "mi"), ("む", "mu"), ("め", "me"), ("も", "mo"),
+    ("や", "ya"), ("ゆ", "yu"), ("よ", "yo"),
+    ("ら", "ra"), ("り", "ri"), ("る", "ru"), ("れ", "re"), ("ろ", "ro"),
+    ("わ", "wa"), ("ゐ", "i"), ("ゑ", "e"), ("を", "o"), ("ん", "n"),
+    ("が", "ga"), ("ぎ", "gi"), ("ぐ", "gu"), ("げ", "ge"), ("ご", "go"),
+    ("ざ", "za"), ("じ", "ji"), ("ず", "zu"), ("ぜ", "ze"), ("ぞ", "zo"),
+    ("だ", "da"), ("ぢ", "ji"), ("づ", "zu"), ("で", "de"), ("ど", "do"),
+    ("ば", "ba"), ("び", "bi"), ("ぶ", "bu"), ("べ", "be"), ("ぼ", "bo"),
+    ("ぱ", "pa"), ("ぴ", "pi"), ("ぷ", "pu"), ("ぺ", "pe"), ("ぽ", "po"),
+    ("ゔ", "vu"),
+    ("ぁ", "a"), ("ぃ", "i"), ("ぅ", "u"), ("ぇ", "e"), ("ぉ", "o"),
+    ("ゃ", "ya"), ("ゅ", "yu"), ("ょ", "yo"), ("ゎ", "wa"),
+];
+
+/// 文字種別（単語区切りの判定に使用）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Script {
+    Ascii,
+    Kana,
+    Other,
+}
+
+/// Transliterator - 日本語をローマ字に変換
+///
+/// かなはヘボン式で変換し、漢字は組み込み辞書の語のみ変換します。
+/// 辞書にない漢字は除去されます。
+pub struct Transliterator;
+
+impl Transliterator {
+    /// 文字列をローマ字に変換
+    ///
+    /// 語の切れ目（文字種の変化・辞書語の前後）にはスペースを挿入します。
+    ///
+    /// # Arguments
+    /// * `text` - 変換する文字列
+    pub fn to_romaji(text: &str) -> String {
+        let chars: Vec<char> = text.chars().map(Self::normalize_char).collect();
+        let mut words: Vec<String> = Vec::new();
+        let mut current = String::new();
+        let mut current_script: Option<Script> = None;
+        let mut sokuon = false;
+        let mut i = 0;
+
+        while i < chars.len() {
+            // 辞書語（最長一致）
+            if let Some((len, reading)) = Self::lookup_kanji(&chars[i..]) {
+                Self::flush(&mut words, &mut current);
+                words.push(reading.to_string());
+                current_script = None;
+                sokuon = false;
+                i += len;
+                continue;
+            }
+
+            let c = chars[i];
+            let script = if c.is_ascii() {
+                Script::Ascii
+            } else if Self::is_kana(c) || c == 'ー' {
+                Script::Kana
+            } else {
+                Script::Other
+            };
+
+            if current_script.is_some_and(|s| s != script) {
+                Self::flush(&mut words, &mut current);
+            }
+            current_script = Some(script);
+
+            match script {
+                Script::Ascii => {
+                    current.push(c);
+                    i += 1;
+                }
+                Script::Kana => {
+                    let hira = Self::to_hiragana(c);
+                    if hira == 'っ' {
+                        sokuon = true;
+                        i += 1;
+                        continue;
+                    }
+                    if hira == 'ー' {
+                        // 長音記号は省略
+                        i += 1;
+                        continue;
+                    }
+
+                    // 拗音（2文字）を優先して検索
+                    let next = chars.get(i + 1).map(|c| Self::to_hiragana(*c));
+                    let (romaji, len) = next
+                        .and_then(|n| {
+                            let pair: String = [hira, n].iter().collect();
+                            Self::lookup_kana(&pair).map(|r| (r, 2))
+                        })
+                        .or_else(|| Self::lookup_kana(&hira.to_string()).map(|r| (r, 1)))
+                        .unwrap_or(("", 1));
+
+                    if sokuon {
+                        if let Some(first) = romaji.chars().next() {
+                            // 「っち」は "tchi" とする
+                            current.push(if romaji.starts_with("ch") { 't' } else { first });
+                        }
+                        sokuon = false;
+                    }
+                    current.push_str(romaji);
+                    i += len;
+                }
+                Script::Other => {
+                    // 辞書にない漢字等は除去し、語の区切りとして扱う
+                    Self::flush(&mut words, &mut current);
+                    i += 1;
+                }
+            }
+        }
+
+        Self::flush(&mut words, &mut current);
+        words.join(" ")
+    }
+
+    fn flush(words: &mut Vec<String>, current: &mut String) {
+        if !current.trim().is_empty() {
+            words.push(current.trim().to_string());
+        }
+        current.clear();
+    }
+
+    /// 全角英数字・記号を半角に正規化
+    fn normalize_char(c: char) -> char {
+        match c {
+            '\u{ff01}'..='\u{ff5e}' => char::from_u32(c as u32 - 0xfee0).unwrap_or(c),
+            '\u{3000}' => ' ',
+            _ => c,
+        }
+    }
+
+    fn is_kana(c: char) -> bool {
+        ('\u{3041}'..='\u{3096}').contains(&c) || ('\u{30a1}'..='\u{30fa}').contains(&c)
+    }
+
+    /// カタカナをひらがなに変換
+    fn to_hiragana(c: char) -> char {
+        if ('\u{30a1}'..='\u{30f6}').contains(&c) {
+            char::from_u32(c as u32 - 0x60).unwrap_or(c)
+        } else {
+            c
+        }
+    }
+
+    fn lookup_kana(kana: &str) -> Option<&'static str> {
+        HIRAGANA
+            .iter()
+            .find(|(k, _)| *k == kana)
+            .map(|(_, romaji)| *romaji)
+    }
+
+    /// 先頭から辞書語を最長一致で検索
+    fn lookup_kanji(chars: &[char]) -> Option<(usize, &'static str)> {
+        KANJI_READINGS
+            .iter()
+            .filter_map(|(word, reading)| {
+                let len = word.chars().count();
+                (chars.len() >= len && word.chars().eq(chars[..len].iter().copied()))
+                    .then_some((len, *reading))
+            })
+            .max_by_key(|(len, _)| *len)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_kana_to_romaji() {
+        assert_eq!(Transliterator::to_romaji("きゃっしゅ"), "kyasshu");
+        assert_eq!(Transliterator::to_romaji("サーバー"), "saba");
+        assert_eq!(Transliterator::to_romaji("マッチ"), "matchi");
+    }
+
+    #[test]
+    fn test_mixed_title() {
+        assert_eq!(
+            Transliterator::to_romaji("DB接続エラー対応"),
+            "DB setsuzoku era taiou"
+        );
+        assert_eq!(
+            Transliterator::to_romaji("CPU使用率上昇"),
+            "CPU shiyouritsu joushou"
+        );
+    }
+
+    #[test]
+    fn test_unknown_kanji_removed() {
+        assert_eq!(Transliterator::to_romaji("鯖の再起動"), "no saikidou");
+    }
+
+    #[test]
+    fn test_fullwidth_normalized() {
+        assert_eq!(Transliterator::to_romaji("ＡＰＩ　障害"), "API shougai");
+    }
+}
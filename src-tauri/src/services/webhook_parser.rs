@@ -0,0 +1,426 @@
+use crate::models::{AlertSource, IncomingAlert, Result, Severity, WorkNoteError};
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+
+/// 重要度を表すラベル名（優先順）
+const SEVERITY_LABELS: &[&str] = &["severity", "priority", "level"];
+
+/// WebhookParser - Webhookペイロードからアラート一覧を抽出
+///
+/// 送信元はペイロードの形から判定します。
+/// - Alertmanager: `alerts[]` に `labels` / `annotations` を持つ形式
+/// - Grafana: Alertmanager互換形式に `orgId` 等が付いた形式、または旧形式（`ruleName` / `evalMatches`）
+/// - 汎用JSON: `alertname` / `name` / `title` を持つオブジェクト、またはその配列
+///
+/// 解決済み（resolved / ok）のアラートは除外します。
+pub struct WebhookParser;
+
+impl WebhookParser {
+    /// ペイロードを解析
+    pub fn parse(body: &str) -> Result<Vec<IncomingAlert>> {
+        let value: Value = serde_json::from_str(body).map_err(|e| {
+            WorkNoteError::ValidationError(format!("Invalid webhook payload: {}", e))
+        })?;
+
+        match &value {
+            Value::Object(object) if object.get("alerts").is_some_and(Value::is_array) => {
+                Ok(Self::parse_alertmanager(object))
+            }
+            Value::Object(object) if object.contains_key("ruleName") => {
+                Ok(Self::parse_grafana_legacy(object).into_iter().collect())
+            }
+            Value::Object(object) => Self::parse_generic(object).map(|alert| vec![alert]),
+            Value::Array(items) => items
+                .iter()
+                .filter_map(Value::as_object)
+                .map(Self::parse_generic)
+                .collect(),
+            _ => Err(WorkNoteError::ValidationError(
+                "Webhook payload must be a JSON object or array".to_string(),
+            )),
+        }
+    }
+
+    /// Alertmanager（およびGrafana Unified Alerting）形式を解析
+    fn parse_alertmanager(payload: &Map<String, Value>) -> Vec<IncomingAlert> {
+        let source = if payload.contains_key("orgId") || payload.contains_key("title") {
+            AlertSource::Grafana
+        } else {
+            AlertSource::Alertmanager
+        };
+        let common_labels = Self::string_map(payload.get("commonLabels"));
+        let common_annotations = Self::string_map(payload.get("commonAnnotations"));
+
+        let alerts = payload
+            .get("alerts")
+            .and_then(Value::as_array)
+            .map(Vec::as_slice)
+            .unwrap_or_default();
+
+        alerts
+            .iter()
+            .filter_map(Value::as_object)
+            .filter(|alert| Self::str_field(alert, "status") != Some("resolved"))
+            .map(|alert| {
+                let mut labels = common_labels.clone();
+                labels.extend(Self::string_map(alert.get("labels")));
+                let mut annotations = common_annotations.clone();
+                annotations.extend(Self::string_map(alert.get("annotations")));
+
+                let name = labels
+                    .get("alertname")
+                    .or_else(|| labels.get("rulename"))
+                    .cloned()
+                    .unwrap_or_else(|| "UnknownAlert".to_string());
+
+                let mut links: Vec<String> = Vec::new();
+                for key in ["runbook_url", "dashboard_url"] {
+                    if let Some(url) = annotations.get(key) {
+                        links.push(url.clone());
+                    }
+                }
+                for key in ["generatorURL", "dashboardURL", "panelURL"] {
+                    if let Some(url) = Self::str_field(alert, key).filter(|s| !s.is_empty()) {
+                        links.push(url.to_string());
+                    }
+                }
+                links.dedup();
+
+                IncomingAlert {
+                    source,
+                    severity: Self::severity_from_labels(&labels),
+                    summary: annotations.get("summary").cloned(),
+                    description: annotations
+                        .get("description")
+                        .or_else(|| annotations.get("message"))
+                        .cloned(),
+                    starts_at: Self::str_field(alert, "startsAt").map(str::to_string),
+                    labels: labels
+                        .into_iter()
+                        .filter(|(key, _)| key != "alertname")
+                        .collect(),
+                    name,
+                    links,
+                    fingerprint: Self::str_field(alert, "fingerprint")
+                        .filter(|s| !s.is_empty())
+                        .map(str::to_string),
+                }
+            })
+            .collect()
+    }
+
+    /// Grafana旧形式（Legacy Alerting）を解析
+    fn parse_grafana_legacy(payload: &Map<String, Value>) -> Option<IncomingAlert> {
+        if Self::str_field(payload, "state") == Some("ok") {
+            return None;
+        }
+
+        let labels = Self::string_map(payload.get("tags"));
+        let matches = payload
+            .get("evalMatches")
+            .and_then(Value::as_array)
+            .map(|matches| {
+                matches
+                    .iter()
+                    .filter_map(|m| {
+                        let metric = m.get("metric").and_then(Value::as_str)?;
+                        let value = m.get("value").map(Self::value_to_string)?;
+                        Some(format!("- {}: {}", metric, value))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        let mut description = Self::str_field(payload, "message").map(str::to_string);
+        if !matches.is_empty() {
+            let matches = matches.join("\n");
+            description = Some(match description {
+                Some(message) => format!("{}\n\n{}", message, matches),
+                None => matches,
+            });
+        }
+
+        Some(IncomingAlert {
+            source: AlertSource::Grafana,
+            name: Self::str_field(payload, "ruleName")
+                .unwrap_or("UnknownAlert")
+                .to_string(),
+            severity: Self::severity_from_labels(&labels),
+            summary: Self::str_field(payload, "title").map(str::to_string),
+            description,
+            labels,
+            starts_at: None,
+            links: Self::str_field(payload, "ruleUrl")
+                .map(|url| vec![url.to_string()])
+                .unwrap_or_default(),
+            fingerprint: None,
+        })
+    }
+
+    /// 汎用JSON形式を解析
+    fn parse_generic(payload: &Map<String, Value>) -> Result<IncomingAlert> {
+        let name = ["alertname", "alert_name", "name", "title"]
+            .iter()
+            .find_map(|key| Self::str_field(payload, key))
+            .filter(|name| !name.trim().is_empty())
+            .ok_or_else(|| {
+                WorkNoteError::ValidationError(
+                    "Webhook payload has no alert name (alertname, name or title)".to_string(),
+                )
+            })?;
+
+        let mut labels = Self::string_map(payload.get("labels"));
+        for key in SEVERITY_LABELS {
+            if let Some(value) = payload.get(*key) {
+                labels
+                    .entry(key.to_string())
+                    .or_insert_with(|| Self::value_to_string(value));
+            }
+        }
+
+        let links = ["url", "link", "runbook_url"]
+            .iter()
+            .filter_map(|key| Self::str_field(payload, key))
+            .map(str::to_string)
+            .collect();
+
+        Ok(IncomingAlert {
+            source: AlertSource::Generic,
+            name: name.trim().to_string(),
+            severity: Self::severity_from_labels(&labels),
+            summary: ["summary", "message"]
+                .iter()
+                .find_map(|key| Self::str_field(payload, key))
+                .map(str::to_string),
+            description: Self::str_field(payload, "description").map(str::to_string),
+            labels,
+            starts_at: ["startsAt", "timestamp", "time"]
+                .iter()
+                .find_map(|key| Self::str_field(payload, key))
+                .map(str::to_string),
+            links,
+            fingerprint: Self::str_field(payload, "fingerprint")
+                .filter(|s| !s.is_empty())
+                .map(str::to_string),
+        })
+    }
+
+    /// ラベルから重要度を決定（該当なしの場合はMedium）
+    pub fn severity_from_labels(labels: &BTreeMap<String, String>) -> Severity {
+        SEVERITY_LABELS
+            .iter()
+            .filter_map(|key| labels.get(*key))
+            .find_map(|value| Self::map_severity(value))
+            .unwrap_or(Severity::Medium)
+    }
+
+    /// 送信元ごとの重要度表記をSeverityに変換
+    fn map_severity(value: &str) -> Option<Severity> {
+        match value.trim().to_lowercase().as_str() {
+            "critical" | "crit" | "fatal" | "emergency" | "page" | "disaster" | "p1" | "sev1" => {
+                Some(Severity::Critical)
+            }
+            "high" | "error" | "major" | "p2" | "sev2" => Some(Severity::High),
+            "warning" | "warn" | "medium" | "average" | "p3" | "sev3" => Some(Severity::Medium),
+            "low" | "info" | "informational" | "minor" | "none" | "p4" | "p5" | "sev4" => {
+                Some(Severity::Low)
+            }
+            _ => None,
+        }
+    }
+
+    /// JSONオブジェクトを文字列のマップに変換
+    fn string_map(value: Option<&Value>) -> BTreeMap<String, String> {
+        value
+            .and_then(Value::as_object)
+            .map(|object| {
+                object
+                    .iter()
+                    .map(|(key, value)| (key.clone(), Self::value_to_string(value)))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    fn str_field<'a>(object: &'a Map<String, Value>, key: &str) -> Option<&'a str> {
+        object.get(key).and_then(Value::as_str)
+    }
+
+    fn value_to_string(value: &Value) -> String {
+        match value {
+            Value::String(s) => s.clone(),
+            other => other.to_string(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALERTMANAGER_PAYLOAD: &str = r#"{
+        "version": "4",
+        "status": "firing",
+        "receiver": "worknote",
+        "groupKey": "{}:{alertname=\"HighCPUUsage\"}",
+        "commonLabels": {"alertname": "HighCPUUsage", "job": "node"},
+        "commonAnnotations": {"runbook_url": "https://wiki.example.com/cpu"},
+        "externalURL": "http://alertmanager.local",
+        "alerts": [
+            {
+                "status": "firing",
+                "labels": {"alertname": "HighCPUUsage", "severity": "critical", "instance": "web-01"},
+                "annotations": {"summary": "CPU使用率が90%を超えています", "description": "web-01 のCPU使用率が高騰"},
+                "startsAt": "2026-01-31T10:00:00Z",
+                "generatorURL": "http://prometheus.local/graph?g0.expr=cpu",
+                "fingerprint": "abc"
+            },
+            {
+                "status": "resolved",
+                "labels": {"alertname": "HighCPUUsage", "severity": "critical", "instance": "web-02"},
+                "annotations": {"summary": "resolved"},
+                "startsAt": "2026-01-31T09:00:00Z"
+            }
+        ]
+    }"#;
+
+    const GRAFANA_PAYLOAD: &str = r#"{
+        "receiver": "worknote",
+        "status": "firing",
+        "orgId": 1,
+        "title": "[FIRING:1] DiskFull",
+        "alerts": [
+            {
+                "status": "firing",
+                "labels": {"alertname": "DiskFull", "priority": "P2"},
+                "annotations": {"summary": "ディスク使用率が95%を超えています"},
+                "startsAt": "2026-01-31T11:00:00Z",
+                "dashboardURL": "http://grafana.local/d/disk",
+                "panelURL": ""
+            }
+        ]
+    }"#;
+
+    const GRAFANA_LEGACY_PAYLOAD: &str = r#"{
+        "title": "[Alerting] Memory alert",
+        "ruleId": 1,
+        "ruleName": "Memory alert",
+        "ruleUrl": "http://grafana.local/d/memory",
+        "state": "alerting",
+        "message": "メモリ使用率が閾値を超えました",
+        "evalMatches": [{"metric": "mem_used", "value": 95.5, "tags": {}}],
+        "tags": {"severity": "warning"}
+    }"#;
+
+    const GENERIC_PAYLOAD: &str = r#"{
+        "alertname": "datadog:12345",
+        "severity": "error",
+        "message": "API のレイテンシが悪化しています",
+        "url": "https://app.datadoghq.com/monitors/12345"
+    }"#;
+
+    #[test]
+    fn test_parse_alertmanager() {
+        let alerts = WebhookParser::parse(ALERTMANAGER_PAYLOAD).unwrap();
+        // resolvedは除外
+        assert_eq!(alerts.len(), 1);
+
+        let alert = &alerts[0];
+        assert_eq!(alert.source, AlertSource::Alertmanager);
+        assert_eq!(alert.name, "HighCPUUsage");
+        assert_eq!(alert.fingerprint.as_deref(), Some("abc"));
+        assert_eq!(alert.severity, Severity::Critical);
+        assert_eq!(
+            alert.summary.as_deref(),
+            Some("CPU使用率が90%を超えています")
+        );
+        assert_eq!(
+            alert.labels.get("instance").map(String::as_str),
+            Some("web-01")
+        );
+        assert_eq!(alert.labels.get("job").map(String::as_str), Some("node"));
+        assert!(!alert.labels.contains_key("alertname"));
+        assert_eq!(
+            alert.links,
+            vec![
+                "https://wiki.example.com/cpu",
+                "http://prometheus.local/graph?g0.expr=cpu"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_grafana() {
+        let alerts = WebhookParser::parse(GRAFANA_PAYLOAD).unwrap();
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].source, AlertSource::Grafana);
+        assert_eq!(alerts[0].name, "DiskFull");
+        assert_eq!(alerts[0].severity, Severity::High);
+        assert_eq!(alerts[0].links, vec!["http://grafana.local/d/disk"]);
+    }
+
+    #[test]
+    fn test_parse_grafana_legacy() {
+        let alerts = WebhookParser::parse(GRAFANA_LEGACY_PAYLOAD).unwrap();
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].source, AlertSource::Grafana);
+        assert_eq!(alerts[0].name, "Memory alert");
+        assert_eq!(alerts[0].severity, Severity::Medium);
+        assert_eq!(
+            alerts[0].description.as_deref(),
+            Some("メモリ使用率が閾値を超えました\n\n- mem_used: 95.5")
+        );
+
+        // 復旧通知は無視
+        let ok = GRAFANA_LEGACY_PAYLOAD.replace("\"alerting\"", "\"ok\"");
+        assert!(WebhookParser::parse(&ok).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_parse_generic() {
+        let alerts = WebhookParser::parse(GENERIC_PAYLOAD).unwrap();
+        assert_eq!(alerts.len(), 1);
+        assert_eq!(alerts[0].source, AlertSource::Generic);
+        assert_eq!(alerts[0].name, "datadog:12345");
+        assert_eq!(alerts[0].severity, Severity::High);
+        assert_eq!(
+            alerts[0].summary.as_deref(),
+            Some("API のレイテンシが悪化しています")
+        );
+
+        let array = format!("[{}, {}]", GENERIC_PAYLOAD, GENERIC_PAYLOAD);
+        assert_eq!(WebhookParser::parse(&array).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_parse_invalid() {
+        assert!(WebhookParser::parse("not json").is_err());
+        assert!(WebhookParser::parse("42").is_err());
+        assert!(WebhookParser::parse(r#"{"foo": "bar"}"#).is_err());
+    }
+
+    #[test]
+    fn test_severity_mapping() {
+        let labels = |value: &str| BTreeMap::from([("severity".to_string(), value.to_string())]);
+        assert_eq!(
+            WebhookParser::severity_from_labels(&labels("page")),
+            Severity::Critical
+        );
+        assert_eq!(
+            WebhookParser::severity_from_labels(&labels("warning")),
+            Severity::Medium
+        );
+        assert_eq!(
+            WebhookParser::severity_from_labels(&labels("info")),
+            Severity::Low
+        );
+        assert_eq!(
+            WebhookParser::severity_from_labels(&labels("unknown")),
+            Severity::Medium
+        );
+        assert_eq!(
+            WebhookParser::severity_from_labels(&BTreeMap::new()),
+            Severity::Medium
+        );
+    }
+}
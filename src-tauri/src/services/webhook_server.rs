@@ -0,0 +1,600 @@
+use crate::models::{Draft, IncomingAlert, Result, WorkNoteError};
+use crate::services::draft_manager::DraftManager;
+use crate::services::webhook_parser::WebhookParser;
+use serde_json::json;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::Duration;
+use tracing::{error, info, warn};
+
+/// リクエストボディの最大サイズ（1MB）
+const MAX_BODY_SIZE: usize = 1024 * 1024;
+
+/// リクエストヘッダーの最大サイズ
+const MAX_HEADER_SIZE: usize = 16 * 1024;
+
+/// 読み書きのタイムアウト
+const IO_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// 下書き作成時に呼ばれるコールバック
+type DraftCallback = dyn Fn(&Draft, &IncomingAlert) + Send + Sync;
+
+/// トークンを送るヘッダー（`Authorization: Bearer` を設定できない送信元向け）
+const TOKEN_HEADER: &str = "x-worknote-token";
+
+/// HTTPリクエスト（必要な部分のみ）
+struct HttpRequest {
+    method: String,
+    path: String,
+    /// `Origin` ヘッダー（ブラウザからのリクエストの場合に付く）
+    origin: Option<String>,
+    /// `Authorization: Bearer` または `X-WorkNote-Token` で送られたトークン
+    token: Option<String>,
+    body: String,
+}
+
+/// HTTPレスポンス
+struct HttpResponse {
+    status: u16,
+    body: serde_json::Value,
+}
+
+impl HttpResponse {
+    fn error(status: u16, message: impl Into<String>) -> Self {
+        HttpResponse {
+            status,
+            body: json!({ "error": message.into() }),
+        }
+    }
+}
+
+/// WebhookServer - アラートWebhookを受信して下書きを作成するローカルHTTPサーバー
+///
+/// `127.0.0.1` のみで待ち受け、`POST /webhook`（`/webhook/alertmanager` 等のサブパスも可）
+/// で受け付けます。受信したアラートごとに `DraftManager::create_alert_draft` で下書きを作成します。
+/// 受信頻度は低い前提のため、リクエストは待ち受けスレッドで1件ずつ処理します。
+///
+/// ローカルで開いたWebページからの送信を防ぐため、共有トークンのないリクエストと
+/// `Origin` ヘッダー付き（ブラウザからの）リクエストは拒否します。
+/// 同じfingerprintのアラートの下書きが残っている場合は、再送とみなして作成しません。
+pub struct WebhookServer {
+    addr: SocketAddr,
+    port: u16,
+    token: String,
+    shutdown: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl WebhookServer {
+    /// サーバーを起動
+    ///
+    /// # Arguments
+    /// * `port` - 待ち受けポート（0の場合は空きポートを使用）
+    /// * `token` - 送信元と共有するトークン（空の場合は起動しない）
+    /// * `draft_manager` - 下書きの保存先
+    /// * `on_draft` - 下書き作成時に呼ばれるコールバック（通知用）
+    pub fn start<F>(
+        port: u16,
+        token: String,
+        draft_manager: DraftManager,
+        on_draft: F,
+    ) -> Result<Self>
+    where
+        F: Fn(&Draft, &IncomingAlert) + Send + Sync + 'static,
+    {
+        if token.trim().is_empty() {
+            return Err(WorkNoteError::ConfigError(
+                "Webhook token is required".to_string(),
+            ));
+        }
+
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port)).map_err(|e| {
+            WorkNoteError::NetworkError(format!(
+                "Failed to bind webhook listener on port {}: {}",
+                port, e
+            ))
+        })?;
+        let addr = listener.local_addr()?;
+
+        let shutdown = Arc::new(AtomicBool::new(false));
+        let thread_shutdown = Arc::clone(&shutdown);
+        let on_draft: Arc<DraftCallback> = Arc::new(on_draft);
+        let thread_token = token.clone();
+
+        let handle = std::thread::Builder::new()
+            .name("worknote-webhook".to_string())
+            .spawn(move || {
+                for stream in listener.incoming() {
+                    if thread_shutdown.load(Ordering::SeqCst) {
+                        break;
+                    }
+                    match stream {
+                        Ok(stream) => Self::handle_connection(
+                            stream,
+                            &thread_token,
+                            &draft_manager,
+                            &*on_draft,
+                        ),
+                        Err(e) => warn!(error = %e, "Failed to accept webhook connection"),
+                    }
+                }
+            })?;
+
+        info!(%addr, "Webhook listener started");
+        Ok(WebhookServer {
+            addr,
+            port,
+            token,
+            shutdown,
+            handle: Some(handle),
+        })
+    }
+
+    /// 待ち受けアドレスを取得
+    pub fn local_addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// 起動時に指定したポートを取得（0の場合、実際のポートは `local_addr` で取得）
+    pub fn port(&self) -> u16 {
+        self.port
+    }
+
+    /// 起動時の共有トークンを取得
+    pub fn token(&self) -> &str {
+        &self.token
+    }
+
+    /// サーバーを停止
+    pub fn stop(mut self) {
+        self.shutdown_thread();
+    }
+
+    fn shutdown_thread(&mut self) {
+        let Some(handle) = self.handle.take() else {
+            return;
+        };
+
+        self.shutdown.store(true, Ordering::SeqCst);
+        // accept()で待機しているスレッドを起こす
+        let _ = TcpStream::connect_timeout(&self.addr, IO_TIMEOUT);
+        if handle.join().is_err() {
+            error!("Webhook listener thread panicked");
+        }
+        info!(addr = %self.addr, "Webhook listener stopped");
+    }
+
+    /// 1件の接続を処理
+    fn handle_connection(
+        stream: TcpStream,
+        token: &str,
+        draft_manager: &DraftManager,
+        on_draft: &DraftCallback,
+    ) {
+        let _ = stream.set_read_timeout(Some(IO_TIMEOUT));
+        let _ = stream.set_write_timeout(Some(IO_TIMEOUT));
+
+        let response = match Self::read_request(&stream) {
+            Ok(request) => Self::route(request, token, draft_manager, on_draft),
+            Err(response) => response,
+        };
+
+        if let Err(e) = Self::write_response(&stream, &response) {
+            warn!(error = %e, "Failed to write webhook response");
+        }
+    }
+
+    /// リクエストを振り分け
+    fn route(
+        request: HttpRequest,
+        token: &str,
+        draft_manager: &DraftManager,
+        on_draft: &DraftCallback,
+    ) -> HttpResponse {
+        // ブラウザ（ローカルで開いたWebページ）からのリクエストは受け付けない
+        if let Some(origin) = &request.origin {
+            warn!(%origin, "Rejected webhook request with Origin header");
+            return HttpResponse::error(403, "Cross-origin requests are not allowed");
+        }
+
+        let path = request.path.split('?').next().unwrap_or_default();
+
+        match (request.method.as_str(), path) {
+            ("GET", "/health") => HttpResponse {
+                status: 200,
+                body: json!({ "status": "ok" }),
+            },
+            (method, path) if path == "/webhook" || path.starts_with("/webhook/") => {
+                if method != "POST" {
+                    return HttpResponse::error(405, "Method not allowed");
+                }
+                if !request
+                    .token
+                    .as_deref()
+                    .is_some_and(|t| Self::token_matches(t, token))
+                {
+                    return HttpResponse::error(401, "Invalid or missing webhook token");
+                }
+                Self::handle_webhook(&request.body, draft_manager, on_draft)
+            }
+            _ => HttpResponse::error(404, "Not found"),
+        }
+    }
+
+    /// Webhookを処理して下書きを作成
+    fn handle_webhook(
+        body: &str,
+        draft_manager: &DraftManager,
+        on_draft: &DraftCallback,
+    ) -> HttpResponse {
+        let alerts = match WebhookParser::parse(body) {
+            Ok(alerts) => alerts,
+            Err(e) => return HttpResponse::error(400, e.to_string()),
+        };
+
+        let mut drafts = Vec::new();
+        let mut skipped = Vec::new();
+        for alert in &alerts {
+            // 同じアラートの下書きが残っていれば再送とみなす
+            if let Some(fingerprint) = &alert.fingerprint {
+                match draft_manager.find_by_alert_fingerprint(fingerprint) {
+                    Ok(Some(draft)) => {
+                        info!(draft_id = %draft.id, alert = %alert.name, "Skipped duplicate alert");
+                        skipped.push(json!({ "id": draft.id, "fingerprint": fingerprint }));
+                        continue;
+                    }
+                    Ok(None) => {}
+                    Err(e) => {
+                        error!(alert = %alert.name, error = %e, "Failed to look up drafts");
+                        return HttpResponse::error(500, e.to_string());
+                    }
+                }
+            }
+
+            match draft_manager
+                .create_alert_draft(alert.to_knowledge_input(), alert.fingerprint.clone())
+            {
+                Ok(draft) => {
+                    info!(draft_id = %draft.id, alert = %alert.name, "Draft created from webhook");
+                    on_draft(&draft, alert);
+                    drafts.push(json!({ "id": draft.id, "title": draft.data.title }));
+                }
+                Err(e) => {
+                    error!(alert = %alert.name, error = %e, "Failed to create draft from webhook");
+                    return HttpResponse::error(500, e.to_string());
+                }
+            }
+        }
+
+        HttpResponse {
+            status: 200,
+            body: json!({ "drafts": drafts, "skipped": skipped }),
+        }
+    }
+
+    /// トークンを比較（一致するまでの時間から推測されないよう全体を比較）
+    fn token_matches(received: &str, expected: &str) -> bool {
+        received.len() == expected.len()
+            && received
+                .bytes()
+                .zip(expected.bytes())
+                .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+                == 0
+    }
+
+    /// HTTPリクエストを読み込む
+    ///
+    /// ヘッダーは `MAX_HEADER_SIZE`、ボディは `Content-Length` までしか読み込みません
+    /// （改行を送らないクライアントでもメモリを使い続けないようにする）。
+    fn read_request(stream: &TcpStream) -> std::result::Result<HttpRequest, HttpResponse> {
+        let mut reader = BufReader::new(stream.take(MAX_HEADER_SIZE as u64 + 1));
+
+        let mut request_line = String::new();
+        reader
+            .read_line(&mut request_line)
+            .map_err(|_| HttpResponse::error(400, "Failed to read request"))?;
+        if request_line.len() > MAX_HEADER_SIZE {
+            return Err(HttpResponse::error(431, "Request headers too large"));
+        }
+        let mut parts = request_line.split_whitespace();
+        let (Some(method), Some(path)) = (parts.next(), parts.next()) else {
+            return Err(HttpResponse::error(400, "Malformed request line"));
+        };
+        let (method, path) = (method.to_string(), path.to_string());
+
+        let mut content_length: Option<usize> = None;
+        let mut origin = None;
+        let mut token = None;
+        let mut header_size = request_line.len();
+        loop {
+            let mut line = String::new();
+            let read = reader
+                .read_line(&mut line)
+                .map_err(|_| HttpResponse::error(400, "Failed to read headers"))?;
+            header_size += read;
+            if header_size > MAX_HEADER_SIZE {
+                return Err(HttpResponse::error(431, "Request headers too large"));
+            }
+
+            let line = line.trim_end();
+            if read == 0 || line.is_empty() {
+                break;
+            }
+
+            if let Some((name, value)) = line.split_once(':') {
+                let name = name.trim().to_ascii_lowercase();
+                let value = value.trim();
+                if name == "content-length" {
+                    content_length = Some(
+                        value
+                            .parse()
+                            .map_err(|_| HttpResponse::error(400, "Invalid Content-Length"))?,
+                    );
+                } else if name == "transfer-encoding" && !value.eq_ignore_ascii_case("identity") {
+                    return Err(HttpResponse::error(411, "Content-Length required"));
+                } else if name == "origin" {
+                    origin = Some(value.to_string());
+                } else if name == TOKEN_HEADER {
+                    token = Some(value.to_string());
+                } else if name == "authorization" {
+                    if let Some(bearer) = value
+                        .split_once(' ')
+                        .filter(|(scheme, _)| scheme.eq_ignore_ascii_case("bearer"))
+                    {
+                        token.get_or_insert_with(|| bearer.1.trim().to_string());
+                    }
+                }
+            }
+        }
+
+        let content_length = content_length.unwrap_or(0);
+        if content_length > MAX_BODY_SIZE {
+            return Err(HttpResponse::error(413, "Payload too large"));
+        }
+
+        // ヘッダーの上限はボディの読み込みには適用しない（読み込むのはContent-Lengthまで）
+        reader.get_mut().set_limit(content_length as u64);
+        let mut body = vec![0; content_length];
+        reader
+            .read_exact(&mut body)
+            .map_err(|_| HttpResponse::error(400, "Failed to read request body"))?;
+        let body = String::from_utf8(body)
+            .map_err(|_| HttpResponse::error(400, "Request body must be UTF-8"))?;
+
+        Ok(HttpRequest {
+            method,
+            path,
+            origin,
+            token,
+            body,
+        })
+    }
+
+    /// HTTPレスポンスを書き込む
+    fn write_response(mut stream: &TcpStream, response: &HttpResponse) -> std::io::Result<()> {
+        let reason = match response.status {
+            200 => "OK",
+            400 => "Bad Request",
+            401 => "Unauthorized",
+            403 => "Forbidden",
+            404 => "Not Found",
+            405 => "Method Not Allowed",
+            411 => "Length Required",
+            413 => "Payload Too Large",
+            431 => "Request Header Fields Too Large",
+            _ => "Internal Server Error",
+        };
+        let body = response.body.to_string();
+
+        write!(
+            stream,
+            "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            response.status,
+            reason,
+            body.len(),
+            body
+        )?;
+        stream.flush()
+    }
+}
+
+impl Drop for WebhookServer {
+    fn drop(&mut self) {
+        self.shutdown_thread();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{Category, Severity};
+    use std::env;
+    use std::fs;
+    use std::sync::Mutex;
+
+    const ALERTMANAGER_PAYLOAD: &str = r#"{
+        "version": "4",
+        "status": "firing",
+        "receiver": "worknote",
+        "alerts": [
+            {
+                "status": "firing",
+                "labels": {"alertname": "HighCPUUsage", "severity": "critical"},
+                "annotations": {"summary": "CPU使用率が90%を超えています"},
+                "startsAt": "2026-01-31T10:00:00Z",
+                "fingerprint": "4a1e2f"
+            }
+        ]
+    }"#;
+
+    const TOKEN: &str = "test-token";
+
+    fn post(addr: SocketAddr, path: &str, body: &str) -> (u16, String) {
+        let authorization = format!("Authorization: Bearer {}\r\n", TOKEN);
+        post_with_headers(addr, path, &authorization, body)
+    }
+
+    fn post_with_headers(addr: SocketAddr, path: &str, headers: &str, body: &str) -> (u16, String) {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        write!(
+            stream,
+            "POST {} HTTP/1.1\r\nHost: localhost\r\n{}Content-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+            path,
+            headers,
+            body.len(),
+            body
+        )
+        .unwrap();
+
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let status = response
+            .split_whitespace()
+            .nth(1)
+            .and_then(|s| s.parse().ok())
+            .unwrap();
+        let body = response
+            .split_once("\r\n\r\n")
+            .map(|(_, body)| body.to_string())
+            .unwrap_or_default();
+        (status, body)
+    }
+
+    #[test]
+    fn test_webhook_creates_draft() {
+        let temp_dir = env::temp_dir().join("worknote_test_webhook_server");
+        let _ = fs::remove_dir_all(&temp_dir);
+
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let received_clone = Arc::clone(&received);
+        let server = WebhookServer::start(
+            0,
+            TOKEN.to_string(),
+            DraftManager::new(temp_dir.clone()),
+            move |draft, alert| {
+                received_clone
+                    .lock()
+                    .unwrap()
+                    .push((draft.id.clone(), alert.name.clone()));
+            },
+        )
+        .unwrap();
+        let addr = server.local_addr();
+        assert_eq!(server.port(), 0);
+        assert_ne!(addr.port(), 0);
+
+        let (status, body) = post(addr, "/webhook/alertmanager", ALERTMANAGER_PAYLOAD);
+        assert_eq!(status, 200);
+        assert!(body.contains("HighCPUUsage 対応"));
+
+        let received = received.lock().unwrap().clone();
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].1, "HighCPUUsage");
+
+        let draft = DraftManager::new(temp_dir.clone())
+            .load_draft(&received[0].0)
+            .unwrap();
+        assert_eq!(draft.data.category, Category::Alerts);
+        assert_eq!(draft.data.severity, Severity::Critical);
+        assert_eq!(draft.data.related_alerts, vec!["HighCPUUsage"]);
+        assert!(draft.data.symptoms.contains("CPU使用率が90%を超えています"));
+        assert_eq!(draft.alert_fingerprint.as_deref(), Some("4a1e2f"));
+
+        // 同じfingerprintの下書きが残っている間は作成しない
+        let (status, body) = post(addr, "/webhook", ALERTMANAGER_PAYLOAD);
+        assert_eq!(status, 200);
+        assert!(body.contains("\"skipped\""));
+        assert!(body.contains(&received[0].0));
+        assert_eq!(
+            DraftManager::new(temp_dir.clone())
+                .list_drafts()
+                .unwrap()
+                .len(),
+            1
+        );
+
+        server.stop();
+
+        // クリーンアップ
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_webhook_rejects_invalid_requests() {
+        let temp_dir = env::temp_dir().join("worknote_test_webhook_server_invalid");
+        let _ = fs::remove_dir_all(&temp_dir);
+
+        let server = WebhookServer::start(
+            0,
+            TOKEN.to_string(),
+            DraftManager::new(temp_dir.clone()),
+            |_, _| {},
+        )
+        .unwrap();
+        let addr = server.local_addr();
+
+        assert_eq!(post(addr, "/webhook", "not json").0, 400);
+        assert_eq!(post(addr, "/unknown", "{}").0, 404);
+
+        // トークンがない・一致しない
+        assert_eq!(
+            post_with_headers(addr, "/webhook", "", ALERTMANAGER_PAYLOAD).0,
+            401
+        );
+        assert_eq!(
+            post_with_headers(
+                addr,
+                "/webhook",
+                "Authorization: Bearer wrong-token\r\n",
+                ALERTMANAGER_PAYLOAD
+            )
+            .0,
+            401
+        );
+        // ブラウザからのリクエスト（Origin付き）はトークンがあっても拒否
+        let headers = format!(
+            "Origin: http://evil.example\r\nX-WorkNote-Token: {}\r\n",
+            TOKEN
+        );
+        assert_eq!(
+            post_with_headers(addr, "/webhook", &headers, ALERTMANAGER_PAYLOAD).0,
+            403
+        );
+
+        let mut stream = TcpStream::connect(addr).unwrap();
+        write!(stream, "GET /webhook HTTP/1.1\r\n\r\n").unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 405"));
+
+        // 改行のない長い行は上限までで読み込みをやめる
+        let mut stream = TcpStream::connect(addr).unwrap();
+        stream
+            .write_all("a".repeat(MAX_HEADER_SIZE + 1).as_bytes())
+            .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        assert!(response.starts_with("HTTP/1.1 431"));
+
+        // 下書きは作成されない
+        assert!(DraftManager::new(temp_dir.clone())
+            .list_drafts()
+            .unwrap()
+            .is_empty());
+
+        drop(server);
+        let _ = fs::remove_dir_all(&temp_dir);
+    }
+
+    #[test]
+    fn test_webhook_requires_token() {
+        let temp_dir = env::temp_dir().join("worknote_test_webhook_server_token");
+        assert!(
+            WebhookServer::start(0, " ".to_string(), DraftManager::new(temp_dir), |_, _| {})
+                .is_err()
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use crate::models::{Result, WorkNoteError};
+use std::io::{Cursor, Read};
+use zip::result::ZipError;
+
+/// 1エントリあたりの展開後の最大サイズ（圧縮爆弾対策）
+const MAX_ENTRY_SIZE: u64 = 50 * 1024 * 1024;
+
+/// ZIPアーカイブ内のエントリ
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ZipEntry {
+    /// アーカイブ内のパス（`/` 区切り）
+    pub name: String,
+    index: usize,
+    is_dir: bool,
+}
+
+impl ZipEntry {
+    /// ディレクトリエントリかどうか
+    pub fn is_dir(&self) -> bool {
+        self.is_dir
+    }
+}
+
+/// ZipArchive - エクスポートされたZIPファイルを読み込む
+///
+/// Confluence/Notionのエクスポートで使われる無圧縮・Deflate形式に対応します（暗号化は非対応）。
+/// 展開後のサイズはエントリごとに `MAX_ENTRY_SIZE` までに制限します。
+pub struct ZipArchive {
+    archive: zip::ZipArchive<Cursor<Vec<u8>>>,
+    entries: Vec<ZipEntry>,
+}
+
+impl ZipArchive {
+    /// ZIPデータを解析
+    pub fn new(data: Vec<u8>) -> Result<Self> {
+        let mut archive = zip::ZipArchive::new(Cursor::new(data)).map_err(invalid)?;
+
+        let mut entries = Vec::with_capacity(archive.len());
+        for index in 0..archive.len() {
+            let file = archive.by_index_raw(index).map_err(invalid)?;
+            entries.push(ZipEntry {
+                name: file.name().replace('\\', "/"),
+                index,
+                is_dir: file.is_dir(),
+            });
+        }
+
+        Ok(ZipArchive { archive, entries })
+    }
+
+    /// エントリ一覧を取得
+    pub fn entries(&self) -> &[ZipEntry] {
+        &self.entries
+    }
+
+    /// エントリを展開
+    pub fn read(&mut self, entry: &ZipEntry) -> Result<Vec<u8>> {
+        let file = self.archive.by_index(entry.index).map_err(invalid)?;
+        // ヘッダーのサイズは偽装できるため、展開しながら上限も確認する
+        if file.size() > MAX_ENTRY_SIZE {
+            return Err(too_large(&entry.name));
+        }
+
+        let mut content = Vec::with_capacity(file.size() as usize);
+        file.take(MAX_ENTRY_SIZE + 1)
+            .read_to_end(&mut content)
+            .map_err(|e| {
+                WorkNoteError::FileError(format!(
+                    "Invalid archive: Failed to extract {}: {}",
+                    entry.name, e
+                ))
+            })?;
+        if content.len() as u64 > MAX_ENTRY_SIZE {
+            return Err(too_large(&entry.name));
+        }
+        Ok(content)
+    }
+}
+
+fn too_large(name: &str) -> WorkNoteError {
+    WorkNoteError::FileError(format!("Invalid archive: Entry is too large: {}", name))
+}
+
+fn invalid(error: ZipError) -> WorkNoteError {
+    WorkNoteError::FileError(format!("Invalid archive: {}", error))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use std::io::Write;
+    use zip::write::SimpleFileOptions;
+    use zip::CompressionMethod;
+
+    /// テスト用のZIPデータを生成
+    pub(crate) fn build_zip(files: &[(&str, &[u8], bool)]) -> Vec<u8> {
+        let mut writer = zip::ZipWriter::new(Cursor::new(Vec::new()));
+
+        for (name, content, deflate) in files {
+            let method = if *deflate {
+                CompressionMethod::Deflated
+            } else {
+                CompressionMethod::Stored
+            };
+            let options = SimpleFileOptions::default().compression_method(method);
+            if name.ends_with('/') {
+                writer.add_directory(*name, options).unwrap();
+            } else {
+                writer.start_file(*name, options).unwrap();
+                writer.write_all(content).unwrap();
+            }
+        }
+
+        writer.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn test_read_entries() {
+        let data = build_zip(&[
+            ("docs/", b"", false),
+            ("docs/a.md", "# 手順\n".as_bytes(), false),
+            ("docs/b.html", "<p>本文</p>".repeat(100).as_bytes(), true),
+        ]);
+        let mut archive = ZipArchive::new(data).unwrap();
+
+        let entries = archive.entries().to_vec();
+        let names: Vec<&str> = entries.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["docs/", "docs/a.md", "docs/b.html"]);
+        assert!(entries[0].is_dir());
+        assert_eq!(archive.read(&entries[1]).unwrap(), "# 手順\n".as_bytes());
+        assert_eq!(
+            archive.read(&entries[2]).unwrap(),
+            "<p>本文</p>".repeat(100).as_bytes()
+        );
+    }
+
+    #[test]
+    fn test_entry_size_limit() {
+        let content = vec![0u8; MAX_ENTRY_SIZE as usize + 1];
+        let mut archive = ZipArchive::new(build_zip(&[("big.md", &content, true)])).unwrap();
+        let entry = archive.entries()[0].clone();
+        assert!(archive
+            .read(&entry)
+            .unwrap_err()
+            .to_string()
+            .contains("too large"));
+    }
+
+    #[test]
+    fn test_invalid_archive() {
+        assert!(ZipArchive::new(b"not a zip".to_vec()).is_err());
+    }
+}
@@ -1,5 +1,6 @@
 import { invoke } from '@tauri-apps/api/core';
-import type { KnowledgeInput, Config, SaveKnowledgeResponse, Category, Severity, ProofreadRequest, ProofreadResponse, Draft } from './types';
+import { listen, type UnlistenFn } from '@tauri-apps/api/event';
+import type { KnowledgeInput, Config, EffectiveConfig, SaveKnowledgeResponse, Category, Severity, ProofreadRequest, ProofreadResponse, Draft, RunbookEntry, WebhookDraftEvent, WebhookStatus, SaveConfigResponse, SetActiveProfileResponse, ImportOptions, ImportReport, CaptureOptions, TimelineSession, TimelineNote, TimelineSummary, TimelineTag, FinalizeTimelineOptions, PostmortemInput, CategoryMigrationReport, ProfileSummary, SearchResult, ShortcutActionEvent, CommitTrailers, PublishDraftsResponse, RunbookCommit, RunbookDiff, RunbookRevision, BlameLine } from './types';
 
 /**
  * 下書きサマリー（一覧表示用）
@@ -8,19 +9,24 @@ export interface DraftSummary {
   id: string;
   title: string;
   category: string;
+  profile?: string;
   updatedAt: string;
 }
 
 /**
- * ナレッジを保存
+ * ナレッジを保存（profile 未指定時は使用中のプロファイル）
  */
-export async function saveKnowledge(input: KnowledgeInput): Promise<SaveKnowledgeResponse> {
+export async function saveKnowledge(
+  input: KnowledgeInput,
+  profile?: string,
+  trailers?: CommitTrailers
+): Promise<SaveKnowledgeResponse> {
   try {
     const result = await invoke<{
       commitHash: string;
       filePath: string;
       prUrl?: string;
-    }>('save_knowledge', { input });
+    }>('save_knowledge', { input, profile, trailers });
     return {
       success: true,
       commitHash: result.commitHash,
@@ -42,11 +48,32 @@ export async function loadConfig(): Promise<Config> {
   return invoke<Config>('load_config');
 }
 
+/**
+ * リポジトリ共有設定（.worknote.toml）を重ねた設定を読み込み（profile 未指定時は使用中のプロファイル）
+ */
+export async function loadEffectiveConfig(profile?: string): Promise<EffectiveConfig> {
+  return invoke<EffectiveConfig>('load_effective_config', { profile });
+}
+
 /**
  * 設定を保存
  */
-export async function saveConfig(config: Config): Promise<void> {
-  await invoke('save_config', { config });
+export async function saveConfig(config: Config): Promise<SaveConfigResponse> {
+  return invoke<SaveConfigResponse>('save_config', { config });
+}
+
+/**
+ * 保存先プロファイルの一覧を取得（先頭は default）
+ */
+export async function listProfiles(): Promise<ProfileSummary[]> {
+  return invoke<ProfileSummary[]>('list_profiles');
+}
+
+/**
+ * 使用中の保存先プロファイルを切り替え
+ */
+export async function setActiveProfile(name: string): Promise<SetActiveProfileResponse> {
+  return invoke<SetActiveProfileResponse>('set_active_profile', { name });
 }
 
 /**
@@ -55,7 +82,8 @@ export async function saveConfig(config: Config): Promise<void> {
 export async function quickSaveKnowledge(
   title: string,
   category: Category,
-  severity: Severity
+  severity: Severity,
+  profile?: string
 ): Promise<SaveKnowledgeResponse> {
   try {
     const result = await invoke<{
@@ -65,7 +93,8 @@ export async function quickSaveKnowledge(
     }>('quick_save_knowledge', {
       title,
       category,
-      severity
+      severity,
+      profile
     });
     return {
       success: true,
@@ -126,8 +155,8 @@ export async function saveDraft(draft: Draft): Promise<void> {
 /**
  * 下書きを作成
  */
-export async function createDraft(data: KnowledgeInput): Promise<Draft> {
-  return invoke<Draft>('create_draft', { data });
+export async function createDraft(data: KnowledgeInput, profile?: string): Promise<Draft> {
+  return invoke<Draft>('create_draft', { data, profile });
 }
 
 /**
@@ -154,6 +183,213 @@ export async function deleteDraft(id: string): Promise<void> {
 /**
  * 下書きを更新
  */
-export async function updateDraft(id: string, data: KnowledgeInput): Promise<Draft> {
-  return invoke<Draft>('update_draft', { id, data });
+export async function updateDraft(id: string, data: KnowledgeInput, profile?: string): Promise<Draft> {
+  return invoke<Draft>('update_draft', { id, data, profile });
+}
+
+/**
+ * 複数の下書きをまとめて公開（separateCommits=true で下書きごとにコミット）
+ *
+ * 下書きはPushに成功した後に削除されます。profile 未指定時は下書きのプロファイルを使います。
+ */
+export async function publishDrafts(
+  draftIds: string[],
+  separateCommits: boolean,
+  profile?: string,
+  trailers?: CommitTrailers
+): Promise<PublishDraftsResponse> {
+  return invoke<PublishDraftsResponse>('publish_drafts', {
+    draftIds,
+    profile,
+    separateCommits,
+    trailers
+  });
+}
+
+/**
+ * 入力中のナレッジをPDFにエクスポート
+ */
+export async function exportKnowledgePdf(input: KnowledgeInput, outputPath: string): Promise<string> {
+  return invoke<string>('export_knowledge_pdf', { input, outputPath });
+}
+
+/**
+ * リポジトリ内のRunbookをPDFにエクスポート
+ */
+export async function exportRunbookPdf(filePath: string, outputPath: string): Promise<string> {
+  return invoke<string>('export_runbook_pdf', { filePath, outputPath });
+}
+
+/**
+ * 指定したRunbookを参照しているRunbook一覧を取得
+ */
+export async function getBacklinks(runbookId: string): Promise<RunbookEntry[]> {
+  return invoke<RunbookEntry[]>('get_backlinks', { runbookId });
+}
+
+/**
+ * アラート名に対応するRunbook一覧を取得
+ */
+export async function findRunbooksByAlert(alertName: string): Promise<RunbookEntry[]> {
+  return invoke<RunbookEntry[]>('find_runbooks_by_alert', { alertName });
+}
+
+/**
+ * Runbookを全文検索
+ */
+export async function searchRunbooks(query: string, limit = 20): Promise<SearchResult[]> {
+  return invoke<SearchResult[]>('search_runbooks', { query, limit });
+}
+
+/**
+ * Runbookのコミット履歴を新しい順に取得（path は RunbookEntry.path）
+ */
+export async function getRunbookHistory(path: string, limit?: number): Promise<RunbookCommit[]> {
+  return invoke<RunbookCommit[]>('get_runbook_history', { path, limit });
+}
+
+/**
+ * Runbookの差分を取得（from 未指定時は作成前から、to 未指定時は HEAD まで）
+ */
+export async function getRunbookDiff(path: string, from?: string, to?: string): Promise<RunbookDiff> {
+  return invoke<RunbookDiff>('get_runbook_diff', { path, from, to });
+}
+
+/**
+ * 指定したリビジョンのRunbookの内容を取得
+ */
+export async function getRunbookRevision(path: string, revision: string): Promise<RunbookRevision> {
+  return invoke<RunbookRevision>('get_runbook_revision', { path, revision });
+}
+
+/**
+ * Runbookの各行を最後に変更したコミットを取得（revision 未指定時は HEAD）
+ */
+export async function getRunbookBlame(path: string, revision?: string): Promise<BlameLine[]> {
+  return invoke<BlameLine[]>('get_runbook_blame', { path, revision });
+}
+
+/**
+ * Webhookリスナーの稼働状況を取得
+ */
+export async function getWebhookStatus(): Promise<WebhookStatus> {
+  return invoke<WebhookStatus>('get_webhook_status');
+}
+
+/**
+ * 設定が変更されたときのイベントを購読（設定画面での保存・プロファイル切り替え）
+ */
+export async function onConfigChanged(handler: (config: Config) => void): Promise<UnlistenFn> {
+  return listen<Config>('config-changed', (event) => handler(event.payload));
+}
+
+/**
+ * グローバルショートカットの操作（Runbook検索・クリップボード取り込み）のイベントを購読
+ */
+export async function onShortcutAction(handler: (event: ShortcutActionEvent) => void): Promise<UnlistenFn> {
+  return listen<ShortcutActionEvent>('shortcut-action', (event) => handler(event.payload));
+}
+
+/**
+ * Webhook受信で下書きが作成されたときのイベントを購読
+ */
+export async function onWebhookDraftCreated(
+  handler: (event: WebhookDraftEvent) => void
+): Promise<UnlistenFn> {
+  return listen<WebhookDraftEvent>('webhook-draft-created', (event) => handler(event.payload));
+}
+
+/**
+ * Confluence/Notionのエクスポートを下書きとして取り込む
+ */
+export async function importRunbooks(path: string, options?: ImportOptions): Promise<ImportReport> {
+  return invoke<ImportReport>('import_runbooks', { path, options });
+}
+
+/**
+ * 既存Runbookをカテゴリ定義に合わせて移行（コミットはしない）
+ */
+export async function migrateCategories(dryRun: boolean): Promise<CategoryMigrationReport> {
+  return invoke<CategoryMigrationReport>('migrate_categories', { dryRun });
+}
+
+/**
+ * Slackスレッド・端末操作の記録・シェル履歴から下書きを作成
+ */
+export async function captureSession(content: string, options: CaptureOptions): Promise<Draft> {
+  return invoke<Draft>('capture_session', { content, options });
+}
+
+/**
+ * タイムラインを開始（タイトルが空の場合は開始日時から生成）
+ */
+export async function startTimeline(title: string): Promise<TimelineSession> {
+  return invoke<TimelineSession>('start_timeline', { title });
+}
+
+/**
+ * タイムラインにメモを追加（id 未指定時は進行中の最新のタイムライン）
+ */
+export async function addTimelineNote(text: string, id?: string, tag?: TimelineTag): Promise<TimelineNote> {
+  return invoke<TimelineNote>('add_timeline_note', { id, text, tag });
+}
+
+/**
+ * タイムラインからメモを削除
+ */
+export async function removeTimelineNote(id: string, noteId: string): Promise<TimelineSession> {
+  return invoke<TimelineSession>('remove_timeline_note', { id, noteId });
+}
+
+/**
+ * 進行中のタイムラインを取得
+ */
+export async function getActiveTimeline(): Promise<TimelineSession | null> {
+  return invoke<TimelineSession | null>('get_active_timeline');
+}
+
+/**
+ * タイムラインを読み込み
+ */
+export async function loadTimeline(id: string): Promise<TimelineSession> {
+  return invoke<TimelineSession>('load_timeline', { id });
+}
+
+/**
+ * タイムライン一覧を取得
+ */
+export async function listTimelines(): Promise<TimelineSummary[]> {
+  return invoke<TimelineSummary[]>('list_timelines');
+}
+
+/**
+ * タイムラインを削除
+ */
+export async function deleteTimeline(id: string): Promise<void> {
+  return invoke<void>('delete_timeline', { id });
+}
+
+/**
+ * タイムラインを確定してナレッジ入力データに変換
+ */
+export async function finalizeTimeline(id: string, options?: FinalizeTimelineOptions): Promise<KnowledgeInput> {
+  return invoke<KnowledgeInput>('finalize_timeline', { id, options });
+}
+
+/**
+ * ポストモーテムを保存（Runbookと同じコミットモードでコミット・Push）
+ */
+export async function savePostmortem(
+  input: PostmortemInput,
+  profile?: string,
+  trailers?: CommitTrailers
+): Promise<SaveKnowledgeResponse> {
+  return invoke<SaveKnowledgeResponse>('save_postmortem', { input, profile, trailers });
+}
+
+/**
+ * ポストモーテムのHTMLプレビューを生成
+ */
+export async function renderPostmortem(input: PostmortemInput): Promise<string> {
+  return invoke<string>('render_postmortem', { input });
 }
@@ -1,14 +1,14 @@
 // WorkNote TypeScript Type Definitions
 
 /**
- * カテゴリ種別
+ * カテゴリ種別（設定のカテゴリID。既定: alerts / ops / troubleshooting / inquiry）
  */
-export type Category = 'alerts' | 'maintenance' | 'ops' | 'troubleshooting' | 'inquiry';
+export type Category = string;
 
 /**
- * 重要度
+ * 重要度（設定の重要度ID。既定: low / medium / high / critical）
  */
-export type Severity = 'low' | 'medium' | 'high' | 'critical';
+export type Severity = string;
 
 /**
  * コミットモード
@@ -41,14 +41,17 @@ export interface CategoryJudgment {
  * ナレッジ入力データ
  */
 export interface KnowledgeInput {
+  id?: string; // RunbookID（例: rb-1a2b3c4d）
   title: string;
   category: Category;
   severity: Severity;
   symptoms: string;
   procedure: string;
   notes?: string;
-  relatedLinks?: string;
+  relatedLinks?: string; // [[runbook-id]] で他のRunbookを参照可能
   judgment?: string; // JSON文字列として保存
+  slug?: string; // ファイル名に使用するslug（ユーザー指定）
+  relatedAlerts?: string[]; // 関連アラート（Prometheusアラート名、DatadogモニターID等）
 }
 
 /**
@@ -57,12 +60,47 @@ export interface KnowledgeInput {
 export interface GitConfig {
   repositoryPath: string;
   savePath: string;
+  postmortemPath: string; // ポストモーテムの保存先（既定: docs/postmortems）
   defaultBranch: string;
   commitMode: CommitMode;
+  remote?: string; // Pull・PR作成先のリモート（既定: origin）
+  pushRemote?: string; // Pushするリモート（未設定時は remote。フォークにPushする場合に指定）
+  hosting?: GitHosting; // PR作成URLの形式（未設定時はホスト名から推定）
+  commitMessage: string; // {title} / {category} / {severity} / {author} / {date} / {id} / {profile} を置換
+  branchName?: string; // PR作成モードのブランチ名（変数はcommitMessageと同じ + {timestamp}）
+  signing?: SigningConfig; // 未設定時は git config に従う
 }
 
 /**
- * Author設定
+ * リモートのホスティングサービス
+ */
+export type GitHosting = 'github' | 'gitlab' | 'bitbucket';
+
+/**
+ * コミットメッセージに追加するtrailer（保存ごとに指定）
+ */
+export interface CommitTrailers {
+  issueKey?: string; // Issue: OPS-123
+  coAuthors?: string[]; // Co-authored-by: Name <email>
+}
+
+/**
+ * コミット署名の方式
+ * - inherit: git config（commit.gpgsign / gpg.format）に従う
+ * - disabled: 署名しない
+ */
+export type SigningMode = 'inherit' | 'disabled' | 'gpg' | 'ssh';
+
+/**
+ * コミット署名設定
+ */
+export interface SigningConfig {
+  mode: SigningMode;
+  key?: string; // GPGは鍵ID、SSHは鍵ファイルのパス（未設定時は user.signingkey）
+}
+
+/**
+ * Author設定（空の項目はリポジトリ → グローバルの git config から補う）
  */
 export interface AuthorConfig {
   name: string;
@@ -73,7 +111,24 @@ export interface AuthorConfig {
  * ショートカット設定
  */
 export interface ShortcutsConfig {
-  quickInput: string;
+  quickInput: string; // 例: CommandOrControl+J
+  detailWindow?: string; // 未設定・空文字は割り当てなし
+  searchRunbooks?: string;
+  captureClipboard?: string;
+}
+
+/**
+ * グローバルショートカットで実行する操作
+ */
+export type ShortcutAction = 'quick-input' | 'detail-window' | 'search-runbooks' | 'capture-clipboard';
+
+/**
+ * ショートカット操作のうちメインウィンドウで処理するもののイベント
+ */
+export interface ShortcutActionEvent {
+  action: ShortcutAction;
+  draftId?: string; // capture-clipboard で作成した下書き
+  error?: string;
 }
 
 /**
@@ -90,6 +145,39 @@ export interface PreferencesConfig {
  */
 export interface ProofreadConfig {
   prompt: string;
+  preset?: string; // 使用するプリセット名（presets から選択）
+  presets?: ProofreadPreset[]; // リポジトリ共有設定（.worknote.toml）で定義
+}
+
+/**
+ * 添削プロンプトのプリセット
+ */
+export interface ProofreadPreset {
+  name: string;
+  label: string;
+  prompt: string;
+}
+
+/**
+ * ファイル命名方式
+ */
+export type NamingScheme = 'ascii' | 'romaji' | 'date-id' | 'slug' | 'pattern';
+
+/**
+ * Webhook受信設定
+ */
+export interface WebhookConfig {
+  enabled: boolean;
+  port: number;
+  token: string; // 送信元が `Authorization: Bearer` または `X-WorkNote-Token` で送る共有トークン
+}
+
+/**
+ * ファイル命名設定
+ */
+export interface NamingConfig {
+  scheme: NamingScheme;
+  pattern: string; // 例: {date}-{category}-{slug}
 }
 
 /**
@@ -98,10 +186,103 @@ export interface ProofreadConfig {
 export interface Config {
   version: number;
   git: GitConfig;
-  author?: AuthorConfig; // 後方互換性のためオプショナル（git configから取得するため不要）
+  author?: AuthorConfig; // 未設定時は git config から取得
   shortcuts: ShortcutsConfig;
   preferences: PreferencesConfig;
   proofread?: ProofreadConfig;
+  naming?: NamingConfig;
+  webhook?: WebhookConfig;
+  taxonomy?: TaxonomyConfig;
+  requiredFields?: string[]; // 保存時に入力必須とする任意項目（notes / relatedLinks など）
+  profiles?: Profile[]; // 追加の保存先プロファイル（トップレベルの git は default）
+  activeProfile?: string; // 使用中のプロファイル名（未設定時は default）
+}
+
+/**
+ * 保存先リポジトリのプロファイル
+ */
+export interface Profile {
+  name: string;
+  label?: string;
+  git: GitConfig;
+  naming?: NamingConfig; // 未設定時はトップレベルの naming
+  author?: AuthorConfig; // 未設定時はトップレベルの author
+}
+
+/**
+ * プロファイル一覧の項目
+ */
+export interface ProfileSummary {
+  name: string;
+  label: string;
+  repositoryPath: string;
+  active: boolean;
+}
+
+/**
+ * リポジトリ共有設定（.worknote.toml）を重ねた設定
+ */
+export interface EffectiveConfig {
+  config: Config;
+  repoConfigPath?: string;
+  managedKeys: string[]; // .worknote.toml で管理されている項目（例: git.savePath）
+}
+
+/**
+ * カテゴリ別判断軸の入力項目
+ */
+export interface JudgmentField {
+  key: string; // 判断軸JSONのキー
+  label: string;
+  placeholder: string;
+}
+
+/**
+ * カテゴリ定義
+ */
+export interface CategoryDefinition {
+  id: string; // フロントマターの category に書く値
+  label: string;
+  directory: string; // 保存先ディレクトリ名（save_path 直下）
+  icon: string;
+  judgmentFields: JudgmentField[];
+  template?: string; // カテゴリ選択時に対応手順へ入れるテンプレート
+}
+
+/**
+ * 重要度定義
+ */
+export interface SeverityDefinition {
+  id: string; // フロントマターの severity に書く値
+  label: string;
+  icon: string;
+}
+
+/**
+ * カテゴリ・重要度の定義（一覧の順序が表示順。重要度は後ろほど高い）
+ */
+export interface TaxonomyConfig {
+  categories: CategoryDefinition[];
+  severities: SeverityDefinition[];
+}
+
+/**
+ * カテゴリ移行で移動・書き換えたファイル
+ */
+export interface MigratedFile {
+  from: string;
+  to: string;
+  categoryRewritten: boolean;
+}
+
+/**
+ * カテゴリ移行の結果
+ */
+export interface CategoryMigrationReport {
+  dryRun: boolean;
+  filesChecked: number;
+  migrated: MigratedFile[];
+  skipped: { path: string; reason: string }[];
 }
 
 /**
@@ -120,6 +301,21 @@ export interface ValidationResult {
   errors: ValidationError[];
 }
 
+/**
+ * 設定保存レスポンス
+ */
+export interface SaveConfigResponse {
+  warnings: string[]; // 保存した設定を反映できなかった項目（設定自体は保存済み）
+}
+
+/**
+ * 保存先プロファイル切り替えレスポンス
+ */
+export interface SetActiveProfileResponse {
+  config: Config; // 切り替え後の設定
+  warnings: string[]; // 切り替えた設定を反映できなかった項目（切り替え自体は保存済み）
+}
+
 /**
  * ナレッジ保存レスポンス
  */
@@ -132,12 +328,27 @@ export interface SaveKnowledgeResponse {
   error?: string;
 }
 
+/**
+ * 下書きの一括公開レスポンス
+ */
+export interface PublishDraftsResponse {
+  success: boolean;
+  /** 作成したコミットのハッシュ（コミット順） */
+  commitHashes: string[];
+  filePaths: string[];
+  prUrl?: string;
+  /** 公開して削除した下書きのID */
+  publishedDrafts: string[];
+}
+
 /**
  * 下書きデータ
  */
 export interface Draft {
   id: string;
   data: KnowledgeInput;
+  profile?: string; // 保存先プロファイル（未設定時は使用中のプロファイル）
+  alertFingerprint?: string; // Webhookで作成した下書きのアラートのfingerprint
   createdAt: string;
   updatedAt: string;
 }
@@ -152,6 +363,7 @@ export type ErrorType =
   | 'ConfigError'
   | 'NetworkError'
   | 'AuthError'
+  | 'SigningError'
   | 'ProofreadError';
 
 /**
@@ -190,3 +402,240 @@ export interface FieldDiff {
   original: string;
   modified: string;
 }
+
+/**
+ * Runbookエントリ（一覧・バックリンク表示用）
+ */
+export interface RunbookEntry {
+  path: string; // 保存先ディレクトリからの相対パス
+  id?: string;
+  category: string;
+  title: string;
+  severity?: string;
+  lastUpdated?: string;
+  author?: string;
+}
+
+/**
+ * Runbook検索結果
+ */
+export interface SearchResult {
+  entry: RunbookEntry;
+  snippet: string; // 最初に一致した行
+  score: number;
+}
+
+/**
+ * Runbookのコミット履歴（新しい順の1件）
+ */
+export interface RunbookCommit {
+  hash: string;
+  shortHash: string;
+  authorName: string;
+  authorEmail: string;
+  date: string; // ISO 8601（コミットしたタイムゾーン）
+  subject: string;
+  path: string; // このコミット時点のパス（移動・リネームを追跡）
+}
+
+export type DiffLineKind = 'context' | 'added' | 'removed';
+
+export interface DiffLine {
+  kind: DiffLineKind;
+  content: string;
+  oldLine: number | null;
+  newLine: number | null;
+}
+
+export interface DiffHunk {
+  oldStart: number;
+  oldLines: number;
+  newStart: number;
+  newLines: number;
+  section: string;
+  lines: DiffLine[];
+}
+
+/**
+ * Runbookの2つのリビジョン間の差分（original/modified は DiffViewer にそのまま渡せる）
+ */
+export interface RunbookDiff {
+  fromRevision?: string;
+  toRevision: string;
+  oldPath?: string;
+  newPath: string;
+  original: string;
+  modified: string;
+  hunks: DiffHunk[];
+  additions: number;
+  deletions: number;
+}
+
+/**
+ * 指定したリビジョンのRunbookの内容
+ */
+export interface RunbookRevision {
+  revision: string;
+  path: string;
+  content: string;
+}
+
+/**
+ * blameの1行（各行を最後に変更したコミット）
+ */
+export interface BlameLine {
+  lineNumber: number;
+  hash: string;
+  authorName: string;
+  date: string;
+  subject: string;
+  content: string;
+}
+
+/**
+ * Webhook受信で下書きが作成されたときのイベント
+ */
+export interface WebhookDraftEvent {
+  draftId: string;
+  title: string;
+  alertName: string;
+  severity: Severity;
+  source: 'alertmanager' | 'grafana' | 'generic';
+}
+
+/**
+ * Webhookリスナーの稼働状況
+ */
+export interface WebhookStatus {
+  running: boolean;
+  address?: string;
+}
+
+/**
+ * インポートのオプション（未指定の項目は推定）
+ */
+export interface ImportOptions {
+  category?: Category;
+  severity?: Severity;
+}
+
+/**
+ * 下書きとして取り込んだドキュメント
+ */
+export interface ImportedDocument {
+  source: string; // ZIP内は archive.zip!/path/page.html
+  draftId: string;
+  title: string;
+  unmappedSections: string[];
+  warnings: string[];
+}
+
+/**
+ * インポート結果
+ */
+export interface ImportReport {
+  imported: ImportedDocument[];
+  skipped: { source: string; reason: string }[];
+}
+
+/**
+ * 記録の取り込み元
+ */
+export type CaptureSource = 'slack' | 'asciinema' | 'script' | 'history';
+
+/**
+ * 記録の取り込みオプション
+ */
+export interface CaptureOptions {
+  source: CaptureSource;
+  title?: string; // 未指定時は内容から生成
+  category?: Category; // 既定: troubleshooting
+  severity?: Severity; // 既定: medium
+}
+
+/**
+ * タイムラインメモの種別
+ */
+export type TimelineTag = 'symptom' | 'action' | 'finding' | 'decision';
+
+/**
+ * タイムラインの1件のメモ
+ */
+export interface TimelineNote {
+  id: string;
+  timestamp: string; // ISO 8601
+  text: string;
+  tag?: TimelineTag;
+}
+
+/**
+ * インシデント対応中のタイムライン
+ */
+export interface TimelineSession {
+  id: string;
+  title: string;
+  startedAt: string;
+  finalizedAt?: string; // 確定後はメモを追加できない
+  notes: TimelineNote[];
+}
+
+/**
+ * タイムライン一覧の要素
+ */
+export interface TimelineSummary {
+  id: string;
+  title: string;
+  startedAt: string;
+  finalizedAt: string | null;
+  noteCount: number;
+}
+
+/**
+ * タイムライン確定時のオプション
+ */
+export interface FinalizeTimelineOptions {
+  category?: Category; // 既定: troubleshooting
+  severity?: Severity; // 既定: medium
+  summarize?: boolean; // 添削バックエンドで要約する
+}
+
+/**
+ * ポストモーテムのタイムライン1行
+ */
+export interface PostmortemTimelineEntry {
+  time: string; // 10:05 など自由形式
+  description: string;
+}
+
+/**
+ * アクションアイテムの状態
+ */
+export type ActionItemStatus = 'open' | 'in-progress' | 'done';
+
+/**
+ * 再発防止のアクションアイテム
+ */
+export interface ActionItem {
+  description: string;
+  owner: string;
+  dueDate?: string; // YYYY-MM-DD
+  status?: ActionItemStatus; // 既定: open
+}
+
+/**
+ * ポストモーテム入力データ
+ */
+export interface PostmortemInput {
+  id?: string; // 初回保存時に採番（pm-xxxxxxxx）
+  title: string;
+  incidentDate: string; // YYYY-MM-DD
+  severity: Severity;
+  summary: string;
+  impact: string;
+  timeline: PostmortemTimelineEntry[];
+  rootCause: string;
+  fiveWhys: string[];
+  actionItems: ActionItem[];
+  lessonsLearned?: string;
+  relatedRunbooks?: string[]; // 関連RunbookのID
+}
@@ -1,4 +1,4 @@
-import type { KnowledgeInput, ValidationResult, ValidationError, Category, Severity } from './types';
+import type { KnowledgeInput, ValidationResult, ValidationError, Category, Severity, TaxonomyConfig } from './types';
 
 // バリデーション定数
 const TITLE_MAX_LENGTH = 100;
@@ -6,9 +6,12 @@ const SYMPTOMS_MAX_LENGTH = 5000;
 const PROCEDURE_MAX_LENGTH = 10000;
 const NOTES_MAX_LENGTH = 5000;
 const LINKS_MAX_LENGTH = 5000;
+const ALERT_NAME_MAX_LENGTH = 200;
+const MAX_RELATED_ALERTS = 50;
 
-const VALID_CATEGORIES: Category[] = ['alerts', 'maintenance', 'ops', 'troubleshooting', 'inquiry'];
-const VALID_SEVERITIES: Severity[] = ['low', 'medium', 'high', 'critical'];
+// 設定（taxonomy）が読めない場合に使う組み込みの定義
+const DEFAULT_CATEGORIES: Category[] = ['alerts', 'ops', 'troubleshooting', 'inquiry'];
+const DEFAULT_SEVERITIES: Severity[] = ['low', 'medium', 'high', 'critical'];
 
 /**
  * タイトルをバリデーション
@@ -31,12 +34,13 @@ export function validateTitle(title: string): ValidationError | null {
 /**
  * カテゴリをバリデーション
  */
-export function validateCategory(category: Category | string): ValidationError | null {
+export function validateCategory(category: Category, taxonomy?: TaxonomyConfig): ValidationError | null {
   if (!category) {
     return { field: 'category', message: 'カテゴリを選択してください' };
   }
 
-  if (!VALID_CATEGORIES.includes(category as Category)) {
+  const validCategories = taxonomy ? taxonomy.categories.map((c) => c.id) : DEFAULT_CATEGORIES;
+  if (!validCategories.includes(category)) {
     return { field: 'category', message: '無効なカテゴリです' };
   }
 
@@ -46,12 +50,13 @@ export function validateCategory(category: Category | string): ValidationError |
 /**
  * 重要度をバリデーション
  */
-export function validateSeverity(severity: Severity | string): ValidationError | null {
+export function validateSeverity(severity: Severity, taxonomy?: TaxonomyConfig): ValidationError | null {
   if (!severity) {
     return { field: 'severity', message: '重要度を選択してください' };
   }
 
-  if (!VALID_SEVERITIES.includes(severity as Severity)) {
+  const validSeverities = taxonomy ? taxonomy.severities.map((s) => s.id) : DEFAULT_SEVERITIES;
+  if (!validSeverities.includes(severity)) {
     return { field: 'severity', message: '無効な重要度です' };
   }
 
@@ -130,20 +135,95 @@ export function validateLinks(relatedLinks: string | undefined): ValidationError
   return null;
 }
 
+/**
+ * 関連アラートをバリデーション
+ */
+export function validateRelatedAlerts(relatedAlerts: string[] | undefined): ValidationError | null {
+  if (!relatedAlerts || relatedAlerts.length === 0) {
+    return null;
+  }
+
+  if (relatedAlerts.length > MAX_RELATED_ALERTS) {
+    return {
+      field: 'relatedAlerts',
+      message: `関連アラートは${MAX_RELATED_ALERTS}件以内で入力してください`
+    };
+  }
+
+  for (const alert of relatedAlerts) {
+    if (alert.length > ALERT_NAME_MAX_LENGTH) {
+      return {
+        field: 'relatedAlerts',
+        message: `関連アラートは1件あたり${ALERT_NAME_MAX_LENGTH}文字以内で入力してください`
+      };
+    }
+    if (/["\u0000-\u001f\u007f]/.test(alert)) {
+      return { field: 'relatedAlerts', message: `関連アラートに使用できない文字が含まれています: ${alert}` };
+    }
+  }
+
+  return null;
+}
+
+/**
+ * カンマまたは改行区切りの文字列を関連アラートの配列に変換
+ */
+export function parseRelatedAlerts(text: string): string[] {
+  return text
+    .split(/[,\n]/)
+    .map((alert) => alert.trim())
+    .filter((alert) => alert.length > 0);
+}
+
+/**
+ * 設定（requiredFields）で入力必須とした任意項目をバリデーション
+ */
+export function validateRequiredFields(
+  input: Partial<KnowledgeInput>,
+  requiredFields: string[]
+): ValidationError[] {
+  const isFilled = (field: string): boolean => {
+    switch (field) {
+      case 'relatedAlerts':
+        return (input.relatedAlerts || []).some((alert) => alert.trim().length > 0);
+      case 'judgment':
+        try {
+          return Object.values(JSON.parse(input.judgment || '{}')).some(
+            (value) => typeof value === 'string' && value.trim().length > 0
+          );
+        } catch {
+          return false;
+        }
+      default: {
+        const value = input[field as keyof KnowledgeInput];
+        return typeof value === 'string' && value.trim().length > 0;
+      }
+    }
+  };
+
+  return requiredFields
+    .filter((field) => !isFilled(field))
+    .map((field) => ({ field, message: 'チームの設定により入力必須です' }));
+}
+
 /**
  * ナレッジ入力データを統合バリデーション
  */
-export function validateKnowledgeInput(input: Partial<KnowledgeInput>): ValidationResult {
+export function validateKnowledgeInput(
+  input: Partial<KnowledgeInput>,
+  taxonomy?: TaxonomyConfig,
+  requiredFields: string[] = []
+): ValidationResult {
   const errors: ValidationError[] = [];
 
   // 各フィールドをバリデーション
   const titleError = validateTitle(input.title || '');
   if (titleError) errors.push(titleError);
 
-  const categoryError = validateCategory(input.category || '');
+  const categoryError = validateCategory(input.category || '', taxonomy);
   if (categoryError) errors.push(categoryError);
 
-  const severityError = validateSeverity(input.severity || '');
+  const severityError = validateSeverity(input.severity || '', taxonomy);
   if (severityError) errors.push(severityError);
 
   const symptomsError = validateSymptoms(input.symptoms || '');
@@ -158,6 +238,11 @@ export function validateKnowledgeInput(input: Partial<KnowledgeInput>): Validati
   const linksError = validateLinks(input.relatedLinks);
   if (linksError) errors.push(linksError);
 
+  const relatedAlertsError = validateRelatedAlerts(input.relatedAlerts);
+  if (relatedAlertsError) errors.push(relatedAlertsError);
+
+  errors.push(...validateRequiredFields(input, requiredFields));
+
   return {
     valid: errors.length === 0,
     errors